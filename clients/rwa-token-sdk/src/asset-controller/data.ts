@@ -1,10 +1,28 @@
-import { Provider } from "@coral-xyz/anchor";
-import { type AssetControllerAccount } from "./types";
+import { BN, Provider } from "@coral-xyz/anchor";
+import {
+	type AssetControllerAccount,
+	type ConversionAccount,
+	type DistributionAccount,
+	type DistributionClaimAccount,
+	type RedemptionRequestAccount,
+	type SubscriptionOfferAccount,
+	type SubscriptionRequestAccount,
+} from "./types";
 import {
 	getAssetControllerPda,
 	getAssetControllerProgram,
+	getConversionPda,
+	getDistributionClaimPda,
+	getDistributionPda,
+	getRedemptionRequestPda,
+	getSubscriptionOfferPda,
+	getSubscriptionRequestPda,
 } from "./utils";
 import { GetProgramAccountsFilter, PublicKey } from "@solana/web3.js";
+import { type ExtensionType, getExtensionData, getMint, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+
+/** Token-2022 extension type of the scaled ui amount config. */
+export const SCALED_UI_AMOUNT_EXTENSION_TYPE = 25;
 
 /**
  * Retrieves a asset controller account associated with a specific asset mint.
@@ -54,4 +72,132 @@ export async function getAssetControllerAccountsWithFilter(
 	return assetAccounts.map((account) =>
 		assetProgram.coder.accounts.decode("AssetControllerAccount", account.account.data)
 	);
-}
\ No newline at end of file
+}
+
+/**
+ * Retrieves a redemption request account.
+ * @param assetMint - The string representation of the asset mint.
+ * @param wallet - The wallet that requested the redemption.
+ * @param id - The id of the request.
+ * @returns A promise resolving to the fetched redemption request account, or `undefined` if it doesn't exist.
+ */
+export async function getRedemptionRequestAccount(
+	assetMint: string,
+	wallet: string,
+	id: BN,
+	provider: Provider
+): Promise<RedemptionRequestAccount | undefined> {
+	const assetProgram = getAssetControllerProgram(provider);
+	return assetProgram.account.redemptionRequestAccount
+		.fetch(getRedemptionRequestPda(assetMint, wallet, id)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves a subscription offer account.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the offer.
+ * @returns A promise resolving to the fetched subscription offer account, or `undefined` if it doesn't exist.
+ */
+export async function getSubscriptionOfferAccount(
+	assetMint: string,
+	id: BN,
+	provider: Provider
+): Promise<SubscriptionOfferAccount | undefined> {
+	const assetProgram = getAssetControllerProgram(provider);
+	return assetProgram.account.subscriptionOfferAccount
+		.fetch(getSubscriptionOfferPda(assetMint, id)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves a subscription request account.
+ * @param assetMint - The string representation of the asset mint.
+ * @param offerId - The id of the offer subscribed to.
+ * @param wallet - The wallet that subscribed.
+ * @param id - The id of the request.
+ * @returns A promise resolving to the fetched subscription request account, or `undefined` if it doesn't exist.
+ */
+export async function getSubscriptionRequestAccount(
+	assetMint: string,
+	offerId: BN,
+	wallet: string,
+	id: BN,
+	provider: Provider
+): Promise<SubscriptionRequestAccount | undefined> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const offer = getSubscriptionOfferPda(assetMint, offerId);
+	return assetProgram.account.subscriptionRequestAccount
+		.fetch(getSubscriptionRequestPda(offer, wallet, id)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves a distribution account.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the distribution.
+ * @returns A promise resolving to the fetched distribution account, or `undefined` if it doesn't exist.
+ */
+export async function getDistributionAccount(
+	assetMint: string,
+	id: BN,
+	provider: Provider
+): Promise<DistributionAccount | undefined> {
+	const assetProgram = getAssetControllerProgram(provider);
+	return assetProgram.account.distributionAccount
+		.fetch(getDistributionPda(assetMint, id)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the claim receipt of an identity for a distribution.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the distribution.
+ * @param identityAccount - The identity account that claimed.
+ * @returns A promise resolving to the fetched claim account, or `undefined` if the identity has not claimed.
+ */
+export async function getDistributionClaimAccount(
+	assetMint: string,
+	id: BN,
+	identityAccount: string,
+	provider: Provider
+): Promise<DistributionClaimAccount | undefined> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const distribution = getDistributionPda(assetMint, id);
+	return assetProgram.account.distributionClaimAccount
+		.fetch(getDistributionClaimPda(distribution, new PublicKey(identityAccount))).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the conversion of the shares of one asset into another.
+ * @param sourceMint - The string representation of the mint converted from.
+ * @param destinationMint - The string representation of the mint converted into.
+ * @returns A promise resolving to the fetched conversion account, or `undefined` if it doesn't exist.
+ */
+export async function getConversionAccount(
+	sourceMint: string,
+	destinationMint: string,
+	provider: Provider
+): Promise<ConversionAccount | undefined> {
+	const assetProgram = getAssetControllerProgram(provider);
+	return assetProgram.account.conversionAccount
+		.fetch(getConversionPda(sourceMint, destinationMint)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the scaled ui amount multiplier in effect for an asset, ui amounts are base units times the multiplier.
+ * Policy limits and issuance amounts are in base units and are not scaled.
+ * @param assetMint - The string representation of the asset mint.
+ * @param timestamp - The unix timestamp to read the multiplier at, defaults to now.
+ * @returns A promise resolving to the multiplier, or `undefined` if the asset does not have the extension.
+ */
+export async function getScaledUiAmountMultiplier(
+	assetMint: string,
+	provider: Provider,
+	timestamp: number = Math.floor(Date.now() / 1000)
+): Promise<number | undefined> {
+	const mint = await getMint(provider.connection, new PublicKey(assetMint), undefined, TOKEN_2022_PROGRAM_ID);
+	const data = getExtensionData(SCALED_UI_AMOUNT_EXTENSION_TYPE as ExtensionType, mint.tlvData);
+	if (!data) {
+		return undefined;
+	}
+	// authority (32), multiplier (f64), new multiplier effective timestamp (i64), new multiplier (f64)
+	const effectiveTimestamp = Number(data.readBigInt64LE(40));
+	return timestamp >= effectiveTimestamp ? data.readDoubleLE(48) : data.readDoubleLE(32);
+}
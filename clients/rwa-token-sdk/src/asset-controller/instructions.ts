@@ -12,6 +12,11 @@ import {
 	getTrackerAccountPda,
 	policyEngineProgramId,
 	getExtraMetasListPda,
+	getPolicyPageAccountMetas,
+	getTransferPolicyPageAccountMetas,
+	getTranchePda,
+	getTrancheSubscriptionPda,
+	getPolicyEngineAuthority,
 } from "../policy-engine";
 import {
 	identityRegistryProgramId,
@@ -22,6 +27,7 @@ import {
 	getWalletIdentityAccountPda,
 	getWalletIdentityAccount,
 	getIdentityAccount,
+	getIdentityRegistryEventAuthority,
 } from "../identity-registry";
 import {
 	type CommonArgs,
@@ -30,6 +36,7 @@ import {
 import {
 	ASSOCIATED_TOKEN_PROGRAM_ID,
 	TOKEN_2022_PROGRAM_ID,
+	TOKEN_PROGRAM_ID,
 	createAssociatedTokenAccountInstruction,
 	createTransferCheckedInstruction,
 	getAccount,
@@ -41,17 +48,45 @@ import {
 	getAssetControllerPda,
 	assetControllerProgramId,
 	getAssetControllerEventAuthority,
+	getRedemptionRequestPda,
+	getSubscriptionOfferPda,
+	getSubscriptionRequestPda,
+	getDistributionPda,
+	getDistributionClaimPda,
+	getConversionPda,
 } from "./utils";
 import { type Provider, BN } from "@coral-xyz/anchor";
+import {
+	type BalanceProof,
+	type ConversionConfig,
+	type DistributionSnapshot,
+	type WithholdingRate,
+} from "./types";
+import { getDistributionAccount } from "./data";
 
 /** Represents arguments for creating an on chain asset controller. */
+/** Optional token-2022 extensions of the asset mint, their authority is the asset controller. */
+export type AssetExtensionsArgs = {
+  /** initial scaled ui amount multiplier used for splits, the extension is not added when omitted */
+  multiplier?: number;
+  /** new token accounts are frozen until the asset controller thaws them */
+  defaultAccountStateFrozen?: boolean;
+  /** interest rate in basis points, cannot be combined with a multiplier */
+  interestRate?: number;
+  /** address of the token group the asset describes */
+  groupPointer?: string;
+  /** address of the token group member the asset describes */
+  groupMemberPointer?: string;
+  pausable?: boolean;
+};
+
 export type CreateAssetControllerIx = {
   decimals: number;
   authority: string;
   name: string;
   uri: string;
   symbol: string;
-} & CommonArgs;
+} & AssetExtensionsArgs & CommonArgs;
 
 /**
  * Builds the transaction instruction to create an Asset Controller.
@@ -69,6 +104,12 @@ export async function getCreateAssetControllerIx(
 			name: args.name,
 			uri: args.uri,
 			symbol: args.symbol,
+			multiplier: args.multiplier ?? null,
+			defaultAccountStateFrozen: args.defaultAccountStateFrozen ?? false,
+			interestRate: args.interestRate ?? null,
+			groupPointer: args.groupPointer ? new PublicKey(args.groupPointer) : null,
+			groupMemberPointer: args.groupMemberPointer ? new PublicKey(args.groupMemberPointer) : null,
+			pausable: args.pausable ?? false,
 		})
 		.accountsStrict({
 			payer: args.payer,
@@ -135,6 +176,8 @@ export type IssueTokenArgs = {
   owner: string;
   wallet?: string;
   timestamp?: BN;
+  /** id of the tranche the issuance is made against */
+  trancheId?: BN;
 } & CommonArgs;
 
 /**
@@ -170,13 +213,110 @@ export async function getIssueTokensIx(
 			systemProgram: SystemProgram.programId,
 			to: new PublicKey(args.wallet || args.owner),
 			walletIdentityAccount: getWalletIdentityAccountPda(args.assetMint, args.wallet || args.owner),
+			tranche: args.trancheId ? getTranchePda(args.assetMint, args.trancheId) : null,
+			trancheSubscription: args.trancheId ? getTrancheSubscriptionPda(args.assetMint, args.trancheId, args.owner) : null,
 			eventAuthority: getAssetControllerEventAuthority(),
 			program: assetControllerProgramId,
 		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.assetMint, "issuance", provider))
 		.instruction();
 	return [ix];
 }
 
+export type IssuanceRecipient = {
+	amount: BN;
+	owner: string;
+	wallet?: string;
+};
+
+/** Represents arguments for issuing an on chain asset/token to several recipients. */
+export type IssueTokensBatchArgs = {
+  recipients: IssuanceRecipient[];
+  authority: string;
+  timestamp?: BN;
+  /** id of the tranche the issuances are made against */
+  trancheId?: BN;
+} & CommonArgs;
+
+/**
+ * Creates transaction instruction to issue tokens to several recipients of a specific asset.
+ * @param args {@link IssueTokensBatchArgs}
+ * @returns A transaction instruction distributing the specified amount to each recipient.
+ */
+export async function getIssueTokensBatchIx(
+	args: IssueTokensBatchArgs,
+	provider: Provider
+): Promise<TransactionInstruction[]> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const remainingAccounts = [
+		...args.recipients.flatMap((recipient) => [
+			{
+				pubkey: new PublicKey(recipient.wallet || recipient.owner),
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: getIdentityAccountPda(args.assetMint, recipient.owner),
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: getWalletIdentityAccountPda(args.assetMint, recipient.wallet || recipient.owner),
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: getTrackerAccountPda(args.assetMint, recipient.owner),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(
+					new PublicKey(args.assetMint),
+					new PublicKey(recipient.wallet || recipient.owner),
+					true,
+					TOKEN_2022_PROGRAM_ID
+				),
+				isWritable: true,
+				isSigner: false,
+			},
+			...(args.trancheId ? [{
+				pubkey: getTrancheSubscriptionPda(args.assetMint, args.trancheId, recipient.owner),
+				isWritable: true,
+				isSigner: false,
+			}] : []),
+		]),
+		...(await getPolicyPageAccountMetas(args.assetMint, "issuance", provider)),
+	];
+	const ixs: TransactionInstruction[] = [
+		ComputeBudgetProgram.setComputeUnitLimit({units: Math.min(1_400_000, 200_000 * args.recipients.length)}),
+	];
+	const ix = await assetProgram.methods
+		.issueTokensBatch(
+			args.recipients.map((recipient) => recipient.amount),
+			args.timestamp || new BN(Date.now() / 1000)
+		)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			policyEngineProgram: policyEngineProgramId,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tranche: args.trancheId ? getTranchePda(args.assetMint, args.trancheId) : null,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+	ixs.push(ix);
+	return ixs;
+}
+
 export type TransferTokensArgs = {
   from: string;
   to: string;
@@ -262,6 +402,7 @@ export async function getTransferTokensIxs(
 			isWritable: true,
 			isSigner: false,
 		},
+		...getTransferPolicyPageAccountMetas(args.assetMint),
 	];
 
 	const ixs: TransactionInstruction[] = [];
@@ -328,8 +469,7 @@ export type SetupAssetControllerArgs = {
   name: string;
   uri: string;
   symbol: string;
-  interestRate?: number;
-};
+} & AssetExtensionsArgs;
 
 /**
  * Generates a new asset controller.
@@ -478,6 +618,124 @@ export async function getDisableMemoTransferIx(
 	return ix;
 }
 
+/** Represents arguments to schedule the scaled ui amount multiplier of an asset. */
+export type UpdateMultiplierArgs = {
+	authority: string;
+	assetMint: string;
+	/** ui amount per base unit, 2 for a 2:1 split, 0.5 for a 1:2 reverse split */
+	multiplier: number;
+	/** unix timestamp from which the multiplier applies */
+	effectiveTimestamp: BN;
+};
+
+/**
+ * Generate Instructions to schedule the scaled ui amount multiplier, for splits and reverse splits
+ * @param args - {@link UpdateMultiplierArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getUpdateMultiplierIx(
+	args: UpdateMultiplierArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.updateMultiplier(args.multiplier, args.effectiveTimestamp)
+		.accountsStrict({
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type UpdateInterestRateArgs = {
+	authority: string;
+	assetMint: string;
+	/** interest rate in basis points */
+	rate: number;
+};
+
+/**
+ * Generate Instructions to change the rate of the interest-bearing extension
+ * @param args - {@link UpdateInterestRateArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getUpdateInterestRateIx(
+	args: UpdateInterestRateArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.updateInterestRate(args.rate)
+		.accountsStrict({
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type PauseAssetArgs = {
+	authority: string;
+	assetMint: string;
+};
+
+/**
+ * Generate Instructions to pause the mints, burns and transfers of a pausable asset
+ * @param args - {@link PauseAssetArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getPauseAssetIx(
+	args: PauseAssetArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.pauseAsset()
+		.accountsStrict({
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+/**
+ * Generate Instructions to resume the mints, burns and transfers of a paused asset
+ * @param args - {@link PauseAssetArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getResumeAssetIx(
+	args: PauseAssetArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.resumeAsset()
+		.accountsStrict({
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
 export type FreezeTokenArgs = {
 	authority: string;
 	owner: string;
@@ -539,6 +797,72 @@ export async function getThawTokenIx(
 	return ix;
 }
 
+export type RecoverWalletArgs = {
+	/** owner of the identity account */
+	owner: string;
+	/** lost wallet, defaults to the identity owner */
+	lostWallet?: string;
+	newWallet: string;
+	authority: string;
+	payer: string;
+	assetMint: string;
+	caseReference: string;
+};
+
+/**
+ * Generate Instructions to move the tokens and identity mapping of a lost wallet to a new wallet
+ * @param args - {@link RecoverWalletArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getRecoverWalletIx(
+	args: RecoverWalletArgs,
+	provider: Provider
+): Promise<TransactionInstruction[]> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const lostWallet = args.lostWallet || args.owner;
+	const ixs: TransactionInstruction[] = [ComputeBudgetProgram.setComputeUnitLimit({units: 450_000})];
+	const ix = await assetProgram.methods
+		.recoverWallet(args.caseReference)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			lostTokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.assetMint),
+				new PublicKey(lostWallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			newWallet: new PublicKey(args.newWallet),
+			newTokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.assetMint),
+				new PublicKey(args.newWallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			lostWalletIdentity: getWalletIdentityAccountPda(args.assetMint, lostWallet),
+			newWalletIdentity: getWalletIdentityAccountPda(args.assetMint, args.newWallet),
+			trackerAccount: getTrackerAccountPda(args.assetMint, args.owner),
+			extraMetasAccount: getExtraMetasListPda(args.assetMint),
+			identityRegistryProgram: identityRegistryProgramId,
+			identityRegistryEventAuthority: getIdentityRegistryEventAuthority(),
+			policyEngineProgram: policyEngineProgramId,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.remainingAccounts(getTransferPolicyPageAccountMetas(args.assetMint))
+		.instruction();
+	ixs.push(ix);
+	return ixs;
+}
+
 export type RevokeTokensArgs = {
 	amount: BN;
 	owner: string;
@@ -594,6 +918,8 @@ export type SeizeTokensArgs = {
 	authority: string;
 	assetMint: string;
 	reason: string;
+	/** enforce counter limits on the holder counters changed by the seize, defaults to false */
+	enforceLimits?: boolean;
 };
 
 /**
@@ -662,11 +988,11 @@ export async function getSeizeTokensIx(
 			isWritable: true,
 			isSigner: false,
 		}, 
-		
+		...getTransferPolicyPageAccountMetas(args.assetMint),
 	];
 	const ixs: TransactionInstruction[] = [ComputeBudgetProgram.setComputeUnitLimit({units: 450_000})];
 	const ix = await assetProgram.methods
-		.seizeTokens(args.amount, args.reason)
+		.seizeTokens(args.amount, args.reason, args.enforceLimits ?? false)
 		.accountsStrict({
 			authority: new PublicKey(args.authority),
 			assetMint: new PublicKey(args.assetMint),
@@ -684,6 +1010,117 @@ export async function getSeizeTokensIx(
 				true,
 				TOKEN_2022_PROGRAM_ID
 			),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			sourceIdentityAccount: getIdentityAccountPda(args.assetMint, args.from),
+			destinationIdentityAccount: getIdentityAccountPda(args.assetMint, args.to),
+			sourceWalletIdentity: getWalletIdentityAccountPda(args.assetMint, args.wallet || args.from),
+			destinationWalletIdentity: getWalletIdentityAccountPda(args.assetMint, args.to),
+			sourceTrackerAccount: getTrackerAccountPda(args.assetMint, args.from),
+			destinationTrackerAccount: getTrackerAccountPda(args.assetMint, args.to),
+			policyEngineProgram: policyEngineProgramId,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			rentReceiver: await getPolicyEngineAuthority(args.assetMint, provider),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+	ixs.push(ix);
+	return ixs;
+}
+
+export type SeizeLeg = {
+	to: string;
+	amount: BN;
+};
+
+export type SeizeTokensMultiArgs = {
+	from: string;
+	wallet?: string;
+	legs: SeizeLeg[];
+	authority: string;
+	payer: string;
+	assetMint: string;
+	reason: string;
+	/** shared by the events of every leg */
+	caseReference: string;
+	/** enforce counter limits on the holder counters changed by the seize, defaults to false */
+	enforceLimits?: boolean;
+};
+
+/**
+ * Seize tokens from a user to several destinations, moving the seized issuances with each leg
+ * @param args - {@link SeizeTokensMultiArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getSeizeTokensMultiIx(
+	args: SeizeTokensMultiArgs,
+	provider: Provider
+): Promise<TransactionInstruction[]> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const remainingAccounts = [
+		...args.legs.flatMap((leg) => [
+			{
+				pubkey: getAssociatedTokenAddressSync(
+					new PublicKey(args.assetMint),
+					new PublicKey(leg.to),
+					true,
+					TOKEN_2022_PROGRAM_ID
+				),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getWalletIdentityAccountPda(args.assetMint, leg.to),
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: getIdentityAccountPda(args.assetMint, leg.to),
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: getTrackerAccountPda(args.assetMint, leg.to),
+				isWritable: true,
+				isSigner: false,
+			},
+		]),
+		...getTransferPolicyPageAccountMetas(args.assetMint),
+	];
+	const ixs: TransactionInstruction[] = [
+		ComputeBudgetProgram.setComputeUnitLimit({units: Math.min(1_400_000, 450_000 * args.legs.length)}),
+	];
+	const ix = await assetProgram.methods
+		.seizeTokensMulti(
+			args.legs.map((leg) => leg.amount),
+			args.reason,
+			args.caseReference,
+			args.enforceLimits ?? false
+		)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			sourceTokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.assetMint),
+				new PublicKey(args.wallet || args.from),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			sourceIdentityAccount: getIdentityAccountPda(args.assetMint, args.from),
+			sourceWalletIdentity: getWalletIdentityAccountPda(args.assetMint, args.wallet || args.from),
+			sourceTrackerAccount: getTrackerAccountPda(args.assetMint, args.from),
+			extraMetasAccount: getExtraMetasListPda(args.assetMint),
+			identityRegistryProgram: identityRegistryProgramId,
+			policyEngineProgram: policyEngineProgramId,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			rentReceiver: await getPolicyEngineAuthority(args.assetMint, provider),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
 			eventAuthority: getAssetControllerEventAuthority(),
 			program: assetControllerProgramId,
 		})
@@ -691,4 +1128,778 @@ export async function getSeizeTokensIx(
 		.instruction();
 	ixs.push(ix);
 	return ixs;
-}
\ No newline at end of file
+}
+
+export type RedemptionRequestArgs = {
+	/** wallet holding the tokens to redeem */
+	wallet: string;
+	/** owner of the identity account, defaults to the wallet */
+	owner?: string;
+	/** id of the request, unique per wallet */
+	id: BN;
+	assetMint: string;
+	payer: string;
+};
+
+export type RequestRedemptionArgs = {
+	amount: BN;
+} & RedemptionRequestArgs;
+
+/**
+ * Generate Instructions to request the redemption of tokens, the amount is locked until the request is settled
+ * @param args - {@link RequestRedemptionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getRequestRedemptionIx(
+	args: RequestRedemptionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const owner = args.owner || args.wallet;
+	const ix = await assetProgram.methods
+		.requestRedemption(args.id, args.amount)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			wallet: new PublicKey(args.wallet),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			tokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.assetMint),
+				new PublicKey(args.wallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			walletIdentityAccount: getWalletIdentityAccountPda(args.assetMint, args.wallet),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+			redemptionRequest: getRedemptionRequestPda(args.assetMint, args.wallet, args.id),
+			policyEngineProgram: policyEngineProgramId,
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type ApproveRedemptionArgs = {
+	authority: string;
+} & RedemptionRequestArgs;
+
+/**
+ * Generate Instructions to approve a redemption request, burning the locked tokens
+ * @param args - {@link ApproveRedemptionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getApproveRedemptionIx(
+	args: ApproveRedemptionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const owner = args.owner || args.wallet;
+	const ix = await assetProgram.methods
+		.approveRedemption()
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			redemptionRequest: getRedemptionRequestPda(args.assetMint, args.wallet, args.id),
+			tokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.assetMint),
+				new PublicKey(args.wallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+			policyEngineProgram: policyEngineProgramId,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type RejectRedemptionArgs = {
+	authority: string;
+	reason: string;
+} & RedemptionRequestArgs;
+
+/**
+ * Generate Instructions to reject a redemption request, releasing the locked tokens
+ * @param args - {@link RejectRedemptionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getRejectRedemptionIx(
+	args: RejectRedemptionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const owner = args.owner || args.wallet;
+	const ix = await assetProgram.methods
+		.rejectRedemption(args.reason)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			redemptionRequest: getRedemptionRequestPda(args.assetMint, args.wallet, args.id),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+			policyEngineProgram: policyEngineProgramId,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+/**
+ * Generate Instructions to cancel a pending redemption request, releasing the locked tokens
+ * @param args - {@link RedemptionRequestArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCancelRedemptionIx(
+	args: RedemptionRequestArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const owner = args.owner || args.wallet;
+	const ix = await assetProgram.methods
+		.cancelRedemption()
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			wallet: new PublicKey(args.wallet),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			redemptionRequest: getRedemptionRequestPda(args.assetMint, args.wallet, args.id),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+			policyEngineProgram: policyEngineProgramId,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+/**
+ * Generate Instructions to close a settled redemption request, the rent goes back to its payer
+ * @param args - {@link RedemptionRequestArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCloseRedemptionRequestIx(
+	args: RedemptionRequestArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.closeRedemptionRequest()
+		.accountsStrict({
+			wallet: new PublicKey(args.wallet),
+			redemptionRequest: getRedemptionRequestPda(args.assetMint, args.wallet, args.id),
+			rentReceiver: new PublicKey(args.payer),
+		})
+		.instruction();
+	return ix;
+}
+
+export type SubscriptionOfferArgs = {
+	/** id of the offer, unique per asset */
+	offerId: BN;
+	assetMint: string;
+	/** mint of the payment token, e.g. a stablecoin */
+	paymentMint: string;
+	/** token program of the payment mint, defaults to the spl token program */
+	paymentTokenProgram?: string;
+};
+
+export type CreateSubscriptionOfferArgs = {
+	authority: string;
+	payer: string;
+	/** token account receiving the payments of settled subscriptions */
+	treasury: string;
+	/** payment base units per whole share */
+	price: BN;
+	/** deposits are closed after the cutoff, pending subscriptions can then be settled by anyone */
+	cutoff: BN;
+} & SubscriptionOfferArgs;
+
+const getPaymentTokenProgram = (args: SubscriptionOfferArgs) =>
+	args.paymentTokenProgram ? new PublicKey(args.paymentTokenProgram) : TOKEN_PROGRAM_ID;
+
+const getSubscriptionVault = (args: SubscriptionOfferArgs) => getAssociatedTokenAddressSync(
+	new PublicKey(args.paymentMint),
+	getSubscriptionOfferPda(args.assetMint, args.offerId),
+	true,
+	getPaymentTokenProgram(args)
+);
+
+/**
+ * Generate Instructions to create an offer to subscribe to the asset with a payment token
+ * @param args - {@link CreateSubscriptionOfferArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCreateSubscriptionOfferIx(
+	args: CreateSubscriptionOfferArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.createSubscriptionOffer(args.offerId, args.price, args.cutoff)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			paymentMint: new PublicKey(args.paymentMint),
+			treasury: new PublicKey(args.treasury),
+			subscriptionOffer: getSubscriptionOfferPda(args.assetMint, args.offerId),
+			vault: getSubscriptionVault(args),
+			paymentTokenProgram: getPaymentTokenProgram(args),
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type UpdateSubscriptionOfferArgs = Omit<CreateSubscriptionOfferArgs, "payer" | "paymentMint" | "paymentTokenProgram">;
+
+/**
+ * Generate Instructions to publish the price and cutoff of a subscription offer
+ * @param args - {@link UpdateSubscriptionOfferArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getUpdateSubscriptionOfferIx(
+	args: UpdateSubscriptionOfferArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.updateSubscriptionOffer(args.price, args.cutoff)
+		.accountsStrict({
+			authority: new PublicKey(args.authority),
+			assetController: getAssetControllerPda(args.assetMint),
+			subscriptionOffer: getSubscriptionOfferPda(args.assetMint, args.offerId),
+			treasury: new PublicKey(args.treasury),
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type SubscriptionRequestArgs = {
+	/** wallet paying for the subscription and receiving the shares */
+	wallet: string;
+	/** owner of the identity account, defaults to the wallet */
+	owner?: string;
+	/** id of the request, unique per wallet and offer */
+	id: BN;
+	payer: string;
+} & SubscriptionOfferArgs;
+
+const getSubscriptionRequest = (args: SubscriptionRequestArgs) => getSubscriptionRequestPda(
+	getSubscriptionOfferPda(args.assetMint, args.offerId),
+	args.wallet,
+	args.id
+);
+
+export type SubscribeArgs = {
+	paymentAmount: BN;
+	/** token account the payment is taken from, defaults to the associated token account of the wallet */
+	paymentAccount?: string;
+} & SubscriptionRequestArgs;
+
+/**
+ * Generate Instructions to deposit a payment into a subscription offer at its current price
+ * @param args - {@link SubscribeArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getSubscribeIx(
+	args: SubscribeArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const owner = args.owner || args.wallet;
+	const ix = await assetProgram.methods
+		.subscribe(args.id, args.paymentAmount)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			wallet: new PublicKey(args.wallet),
+			subscriptionOffer: getSubscriptionOfferPda(args.assetMint, args.offerId),
+			assetMint: new PublicKey(args.assetMint),
+			paymentMint: new PublicKey(args.paymentMint),
+			paymentAccount: args.paymentAccount ? new PublicKey(args.paymentAccount) : getAssociatedTokenAddressSync(
+				new PublicKey(args.paymentMint),
+				new PublicKey(args.wallet),
+				true,
+				getPaymentTokenProgram(args)
+			),
+			vault: getSubscriptionVault(args),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			walletIdentityAccount: getWalletIdentityAccountPda(args.assetMint, args.wallet),
+			subscriptionRequest: getSubscriptionRequest(args),
+			paymentTokenProgram: getPaymentTokenProgram(args),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type SettleSubscriptionArgs = {
+	/** authority of the asset, or anyone once the cutoff has passed */
+	signer: string;
+	treasury: string;
+	/** id of the tranche the shares are issued from */
+	trancheId?: BN;
+} & SubscriptionRequestArgs;
+
+/**
+ * Generate Instructions to issue the shares of a pending subscription
+ * @param args - {@link SettleSubscriptionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getSettleSubscriptionIx(
+	args: SettleSubscriptionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const owner = args.owner || args.wallet;
+	const ix = await assetProgram.methods
+		.settleSubscription()
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			signer: new PublicKey(args.signer),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			subscriptionOffer: getSubscriptionOfferPda(args.assetMint, args.offerId),
+			subscriptionRequest: getSubscriptionRequest(args),
+			wallet: new PublicKey(args.wallet),
+			tokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.assetMint),
+				new PublicKey(args.wallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			paymentMint: new PublicKey(args.paymentMint),
+			vault: getSubscriptionVault(args),
+			treasury: new PublicKey(args.treasury),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			paymentTokenProgram: getPaymentTokenProgram(args),
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			policyEngineProgram: policyEngineProgramId,
+			tranche: args.trancheId ? getTranchePda(args.assetMint, args.trancheId) : null,
+			trancheSubscription: args.trancheId ? getTrancheSubscriptionPda(args.assetMint, args.trancheId, owner) : null,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.assetMint, "issuance", provider))
+		.instruction();
+	return ix;
+}
+
+export type RefundSubscriptionArgs = {
+	/** authority of the asset, or the wallet or anyone after the cutoff when the identity of the request was revoked */
+	signer: string;
+	reason: string;
+	/** token account the payment is refunded to, defaults to the associated token account of the wallet */
+	paymentAccount?: string;
+} & Omit<SubscriptionRequestArgs, "payer">;
+
+/**
+ * Generate Instructions to refund the payment of a pending subscription
+ * @param args - {@link RefundSubscriptionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getRefundSubscriptionIx(
+	args: RefundSubscriptionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.refundSubscription(args.reason)
+		.accountsStrict({
+			signer: new PublicKey(args.signer),
+			assetController: getAssetControllerPda(args.assetMint),
+			subscriptionOffer: getSubscriptionOfferPda(args.assetMint, args.offerId),
+			subscriptionRequest: getSubscriptionRequestPda(
+				getSubscriptionOfferPda(args.assetMint, args.offerId),
+				args.wallet,
+				args.id
+			),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner || args.wallet),
+			paymentMint: new PublicKey(args.paymentMint),
+			vault: getSubscriptionVault(args),
+			paymentAccount: args.paymentAccount ? new PublicKey(args.paymentAccount) : getAssociatedTokenAddressSync(
+				new PublicKey(args.paymentMint),
+				new PublicKey(args.wallet),
+				true,
+				getPaymentTokenProgram(args)
+			),
+			paymentTokenProgram: getPaymentTokenProgram(args),
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+/**
+ * Generate Instructions to close a settled or refunded subscription request, the rent goes back to its payer
+ * @param args - {@link SubscriptionRequestArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCloseSubscriptionRequestIx(
+	args: SubscriptionRequestArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.closeSubscriptionRequest()
+		.accountsStrict({
+			wallet: new PublicKey(args.wallet),
+			subscriptionRequest: getSubscriptionRequest(args),
+			rentReceiver: new PublicKey(args.payer),
+		})
+		.instruction();
+	return ix;
+}
+
+export type DistributionArgs = {
+	/** id of the distribution, unique per asset */
+	distributionId: BN;
+	assetMint: string;
+	/** mint the distribution is paid in */
+	payoutMint: string;
+	/** token program of the payout mint, defaults to the spl token program */
+	payoutTokenProgram?: string;
+};
+
+const getPayoutTokenProgram = (args: DistributionArgs) =>
+	args.payoutTokenProgram ? new PublicKey(args.payoutTokenProgram) : TOKEN_PROGRAM_ID;
+
+const getDistributionVault = (args: DistributionArgs) => getAssociatedTokenAddressSync(
+	new PublicKey(args.payoutMint),
+	getDistributionPda(args.assetMint, args.distributionId),
+	true,
+	getPayoutTokenProgram(args)
+);
+
+export type CreateDistributionArgs = {
+	authority: string;
+	payer: string;
+	/** token account of the authority funding the distribution */
+	fundingAccount: string;
+	snapshot: DistributionSnapshot;
+	amount: BN;
+	/** claims are closed after the expiry */
+	expiry: BN;
+	/** rates withheld per country group of the policy engine mapping */
+	withholdingRates?: WithholdingRate[];
+	/** token account receiving the withheld amounts, required with withholding rates */
+	taxVault?: string;
+} & DistributionArgs;
+
+/**
+ * Generate Instructions to fund a distribution paid pro-rata to the balances at the record date
+ * @param args - {@link CreateDistributionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCreateDistributionIx(
+	args: CreateDistributionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.createDistribution(args.distributionId, args.snapshot, args.amount, args.expiry, args.withholdingRates ?? [])
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			authority: new PublicKey(args.authority),
+			assetMint: new PublicKey(args.assetMint),
+			assetController: getAssetControllerPda(args.assetMint),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			payoutMint: new PublicKey(args.payoutMint),
+			fundingAccount: new PublicKey(args.fundingAccount),
+			distribution: getDistributionPda(args.assetMint, args.distributionId),
+			vault: getDistributionVault(args),
+			taxVault: args.taxVault ? new PublicKey(args.taxVault) : null,
+			payoutTokenProgram: getPayoutTokenProgram(args),
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			policyEngineProgram: policyEngineProgramId,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type ClaimDistributionArgs = {
+	/** holder or crank paying for the claim receipt */
+	payer: string;
+	/** owner of the identity account claiming */
+	owner: string;
+	/** token account of the owner receiving the payout, defaults to its associated token account */
+	destination?: string;
+	/** required for merkle snapshots */
+	proof?: BalanceProof;
+} & DistributionArgs;
+
+/**
+ * Generate Instructions to claim the share of an identity in a distribution
+ * @param args - {@link ClaimDistributionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getClaimDistributionIx(
+	args: ClaimDistributionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const distribution = getDistributionPda(args.assetMint, args.distributionId);
+	const identityAccount = getIdentityAccountPda(args.assetMint, args.owner);
+	const taxVault = (await getDistributionAccount(args.assetMint, args.distributionId, provider))?.taxVault;
+	const ix = await assetProgram.methods
+		.claimDistribution(args.proof ?? null)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			assetMint: new PublicKey(args.assetMint),
+			distribution,
+			payoutMint: new PublicKey(args.payoutMint),
+			vault: getDistributionVault(args),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount,
+			trackerAccount: args.proof ? null : getTrackerAccountPda(args.assetMint, args.owner),
+			destination: args.destination ? new PublicKey(args.destination) : getAssociatedTokenAddressSync(
+				new PublicKey(args.payoutMint),
+				new PublicKey(args.owner),
+				true,
+				getPayoutTokenProgram(args)
+			),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			taxVault: taxVault && !taxVault.equals(PublicKey.default) ? taxVault : null,
+			distributionClaim: getDistributionClaimPda(distribution, identityAccount),
+			payoutTokenProgram: getPayoutTokenProgram(args),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type ReclaimDistributionArgs = {
+	authority: string;
+	/** token account receiving the unclaimed funds */
+	destination: string;
+} & DistributionArgs;
+
+/**
+ * Generate Instructions to reclaim the unclaimed funds of an expired distribution
+ * @param args - {@link ReclaimDistributionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getReclaimDistributionIx(
+	args: ReclaimDistributionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.reclaimDistribution()
+		.accountsStrict({
+			authority: new PublicKey(args.authority),
+			assetController: getAssetControllerPda(args.assetMint),
+			distribution: getDistributionPda(args.assetMint, args.distributionId),
+			payoutMint: new PublicKey(args.payoutMint),
+			vault: getDistributionVault(args),
+			destination: new PublicKey(args.destination),
+			payoutTokenProgram: getPayoutTokenProgram(args),
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type ConversionArgs = {
+	/** mint the shares are converted from */
+	sourceMint: string;
+	/** mint the shares are converted into */
+	destinationMint: string;
+};
+
+export type ConversionAuthorityArgs = {
+	sourceAuthority: string;
+	destinationAuthority: string;
+} & ConversionArgs;
+
+const getConversionAuthorities = (args: ConversionAuthorityArgs) => ({
+	sourceAuthority: new PublicKey(args.sourceAuthority),
+	destinationAuthority: new PublicKey(args.destinationAuthority),
+	sourceAssetController: getAssetControllerPda(args.sourceMint),
+	destinationAssetController: getAssetControllerPda(args.destinationMint),
+	conversion: getConversionPda(args.sourceMint, args.destinationMint),
+});
+
+export type CreateConversionArgs = {
+	payer: string;
+	config: ConversionConfig;
+} & ConversionAuthorityArgs;
+
+/**
+ * Generate Instructions to open a conversion between two assets, signed by the authorities of both assets
+ * @param args - {@link CreateConversionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCreateConversionIx(
+	args: CreateConversionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.createConversion(args.config)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			...getConversionAuthorities(args),
+			sourceMint: new PublicKey(args.sourceMint),
+			destinationMint: new PublicKey(args.destinationMint),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type UpdateConversionArgs = {
+	config: ConversionConfig;
+} & ConversionAuthorityArgs;
+
+/**
+ * Generate Instructions to change the ratio and holding period treatment of a conversion
+ * @param args - {@link UpdateConversionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getUpdateConversionIx(
+	args: UpdateConversionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.updateConversion(args.config)
+		.accountsStrict({
+			...getConversionAuthorities(args),
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.instruction();
+	return ix;
+}
+
+export type CloseConversionArgs = {
+	/** payer of the conversion, receives the rent back */
+	rentReceiver: string;
+} & ConversionAuthorityArgs;
+
+/**
+ * Generate Instructions to close a conversion
+ * @param args - {@link CloseConversionArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getCloseConversionIx(
+	args: CloseConversionArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.closeConversion()
+		.accountsStrict({
+			...getConversionAuthorities(args),
+			rentReceiver: new PublicKey(args.rentReceiver),
+		})
+		.instruction();
+	return ix;
+}
+
+export type ConvertSharesArgs = {
+	payer: string;
+	/** holder of the shares, owner of the identity accounts of both assets */
+	wallet: string;
+	/** source base units to convert */
+	amount: BN;
+} & ConversionArgs;
+
+/**
+ * Generate Instructions to convert shares of a wallet at the ratio of the conversion
+ * @param args - {@link ConvertSharesArgs}
+ * @returns - {@link TransactionInstruction}
+ * */
+export async function getConvertSharesIx(
+	args: ConvertSharesArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const assetProgram = getAssetControllerProgram(provider);
+	const ix = await assetProgram.methods
+		.convertShares(args.amount)
+		.accountsStrict({
+			payer: new PublicKey(args.payer),
+			wallet: new PublicKey(args.wallet),
+			conversion: getConversionPda(args.sourceMint, args.destinationMint),
+			sourceMint: new PublicKey(args.sourceMint),
+			destinationMint: new PublicKey(args.destinationMint),
+			sourceAssetController: getAssetControllerPda(args.sourceMint),
+			destinationAssetController: getAssetControllerPda(args.destinationMint),
+			sourceTokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.sourceMint),
+				new PublicKey(args.wallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			destinationTokenAccount: getAssociatedTokenAddressSync(
+				new PublicKey(args.destinationMint),
+				new PublicKey(args.wallet),
+				true,
+				TOKEN_2022_PROGRAM_ID
+			),
+			sourceIdentityRegistry: getIdentityRegistryPda(args.sourceMint),
+			sourceIdentityAccount: getIdentityAccountPda(args.sourceMint, args.wallet),
+			sourceTrackerAccount: getTrackerAccountPda(args.sourceMint, args.wallet),
+			sourcePolicyEngine: getPolicyEnginePda(args.sourceMint),
+			sourceRentReceiver: await getPolicyEngineAuthority(args.sourceMint, provider),
+			destinationIdentityRegistry: getIdentityRegistryPda(args.destinationMint),
+			destinationIdentityAccount: getIdentityAccountPda(args.destinationMint, args.wallet),
+			destinationTrackerAccount: getTrackerAccountPda(args.destinationMint, args.wallet),
+			destinationPolicyEngine: getPolicyEnginePda(args.destinationMint),
+			policyEngineProgram: policyEngineProgramId,
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getAssetControllerEventAuthority(),
+			program: assetControllerProgramId,
+		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.destinationMint, "issuance", provider))
+		.instruction();
+	return ix;
+}
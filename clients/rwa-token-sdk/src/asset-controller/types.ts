@@ -1,6 +1,42 @@
-import { type IdlAccounts } from "@coral-xyz/anchor";
+import { type IdlAccounts, type IdlTypes } from "@coral-xyz/anchor";
 import { AssetControllerIdlTypes } from "../programs";
 
 /** Represents on chain asset controller account. */
 export type AssetControllerAccount =
   IdlAccounts<AssetControllerIdlTypes>["assetControllerAccount"];
+
+/** Represents on chain redemption request account. */
+export type RedemptionRequestAccount =
+  IdlAccounts<AssetControllerIdlTypes>["redemptionRequestAccount"];
+
+/** Represents on chain subscription offer account. */
+export type SubscriptionOfferAccount =
+  IdlAccounts<AssetControllerIdlTypes>["subscriptionOfferAccount"];
+
+/** Represents on chain subscription request account. */
+export type SubscriptionRequestAccount =
+  IdlAccounts<AssetControllerIdlTypes>["subscriptionRequestAccount"];
+
+/** Represents on chain distribution account. */
+export type DistributionAccount =
+  IdlAccounts<AssetControllerIdlTypes>["distributionAccount"];
+
+/** Represents on chain distribution claim account. */
+export type DistributionClaimAccount =
+  IdlAccounts<AssetControllerIdlTypes>["distributionClaimAccount"];
+
+/** Source of the record date balances of a distribution. */
+export type DistributionSnapshot = IdlTypes<AssetControllerIdlTypes>["distributionSnapshot"];
+
+/** Record date balance of an identity proven against a merkle snapshot. */
+export type BalanceProof = IdlTypes<AssetControllerIdlTypes>["balanceProof"];
+
+/** Withholding rate of a country group of a distribution. */
+export type WithholdingRate = IdlTypes<AssetControllerIdlTypes>["withholdingRate"];
+
+/** Represents on chain conversion account. */
+export type ConversionAccount =
+  IdlAccounts<AssetControllerIdlTypes>["conversionAccount"];
+
+/** Ratio and holding period treatment of a conversion. */
+export type ConversionConfig = IdlTypes<AssetControllerIdlTypes>["conversionConfig"];
@@ -1,5 +1,6 @@
 import { PublicKey } from "@solana/web3.js";
-import { type Idl, Program, type Provider, utils } from "@coral-xyz/anchor";
+import { createHash } from "crypto";
+import { BN, type Idl, Program, type Provider, utils } from "@coral-xyz/anchor";
 import { AssetControllerIdlTypes } from "../programs";
 
 import * as AssetControllerIdl from "../programs/idls/AssetController.json";
@@ -30,4 +31,148 @@ export const getAssetControllerPda = (assetMint: string) =>
 export const getAssetControllerEventAuthority = () => PublicKey.findProgramAddressSync(
 	[utils.bytes.utf8.encode("__event_authority")],
 	assetControllerProgramId
-)[0];
\ No newline at end of file
+)[0];
+
+/**
+ * Retrieves the pda of a redemption request.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param wallet - The wallet requesting the redemption.
+ * @param id - The id of the request, unique per wallet.
+ * @returns The redemption request pda.
+ */
+export const getRedemptionRequestPda = (assetMint: string, wallet: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[
+			utils.bytes.utf8.encode("redemption-request"),
+			new PublicKey(assetMint).toBuffer(),
+			new PublicKey(wallet).toBuffer(),
+			id.toArrayLike(Buffer, "le", 8),
+		],
+		assetControllerProgramId
+	)[0];
+
+/**
+ * Retrieves the pda of a subscription offer.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The id of the offer, unique per asset.
+ * @returns The subscription offer pda.
+ */
+export const getSubscriptionOfferPda = (assetMint: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[
+			utils.bytes.utf8.encode("subscription-offer"),
+			new PublicKey(assetMint).toBuffer(),
+			id.toArrayLike(Buffer, "le", 8),
+		],
+		assetControllerProgramId
+	)[0];
+
+/**
+ * Retrieves the pda of a subscription request.
+ * @param offer - The subscription offer.
+ * @param wallet - The wallet subscribing.
+ * @param id - The id of the request, unique per wallet and offer.
+ * @returns The subscription request pda.
+ */
+export const getSubscriptionRequestPda = (offer: PublicKey, wallet: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[
+			utils.bytes.utf8.encode("subscription-request"),
+			offer.toBuffer(),
+			new PublicKey(wallet).toBuffer(),
+			id.toArrayLike(Buffer, "le", 8),
+		],
+		assetControllerProgramId
+	)[0];
+
+/**
+ * Retrieves the pda of a distribution.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The id of the distribution, unique per asset.
+ * @returns The distribution pda.
+ */
+export const getDistributionPda = (assetMint: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[
+			utils.bytes.utf8.encode("distribution"),
+			new PublicKey(assetMint).toBuffer(),
+			id.toArrayLike(Buffer, "le", 8),
+		],
+		assetControllerProgramId
+	)[0];
+
+/**
+ * Retrieves the pda of the claim receipt of an identity for a distribution.
+ * @param distribution - The distribution.
+ * @param identityAccount - The identity account claiming.
+ * @returns The distribution claim pda.
+ */
+export const getDistributionClaimPda = (distribution: PublicKey, identityAccount: PublicKey) =>
+	PublicKey.findProgramAddressSync(
+		[
+			utils.bytes.utf8.encode("distribution-claim"),
+			distribution.toBuffer(),
+			identityAccount.toBuffer(),
+		],
+		assetControllerProgramId
+	)[0];
+
+/**
+ * Retrieves the pda of the conversion of the shares of one asset into another.
+ * @param sourceMint - The string representation of the mint converted from.
+ * @param destinationMint - The string representation of the mint converted into.
+ * @returns The conversion pda.
+ */
+export const getConversionPda = (sourceMint: string, destinationMint: string) =>
+	PublicKey.findProgramAddressSync(
+		[
+			utils.bytes.utf8.encode("conversion"),
+			new PublicKey(sourceMint).toBuffer(),
+			new PublicKey(destinationMint).toBuffer(),
+		],
+		assetControllerProgramId
+	)[0];
+
+const sha256 = (...data: Buffer[]) => createHash("sha256").update(Buffer.concat(data)).digest();
+
+/**
+ * Builds the merkle tree of record date balances committed by a merkle distribution snapshot.
+ * @param balances - The balance of each identity account at the record date.
+ * @returns The root, the total balance and a proof getter for each identity account.
+ */
+export const getBalanceMerkleTree = (balances: { identityAccount: PublicKey; balance: BN }[]) => {
+	const leaves = balances.map(({ identityAccount, balance }) =>
+		sha256(identityAccount.toBuffer(), balance.toArrayLike(Buffer, "le", 8))
+	);
+	const layers = [leaves];
+	while (layers[layers.length - 1].length > 1) {
+		const layer = layers[layers.length - 1];
+		const next: Buffer[] = [];
+		for (let i = 0; i < layer.length; i += 2) {
+			if (i + 1 === layer.length) {
+				next.push(layer[i]);
+			} else {
+				const [left, right] = Buffer.compare(layer[i], layer[i + 1]) <= 0 ? [layer[i], layer[i + 1]] : [layer[i + 1], layer[i]];
+				next.push(sha256(left, right));
+			}
+		}
+		layers.push(next);
+	}
+	const getProof = (identityAccount: PublicKey) => {
+		let index = balances.findIndex((entry) => entry.identityAccount.equals(identityAccount));
+		const proof: number[][] = [];
+		for (const layer of layers.slice(0, -1)) {
+			const sibling = index % 2 === 0 ? index + 1 : index - 1;
+			if (sibling < layer.length) {
+				proof.push([...layer[sibling]]);
+			}
+			index = Math.floor(index / 2);
+		}
+		return { balance: balances.find((entry) => entry.identityAccount.equals(identityAccount))!.balance, proof };
+	};
+	return {
+		root: [...layers[layers.length - 1][0]],
+		totalBalance: balances.reduce((total, { balance }) => total.add(balance), new BN(0)),
+		getProof,
+	};
+};
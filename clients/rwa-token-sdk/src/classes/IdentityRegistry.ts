@@ -13,8 +13,14 @@ import {
 	getDetachWalletFromIdentityIx,
 	ChangeCountryArgs,
 	getChangeCountryIx,
+	ChangeBeneficialOwnersArgs,
+	getChangeBeneficialOwnersIx,
 	RevokeIdentityAccountArgs,
 	getRevokeIdentityAccountIx,
+	MigrateIdentityRegistryArgs,
+	getMigrateIdentityRegistryIx,
+	MigrateIdentityAccountArgs,
+	getMigrateIdentityAccountIx,
 } from "../identity-registry";
 import { type RwaClient } from "./Client";
 
@@ -58,6 +64,11 @@ export class IdentityRegistry {
 		return changeCountryIx;
 	}
 
+	async changeBeneficialOwners(changeBeneficialOwnersArgs: ChangeBeneficialOwnersArgs): Promise<TransactionInstruction> {
+		const changeBeneficialOwnersIx = await getChangeBeneficialOwnersIx(changeBeneficialOwnersArgs, this.rwaClient.provider);
+		return changeBeneficialOwnersIx;
+	}
+
 	/**
    * Asynchronously update user account identity
    * @param - {@link AddLevelToIdentityAccountArgs}
@@ -98,6 +109,26 @@ export class IdentityRegistry {
 		return revokeIdentityAccountIx;
 	}
 
+	/**
+   * Asynchronously migrates a version 1 identity registry
+   * @param - {@link MigrateIdentityRegistryArgs}
+   * @returns A Promise that resolves to the instruction to migrate the identity registry.
+   */
+	async migrateIdentityRegistry(migrateArgs: MigrateIdentityRegistryArgs): Promise<TransactionInstruction> {
+		const migrateIx = await getMigrateIdentityRegistryIx(migrateArgs, this.rwaClient.provider);
+		return migrateIx;
+	}
+
+	/**
+   * Asynchronously migrates a version 1 identity account
+   * @param - {@link MigrateIdentityAccountArgs}
+   * @returns A Promise that resolves to the instruction to migrate the identity account.
+   */
+	async migrateIdentityAccount(migrateArgs: MigrateIdentityAccountArgs): Promise<TransactionInstruction> {
+		const migrateIx = await getMigrateIdentityAccountIx(migrateArgs, this.rwaClient.provider);
+		return migrateIx;
+	}
+
 	/**
    * Retrieves the identity registry pda account for a specific asset mint.
    * @param assetMint - The string representation of the asset's mint address.
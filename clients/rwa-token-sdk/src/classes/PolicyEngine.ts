@@ -2,23 +2,79 @@ import { type IxReturn } from "../utils";
 import {
 	AddLockArgs,
 	type AttachPolicyArgs,
+	AttachPolicyToPageArgs,
+	CastVoteArgs,
+	ChangeCounterLimitEnforcementModeArgs,
 	ChangeCounterLimitsArgs,
 	ChangeCountersArgs,
+	ChangeCountryCountersArgs,
 	ChangeIssuancePoliciesArgs,
 	ChangeMappingArgs,
+	ChangePolicyApplicabilityArgs,
+	ChangePolicyEnforcementModeArgs,
+	ChangeTimelockArgs,
+	ClosePolicyPageArgs,
+	CloseTrancheArgs,
+	CloseProposalArgs,
+	CreateProposalArgs,
+	DelegateVotesArgs,
+	FinalizeProposalArgs,
+	UndelegateVotesArgs,
+	OpenSnapshotArgs,
+	CreatePolicyPageArgs,
 	DetachPolicyArgs,
+	DetachPolicyFromPageArgs,
 	getAddLockIx,
 	getAttachToPolicyEngineIx,
+	getAttachToPolicyPageIx,
+	getCancelChangeIx,
+	getCancelReconciliationIx,
+	getChangeCounterLimitEnforcementModeIx,
 	getChangeCounterLimitsIx,
 	getChangeCountersIx,
+	getChangeCountryCountersIx,
 	getChangeIssuancePoliciesIx,
 	getChangeMappingIx,
+	getChangePolicyApplicabilityIx,
+	getChangePolicyEnforcementModeIx,
+	getChangeTimelockIx,
+	getChangeTrancheIx,
+	getClosePolicyPageIx,
+	getCloseTrancheIx,
+	getOpenSnapshotIx,
+	getCreateProposalIx,
+	getCastVoteIx,
+	getFinalizeProposalIx,
+	getCloseProposalIx,
+	getDelegateVotesIx,
+	getUndelegateVotesIx,
+	getCommitReconciliationIx,
+	getCreatePolicyPageIx,
+	getCreateTrancheIx,
 	getDetachFromPolicyEngineIx,
+	getDetachFromPolicyPageIx,
+	getExecuteChangeIx,
 	getPolicyEnginePda,
+	getProposeChangeIx,
+	getReconcileCountersIxs,
 	getRemoveLockIx,
+	getRemoveExpiredTrancheLocksIx,
+	getMigratePolicyEngineIx,
+	getMigrateTrackerAccountIx,
 	getSetCountersIx,
+	getStartReconciliationIx,
+	getUpdateExtraMetasIx,
+	PendingChangeArgs,
+	ProposeChangeArgs,
+	ReconcileCountersArgs,
+	ReconciliationArgs,
 	RemoveLockArgs,
+	RemoveExpiredTrancheLocksArgs,
+	MigratePolicyEngineArgs,
+	MigrateTrackerAccountArgs,
 	SetCountersArgs,
+	TrancheArgs,
+	UpdateExtraMetasArgs,
 } from "../policy-engine";
 import { type RwaClient } from "./Client";
 import { type PublicKey } from "@solana/web3.js";
@@ -59,6 +115,240 @@ export class PolicyEngine {
 		return attachPolicyIx;
 	}
 
+	/**
+   * Asynchronously creates an overflow page holding policies evaluated by the given scope.
+   * @param - {@link CreatePolicyPageArgs}
+   * @returns A Promise that resolves to the instructions to create a policy page.
+   * */
+	async createPolicyPage(args: CreatePolicyPageArgs): Promise<IxReturn> {
+		const createPolicyPageIx = await getCreatePolicyPageIx(
+			args,
+			this.rwaClient.provider
+		);
+		return createPolicyPageIx;
+	}
+
+	/**
+   * Asynchronously closes an empty policy page.
+   * @param - {@link ClosePolicyPageArgs}
+   * @returns A Promise that resolves to the instructions to close a policy page.
+   * */
+	async closePolicyPage(args: ClosePolicyPageArgs): Promise<IxReturn> {
+		const closePolicyPageIx = await getClosePolicyPageIx(
+			args,
+			this.rwaClient.provider
+		);
+		return closePolicyPageIx;
+	}
+
+	/**
+   * Asynchronously creates an offering tranche issuances can be made against.
+   * @param - {@link TrancheArgs}
+   * @returns A Promise that resolves to the instructions to create a tranche.
+   * */
+	async createTranche(args: TrancheArgs): Promise<IxReturn> {
+		const createTrancheIx = await getCreateTrancheIx(
+			args,
+			this.rwaClient.provider
+		);
+		return createTrancheIx;
+	}
+
+	/**
+   * Asynchronously changes the window, caps, eligibility and lock period of a tranche.
+   * @param - {@link TrancheArgs}
+   * @returns A Promise that resolves to the instructions to change a tranche.
+   * */
+	async changeTranche(args: TrancheArgs): Promise<IxReturn> {
+		const changeTrancheIx = await getChangeTrancheIx(
+			args,
+			this.rwaClient.provider
+		);
+		return changeTrancheIx;
+	}
+
+	/**
+   * Asynchronously closes a tranche.
+   * @param - {@link CloseTrancheArgs}
+   * @returns A Promise that resolves to the instructions to close a tranche.
+   * */
+	async closeTranche(args: CloseTrancheArgs): Promise<IxReturn> {
+		const closeTrancheIx = await getCloseTrancheIx(
+			args,
+			this.rwaClient.provider
+		);
+		return closeTrancheIx;
+	}
+
+	/**
+   * Asynchronously opens a record date snapshot of the holder balances.
+   * @param - {@link OpenSnapshotArgs}
+   * @returns A Promise that resolves to the instructions to open a snapshot.
+   * */
+	async openSnapshot(args: OpenSnapshotArgs): Promise<IxReturn> {
+		const openSnapshotIx = await getOpenSnapshotIx(
+			args,
+			this.rwaClient.provider
+		);
+		return openSnapshotIx;
+	}
+
+	/**
+   * Asynchronously creates a proposal voted with the balances at the record date of the latest snapshot.
+   * @param - {@link CreateProposalArgs}
+   * @returns A Promise that resolves to the instructions to create a proposal.
+   * */
+	async createProposal(args: CreateProposalArgs): Promise<IxReturn> {
+		const createProposalIx = await getCreateProposalIx(
+			args,
+			this.rwaClient.provider
+		);
+		return createProposalIx;
+	}
+
+	/**
+   * Asynchronously votes the record date balance of an identity, owned or delegated to the voter.
+   * @param - {@link CastVoteArgs}
+   * @returns A Promise that resolves to the instructions to cast the vote.
+   * */
+	async castVote(args: CastVoteArgs): Promise<IxReturn> {
+		const castVoteIx = await getCastVoteIx(
+			args,
+			this.rwaClient.provider
+		);
+		return castVoteIx;
+	}
+
+	/**
+   * Asynchronously finalizes a proposal after its voting window, emitting the final tally.
+   * @param - {@link FinalizeProposalArgs}
+   * @returns A Promise that resolves to the instructions to finalize the proposal.
+   * */
+	async finalizeProposal(args: FinalizeProposalArgs): Promise<IxReturn> {
+		const finalizeProposalIx = await getFinalizeProposalIx(
+			args,
+			this.rwaClient.provider
+		);
+		return finalizeProposalIx;
+	}
+
+	/**
+   * Asynchronously closes a finalized proposal.
+   * @param - {@link CloseProposalArgs}
+   * @returns A Promise that resolves to the instructions to close the proposal.
+   * */
+	async closeProposal(args: CloseProposalArgs): Promise<IxReturn> {
+		const closeProposalIx = await getCloseProposalIx(
+			args,
+			this.rwaClient.provider
+		);
+		return closeProposalIx;
+	}
+
+	/**
+   * Asynchronously delegates the voting power of an identity to another identity.
+   * @param - {@link DelegateVotesArgs}
+   * @returns A Promise that resolves to the instructions to delegate the voting power.
+   * */
+	async delegateVotes(args: DelegateVotesArgs): Promise<IxReturn> {
+		const delegateVotesIx = await getDelegateVotesIx(
+			args,
+			this.rwaClient.provider
+		);
+		return delegateVotesIx;
+	}
+
+	/**
+   * Asynchronously revokes the delegation of the voting power of an identity.
+   * @param - {@link UndelegateVotesArgs}
+   * @returns A Promise that resolves to the instructions to revoke the delegation.
+   * */
+	async undelegateVotes(args: UndelegateVotesArgs): Promise<IxReturn> {
+		const undelegateVotesIx = await getUndelegateVotesIx(
+			args,
+			this.rwaClient.provider
+		);
+		return undelegateVotesIx;
+	}
+
+	/**
+   * Asynchronously attaches a policy to a policy page.
+   * @param - {@link AttachPolicyToPageArgs}
+   * @returns A Promise that resolves to the instructions to attach a policy.
+   * */
+	async attachPolicyToPage(policyArgs: AttachPolicyToPageArgs): Promise<IxReturn> {
+		const attachPolicyIx = await getAttachToPolicyPageIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return attachPolicyIx;
+	}
+
+	/**
+   * Asynchronously detaches a policy from a policy page.
+   * @param - {@link DetachPolicyFromPageArgs}
+   * @returns A Promise that resolves to the instructions to detach a policy.
+   * */
+	async detachPolicyFromPage(policyArgs: DetachPolicyFromPageArgs): Promise<IxReturn> {
+		const detachPolicyIx = await getDetachFromPolicyPageIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return detachPolicyIx;
+	}
+
+	/**
+   * Asynchronously rewrites the extra metas of a mint created before the policy page slots existed.
+   * @param - {@link UpdateExtraMetasArgs}
+   * @returns A Promise that resolves to the instructions to update the extra metas.
+   * */
+	async updateExtraMetas(args: UpdateExtraMetasArgs): Promise<IxReturn> {
+		const updateExtraMetasIx = await getUpdateExtraMetasIx(
+			args,
+			this.rwaClient.provider
+		);
+		return updateExtraMetasIx;
+	}
+
+	/**
+   * Asynchronously switches a policy between enforce, monitor and disabled.
+   * @param - {@link ChangePolicyEnforcementModeArgs}
+   * @returns A Promise that resolves to the instructions to change the enforcement mode.
+   * */
+	async changePolicyEnforcementMode(policyArgs: ChangePolicyEnforcementModeArgs): Promise<IxReturn> {
+		const changeModeIx = await getChangePolicyEnforcementModeIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return changeModeIx;
+	}
+
+	/**
+   * Asynchronously chooses the lifecycle events a policy is evaluated on.
+   * @param - {@link ChangePolicyApplicabilityArgs}
+   * @returns A Promise that resolves to the instructions to change the applicability.
+   * */
+	async changePolicyApplicability(policyArgs: ChangePolicyApplicabilityArgs): Promise<IxReturn> {
+		const changeApplicabilityIx = await getChangePolicyApplicabilityIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return changeApplicabilityIx;
+	}
+
+	/**
+   * Asynchronously switches a counter limit between enforce, monitor and disabled.
+   * @param - {@link ChangeCounterLimitEnforcementModeArgs}
+   * @returns A Promise that resolves to the instructions to change the enforcement mode.
+   * */
+	async changeCounterLimitEnforcementMode(policyArgs: ChangeCounterLimitEnforcementModeArgs): Promise<IxReturn> {
+		const changeModeIx = await getChangeCounterLimitEnforcementModeIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return changeModeIx;
+	}
+
 	async changeCounterLimits(policyArgs: ChangeCounterLimitsArgs): Promise<IxReturn> {
 		const changeCounterLimitsIx = await getChangeCounterLimitsIx(
 			policyArgs,
@@ -75,6 +365,19 @@ export class PolicyEngine {
 		return changeCountersIx;
 	}
 
+	/**
+   * Asynchronously adds or removes counters keeping one holder count per country group.
+   * @param - {@link ChangeCountryCountersArgs}
+   * @returns A Promise that resolves to the instructions to change the country counters.
+   * */
+	async changeCountryCounters(policyArgs: ChangeCountryCountersArgs): Promise<IxReturn> {
+		const changeCountryCountersIx = await getChangeCountryCountersIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return changeCountryCountersIx;
+	}
+
 	async changeMapping(policyArgs: ChangeMappingArgs): Promise<IxReturn> {
 		const changeMappingIx = await getChangeMappingIx(
 			policyArgs,
@@ -99,6 +402,85 @@ export class PolicyEngine {
 		return setCountersIx;
 	}
 
+	async changeTimelock(policyArgs: ChangeTimelockArgs): Promise<IxReturn> {
+		const changeTimelockIx = await getChangeTimelockIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return changeTimelockIx;
+	}
+
+	/**
+   * Asynchronously queues a configuration change, executable once the timelock delay has passed.
+   * @param - {@link ProposeChangeArgs}
+   * @returns A Promise that resolves to the instructions to propose the change.
+   * */
+	async proposeChange(policyArgs: ProposeChangeArgs): Promise<IxReturn> {
+		const proposeChangeIx = await getProposeChangeIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return proposeChangeIx;
+	}
+
+	async cancelChange(policyArgs: PendingChangeArgs): Promise<IxReturn> {
+		const cancelChangeIx = await getCancelChangeIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return cancelChangeIx;
+	}
+
+	async executeChange(policyArgs: PendingChangeArgs): Promise<IxReturn> {
+		const executeChangeIx = await getExecuteChangeIx(
+			policyArgs,
+			this.rwaClient.provider
+		);
+		return executeChangeIx;
+	}
+
+	/**
+   * Asynchronously starts recomputing the counters from the tracker accounts.
+   * @param - {@link ReconciliationArgs}
+   * @returns A Promise that resolves to the instructions to start the reconciliation.
+   * */
+	async startReconciliation(args: ReconciliationArgs): Promise<IxReturn> {
+		const startReconciliationIx = await getStartReconciliationIx(
+			args,
+			this.rwaClient.provider
+		);
+		return startReconciliationIx;
+	}
+
+	/**
+   * Asynchronously counts identities into the reconciliation, one instruction per batch.
+   * @param - {@link ReconcileCountersArgs}
+   * @returns A Promise that resolves to the instructions to reconcile the identities.
+   * */
+	async reconcileCounters(args: ReconcileCountersArgs): Promise<IxReturn> {
+		const reconcileCountersIxs = await getReconcileCountersIxs(
+			args,
+			this.rwaClient.provider
+		);
+		return reconcileCountersIxs;
+	}
+
+	async commitReconciliation(args: ReconciliationArgs): Promise<IxReturn> {
+		const commitReconciliationIx = await getCommitReconciliationIx(
+			args,
+			this.rwaClient.provider
+		);
+		return commitReconciliationIx;
+	}
+
+	async cancelReconciliation(args: ReconciliationArgs): Promise<IxReturn> {
+		const cancelReconciliationIx = await getCancelReconciliationIx(
+			args,
+			this.rwaClient.provider
+		);
+		return cancelReconciliationIx;
+	}
+
 	async addLock(args: AddLockArgs): Promise<IxReturn> {
 		const addLockIx = await getAddLockIx(
 			args,
@@ -115,6 +497,30 @@ export class PolicyEngine {
 		return removeLockIx;	
 	}
 
+	async removeExpiredTrancheLocks(args: RemoveExpiredTrancheLocksArgs): Promise<IxReturn> {
+		const removeExpiredTrancheLocksIx = await getRemoveExpiredTrancheLocksIx(
+			args,
+			this.rwaClient.provider
+		);
+		return removeExpiredTrancheLocksIx;
+	}
+
+	async migratePolicyEngine(args: MigratePolicyEngineArgs): Promise<IxReturn> {
+		const migratePolicyEngineIx = await getMigratePolicyEngineIx(
+			args,
+			this.rwaClient.provider
+		);
+		return migratePolicyEngineIx;
+	}
+
+	async migrateTrackerAccount(args: MigrateTrackerAccountArgs): Promise<IxReturn> {
+		const migrateTrackerAccountIx = await getMigrateTrackerAccountIx(
+			args,
+			this.rwaClient.provider
+		);
+		return migrateTrackerAccountIx;
+	}
+
 	/**
    * Retrieves the policy registry pda account for a specific asset mint.
    * @param assetMint - The string representation of the asset's mint address.
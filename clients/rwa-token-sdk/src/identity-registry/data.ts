@@ -6,6 +6,15 @@ import {
 	getIdentityRegistryProgram,
 } from "./utils";
 import { GetProgramAccountsFilter, PublicKey } from "@solana/web3.js";
+import {
+	AccountState,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	getAssociatedTokenAddressSync,
+	getDefaultAccountState,
+	getMint,
+	TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { assetControllerProgramId, getAssetControllerPda } from "../asset-controller/utils";
 
 /**
  * Retrieves identity registry account associated with a specific asset mint.
@@ -147,4 +156,35 @@ export async function getWalletIdentityAccountsWithFilter(
 	return walletIdentityAccounts.map((account) =>
 		identityRegistryProgram.coder.accounts.decode("walletIdentity", account.account.data)
 	);
-}
\ No newline at end of file
+}
+
+/**
+ * Retrieves the accounts the identity registry uses to thaw or freeze the token account of a wallet through the asset controller.
+ * They are only set for assets whose token accounts are frozen by default, the accounts are `null` otherwise
+ * or when the mint does not exist yet.
+ * @param assetMint - The string representation of the asset mint.
+ * @param wallet - The wallet whose associated token account is thawed or frozen.
+ * @returns A promise resolving to the wallet activation accounts.
+ */
+export async function getWalletActivationAccounts(
+	assetMint: string,
+	wallet: string,
+	provider: Provider
+) {
+	const mint = await getMint(provider.connection, new PublicKey(assetMint), undefined, TOKEN_2022_PROGRAM_ID)
+		.catch(() : undefined => undefined);
+	const frozenByDefault = mint !== undefined && getDefaultAccountState(mint)?.state === AccountState.Frozen;
+	return {
+		assetControllerProgram: frozenByDefault ? assetControllerProgramId : null,
+		assetController: frozenByDefault ? getAssetControllerPda(assetMint) : null,
+		tokenAccountOwner: frozenByDefault ? new PublicKey(wallet) : null,
+		tokenAccount: frozenByDefault ? getAssociatedTokenAddressSync(
+			new PublicKey(assetMint),
+			new PublicKey(wallet),
+			true,
+			TOKEN_2022_PROGRAM_ID
+		) : null,
+		tokenProgram: frozenByDefault ? TOKEN_2022_PROGRAM_ID : null,
+		associatedTokenProgram: frozenByDefault ? ASSOCIATED_TOKEN_PROGRAM_ID : null,
+	};
+}
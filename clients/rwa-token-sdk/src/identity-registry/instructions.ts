@@ -13,7 +13,9 @@ import {
 } from "./utils";
 import { BN, Provider } from "@coral-xyz/anchor";
 import { getPolicyEnginePda, getTrackerAccountPda, policyEngineProgramId } from "../policy-engine/utils";
+import { getPolicyPageAccountMetas } from "../policy-engine/data";
 import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { getWalletActivationAccounts } from "./data";
 
 /** Represents arguments for creating an on identity registry on chain. */
 export type CreateIdentityRegistryArgs = {
@@ -81,6 +83,7 @@ export async function getCreateIdentityAccountIx(
 			trackerAccount: getTrackerAccountPda(args.assetMint, args.owner),
 			assetMint: new PublicKey(args.assetMint),
 			policyEngineProgram: policyEngineProgramId,
+			...await getWalletActivationAccounts(args.assetMint, args.owner, provider),
 		})
 		.instruction();
 	return ix;
@@ -109,6 +112,8 @@ export async function getAttachWalletToIdentityIx(
 			systemProgram: SystemProgram.programId,
 			eventAuthority: getIdentityRegistryEventAuthority(),
 			program: identityProgram.programId,
+			...await getWalletActivationAccounts(args.assetMint, args.wallet, provider),
+			assetControllerSigner: null,
 		})
 		.instruction();
 	return ix;
@@ -119,6 +124,11 @@ export async function getDetachWalletFromIdentityIx(
 	provider: Provider
 ): Promise<TransactionInstruction> {
 	const identityProgram = getIdentityRegistryProgram(provider);
+	const { assetControllerProgram, assetController, tokenProgram } = await getWalletActivationAccounts(
+		args.assetMint,
+		args.wallet,
+		provider
+	);
 	const ix = await identityProgram.methods
 		.detachWalletFromIdentity()
 		.accountsStrict({
@@ -136,6 +146,10 @@ export async function getDetachWalletFromIdentityIx(
 				true,
 				TOKEN_2022_PROGRAM_ID
 			),
+			assetControllerProgram,
+			assetController,
+			tokenProgram,
+			assetControllerSigner: null,
 		})
 		.instruction();
 	return ix;
@@ -172,6 +186,76 @@ export async function getChangeCountryIx(
 			eventAuthority: getIdentityRegistryEventAuthority(),
 			program: identityProgram.programId,
 		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.assetMint, "levelsChange", provider))
+		.instruction();
+	return ix;
+}
+
+/** Represents the arguments required to change the beneficial owners of an identity account. */
+export type ChangeBeneficialOwnersArgs = {
+	owner: string;
+	beneficialOwners: number;
+	ignorePolicy?: boolean;
+} & CommonArgs;
+
+/**
+ * Builds the transaction instruction to change the number of beneficial owners behind an identity account
+ * @param args - {@link ChangeBeneficialOwnersArgs}.
+ * @returns Change beneficial owners transaction instruction.
+ */
+export async function getChangeBeneficialOwnersIx(
+	args: ChangeBeneficialOwnersArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const identityProgram = getIdentityRegistryProgram(provider);
+	const ix = await identityProgram.methods
+		.changeBeneficialOwners(args.beneficialOwners, args.ignorePolicy ?? false)
+		.accountsStrict({
+			signer: args.signer,
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			payer: args.payer,
+			policyEngineProgram: policyEngineProgramId,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			trackerAccount: getTrackerAccountPda(args.assetMint, args.owner),
+			assetMint: args.assetMint,
+			eventAuthority: getIdentityRegistryEventAuthority(),
+			program: identityProgram.programId,
+		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.assetMint, "levelsChange", provider))
+		.instruction();
+	return ix;
+}
+
+/** Represents the arguments required to hand an identity account over to another of its wallets. */
+export type ChangeIdentityOwnerArgs = {
+	/** first owner of the identity, the identity account keeps the address derived from it */
+	owner: string;
+	newOwner: string;
+} & CommonArgs;
+
+/**
+ * Builds the transaction instruction to change the owner of an identity account
+ * @param args - {@link ChangeIdentityOwnerArgs}.
+ * @returns Change identity owner transaction instruction.
+ */
+export async function getChangeIdentityOwnerIx(
+	args: ChangeIdentityOwnerArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const identityProgram = getIdentityRegistryProgram(provider);
+	const ix = await identityProgram.methods
+		.changeIdentityOwner(new PublicKey(args.newOwner))
+		.accountsStrict({
+			payer: args.payer,
+			signer: args.signer,
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			walletIdentity: getWalletIdentityAccountPda(args.assetMint, args.newOwner),
+			assetMint: args.assetMint,
+			eventAuthority: getIdentityRegistryEventAuthority(),
+			program: identityProgram.programId,
+		})
 		.instruction();
 	return ix;
 }
@@ -210,6 +294,7 @@ export async function getAddLevelToIdentityAccount(
 			eventAuthority: getIdentityRegistryEventAuthority(),
 			program: identityProgram.programId,
 		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.assetMint, "levelsChange", provider))
 		.instruction();
 	return ix;
 }
@@ -248,6 +333,7 @@ export async function getRemoveLevelFromIdentityAccount(
 			eventAuthority: getIdentityRegistryEventAuthority(),
 			program: identityProgram.programId,
 		})
+		.remainingAccounts(await getPolicyPageAccountMetas(args.assetMint, "levelsChange", provider))
 		.instruction();
 	return ix;
 }
@@ -263,6 +349,11 @@ export async function getRevokeIdentityAccountIx(
 	provider: Provider
 ): Promise<TransactionInstruction> {
 	const identityProgram = getIdentityRegistryProgram(provider);
+	const { assetControllerProgram, assetController, tokenAccount, tokenProgram } = await getWalletActivationAccounts(
+		args.assetMint,
+		args.owner,
+		provider
+	);
 	const ix = await identityProgram.methods
 		.revokeIdentityAccount(new PublicKey(args.owner))
 		.accountsStrict({
@@ -276,7 +367,64 @@ export async function getRevokeIdentityAccountIx(
 			trackerAccount: getTrackerAccountPda(args.assetMint, args.owner),
 			assetMint: new PublicKey(args.assetMint),
 			policyEngineProgram: policyEngineProgramId,
+			assetControllerProgram,
+			assetController,
+			tokenAccount,
+			tokenProgram,
 		})
 		.instruction();
 	return ix;
-}
\ No newline at end of file
+}
+/** Represents the arguments required to migrate a version 1 identity registry. */
+export type MigrateIdentityRegistryArgs = {
+	signer: string;
+} & CommonArgs;
+
+/**
+ * Builds the transaction instruction to migrate a version 1 identity registry, before any of its identities
+ * @param args - {@link MigrateIdentityRegistryArgs}.
+ * @returns Migrate identity registry transaction instruction.
+ */
+export async function getMigrateIdentityRegistryIx(
+	args: MigrateIdentityRegistryArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const identityProgram = getIdentityRegistryProgram(provider);
+	const ix = await identityProgram.methods
+		.migrateIdentityRegistry()
+		.accountsStrict({
+			payer: args.payer,
+			signer: args.signer,
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			systemProgram: SystemProgram.programId,
+		})
+		.instruction();
+	return ix;
+}
+
+/** Represents the arguments required to migrate a version 1 identity account. */
+export type MigrateIdentityAccountArgs = {
+	owner: string;
+} & CommonArgs;
+
+/**
+ * Builds the transaction instruction to migrate a version 1 identity account, can be signed by any payer
+ * @param args - {@link MigrateIdentityAccountArgs}.
+ * @returns Migrate identity account transaction instruction.
+ */
+export async function getMigrateIdentityAccountIx(
+	args: MigrateIdentityAccountArgs,
+	provider: Provider
+): Promise<TransactionInstruction> {
+	const identityProgram = getIdentityRegistryProgram(provider);
+	const ix = await identityProgram.methods
+		.migrateIdentityAccount()
+		.accountsStrict({
+			payer: args.payer,
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			systemProgram: SystemProgram.programId,
+		})
+		.instruction();
+	return ix;
+}
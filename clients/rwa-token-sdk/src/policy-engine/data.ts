@@ -1,7 +1,8 @@
-import { Provider } from "@coral-xyz/anchor";
-import { type PolicyEngineAccount, type  TrackerAccount } from "./types";
-import { getPolicyEnginePda, getPolicyEngineProgram, getTrackerAccountPda } from "./utils";
-import { GetProgramAccountsFilter, PublicKey } from "@solana/web3.js";
+import { BN, Provider } from "@coral-xyz/anchor";
+import { type PendingChangeAccount, type PolicyEngineAccount, type PolicyPageAccount, type ReconciliationAccount, type  TrackerAccount, type TrancheAccount, type TrancheSubscriptionAccount, type ProposalAccount, type VoteAccount, type DelegationAccount } from "./types";
+import { getPendingChangePda, getPolicyEnginePda, getPolicyEngineProgram, getPolicyPagePda, getReconciliationPda, getTrackerAccountPda, getTranchePda, getTrancheSubscriptionPda, getProposalPda, getVotePda, getDelegationPda } from "./utils";
+import { AccountMeta, GetProgramAccountsFilter, PublicKey } from "@solana/web3.js";
+import { getMint, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 
 /**
  * Retrieves policy engine account associated with a specific asset mint.
@@ -58,4 +59,266 @@ export async function getTrackerAccount(
 	const policyEngineProgram = getPolicyEngineProgram(provider);
 	const trackerPda = getTrackerAccountPda(assetMint, owner);
 	return await policyEngineProgram.account.trackerAccount.fetch(trackerPda).catch(() : undefined => undefined);
-}
\ No newline at end of file
+}
+
+/**
+ * Retrieves a pending change account associated with a specific asset mint and change id.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The sequential id of the proposed change.
+ * @returns A promise resolving to the fetched pending change account, or `undefined` if it doesn't exist.
+ */
+export async function getPendingChangeAccount(
+	assetMint: string,
+	id: BN,
+	provider: Provider
+): Promise<PendingChangeAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	const pendingChangePda = getPendingChangePda(assetMint, id);
+	return await policyEngineProgram.account.pendingChangeAccount.fetch(pendingChangePda).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the counter reconciliation in progress for a specific asset mint.
+ * @param assetMint - The string representation of the asset mint.
+ * @returns A promise resolving to {@link ReconciliationAccount}, or `undefined` if no reconciliation is in progress.
+ */
+export async function getReconciliationAccount(
+	assetMint: string,
+	provider: Provider
+): Promise<ReconciliationAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.reconciliationAccount.fetch(getReconciliationPda(assetMint)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves a policy page of the policy engine associated with a specific asset mint.
+ * @param assetMint - The string representation of the asset mint.
+ * @param index - The index of the page.
+ * @returns A promise resolving to {@link PolicyPageAccount}, or `undefined` if it doesn't exist.
+ */
+export async function getPolicyPageAccount(
+	assetMint: string,
+	index: number,
+	provider: Provider
+): Promise<PolicyPageAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.policyPageAccount.fetch(getPolicyPagePda(assetMint, index)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves a tranche of a specific asset mint.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the tranche.
+ * @returns A promise resolving to {@link TrancheAccount}, or `undefined` if it doesn't exist.
+ */
+export async function getTrancheAccount(
+	assetMint: string,
+	id: BN,
+	provider: Provider
+): Promise<TrancheAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.trancheAccount.fetch(getTranchePda(assetMint, id)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the amount an identity subscribed to a tranche.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the tranche.
+ * @param owner - The string representation of the identity's owner.
+ * @returns A promise resolving to {@link TrancheSubscriptionAccount}, or `undefined` if it doesn't exist.
+ */
+export async function getTrancheSubscriptionAccount(
+	assetMint: string,
+	id: BN,
+	owner: string,
+	provider: Provider
+): Promise<TrancheSubscriptionAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.trancheSubscriptionAccount.fetch(getTrancheSubscriptionPda(assetMint, id, owner)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves a proposal of a specific asset mint.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the proposal.
+ * @returns A promise resolving to {@link ProposalAccount}, or `undefined` if it doesn't exist.
+ */
+export async function getProposalAccount(
+	assetMint: string,
+	id: BN,
+	provider: Provider
+): Promise<ProposalAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.proposalAccount.fetch(getProposalPda(assetMint, id)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the vote cast with the balance of an identity on a proposal.
+ * @param assetMint - The string representation of the asset mint.
+ * @param id - The id of the proposal.
+ * @param owner - The string representation of the owner of the identity whose balance was voted.
+ * @returns A promise resolving to {@link VoteAccount}, or `undefined` if it doesn't exist.
+ */
+export async function getVoteAccount(
+	assetMint: string,
+	id: BN,
+	owner: string,
+	provider: Provider
+): Promise<VoteAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.voteAccount.fetch(getVotePda(assetMint, id, owner)).catch(() : undefined => undefined);
+}
+
+/**
+ * Retrieves the delegation of the voting power of an identity.
+ * @param assetMint - The string representation of the asset mint.
+ * @param owner - The string representation of the owner of the delegating identity.
+ * @returns A promise resolving to {@link DelegationAccount}, or `undefined` if it doesn't exist.
+ */
+export async function getDelegationAccount(
+	assetMint: string,
+	owner: string,
+	provider: Provider
+): Promise<DelegationAccount | undefined> {
+	const policyEngineProgram = getPolicyEngineProgram(provider);
+	return await policyEngineProgram.account.delegationAccount.fetch(getDelegationPda(assetMint, owner)).catch(() : undefined => undefined);
+}
+
+/**
+ * Builds the remaining accounts carrying the policy pages evaluated by an operation.
+ * Transfers resolve their pages through the extra account metas and do not need this.
+ * @param assetMint - The string representation of the asset mint.
+ * @param scope - The operation evaluating the pages.
+ * @returns A promise resolving to the readonly account metas of the pages of that scope.
+ */
+export async function getPolicyPageAccountMetas(
+	assetMint: string,
+	scope: "transfer" | "issuance" | "levelsChange",
+	provider: Provider
+): Promise<AccountMeta[]> {
+	const policyEngine = await getPolicyEngineAccount(assetMint, provider);
+	if (!policyEngine) {
+		return [];
+	}
+	return policyEngine.policyPages
+		.filter((page) => scope in page.scope)
+		.map((page) => ({
+			pubkey: getPolicyPagePda(assetMint, page.index),
+			isSigner: false,
+			isWritable: false,
+		}));
+}
+
+/**
+ * Retrieves the authority of a policy engine, which receives the rent freed by shrinking trackers.
+ * @param assetMint - The string representation of the asset mint.
+ * @returns A promise resolving to the authority of the policy engine.
+ */
+export async function getPolicyEngineAuthority(assetMint: string, provider: Provider): Promise<PublicKey> {
+	const policyEngine = await getPolicyEngineAccount(assetMint, provider);
+	if (!policyEngine) {
+		throw new Error("policy engine not found");
+	}
+	return policyEngine.authority;
+}
+
+/** rolling window of the tracker outbound volume, mirrors the weekly buckets kept on chain */
+export const VOLUME_WINDOW_BUCKETS = 13;
+export const VOLUME_BUCKET_DURATION = 7 * 24 * 60 * 60;
+
+export interface AffiliateVolumeAllowance {
+	/** greater of 1% of the supply and the configured amount */
+	allowance: BN;
+	/** outbound volume over the rolling 3 month window */
+	volume: BN;
+	remaining: BN;
+}
+
+/**
+ * Computes what an affiliate can still sell under the most restrictive affiliate volume policy, as evaluated by the transfer hook.
+ * The identity filters of the policies are not evaluated, the owner is assumed to be an affiliate.
+ * @param assetMint - The string representation of the asset mint.
+ * @param owner - The owner of the identity account.
+ * @returns A promise resolving to {@link AffiliateVolumeAllowance}, or `undefined` if no affiliate volume policy applies to transfers.
+ */
+export async function getAffiliateVolumeAllowance(
+	assetMint: string,
+	owner: string,
+	provider: Provider
+): Promise<AffiliateVolumeAllowance | undefined> {
+	const policyEngine = await getPolicyEngineAccount(assetMint, provider);
+	if (!policyEngine) {
+		return undefined;
+	}
+	const pages = await Promise.all(
+		policyEngine.policyPages
+			.filter((page) => "transfer" in page.scope)
+			.map((page) => getPolicyPageAccount(assetMint, page.index, provider))
+	);
+	const amounts = [policyEngine.policies, ...pages.map((page) => page?.policies ?? [])]
+		.flat()
+		.filter((policy) => policy.applicability.transfer && !("disabled" in policy.enforcementMode))
+		.map((policy) => policy.policyType.affiliateVolumeLimit?.amount)
+		.filter((amount): amount is BN => amount !== undefined);
+	if (amounts.length === 0) {
+		return undefined;
+	}
+
+	const mint = await getMint(provider.connection, new PublicKey(assetMint), undefined, TOKEN_2022_PROGRAM_ID);
+	const onePercent = new BN(mint.supply.toString()).divn(100);
+	const allowance = amounts
+		.map((amount) => BN.max(onePercent, amount))
+		.reduce((min, value) => BN.min(min, value));
+
+	let volume = new BN(0);
+	const tracker = await getTrackerAccount(assetMint, owner, provider);
+	if (tracker) {
+		const current = Math.floor(Date.now() / 1000 / VOLUME_BUCKET_DURATION);
+		const lastBucket = tracker.outboundVolume.lastBucket.toNumber();
+		for (let age = 0; age < VOLUME_WINDOW_BUCKETS; age++) {
+			const bucket = lastBucket - age;
+			if (current - bucket < VOLUME_WINDOW_BUCKETS) {
+				const slot = ((bucket % VOLUME_WINDOW_BUCKETS) + VOLUME_WINDOW_BUCKETS) % VOLUME_WINDOW_BUCKETS;
+				volume = volume.add(tracker.outboundVolume.buckets[slot]);
+			}
+		}
+	}
+
+	return {
+		allowance,
+		volume,
+		remaining: allowance.gt(volume) ? allowance.sub(volume) : new BN(0),
+	};
+}
+
+/**
+ * Computes the balance of an identity at the record date of a snapshot, as checkpointed by its tracker.
+ * @param assetMint - The string representation of the asset mint.
+ * @param owner - The owner of the identity account.
+ * @param snapshotId - The id of the snapshot.
+ * @returns A promise resolving to the balance, or `undefined` if the snapshot is no longer readable.
+ */
+export async function getSnapshotBalance(
+	assetMint: string,
+	owner: string,
+	snapshotId: BN,
+	provider: Provider
+): Promise<BN | undefined> {
+	const policyEngine = await getPolicyEngineAccount(assetMint, provider);
+	if (
+		!policyEngine
+		|| snapshotId.isZero()
+		|| snapshotId.gt(policyEngine.snapshot.id)
+		|| policyEngine.snapshot.id.sub(snapshotId).gten(policyEngine.snapshot.reservedUntil.length)
+	) {
+		return undefined;
+	}
+	const tracker = await getTrackerAccount(assetMint, owner, provider);
+	if (!tracker) {
+		return new BN(0);
+	}
+	const checkpoint = tracker.checkpoints
+		.filter((checkpoint) => checkpoint.snapshotId.gte(snapshotId))
+		.sort((a, b) => a.snapshotId.cmp(b.snapshotId))[0];
+	return checkpoint ? checkpoint.balance : tracker.totalAmount;
+}
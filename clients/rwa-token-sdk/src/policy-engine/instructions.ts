@@ -6,14 +6,22 @@ import {
 import { type CommonArgs, type IxReturn } from "../utils";
 import {
 	getExtraMetasListPda,
+	getPendingChangePda,
 	getPolicyEnginePda,
 	getPolicyEngineProgram,
 	getPolicyEngineEventAuthority,
+	getPolicyPagePda,
+	getReconciliationPda,
 	getTrackerAccountPda,
+	getTranchePda,
+	getProposalPda,
+	getVotePda,
+	getDelegationPda,
 } from "./utils";
-import { type PolicyType, type IdentityFilter, Counter, CounterLimit, IssuancePolicies } from "./types";
+import { type PolicyType, type IdentityFilter, Counter, CounterLimit, CountryCounter, IssuancePolicies, EnforcementMode, Timelock, PolicyEngineChange, PolicyPageScope, PolicyApplicability, TrancheConfig, ProposalConfig } from "./types";
 import { BN, Provider } from "@coral-xyz/anchor";
 import { getIdentityAccountPda, getIdentityRegistryPda } from "../identity-registry";
+import { getPendingChangeAccount, getPolicyEngineAuthority, getPolicyPageAccount } from "./data";
 
 /** Represents the arguments required to create a policy engine account. */
 export type CreatePolicyEngineArgs = {
@@ -55,6 +63,9 @@ export type AttachPolicyArgs = {
   identityFilter: IdentityFilter;
   policyType: PolicyType;
   customError?: number;
+  enforcementMode?: EnforcementMode;
+  /** defaults to the events the policy type was always evaluated on */
+  applicability?: PolicyApplicability;
 };
 
 /** Represents the arguments required to detach a policy from an asset. */
@@ -81,7 +92,7 @@ export async function getAttachToPolicyEngineIx(
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
 	const ix = await policyProgram.methods
-		.attachToPolicyEngine(args.identityFilter, args.policyType, args.customError ?? 0)
+		.attachToPolicyEngine(args.identityFilter, args.policyType, args.customError ?? 0, args.enforcementMode ?? { enforce: {} }, args.applicability ?? null)
 		.accountsStrict({
 			signer: new PublicKey(args.authority),
 			payer: args.payer,
@@ -125,12 +136,301 @@ export async function getChangeIssuancePoliciesIx(
 	};
 }
 
+export type ChangeTimelockArgs = {
+	authority: string;
+	payer: string;
+	assetMint: string;
+	timelock: Timelock;
+}
+
+/**
+ * Generate instructions to change the delay of queued changes and whether direct changes are allowed.
+ * @param args {@link ChangeTimelockArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getChangeTimelockIx(
+	args: ChangeTimelockArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.changeTimelock(args.timelock)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type ProposeChangeArgs = {
+	authority: string;
+	payer: string;
+	assetMint: string;
+	change: PolicyEngineChange;
+}
+
+/**
+ * Generate instructions to queue a configuration change on the policy engine.
+ * The change id is read from the policy engine account.
+ * @param args {@link ProposeChangeArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getProposeChangeIx(
+	args: ProposeChangeArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const policyEngine = getPolicyEnginePda(args.assetMint);
+	const policyEngineAccount = await policyProgram.account.policyEngineAccount.fetch(policyEngine);
+	const ix = await policyProgram.methods
+		.proposeChange(args.change)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine,
+			pendingChange: getPendingChangePda(args.assetMint, policyEngineAccount.nextChangeId),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type PendingChangeArgs = {
+	authority: string;
+	/** account that paid for the proposal, receives the rent back */
+	payer: string;
+	assetMint: string;
+	id: BN;
+}
+
+/**
+ * Generate instructions to cancel a queued configuration change.
+ * @param args {@link PendingChangeArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCancelChangeIx(
+	args: PendingChangeArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.cancelChange()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			rentReceiver: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			pendingChange: getPendingChangePda(args.assetMint, args.id),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/**
+ * Generate instructions to execute a queued configuration change once its delay has passed.
+ * @param args {@link PendingChangeArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getExecuteChangeIx(
+	args: PendingChangeArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	// policy page changes are applied to the page of the queued change
+	const change = (await getPendingChangeAccount(args.assetMint, args.id, provider))?.change;
+	const pageIndex = change?.createPolicyPage?.index ?? change?.attachToPolicyPage?.index
+		?? change?.detachFromPolicyPage?.index ?? change?.closePolicyPage?.index;
+	const policyPage = pageIndex !== undefined ? getPolicyPagePda(args.assetMint, pageIndex) : null;
+	const policyPageRentReceiver = change?.closePolicyPage
+		? (await getPolicyPageAccount(args.assetMint, change.closePolicyPage.index, provider))?.payer ?? null
+		: null;
+	const ix = await policyProgram.methods
+		.executeChange()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			rentReceiver: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			pendingChange: getPendingChangePda(args.assetMint, args.id),
+			policyPage,
+			policyPageRentReceiver,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type ReconciliationArgs = {
+	authority: string;
+	/** account paying for the reconciliation, receives the rent back */
+	payer: string;
+	assetMint: string;
+}
+
+/**
+ * Generate instructions to start recomputing the counters from the tracker accounts.
+ * @param args {@link ReconciliationArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getStartReconciliationIx(
+	args: ReconciliationArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.startReconciliation()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			reconciliation: getReconciliationPda(args.assetMint),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type ReconcileCountersArgs = {
+	authority: string;
+	assetMint: string;
+	/** owners of the identities to count, every identity must be reconciled exactly once */
+	owners: string[];
+	/** number of identities per instruction, defaults to 10 */
+	batchSize?: number;
+}
+
+/**
+ * Generate instructions to count identities into the reconciliation, one instruction per batch.
+ * Identities are sorted by identity account as the program requires, so all owners should be passed at once
+ * or already be sorted across calls.
+ * @param args {@link ReconcileCountersArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getReconcileCountersIxs(
+	args: ReconcileCountersArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const batchSize = args.batchSize ?? 10;
+	const identities = args.owners
+		.map((owner) => ({
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+		}))
+		.sort((a, b) => Buffer.compare(a.identityAccount.toBuffer(), b.identityAccount.toBuffer()));
+
+	const ixs: TransactionInstruction[] = [];
+	for (let i = 0; i < identities.length; i += batchSize) {
+		const ix = await policyProgram.methods
+			.reconcileCounters()
+			.accountsStrict({
+				signer: new PublicKey(args.authority),
+				policyEngine: getPolicyEnginePda(args.assetMint),
+				identityRegistry: getIdentityRegistryPda(args.assetMint),
+				reconciliation: getReconciliationPda(args.assetMint),
+			})
+			.remainingAccounts(identities.slice(i, i + batchSize).flatMap((identity) => [
+				{ pubkey: identity.identityAccount, isSigner: false, isWritable: false },
+				{ pubkey: identity.trackerAccount, isSigner: false, isWritable: false },
+			]))
+			.instruction();
+		ixs.push(ix);
+	}
+	return {
+		ixs,
+		signers: [],
+	};
+}
+
+/**
+ * Generate instructions to overwrite the counters with the reconciled values.
+ * @param args {@link ReconciliationArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCommitReconciliationIx(
+	args: ReconciliationArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.commitReconciliation()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			rentReceiver: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			reconciliation: getReconciliationPda(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/**
+ * Generate instructions to drop a reconciliation in progress.
+ * @param args {@link ReconciliationArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCancelReconciliationIx(
+	args: ReconciliationArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.cancelReconciliation()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			rentReceiver: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			reconciliation: getReconciliationPda(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
 export type SetCountersArgs = {
 	authority: string;
 	payer: string;
 	assetMint: string;
 	changedCounters: number[];
 	values: BN[];
+	/** skips checking the affected counter limits against the current counter values */
+	skipLimitValidation?: boolean;
 }
 
 export async function getSetCountersIx(
@@ -139,7 +439,7 @@ export async function getSetCountersIx(
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
 	const ix = await policyProgram.methods
-		.setCounters(Buffer.from(args.changedCounters), args.values)
+		.setCounters(Buffer.from(args.changedCounters), args.values, args.skipLimitValidation ?? false)
 		.accountsStrict({
 			signer: new PublicKey(args.authority),
 			payer: args.payer,
@@ -173,6 +473,7 @@ export async function getChangeMappingIx(
 			signer: new PublicKey(args.authority),
 			payer: args.payer,
 			policyEngine: getPolicyEnginePda(args.assetMint),
+			systemProgram: SystemProgram.programId,
 			eventAuthority: getPolicyEngineEventAuthority(),
 			program: policyProgram.programId,
 		})
@@ -189,6 +490,8 @@ export type ChangeCountersArgs = {
 	assetMint: string;
 	removedCounters: Buffer;
 	addedCounters: Counter[];
+	/** skips checking the affected counter limits against the current counter values */
+	skipLimitValidation?: boolean;
 }
 
 /**
@@ -207,7 +510,7 @@ export async function getChangeCountersIx(
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
 	const ix = await policyProgram.methods
-		.changeCounters(args.removedCounters, args.addedCounters)
+		.changeCounters(args.removedCounters, args.addedCounters, args.skipLimitValidation ?? false)
 		.accountsStrict({
 			signer: new PublicKey(args.authority),
 			payer: args.payer,
@@ -223,32 +526,28 @@ export async function getChangeCountersIx(
 	};
 }
 
-export type ChangeCounterLimitsArgs = {
+export type ChangeCountryCountersArgs = {
 	authority: string;
 	payer: string;
 	assetMint: string;
-	removedCounterLimits: Buffer;
-	addedCounterLimits: CounterLimit[];
+	removedCountryCounters: Buffer;
+	addedCountryCounters: CountryCounter[];
+	/** skips checking the affected counter limits against the current counter values */
+	skipLimitValidation?: boolean;
 }
 
-
 /**
- * Generate instructions to connect am policy to an asset.
- *
- * This function constructs an instruction to attach a policy account to an asset
- * using the provided arguments. It calls the policy engine program to attach the policy account,
- * and returns the generated instruction along with the required signers.
- *
- * @param args {@link AttachPolicyArgs}
+ * Generate instructions to add or remove counters keeping one holder count per country group.
+ * @param args {@link ChangeCountryCountersArgs}
  * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
  */
-export async function getChangeCounterLimitsIx(
-	args: ChangeCounterLimitsArgs,
+export async function getChangeCountryCountersIx(
+	args: ChangeCountryCountersArgs,
 	provider: Provider
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
 	const ix = await policyProgram.methods
-		.changeCounterLimits(args.removedCounterLimits, args.addedCounterLimits)
+		.changeCountryCounters(args.removedCountryCounters, args.addedCountryCounters, args.skipLimitValidation ?? false)
 		.accountsStrict({
 			signer: new PublicKey(args.authority),
 			payer: args.payer,
@@ -264,24 +563,34 @@ export async function getChangeCounterLimitsIx(
 	};
 }
 
+export type ChangeCounterLimitsArgs = {
+	authority: string;
+	payer: string;
+	assetMint: string;
+	removedCounterLimits: Buffer;
+	addedCounterLimits: CounterLimit[];
+	/** skips checking the affected counter limits against the current counter values */
+	skipLimitValidation?: boolean;
+}
+
+
 /**
- * Generate instructions to detach an identity policy account to an asset.
+ * Generate instructions to connect am policy to an asset.
  *
  * This function constructs an instruction to attach a policy account to an asset
- * using the provided arguments. It creates a new policy account, calls the policy
- * engine program to attach the policy account, and returns the generated instruction
- * along with the required signers.
+ * using the provided arguments. It calls the policy engine program to attach the policy account,
+ * and returns the generated instruction along with the required signers.
  *
  * @param args {@link AttachPolicyArgs}
  * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
  */
-export async function getDetachFromPolicyEngineIx(
-	args: DetachPolicyArgs,
+export async function getChangeCounterLimitsIx(
+	args: ChangeCounterLimitsArgs,
 	provider: Provider
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
 	const ix = await policyProgram.methods
-		.detachFromPolicyEngine(args.hash)
+		.changeCounterLimits(args.removedCounterLimits, args.addedCounterLimits, args.skipLimitValidation ?? false)
 		.accountsStrict({
 			signer: new PublicKey(args.authority),
 			payer: args.payer,
@@ -297,36 +606,32 @@ export async function getDetachFromPolicyEngineIx(
 	};
 }
 
-export interface AddLockArgs {
-	payer: string;
+export type ChangePolicyEnforcementModeArgs = {
 	authority: string;
-	owner: string;
+	payer: string;
 	assetMint: string;
-	reason: BN;
-	reasonString: string;
-	amount: BN;
-	lockTime: BN;
+	hash: string;
+	enforcementMode: EnforcementMode;
 }
 
-export async function getAddLockIx(
-	args: AddLockArgs,
+/**
+ * Generate instructions to switch a policy between enforce, monitor and disabled.
+ * @param args {@link ChangePolicyEnforcementModeArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getChangePolicyEnforcementModeIx(
+	args: ChangePolicyEnforcementModeArgs,
 	provider: Provider
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
-	const trackerAccount = getTrackerAccountPda(args.assetMint, args.owner);
 	const ix = await policyProgram.methods
-		.addLock(args.amount, args.lockTime, args.reason, args.reasonString)
+		.changePolicyEnforcementMode(args.hash, args.enforcementMode)
 		.accountsStrict({
-			payer: args.payer,
 			signer: new PublicKey(args.authority),
-			trackerAccount,
-			assetMint: new PublicKey(args.assetMint),
-			eventAuthority: getPolicyEngineEventAuthority(),
-			identityRegistry: getIdentityRegistryPda(args.assetMint),
-			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			payer: args.payer,
 			policyEngine: getPolicyEnginePda(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
 			program: policyProgram.programId,
-			systemProgram: SystemProgram.programId,
 		})
 		.instruction();
 	return {
@@ -335,22 +640,441 @@ export async function getAddLockIx(
 	};
 }
 
-export interface RemoveLockArgs {
-	payer: string;
+export type ChangePolicyApplicabilityArgs = {
 	authority: string;
-	owner: string;
+	payer: string;
 	assetMint: string;
-	index: number;
+	hash: string;
+	applicability: PolicyApplicability;
 }
 
-export async function getRemoveLockIx(
-	args: RemoveLockArgs,
+/**
+ * Generate instructions to choose the lifecycle events a policy is evaluated on.
+ * @param args {@link ChangePolicyApplicabilityArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getChangePolicyApplicabilityIx(
+	args: ChangePolicyApplicabilityArgs,
 	provider: Provider
 ): Promise<IxReturn> {
 	const policyProgram = getPolicyEngineProgram(provider);
-	const trackerAccount = getTrackerAccountPda(args.assetMint, args.owner);
 	const ix = await policyProgram.methods
-		.removeLock(args.index)
+		.changePolicyApplicability(args.hash, args.applicability)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type ChangeCounterLimitEnforcementModeArgs = {
+	authority: string;
+	payer: string;
+	assetMint: string;
+	index: number;
+	enforcementMode: EnforcementMode;
+}
+
+/**
+ * Generate instructions to switch a counter limit between enforce, monitor and disabled.
+ * @param args {@link ChangeCounterLimitEnforcementModeArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getChangeCounterLimitEnforcementModeIx(
+	args: ChangeCounterLimitEnforcementModeArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.changeCounterLimitEnforcementMode(args.index, args.enforcementMode)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/**
+ * Generate instructions to detach an identity policy account to an asset.
+ *
+ * This function constructs an instruction to attach a policy account to an asset
+ * using the provided arguments. It creates a new policy account, calls the policy
+ * engine program to attach the policy account, and returns the generated instruction
+ * along with the required signers.
+ *
+ * @param args {@link AttachPolicyArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getDetachFromPolicyEngineIx(
+	args: DetachPolicyArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.detachFromPolicyEngine(args.hash)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to create a policy page. */
+export type CreatePolicyPageArgs = {
+	authority: string;
+	assetMint: string;
+	payer: string;
+	/** transfer pages must use an index below MAX_TRANSFER_POLICY_PAGES */
+	index: number;
+	scope: PolicyPageScope;
+};
+
+/**
+ * Generate instructions to create an overflow page holding policies evaluated by the given scope.
+ * @param args {@link CreatePolicyPageArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCreatePolicyPageIx(
+	args: CreatePolicyPageArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.createPolicyPage(args.index, args.scope)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			policyPage: getPolicyPagePda(args.assetMint, args.index),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to close an empty policy page. */
+export type ClosePolicyPageArgs = {
+	authority: string;
+	assetMint: string;
+	payer: string;
+	index: number;
+	/** account that paid for the page, receives the rent back */
+	rentReceiver: string;
+};
+
+/**
+ * Generate instructions to close an empty policy page.
+ * @param args {@link ClosePolicyPageArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getClosePolicyPageIx(
+	args: ClosePolicyPageArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.closePolicyPage()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			policyPage: getPolicyPagePda(args.assetMint, args.index),
+			rentReceiver: args.rentReceiver,
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to create or change a tranche. */
+export type TrancheArgs = {
+	authority: string;
+	assetMint: string;
+	payer: string;
+	id: BN;
+	config: TrancheConfig;
+};
+
+/**
+ * Generate instructions to create an offering tranche issuances can be made against.
+ * @param args {@link TrancheArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCreateTrancheIx(
+	args: TrancheArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.createTranche(args.id, args.config)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tranche: getTranchePda(args.assetMint, args.id),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/**
+ * Generate instructions to change the window, caps, eligibility and lock period of a tranche.
+ * @param args {@link TrancheArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getChangeTrancheIx(
+	args: TrancheArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.changeTranche(args.config)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tranche: getTranchePda(args.assetMint, args.id),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to close a tranche. */
+export type CloseTrancheArgs = {
+	authority: string;
+	assetMint: string;
+	id: BN;
+	/** account that paid for the tranche, receives the rent back */
+	rentReceiver: string;
+};
+
+/**
+ * Generate instructions to close a tranche.
+ * @param args {@link CloseTrancheArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCloseTrancheIx(
+	args: CloseTrancheArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.closeTranche()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			tranche: getTranchePda(args.assetMint, args.id),
+			rentReceiver: args.rentReceiver,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to attach a policy to a policy page. */
+export type AttachPolicyToPageArgs = AttachPolicyArgs & {
+	index: number;
+};
+
+/**
+ * Generate instructions to attach a policy to a policy page.
+ * @param args {@link AttachPolicyToPageArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getAttachToPolicyPageIx(
+	args: AttachPolicyToPageArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.attachToPolicyPage(args.identityFilter, args.policyType, args.customError ?? 0, args.enforcementMode ?? { enforce: {} }, args.applicability ?? null)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			policyPage: getPolicyPagePda(args.assetMint, args.index),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to detach a policy from a policy page. */
+export type DetachPolicyFromPageArgs = DetachPolicyArgs & {
+	index: number;
+};
+
+/**
+ * Generate instructions to detach a policy from a policy page.
+ * @param args {@link DetachPolicyFromPageArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getDetachFromPolicyPageIx(
+	args: DetachPolicyFromPageArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.detachFromPolicyPage(args.hash)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			policyPage: getPolicyPagePda(args.assetMint, args.index),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to rewrite the extra metas of an existing mint. */
+export type UpdateExtraMetasArgs = {
+	authority: string;
+	assetMint: string;
+	payer: string;
+};
+
+/**
+ * Generate instructions to rewrite the extra metas of a mint created before the policy page slots existed.
+ * @param args {@link UpdateExtraMetasArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getUpdateExtraMetasIx(
+	args: UpdateExtraMetasArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.updateExtraMetas()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			assetMint: args.assetMint,
+			extraMetasAccount: getExtraMetasListPda(args.assetMint),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export interface AddLockArgs {
+	payer: string;
+	authority: string;
+	owner: string;
+	assetMint: string;
+	reason: BN;
+	reasonString: string;
+	amount: BN;
+	lockTime: BN;
+}
+
+export async function getAddLockIx(
+	args: AddLockArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const trackerAccount = getTrackerAccountPda(args.assetMint, args.owner);
+	const ix = await policyProgram.methods
+		.addLock(args.amount, args.lockTime, args.reason, args.reasonString)
+		.accountsStrict({
+			payer: args.payer,
+			signer: new PublicKey(args.authority),
+			trackerAccount,
+			assetMint: new PublicKey(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			program: policyProgram.programId,
+			systemProgram: SystemProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export interface RemoveLockArgs {
+	payer: string;
+	authority: string;
+	owner: string;
+	assetMint: string;
+	index: number;
+}
+
+export async function getRemoveLockIx(
+	args: RemoveLockArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const trackerAccount = getTrackerAccountPda(args.assetMint, args.owner);
+	const ix = await policyProgram.methods
+		.removeLock(args.index)
 		.accountsStrict({
 			payer: args.payer,
 			signer: new PublicKey(args.authority),
@@ -369,3 +1093,360 @@ export async function getRemoveLockIx(
 		signers: [],
 	};
 }
+
+export interface RemoveExpiredTrancheLocksArgs {
+	owner: string;
+	assetMint: string;
+}
+
+/**
+ * Generate instructions to remove the expired tranche locks of a tracker, the freed rent goes to the policy engine authority.
+ * @param args {@link RemoveExpiredTrancheLocksArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getRemoveExpiredTrancheLocksIx(
+	args: RemoveExpiredTrancheLocksArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.removeExpiredTrancheLocks()
+		.accountsStrict({
+			assetMint: new PublicKey(args.assetMint),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			rentReceiver: await getPolicyEngineAuthority(args.assetMint, provider),
+			trackerAccount: getTrackerAccountPda(args.assetMint, args.owner),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type MigratePolicyEngineArgs = {
+	authority: string;
+	payer: string;
+	assetMint: string;
+};
+
+/**
+ * Generate instructions to migrate a version 1 policy engine to the current layout.
+ * @param args {@link MigratePolicyEngineArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getMigratePolicyEngineIx(
+	args: MigratePolicyEngineArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.migratePolicyEngine()
+		.accountsStrict({
+			payer: args.payer,
+			signer: args.authority,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			systemProgram: SystemProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type MigrateTrackerAccountArgs = {
+	payer: string;
+	assetMint: string;
+	owner: string;
+};
+
+/**
+ * Generate instructions to migrate a version 1 tracker account to the current layout, can be signed by any payer.
+ * @param args {@link MigrateTrackerAccountArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getMigrateTrackerAccountIx(
+	args: MigrateTrackerAccountArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.migrateTrackerAccount()
+		.accountsStrict({
+			payer: args.payer,
+			trackerAccount: getTrackerAccountPda(args.assetMint, args.owner),
+			systemProgram: SystemProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+export type OpenSnapshotArgs = {
+	authority: string;
+	assetMint: string;
+};
+
+/**
+ * Generate instructions to open a record date snapshot of the holder balances at the current time.
+ * @param args {@link OpenSnapshotArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getOpenSnapshotIx(
+	args: OpenSnapshotArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.openSnapshot()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			assetMint: new PublicKey(args.assetMint),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to create a proposal. */
+export type CreateProposalArgs = {
+	authority: string;
+	assetMint: string;
+	payer: string;
+	id: BN;
+	config: ProposalConfig;
+};
+
+/**
+ * Generate instructions to create a proposal voted with the balances at the record date of the latest snapshot.
+ * @param args {@link CreateProposalArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCreateProposalIx(
+	args: CreateProposalArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.createProposal(args.id, args.config)
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			payer: args.payer,
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			proposal: getProposalPda(args.assetMint, args.id),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to vote on a proposal. */
+export type CastVoteArgs = {
+	assetMint: string;
+	payer: string;
+	id: BN;
+	/** index of the option voted */
+	option: number;
+	/** owner of the voter identity, signs the vote */
+	voter: string;
+	/** owner of the identity whose balance is voted, defaults to the voter */
+	owner?: string;
+};
+
+/**
+ * Generate instructions to vote the record date balance of an identity, owned or delegated to the voter.
+ * @param args {@link CastVoteArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCastVoteIx(
+	args: CastVoteArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const owner = args.owner ?? args.voter;
+	const ix = await policyProgram.methods
+		.castVote(args.option)
+		.accountsStrict({
+			payer: args.payer,
+			signer: new PublicKey(args.voter),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			assetMint: new PublicKey(args.assetMint),
+			proposal: getProposalPda(args.assetMint, args.id),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			voterIdentityAccount: getIdentityAccountPda(args.assetMint, args.voter),
+			identityAccount: getIdentityAccountPda(args.assetMint, owner),
+			trackerAccount: getTrackerAccountPda(args.assetMint, owner),
+			delegation: getDelegationPda(args.assetMint, owner),
+			vote: getVotePda(args.assetMint, args.id, owner),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to finalize a proposal. */
+export type FinalizeProposalArgs = {
+	assetMint: string;
+	id: BN;
+};
+
+/**
+ * Generate instructions to end a proposal after its voting window and emit the final tally.
+ * @param args {@link FinalizeProposalArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getFinalizeProposalIx(
+	args: FinalizeProposalArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.finalizeProposal()
+		.accountsStrict({
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			proposal: getProposalPda(args.assetMint, args.id),
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to close a proposal. */
+export type CloseProposalArgs = {
+	authority: string;
+	assetMint: string;
+	id: BN;
+	/** account that paid for the proposal, receives the rent back */
+	rentReceiver: string;
+};
+
+/**
+ * Generate instructions to close a finalized proposal.
+ * @param args {@link CloseProposalArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getCloseProposalIx(
+	args: CloseProposalArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.closeProposal()
+		.accountsStrict({
+			signer: new PublicKey(args.authority),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			proposal: getProposalPda(args.assetMint, args.id),
+			rentReceiver: args.rentReceiver,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to delegate the voting power of an identity. */
+export type DelegateVotesArgs = {
+	assetMint: string;
+	payer: string;
+	/** owner of the delegating identity */
+	owner: string;
+	/** owner of the identity receiving the voting power */
+	delegate: string;
+};
+
+/**
+ * Generate instructions to delegate the voting power of an identity to another identity.
+ * @param args {@link DelegateVotesArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getDelegateVotesIx(
+	args: DelegateVotesArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.delegateVotes()
+		.accountsStrict({
+			payer: args.payer,
+			signer: new PublicKey(args.owner),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			assetMint: new PublicKey(args.assetMint),
+			identityRegistry: getIdentityRegistryPda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			delegateIdentityAccount: getIdentityAccountPda(args.assetMint, args.delegate),
+			delegation: getDelegationPda(args.assetMint, args.owner),
+			systemProgram: SystemProgram.programId,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
+
+/** Represents the arguments required to revoke the delegation of the voting power of an identity. */
+export type UndelegateVotesArgs = {
+	assetMint: string;
+	/** owner of the delegating identity */
+	owner: string;
+	/** account that paid for the delegation, receives the rent back */
+	rentReceiver: string;
+};
+
+/**
+ * Generate instructions to revoke the delegation of the voting power of an identity.
+ * @param args {@link UndelegateVotesArgs}
+ * @returns - {@link IxReturn}, a list of transaction instructions and a new key pair responsible to sign it.
+ */
+export async function getUndelegateVotesIx(
+	args: UndelegateVotesArgs,
+	provider: Provider
+): Promise<IxReturn> {
+	const policyProgram = getPolicyEngineProgram(provider);
+	const ix = await policyProgram.methods
+		.undelegateVotes()
+		.accountsStrict({
+			signer: new PublicKey(args.owner),
+			policyEngine: getPolicyEnginePda(args.assetMint),
+			identityAccount: getIdentityAccountPda(args.assetMint, args.owner),
+			delegation: getDelegationPda(args.assetMint, args.owner),
+			rentReceiver: args.rentReceiver,
+			eventAuthority: getPolicyEngineEventAuthority(),
+			program: policyProgram.programId,
+		})
+		.instruction();
+	return {
+		ixs: [ix],
+		signers: [],
+	};
+}
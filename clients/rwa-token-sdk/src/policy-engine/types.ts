@@ -14,13 +14,23 @@ export type TrackerAccount = IdlAccounts<PolicyEngineIdlTypes>["trackerAccount"]
 export type HolderLimit = IdlTypes<PolicyEngineIdlTypes>["counterLimit"]["holdersLimit"];
 export type GroupedHolderLimit = IdlTypes<PolicyEngineIdlTypes>["counterLimit"]["groupedHoldersLimit"];
 export type PercentageLimit = IdlTypes<PolicyEngineIdlTypes>["counterLimit"]["percentageLimit"];
+export type CountryHoldersLimit = IdlTypes<PolicyEngineIdlTypes>["counterLimit"]["countryHoldersLimit"];
 export type CounterLimit = IdlTypes<PolicyEngineIdlTypes>["counterLimit"];
 
+/** Represents how a policy or counter limit reacts when violated. */
+export type EnforcementMode = IdlTypes<PolicyEngineIdlTypes>["enforcementMode"];
+
+/** Represents the lifecycle events (transfer, issuance, burn, seize, levels change) a policy is evaluated on. */
+export type PolicyApplicability = IdlTypes<PolicyEngineIdlTypes>["policyApplicability"];
+
 
 export type IdentityLevel = IdlTypes<PolicyEngineIdlTypes>["identityLevel"];
 
 export type Counter = IdlTypes<PolicyEngineIdlTypes>["counter"];
 
+/** Represents on chain counter keeping one holder count per country group. */
+export type CountryCounter = IdlTypes<PolicyEngineIdlTypes>["countryCounter"];
+
 /** Represents on chain policy. */
 export type Policy = IdlTypes<PolicyEngineIdlTypes>["policy"];
 
@@ -43,5 +53,44 @@ export type FilterMode = IdlTypes<PolicyEngineIdlTypes>["filterMode"];
 
 export type IssuancePolicies = IdlTypes<PolicyEngineIdlTypes>["issuancePolicies"];
 
+/** Represents the delay applied to queued policy engine changes. */
+export type Timelock = IdlTypes<PolicyEngineIdlTypes>["timelock"];
+
+/** Represents a configuration change that can be queued on the policy engine. */
+export type PolicyEngineChange = IdlTypes<PolicyEngineIdlTypes>["policyEngineChange"];
+
+/** Represents on chain pending change account pda. */
+export type PendingChangeAccount = IdlAccounts<PolicyEngineIdlTypes>["pendingChangeAccount"];
+
+/** Represents on chain counter reconciliation account pda. */
+export type ReconciliationAccount = IdlAccounts<PolicyEngineIdlTypes>["reconciliationAccount"];
+
+/** Operation evaluating the policies of a policy page. */
+export type PolicyPageScope = IdlTypes<PolicyEngineIdlTypes>["policyPageScope"];
+
+/** Represents on chain policy page account pda, holding policies that do not fit in the policy engine. */
+export type PolicyPageAccount = IdlAccounts<PolicyEngineIdlTypes>["policyPageAccount"];
+
+/** Represents the window, caps, eligibility and lock period of an offering tranche. */
+export type TrancheConfig = IdlTypes<PolicyEngineIdlTypes>["trancheConfig"];
+
+/** Represents on chain tranche account pda. */
+export type TrancheAccount = IdlAccounts<PolicyEngineIdlTypes>["trancheAccount"];
+
+/** Represents on chain pda holding the amount an identity subscribed to a tranche. */
+export type TrancheSubscriptionAccount = IdlAccounts<PolicyEngineIdlTypes>["trancheSubscriptionAccount"];
+
+/** Represents the question, options, voting window, quorum and eligibility of a proposal. */
+export type ProposalConfig = IdlTypes<PolicyEngineIdlTypes>["proposalConfig"];
+
+/** Represents on chain proposal account pda. */
+export type ProposalAccount = IdlAccounts<PolicyEngineIdlTypes>["proposalAccount"];
+
+/** Represents on chain pda of the vote cast with the balance of an identity. */
+export type VoteAccount = IdlAccounts<PolicyEngineIdlTypes>["voteAccount"];
+
+/** Represents on chain pda delegating the voting power of an identity. */
+export type DelegationAccount = IdlAccounts<PolicyEngineIdlTypes>["delegationAccount"];
+
 export type Lock = IdlTypes<PolicyEngineIdlTypes>["lock"];
 export type Issuance = IdlTypes<PolicyEngineIdlTypes>["issuance"];
\ No newline at end of file
@@ -1,4 +1,4 @@
-import { type Idl, Program, type Provider, utils } from "@coral-xyz/anchor";
+import { BN, type Idl, Program, type Provider, utils } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { type PolicyEngineIdlTypes } from "../programs/types";
 import { getIdentityAccountPda } from "../identity-registry";
@@ -45,6 +45,108 @@ export const getTrackerAccountPda = (assetMint: string, owner: string) =>
 		policyEngineProgramId
 	)[0];
 
+/**
+ * Retrieves the pending change pda for a specific asset mint and change id.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The sequential id of the proposed change.
+ * @returns The pending change pda.
+ */
+export const getPendingChangePda = (assetMint: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("pending-change"), getPolicyEnginePda(assetMint).toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+		policyEngineProgramId
+	)[0];
+
+/**
+ * Retrieves the counter reconciliation pda for a specific asset mint.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @returns The reconciliation pda.
+ */
+export const getReconciliationPda = (assetMint: string) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("reconciliation"), getPolicyEnginePda(assetMint).toBuffer()],
+		policyEngineProgramId
+	)[0];
+
+export const getPolicyPagePda = (assetMint: string, index: number) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("policy-page"), getPolicyEnginePda(assetMint).toBuffer(), Buffer.from([index])],
+		policyEngineProgramId
+	)[0];
+
+/**
+ * Retrieves the tranche pda for a specific asset mint and tranche id.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The id of the tranche.
+ * @returns The tranche pda.
+ */
+export const getTranchePda = (assetMint: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("tranche"), getPolicyEnginePda(assetMint).toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+		policyEngineProgramId
+	)[0];
+
+/**
+ * Retrieves the pda holding the amount an identity subscribed to a tranche.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The id of the tranche.
+ * @param owner - The string representation of the identity's owner.
+ * @returns The tranche subscription pda.
+ */
+export const getTrancheSubscriptionPda = (assetMint: string, id: BN, owner: string) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("tranche-subscription"), getTranchePda(assetMint, id).toBuffer(), getIdentityAccountPda(assetMint, owner).toBuffer()],
+		policyEngineProgramId
+	)[0];
+
+/**
+ * Retrieves the proposal pda for a specific asset mint and proposal id.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The id of the proposal.
+ * @returns The proposal pda.
+ */
+export const getProposalPda = (assetMint: string, id: BN) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("proposal"), getPolicyEnginePda(assetMint).toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+		policyEngineProgramId
+	)[0];
+
+/**
+ * Retrieves the pda of the vote cast with the balance of an identity on a proposal.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param id - The id of the proposal.
+ * @param owner - The string representation of the owner of the identity whose balance is voted.
+ * @returns The vote pda.
+ */
+export const getVotePda = (assetMint: string, id: BN, owner: string) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("vote"), getProposalPda(assetMint, id).toBuffer(), getIdentityAccountPda(assetMint, owner).toBuffer()],
+		policyEngineProgramId
+	)[0];
+
+/**
+ * Retrieves the pda delegating the voting power of an identity.
+ * @param assetMint - The string representation of the asset's mint address.
+ * @param owner - The string representation of the owner of the delegating identity.
+ * @returns The delegation pda.
+ */
+export const getDelegationPda = (assetMint: string, owner: string) =>
+	PublicKey.findProgramAddressSync(
+		[utils.bytes.utf8.encode("delegation"), getPolicyEnginePda(assetMint).toBuffer(), getIdentityAccountPda(assetMint, owner).toBuffer()],
+		policyEngineProgramId
+	)[0];
+
+/** Number of transfer policy page slots reserved in the extra account metas. */
+export const MAX_TRANSFER_POLICY_PAGES = 4;
+
+/** Account metas of the transfer policy page slots, appended after the other transfer hook accounts. */
+export const getTransferPolicyPageAccountMetas = (assetMint: string) =>
+	Array.from({ length: MAX_TRANSFER_POLICY_PAGES }, (_, index) => ({
+		pubkey: getPolicyPagePda(assetMint, index),
+		isWritable: false,
+		isSigner: false,
+	}));
+
 export const getPolicyEngineEventAuthority = () => PublicKey.findProgramAddressSync(
 	[utils.bytes.utf8.encode("__event_authority")],
 	policyEngineProgramId
@@ -14,19 +14,19 @@ export type AssetController = {
   },
   "instructions": [
     {
-      "name": "createAssetController",
+      "name": "activateWallet",
       "docs": [
-        "create an rwa asset"
+        "create and thaw the token account of a wallet attached to an identity, called by the identity registry"
       ],
       "discriminator": [
-        97,
-        185,
-        6,
-        250,
-        248,
-        242,
-        68,
-        105
+        30,
+        25,
+        98,
+        66,
+        55,
+        173,
+        223,
+        213
       ],
       "accounts": [
         {
@@ -35,11 +35,20 @@ export type AssetController = {
           "signer": true
         },
         {
-          "name": "authority"
+          "name": "identityRegistry",
+          "docs": [
+            "signs when a wallet is attached to an identity"
+          ],
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
         },
         {
           "name": "assetController",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -50,177 +59,94 @@ export type AssetController = {
           }
         },
         {
-          "name": "assetMint",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "extraMetasAccount",
-          "writable": true
-        },
-        {
-          "name": "policyEngineAccount",
-          "writable": true
-        },
-        {
-          "name": "identityRegistryAccount",
-          "writable": true
-        },
-        {
-          "name": "policyEngine",
-          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
-        },
-        {
-          "name": "identityRegistry",
-          "address": "GZsnjqT3c5zbHqsctrJ4EG4rbEfo7ZXyyUG7aDJNmxfA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+          "name": "wallet"
         },
         {
-          "name": "eventAuthority",
+          "name": "tokenAccount",
+          "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "createAssetControllerArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "disableMemoTransfer",
-      "docs": [
-        "memo transfer disable"
-      ],
-      "discriminator": [
-        68,
-        156,
-        197,
-        9,
-        43,
-        91,
-        114,
-        19
-      ],
-      "accounts": [
-        {
-          "name": "owner",
-          "signer": true
-        },
-        {
-          "name": "authority",
-          "signer": true
-        },
-        {
-          "name": "assetMint"
-        },
-        {
-          "name": "assetController",
-          "pda": {
-            "seeds": [
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
               {
                 "kind": "account",
                 "path": "assetMint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
-        {
-          "name": "tokenAccount",
-          "writable": true
-        },
         {
           "name": "tokenProgram",
           "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         },
         {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
-          "name": "program"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "enableMemoTransfer",
+      "name": "approveRedemption",
       "docs": [
-        "memo transfer enable"
+        "approve a redemption request, burning the locked tokens"
       ],
       "discriminator": [
-        186,
-        78,
-        97,
-        172,
-        71,
-        172,
-        99,
-        0
+        94,
+        27,
+        164,
+        127,
+        156,
+        216,
+        161,
+        39
       ],
       "accounts": [
         {
@@ -228,16 +154,18 @@ export type AssetController = {
           "writable": true,
           "signer": true
         },
-        {
-          "name": "owner",
-          "signer": true
-        },
         {
           "name": "authority",
           "signer": true
         },
         {
-          "name": "assetMint"
+          "name": "assetMint",
+          "writable": true,
+          "relations": [
+            "redemptionRequest",
+            "identityRegistry",
+            "trackerAccount"
+          ]
         },
         {
           "name": "assetController",
@@ -250,6 +178,10 @@ export type AssetController = {
             ]
           }
         },
+        {
+          "name": "redemptionRequest",
+          "writable": true
+        },
         {
           "name": "tokenAccount",
           "writable": true,
@@ -257,7 +189,8 @@ export type AssetController = {
             "seeds": [
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "redemption_request.wallet",
+                "account": "redemptionRequestAccount"
               },
               {
                 "kind": "account",
@@ -308,24 +241,44 @@ export type AssetController = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "identityAccount",
+          "relations": [
+            "redemptionRequest"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "trackerAccount",
+          "writable": true
         },
         {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   95,
                   95,
                   101,
@@ -355,28 +308,40 @@ export type AssetController = {
       "args": []
     },
     {
-      "name": "freezeTokenAccount",
+      "name": "cancelRedemption",
       "docs": [
-        "freeze token account"
+        "cancel a pending redemption request, releasing the locked tokens"
       ],
       "discriminator": [
-        138,
-        168,
-        178,
-        109,
-        205,
-        224,
-        209,
-        93
+        197,
+        243,
+        101,
+        86,
+        2,
+        37,
+        105,
+        106
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
+          "writable": true,
           "signer": true
         },
+        {
+          "name": "wallet",
+          "signer": true,
+          "relations": [
+            "redemptionRequest"
+          ]
+        },
         {
           "name": "assetMint",
-          "writable": true
+          "relations": [
+            "redemptionRequest",
+            "identityRegistry",
+            "trackerAccount"
+          ]
         },
         {
           "name": "assetController",
@@ -390,80 +355,157 @@ export type AssetController = {
           }
         },
         {
-          "name": "tokenAccount",
+          "name": "redemptionRequest",
           "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "redemptionRequest"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "issueTokens",
+      "name": "claimDistribution",
       "docs": [
-        "issue shares of the rwa asset"
+        "claim the share of an identity in a distribution, the proof is required for merkle snapshots"
       ],
       "discriminator": [
-        40,
-        207,
-        145,
-        106,
-        249,
-        54,
-        23,
-        179
+        204,
+        156,
+        94,
+        85,
+        2,
+        125,
+        232,
+        180
       ],
       "accounts": [
         {
           "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "authority",
+          "docs": [
+            "holder or crank claiming on its behalf"
+          ],
           "writable": true,
           "signer": true
         },
         {
           "name": "assetMint",
-          "writable": true,
           "relations": [
+            "distribution",
             "identityRegistry",
-            "trackerAccount"
+            "trackerAccount",
+            "policyEngine"
           ]
         },
         {
-          "name": "assetController",
+          "name": "distribution",
+          "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
               {
                 "kind": "account",
                 "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "distribution.id",
+                "account": "distributionAccount"
               }
             ]
           }
         },
         {
-          "name": "to"
+          "name": "payoutMint",
+          "relations": [
+            "distribution"
+          ]
         },
         {
-          "name": "tokenAccount",
+          "name": "vault",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "to"
+                "path": "distribution"
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "payoutTokenProgram"
               },
               {
                 "kind": "account",
-                "path": "assetMint"
+                "path": "payoutMint"
               }
             ],
             "program": {
@@ -514,35 +556,82 @@ export type AssetController = {
         {
           "name": "identityAccount",
           "relations": [
-            "walletIdentityAccount"
+            "trackerAccount"
           ]
         },
         {
           "name": "trackerAccount",
-          "writable": true
+          "docs": [
+            "required for tracker snapshots, read at the snapshot of the distribution"
+          ],
+          "optional": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+          "name": "destination",
+          "docs": [
+            "token account of the identity owner receiving the payout"
+          ],
+          "writable": true
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "policyEngine",
+          "docs": [
+            "maps the country of the identity to the group of its withholding rate and holds the snapshot"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "taxVault",
+          "docs": [
+            "required when the distribution has a tax vault"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "policyEngineProgram",
-          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+          "name": "distributionClaim",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "distribution"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
         },
         {
-          "name": "policyEngine",
-          "writable": true
+          "name": "payoutTokenProgram"
         },
         {
-          "name": "walletIdentityAccount"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
           "name": "eventAuthority",
@@ -579,293 +668,159 @@ export type AssetController = {
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "issuanceTimestamp",
-          "type": "i64"
+          "name": "proof",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "balanceProof"
+              }
+            }
+          }
         }
       ]
     },
     {
-      "name": "revokeTokens",
+      "name": "closeConversion",
       "docs": [
-        "revoke shares of the rwa asset"
+        "close a conversion, no further shares can be converted through it"
       ],
       "discriminator": [
-        215,
-        42,
-        15,
-        134,
-        173,
-        80,
-        33,
-        21
+        213,
+        137,
+        78,
+        66,
+        189,
+        82,
+        190,
+        24
       ],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
+          "name": "sourceAuthority",
           "signer": true
         },
         {
-          "name": "assetMint",
-          "writable": true,
-          "relations": [
-            "identityRegistry",
-            "trackerAccount"
-          ]
+          "name": "destinationAuthority",
+          "signer": true
         },
         {
-          "name": "assetController",
+          "name": "sourceAssetController",
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "assetMint"
+                "path": "conversion.source_mint",
+                "account": "conversionAccount"
               }
             ]
           }
         },
         {
-          "name": "revokeTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "identityRegistry",
-          "relations": [
-            "identityAccount"
-          ]
-        },
-        {
-          "name": "identityAccount",
-          "relations": [
-            "walletIdentityAccount"
-          ]
+          "name": "destinationAssetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "conversion.destination_mint",
+                "account": "conversionAccount"
+              }
+            ]
+          }
         },
         {
-          "name": "trackerAccount",
+          "name": "conversion",
           "writable": true
         },
         {
-          "name": "policyEngineProgram",
-          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
-        },
-        {
-          "name": "policyEngine",
+          "name": "rentReceiver",
           "writable": true
-        },
-        {
-          "name": "walletIdentityAccount"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "reason",
-          "type": "string"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "seizeTokens",
+      "name": "closeRedemptionRequest",
       "docs": [
-        "seize shares of the rwa asset"
+        "close a settled redemption request"
       ],
       "discriminator": [
-        79,
-        30,
+        36,
+        82,
+        76,
         69,
-        54,
-        78,
-        1,
-        16,
-        23
+        221,
+        105,
+        238,
+        43
       ],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "assetMint"
-        },
-        {
-          "name": "assetController",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "assetMint"
-              }
-            ]
-          }
+          "name": "wallet",
+          "signer": true,
+          "relations": [
+            "redemptionRequest"
+          ]
         },
         {
-          "name": "destinationTokenAccount",
+          "name": "redemptionRequest",
           "writable": true
         },
         {
-          "name": "sourceTokenAccount",
+          "name": "rentReceiver",
           "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "reason",
-          "type": "string"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "thawTokenAccount",
+      "name": "closeSubscriptionRequest",
       "docs": [
-        "thaw token account"
+        "close a settled or refunded subscription request"
       ],
       "discriminator": [
-        199,
-        172,
-        96,
-        93,
-        244,
-        252,
-        137,
-        171
+        152,
+        5,
+        40,
+        129,
+        116,
+        153,
+        173,
+        191
       ],
       "accounts": [
         {
-          "name": "authority",
-          "signer": true
+          "name": "wallet",
+          "signer": true,
+          "relations": [
+            "subscriptionRequest"
+          ]
         },
         {
-          "name": "assetMint",
+          "name": "subscriptionRequest",
           "writable": true
         },
         {
-          "name": "assetController",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "assetMint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "tokenAccount",
+          "name": "rentReceiver",
           "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         }
       ],
       "args": []
     },
     {
-      "name": "updateMetadata",
+      "name": "convertShares",
       "docs": [
-        "edit metadata of the rwa asset"
+        "convert shares of the wallet at the ratio of the conversion, the issuance policy pages of the destination",
+        "asset are passed in the remaining accounts"
       ],
       "discriminator": [
-        170,
-        182,
-        43,
-        239,
-        97,
-        78,
-        225,
-        186
+        38,
+        55,
+        152,
+        2,
+        231,
+        47,
+        144,
+        98
       ],
       "accounts": [
         {
@@ -874,308 +829,5875 @@ export type AssetController = {
           "signer": true
         },
         {
-          "name": "authority",
-          "writable": true,
+          "name": "wallet",
           "signer": true
         },
         {
-          "name": "assetMint",
-          "writable": true
+          "name": "conversion"
         },
         {
-          "name": "assetController",
+          "name": "sourceMint",
+          "writable": true,
+          "relations": [
+            "conversion"
+          ]
+        },
+        {
+          "name": "destinationMint",
+          "writable": true,
+          "relations": [
+            "conversion"
+          ]
+        },
+        {
+          "name": "sourceAssetController",
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "assetMint"
+                "path": "sourceMint"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "eventAuthority",
+          "name": "destinationAssetController",
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
+                "kind": "account",
+                "path": "destinationMint"
               }
             ]
           }
         },
         {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "updateAssetMetadataArgs"
-            }
-          }
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "assetControllerAccount",
-      "discriminator": [
-        70,
-        136,
-        149,
+          "name": "sourceTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "sourceMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "destinationTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "destinationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sourceIdentityRegistry"
+        },
+        {
+          "name": "sourceIdentityAccount"
+        },
+        {
+          "name": "sourceTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "sourcePolicyEngine",
+          "writable": true
+        },
+        {
+          "name": "sourceRentReceiver",
+          "writable": true
+        },
+        {
+          "name": "destinationIdentityRegistry"
+        },
+        {
+          "name": "destinationIdentityAccount"
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "destinationPolicyEngine",
+          "writable": true
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createAssetController",
+      "docs": [
+        "create an rwa asset"
+      ],
+      "discriminator": [
+        97,
+        185,
+        6,
+        250,
+        248,
+        242,
+        68,
+        105
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority"
+        },
+        {
+          "name": "assetController",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assetMint",
+          "docs": [
+            "created with its extensions by the handler, the scaled ui amount extension cannot be set through the mint constraints"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "extraMetasAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngineAccount",
+          "writable": true
+        },
+        {
+          "name": "identityRegistryAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngine",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "identityRegistry",
+          "address": "GZsnjqT3c5zbHqsctrJ4EG4rbEfo7ZXyyUG7aDJNmxfA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "createAssetControllerArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createConversion",
+      "docs": [
+        "open a conversion from the shares of one asset into another, signed by the authorities of both assets"
+      ],
+      "discriminator": [
+        120,
+        28,
+        123,
+        9,
+        31,
+        138,
+        104,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sourceAuthority",
+          "signer": true
+        },
+        {
+          "name": "destinationAuthority",
+          "signer": true
+        },
+        {
+          "name": "sourceMint"
+        },
+        {
+          "name": "destinationMint"
+        },
+        {
+          "name": "sourceAssetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sourceMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationAssetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "destinationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "conversion",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  118,
+                  101,
+                  114,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sourceMint"
+              },
+              {
+                "kind": "account",
+                "path": "destinationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "conversionConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createDistribution",
+      "docs": [
+        "fund a distribution to the holders of the asset, paid pro-rata to the balances at the record date",
+        "net of the withholding rate of the country group of each holder"
+      ],
+      "discriminator": [
+        237,
+        83,
+        162,
+        50,
+        49,
+        6,
+        28,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "policyEngine",
+          "docs": [
+            "holds the snapshot the tracker balances are read from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "payoutMint"
+        },
+        {
+          "name": "fundingAccount",
+          "writable": true
+        },
+        {
+          "name": "distribution",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "distribution"
+              },
+              {
+                "kind": "account",
+                "path": "payoutTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "payoutMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "taxVault",
+          "docs": [
+            "receives the withheld amounts, required with withholding rates"
+          ],
+          "optional": true
+        },
+        {
+          "name": "payoutTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "snapshot",
+          "type": {
+            "defined": {
+              "name": "distributionSnapshot"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        },
+        {
+          "name": "withholdingRates",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "withholdingRate"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSubscriptionOffer",
+      "docs": [
+        "create an offer to subscribe to the asset with a payment token, the price is payment units per whole share"
+      ],
+      "discriminator": [
+        162,
+        75,
+        199,
+        155,
+        80,
+        85,
+        48,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint"
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "subscriptionOffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "subscriptionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "paymentTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "paymentTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "cutoff",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "deactivateWallet",
+      "docs": [
+        "freeze the token account of a wallet detached from an identity, called by the identity registry"
+      ],
+      "discriminator": [
+        32,
+        67,
+        58,
+        106,
+        174,
+        138,
+        49,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "identityRegistry",
+          "docs": [
+            "signs when a wallet is detached from an identity or the identity is revoked"
+          ],
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "disableMemoTransfer",
+      "docs": [
+        "memo transfer disable"
+      ],
+      "discriminator": [
+        68,
+        156,
+        197,
+        9,
+        43,
+        91,
+        114,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint"
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "enableMemoTransfer",
+      "docs": [
+        "memo transfer enable"
+      ],
+      "discriminator": [
+        186,
+        78,
+        97,
+        172,
+        71,
+        172,
+        99,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint"
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeTokenAccount",
+      "docs": [
+        "freeze token account"
+      ],
+      "discriminator": [
+        138,
+        168,
+        178,
+        109,
+        205,
+        224,
+        209,
+        93
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "issueTokens",
+      "docs": [
+        "issue shares of the rwa asset"
+      ],
+      "discriminator": [
+        40,
+        207,
+        145,
+        106,
+        249,
+        54,
+        23,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true,
+          "relations": [
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "to"
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "to"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "walletIdentityAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "walletIdentityAccount"
+        },
+        {
+          "name": "tranche",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "trancheSubscription",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "issuanceTimestamp",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "issueTokensBatch",
+      "docs": [
+        "issue shares of the rwa asset to several wallets, recipients are passed in the remaining accounts"
+      ],
+      "discriminator": [
+        164,
+        164,
+        66,
+        51,
+        231,
+        234,
+        197,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true,
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "identityRegistry"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "tranche",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amounts",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "issuanceTimestamp",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "pauseAsset",
+      "docs": [
+        "pause the mints, burns and transfers of a pausable asset"
+      ],
+      "discriminator": [
+        74,
+        48,
+        215,
+        205,
+        232,
+        186,
+        89,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reclaimDistribution",
+      "docs": [
+        "reclaim the unclaimed funds of an expired distribution"
+      ],
+      "discriminator": [
+        27,
+        225,
+        29,
+        88,
+        248,
+        121,
+        134,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "distribution.asset_mint",
+                "account": "distributionAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "distribution",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "distribution.asset_mint",
+                "account": "distributionAccount"
+              },
+              {
+                "kind": "account",
+                "path": "distribution.id",
+                "account": "distributionAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payoutMint",
+          "relations": [
+            "distribution"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "distribution"
+              },
+              {
+                "kind": "account",
+                "path": "payoutTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "payoutMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "payoutTokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recoverWallet",
+      "docs": [
+        "recover a lost wallet, moving its tokens and identity mapping to a new wallet"
+      ],
+      "discriminator": [
+        75,
+        182,
+        48,
+        181,
+        146,
+        225,
+        17,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lostTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "lost_wallet_identity.wallet",
+                "account": "walletIdentity"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "newWallet"
+        },
+        {
+          "name": "newTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "newWallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "writable": true,
+          "relations": [
+            "lostWalletIdentity"
+          ]
+        },
+        {
+          "name": "lostWalletIdentity",
+          "writable": true
+        },
+        {
+          "name": "newWalletIdentity",
+          "writable": true
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "extraMetasAccount"
+        },
+        {
+          "name": "identityRegistryProgram",
+          "address": "GZsnjqT3c5zbHqsctrJ4EG4rbEfo7ZXyyUG7aDJNmxfA"
+        },
+        {
+          "name": "identityRegistryEventAuthority"
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "caseReference",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "refundSubscription",
+      "docs": [
+        "refund the payment of a pending subscription"
+      ],
+      "discriminator": [
+        31,
+        146,
+        27,
+        46,
+        118,
+        79,
+        66,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "docs": [
+            "authority of the asset, or the wallet or anyone after the cutoff when the request cannot be settled"
+          ],
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "subscription_offer.asset_mint",
+                "account": "subscriptionOfferAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionOffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscription_offer.asset_mint",
+                "account": "subscriptionOfferAccount"
+              },
+              {
+                "kind": "account",
+                "path": "subscription_offer.id",
+                "account": "subscriptionOfferAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionRequest",
+          "writable": true
+        },
+        {
+          "name": "identityAccount"
+        },
+        {
+          "name": "paymentMint",
+          "relations": [
+            "subscriptionOffer"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "subscriptionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "paymentTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "paymentAccount",
+          "writable": true
+        },
+        {
+          "name": "paymentTokenProgram"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "rejectRedemption",
+      "docs": [
+        "reject a redemption request, releasing the locked tokens"
+      ],
+      "discriminator": [
+        137,
+        154,
+        82,
+        200,
+        41,
+        45,
+        174,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "redemptionRequest",
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionRequest",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "redemptionRequest"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "requestRedemption",
+      "docs": [
+        "request the redemption of tokens, the amount is locked until the request is settled"
+      ],
+      "discriminator": [
+        14,
+        62,
+        182,
+        237,
+        59,
+        79,
+        149,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "walletIdentityAccount"
+          ]
+        },
+        {
+          "name": "walletIdentityAccount"
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "redemptionRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "resumeAsset",
+      "docs": [
+        "resume the mints, burns and transfers of a paused asset"
+      ],
+      "discriminator": [
+        215,
+        114,
+        89,
+        79,
+        69,
+        44,
+        72,
+        112
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeTokens",
+      "docs": [
+        "revoke shares of the rwa asset"
+      ],
+      "discriminator": [
+        215,
+        42,
+        15,
+        134,
+        173,
+        80,
+        33,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true,
+          "relations": [
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "revokeTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "walletIdentityAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "walletIdentityAccount"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "seizeTokens",
+      "docs": [
+        "seize shares of the rwa asset. the seized amount takes the newest issuance lots of the source first and",
+        "they keep their issue time on the destination, so seized shares can still be held up by the lock periods",
+        "of the issuance policies while the source keeps its oldest lots. locks of the source are not moved."
+      ],
+      "discriminator": [
+        79,
+        30,
+        69,
+        54,
+        78,
+        1,
+        16,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "sourceTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "sourceIdentityAccount",
+            "destinationIdentityAccount"
+          ]
+        },
+        {
+          "name": "sourceIdentityAccount"
+        },
+        {
+          "name": "destinationIdentityAccount"
+        },
+        {
+          "name": "sourceWalletIdentity"
+        },
+        {
+          "name": "destinationWalletIdentity"
+        },
+        {
+          "name": "sourceTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        },
+        {
+          "name": "enforceLimits",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "seizeTokensMulti",
+      "docs": [
+        "seize shares of the rwa asset to several destinations, legs are passed in the remaining accounts.",
+        "each leg takes the newest issuance lots left on the source, like `seize_tokens`"
+      ],
+      "discriminator": [
+        210,
+        145,
+        121,
+        9,
+        115,
+        202,
+        80,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sourceTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "sourceIdentityAccount"
+          ]
+        },
+        {
+          "name": "sourceIdentityAccount"
+        },
+        {
+          "name": "sourceWalletIdentity"
+        },
+        {
+          "name": "sourceTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "extraMetasAccount"
+        },
+        {
+          "name": "identityRegistryProgram",
+          "address": "GZsnjqT3c5zbHqsctrJ4EG4rbEfo7ZXyyUG7aDJNmxfA"
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amounts",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "reason",
+          "type": "string"
+        },
+        {
+          "name": "caseReference",
+          "type": "string"
+        },
+        {
+          "name": "enforceLimits",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "settleSubscription",
+      "docs": [
+        "issue the shares of a pending subscription, the issuance policy pages are passed in the remaining accounts"
+      ],
+      "discriminator": [
+        140,
+        212,
+        22,
+        211,
+        219,
+        187,
+        4,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "docs": [
+            "authority of the asset, or anyone once the cutoff of the offer has passed"
+          ],
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true,
+          "relations": [
+            "subscriptionOffer",
+            "subscriptionRequest",
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionOffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "subscription_offer.id",
+                "account": "subscriptionOfferAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionRequest",
+          "writable": true
+        },
+        {
+          "name": "wallet",
+          "relations": [
+            "subscriptionRequest"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "paymentMint",
+          "relations": [
+            "subscriptionOffer"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "subscriptionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "paymentTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "relations": [
+            "subscriptionOffer"
+          ]
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "subscriptionRequest"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "paymentTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "tranche",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "trancheSubscription",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "subscribe",
+      "docs": [
+        "deposit a payment into a subscription offer at its current price"
+      ],
+      "discriminator": [
+        254,
+        28,
+        191,
+        138,
+        156,
+        179,
+        183,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "wallet",
+          "signer": true
+        },
+        {
+          "name": "subscriptionOffer",
+          "writable": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "subscriptionOffer",
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "paymentMint",
+          "relations": [
+            "subscriptionOffer"
+          ]
+        },
+        {
+          "name": "paymentAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "subscriptionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "paymentTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "walletIdentityAccount"
+          ]
+        },
+        {
+          "name": "walletIdentityAccount"
+        },
+        {
+          "name": "subscriptionRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionOffer"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentTokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "paymentAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "thawTokenAccount",
+      "docs": [
+        "thaw token account"
+      ],
+      "discriminator": [
+        199,
+        172,
+        96,
+        93,
+        244,
+        252,
+        137,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConversion",
+      "docs": [
+        "change the ratio and holding period treatment of a conversion"
+      ],
+      "discriminator": [
+        189,
+        123,
+        204,
+        76,
+        155,
+        96,
+        26,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "sourceAuthority",
+          "signer": true
+        },
+        {
+          "name": "destinationAuthority",
+          "signer": true
+        },
+        {
+          "name": "sourceAssetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "conversion.source_mint",
+                "account": "conversionAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationAssetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "conversion.destination_mint",
+                "account": "conversionAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "conversion",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "conversionConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateInterestRate",
+      "docs": [
+        "change the rate of the interest-bearing extension of the asset"
+      ],
+      "discriminator": [
+        75,
+        8,
+        255,
+        41,
+        123,
+        59,
+        135,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "rate",
+          "type": "i16"
+        }
+      ]
+    },
+    {
+      "name": "updateMetadata",
+      "docs": [
+        "edit metadata of the rwa asset"
+      ],
+      "discriminator": [
+        170,
+        182,
+        43,
+        239,
+        97,
+        78,
+        225,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateAssetMetadataArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMultiplier",
+      "docs": [
+        "schedule the scaled ui amount multiplier of the asset, for splits and reverse splits"
+      ],
+      "discriminator": [
+        46,
+        178,
+        53,
+        101,
+        34,
+        163,
+        246,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "writable": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "multiplier",
+          "type": "f64"
+        },
+        {
+          "name": "effectiveTimestamp",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateSubscriptionOffer",
+      "docs": [
+        "publish the price and cutoff of a subscription offer"
+      ],
+      "discriminator": [
+        239,
+        59,
+        38,
+        161,
+        224,
+        14,
+        73,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "subscription_offer.asset_mint",
+                "account": "subscriptionOfferAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionOffer",
+          "writable": true
+        },
+        {
+          "name": "treasury"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "cutoff",
+          "type": "i64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "assetControllerAccount",
+      "discriminator": [
+        70,
+        136,
+        149,
+        138,
+        12,
+        87,
+        52,
+        105
+      ]
+    },
+    {
+      "name": "conversionAccount",
+      "discriminator": [
+        173,
+        100,
+        10,
+        12,
+        148,
+        37,
+        250,
+        104
+      ]
+    },
+    {
+      "name": "distributionAccount",
+      "discriminator": [
+        98,
+        90,
+        112,
+        65,
+        49,
+        161,
+        198,
+        154
+      ]
+    },
+    {
+      "name": "distributionClaimAccount",
+      "discriminator": [
+        4,
+        155,
+        210,
+        5,
+        219,
+        141,
+        120,
+        1
+      ]
+    },
+    {
+      "name": "identityAccount",
+      "discriminator": [
+        194,
+        90,
+        181,
+        160,
+        182,
+        206,
+        116,
+        158
+      ]
+    },
+    {
+      "name": "identityRegistryAccount",
+      "discriminator": [
+        154,
+        254,
+        118,
+        4,
+        115,
+        36,
+        125,
+        78
+      ]
+    },
+    {
+      "name": "policyEngineAccount",
+      "discriminator": [
+        124,
+        85,
+        205,
+        80,
+        2,
+        18,
+        26,
+        45
+      ]
+    },
+    {
+      "name": "redemptionRequestAccount",
+      "discriminator": [
+        67,
+        149,
+        141,
+        116,
+        53,
+        129,
+        244,
+        10
+      ]
+    },
+    {
+      "name": "subscriptionOfferAccount",
+      "discriminator": [
+        78,
+        153,
+        114,
+        67,
+        157,
+        184,
+        89,
+        14
+      ]
+    },
+    {
+      "name": "subscriptionRequestAccount",
+      "discriminator": [
+        86,
+        255,
+        108,
+        169,
+        102,
+        30,
+        217,
+        3
+      ]
+    },
+    {
+      "name": "trackerAccount",
+      "discriminator": [
+        83,
+        95,
+        166,
+        148,
+        57,
+        30,
+        90,
+        210
+      ]
+    },
+    {
+      "name": "walletIdentity",
+      "discriminator": [
+        101,
+        142,
+        55,
+        104,
+        168,
+        77,
+        57,
+        85
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "assetMetadataEvent",
+      "discriminator": [
+        90,
+        19,
+        200,
+        229,
+        103,
+        82,
+        218,
+        16
+      ]
+    },
+    {
+      "name": "burnEvent",
+      "discriminator": [
+        33,
+        89,
+        47,
+        117,
+        82,
+        124,
+        238,
+        250
+      ]
+    },
+    {
+      "name": "conversionEvent",
+      "discriminator": [
+        251,
+        192,
+        66,
+        23,
+        58,
+        76,
+        30,
+        104
+      ]
+    },
+    {
+      "name": "distributionClaimedEvent",
+      "discriminator": [
+        153,
+        209,
+        247,
+        11,
+        228,
+        82,
+        142,
+        83
+      ]
+    },
+    {
+      "name": "distributionCreatedEvent",
+      "discriminator": [
+        232,
+        59,
+        100,
+        162,
+        159,
+        87,
+        157,
+        199
+      ]
+    },
+    {
+      "name": "distributionReclaimedEvent",
+      "discriminator": [
+        107,
+        82,
+        225,
+        132,
+        57,
+        63,
+        240,
+        170
+      ]
+    },
+    {
+      "name": "extensionMetadataEvent",
+      "discriminator": [
+        22,
+        198,
+        253,
+        69,
+        234,
+        122,
+        248,
+        117
+      ]
+    },
+    {
+      "name": "issueEvent",
+      "discriminator": [
+        220,
+        74,
+        136,
+        189,
+        186,
+        247,
+        253,
+        140
+      ]
+    },
+    {
+      "name": "redemptionApprovedEvent",
+      "discriminator": [
+        189,
+        92,
+        45,
+        49,
+        86,
+        103,
+        218,
+        182
+      ]
+    },
+    {
+      "name": "redemptionCancelledEvent",
+      "discriminator": [
+        90,
+        44,
+        227,
         138,
-        12,
+        227,
+        113,
+        115,
+        128
+      ]
+    },
+    {
+      "name": "redemptionRejectedEvent",
+      "discriminator": [
+        101,
+        193,
         87,
-        52,
-        105
+        108,
+        156,
+        144,
+        29,
+        114
+      ]
+    },
+    {
+      "name": "redemptionRequestedEvent",
+      "discriminator": [
+        202,
+        47,
+        51,
+        231,
+        221,
+        144,
+        164,
+        57
+      ]
+    },
+    {
+      "name": "revokeEvent",
+      "discriminator": [
+        87,
+        202,
+        67,
+        213,
+        43,
+        84,
+        177,
+        3
+      ]
+    },
+    {
+      "name": "seizeEvent",
+      "discriminator": [
+        100,
+        186,
+        127,
+        43,
+        145,
+        98,
+        208,
+        78
+      ]
+    },
+    {
+      "name": "seizeLegEvent",
+      "discriminator": [
+        240,
+        27,
+        154,
+        255,
+        136,
+        39,
+        57,
+        113
+      ]
+    },
+    {
+      "name": "shareConversionEvent",
+      "discriminator": [
+        115,
+        33,
+        157,
+        130,
+        244,
+        17,
+        250,
+        176
+      ]
+    },
+    {
+      "name": "subscriptionOfferEvent",
+      "discriminator": [
+        130,
+        121,
+        132,
+        231,
+        117,
+        225,
+        150,
+        39
+      ]
+    },
+    {
+      "name": "subscriptionRefundedEvent",
+      "discriminator": [
+        73,
+        120,
+        44,
+        10,
+        177,
+        111,
+        131,
+        235
+      ]
+    },
+    {
+      "name": "subscriptionRequestedEvent",
+      "discriminator": [
+        230,
+        108,
+        98,
+        22,
+        208,
+        63,
+        151,
+        46
+      ]
+    },
+    {
+      "name": "subscriptionSettledEvent",
+      "discriminator": [
+        83,
+        42,
+        75,
+        196,
+        215,
+        50,
+        37,
+        185
+      ]
+    },
+    {
+      "name": "walletRecoveredEvent",
+      "discriminator": [
+        0,
+        89,
+        60,
+        127,
+        96,
+        132,
+        152,
+        152
       ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "transferMintNotApproved",
+      "msg": "Transfer hasnt been approved for the asset mint"
+    },
+    {
+      "code": 6001,
+      "name": "transferFromNotApproved",
+      "msg": "Transfer hasnt been approved for from account"
+    },
+    {
+      "code": 6002,
+      "name": "transferToNotApproved",
+      "msg": "Transfer hasnt been approved for to account"
+    },
+    {
+      "code": 6003,
+      "name": "transferAmountNotApproved",
+      "msg": "Transfer hasnt been approved for the specified amount"
+    },
+    {
+      "code": 6004,
+      "name": "invalidPolicyAccount",
+      "msg": "Invalid policy account passed"
+    },
+    {
+      "code": 6005,
+      "name": "transferSlotNotApproved",
+      "msg": "Invalid slot for approve account"
+    },
+    {
+      "code": 6006,
+      "name": "transferHistoryFull",
+      "msg": "Transfer history is full"
+    },
+    {
+      "code": 6007,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6008,
+      "name": "invalidPdaPassedIn",
+      "msg": "Pda passed in for transfer is wrong"
+    },
+    {
+      "code": 6009,
+      "name": "tokenAccountNotInitialized",
+      "msg": "Token account is not initialized"
+    },
+    {
+      "code": 6010,
+      "name": "tokenAccountAlreadyInitialized",
+      "msg": "Token account is already initialized"
+    },
+    {
+      "code": 6011,
+      "name": "invalidIdentityAccounts",
+      "msg": "Invalid identity accounts"
+    },
+    {
+      "code": 6012,
+      "name": "invalidAuthority",
+      "msg": "Invalid authority"
+    },
+    {
+      "code": 6013,
+      "name": "invalidSeizeLegs",
+      "msg": "Invalid seize legs"
+    },
+    {
+      "code": 6014,
+      "name": "invalidRecoveryWallet",
+      "msg": "Recovery wallet must differ from the lost wallet"
+    },
+    {
+      "code": 6015,
+      "name": "invalidRedemptionAmount",
+      "msg": "Redemption amount must be positive and covered by the token account balance"
+    },
+    {
+      "code": 6016,
+      "name": "invalidRedemptionState",
+      "msg": "Redemption request is not in a valid state for this operation"
+    },
+    {
+      "code": 6017,
+      "name": "invalidIssuanceBatch",
+      "msg": "Invalid issuance batch"
+    },
+    {
+      "code": 6018,
+      "name": "invalidSubscriptionPrice",
+      "msg": "Subscription price must be positive"
+    },
+    {
+      "code": 6019,
+      "name": "invalidSubscriptionAmount",
+      "msg": "Subscription amount must be positive and buy at least one share unit"
+    },
+    {
+      "code": 6020,
+      "name": "invalidSubscriptionState",
+      "msg": "Subscription request is not in a valid state for this operation"
+    },
+    {
+      "code": 6021,
+      "name": "subscriptionOfferClosed",
+      "msg": "Subscription offer is closed for deposits"
+    },
+    {
+      "code": 6022,
+      "name": "subscriptionSettlementNotAllowed",
+      "msg": "Subscriptions can only be settled by the authority before the cutoff"
+    },
+    {
+      "code": 6023,
+      "name": "invalidDistribution",
+      "msg": "Distribution must be funded against a non empty snapshot and expire after its record date"
+    },
+    {
+      "code": 6024,
+      "name": "invalidDistributionClaim",
+      "msg": "Distribution claim is empty or exceeds the funded amount"
+    },
+    {
+      "code": 6025,
+      "name": "invalidBalanceProof",
+      "msg": "Balance proof does not match the distribution snapshot"
+    },
+    {
+      "code": 6026,
+      "name": "distributionExpired",
+      "msg": "Distribution has expired"
+    },
+    {
+      "code": 6027,
+      "name": "distributionNotReclaimable",
+      "msg": "Distribution can only be reclaimed once after its expiry"
+    },
+    {
+      "code": 6028,
+      "name": "invalidWithholdingRates",
+      "msg": "Withholding rates must be at most 10000 bps with one rate per country group"
+    },
+    {
+      "code": 6029,
+      "name": "taxVaultMissing",
+      "msg": "Tax vault of the distribution is missing"
+    },
+    {
+      "code": 6030,
+      "name": "invalidConversionRatio",
+      "msg": "Conversion ratio must have a non-zero numerator and denominator"
+    },
+    {
+      "code": 6031,
+      "name": "invalidConversionAmount",
+      "msg": "Converted amount is zero or overflows"
+    },
+    {
+      "code": 6032,
+      "name": "incompatibleExtensions",
+      "msg": "Scaled ui amount and interest-bearing extensions cannot be combined"
+    },
+    {
+      "code": 6033,
+      "name": "redemptionBalanceInsufficient",
+      "msg": "Token account no longer holds the amount of the redemption request"
     },
     {
-      "name": "identityAccount",
-      "discriminator": [
-        194,
-        90,
-        181,
-        160,
-        182,
-        206,
-        116,
-        158
-      ]
+      "code": 6034,
+      "name": "subscriptionRefundNotAllowed",
+      "msg": "Subscriptions can only be refunded by the authority, or by the wallet or after the cutoff once they cannot be settled"
+    }
+  ],
+  "types": [
+    {
+      "name": "assetControllerAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "assetMint",
+            "docs": [
+              "mint pubkey"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "authority has the ability to seize, freeze token accounts, etc."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "name": "identityRegistryAccount",
-      "discriminator": [
-        154,
-        254,
-        118,
-        4,
-        115,
-        36,
-        125,
-        78
-      ]
+      "name": "assetMetadataEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "string"
+          },
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "decimals",
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "policyEngineAccount",
-      "discriminator": [
-        124,
-        85,
-        205,
-        80,
-        2,
-        18,
-        26,
-        45
-      ]
+      "name": "balanceProof",
+      "docs": [
+        "balance of an identity at the record date, proven against the merkle root of the distribution"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "balance",
+            "type": "u64"
+          },
+          {
+            "name": "proof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "burnEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "checkpoint",
+      "docs": [
+        "balance of a tracker before its first change after a snapshot was opened"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "snapshotId",
+            "type": "u64"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "conversionAccount",
+      "docs": [
+        "route converting the shares of the source mint into shares of the destination mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "sourceMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destinationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "receives the rent back when the conversion is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "ratioNumerator",
+            "type": "u64"
+          },
+          {
+            "name": "ratioDenominator",
+            "type": "u64"
+          },
+          {
+            "name": "preserveHoldingPeriods",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "conversionConfig",
+      "docs": [
+        "terms of the conversion of one share class into another, set by the authorities of both classes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ratioNumerator",
+            "docs": [
+              "destination base units received per `ratio_denominator` source base units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratioDenominator",
+            "type": "u64"
+          },
+          {
+            "name": "preserveHoldingPeriods",
+            "docs": [
+              "issue the destination shares at the issue time of the converted lots instead of the conversion time"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "conversionEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destinationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "conversion",
+            "type": "pubkey"
+          },
+          {
+            "name": "ratioNumerator",
+            "type": "u64"
+          },
+          {
+            "name": "ratioDenominator",
+            "type": "u64"
+          },
+          {
+            "name": "preserveHoldingPeriods",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "counter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "countBeneficialOwners",
+            "docs": [
+              "counts every beneficial owner of an identity instead of the identity once"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "trackerAccount",
-      "discriminator": [
-        83,
-        95,
-        166,
-        148,
-        57,
-        30,
-        90,
-        210
-      ]
+      "name": "counterLimit",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "holdersLimit",
+            "fields": [
+              {
+                "name": "max",
+                "type": "u64"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "counterId",
+                "type": "u8"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningThreshold",
+                "docs": [
+                  "emits a warning event when the counter crosses this value, 0 to disable"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "groupedHoldersLimit",
+            "fields": [
+              {
+                "name": "max",
+                "type": "u64"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "counters",
+                "type": "bytes"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningThreshold",
+                "docs": [
+                  "emits a warning event when any of the counters crosses this value, 0 to disable"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "percentageLimit",
+            "fields": [
+              {
+                "name": "higherCounterId",
+                "type": "u8"
+              },
+              {
+                "name": "lowerCounterId",
+                "type": "u8"
+              },
+              {
+                "name": "minPercentage",
+                "type": "u8"
+              },
+              {
+                "name": "maxPercentage",
+                "type": "u8"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningPercentage",
+                "docs": [
+                  "emits a warning event when the percentage crosses this value, 0 to disable"
+                ],
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "countryHoldersLimit",
+            "fields": [
+              {
+                "name": "max",
+                "type": "u64"
+              },
+              {
+                "name": "counterId",
+                "type": "u8"
+              },
+              {
+                "name": "groups",
+                "docs": [
+                  "country groups the limit applies to, all groups if empty"
+                ],
+                "type": "bytes"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningThreshold",
+                "docs": [
+                  "emits a warning event when any of the groups crosses this value, 0 to disable"
+                ],
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "name": "walletIdentity",
-      "discriminator": [
-        101,
-        142,
-        55,
-        104,
-        168,
-        77,
-        57,
-        85
-      ]
-    }
-  ],
-  "events": [
+      "name": "countryCounter",
+      "docs": [
+        "holders counter keeping one value per country group, the group of an identity being `mapping[country]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "docs": [
+              "shares the id space of `counters`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "countBeneficialOwners",
+            "docs": [
+              "counts every beneficial owner of an identity instead of the identity once"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "values",
+            "docs": [
+              "value of each country group in use, padded with zeros when added and when the mapping uses new groups"
+            ],
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "assetMetadataEvent",
-      "discriminator": [
-        90,
-        19,
-        200,
-        229,
-        103,
-        82,
-        218,
-        16
-      ]
+      "name": "createAssetControllerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "multiplier",
+            "docs": [
+              "initial multiplier of the scaled ui amount extension used for splits, none to create the asset without it"
+            ],
+            "type": {
+              "option": "f64"
+            }
+          },
+          {
+            "name": "defaultAccountStateFrozen",
+            "docs": [
+              "new token accounts are frozen until the asset controller thaws them"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "interestRate",
+            "docs": [
+              "interest rate in basis points of the interest-bearing extension, cannot be combined with a multiplier"
+            ],
+            "type": {
+              "option": "i16"
+            }
+          },
+          {
+            "name": "groupPointer",
+            "docs": [
+              "address of the token group the asset describes"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "groupMemberPointer",
+            "docs": [
+              "address of the token group member the asset describes"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pausable",
+            "docs": [
+              "lets the asset controller pause mints, burns and transfers"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "burnEvent",
-      "discriminator": [
-        33,
-        89,
-        47,
-        117,
-        82,
-        124,
-        238,
-        250
-      ]
+      "name": "distributionAccount",
+      "docs": [
+        "payout to the holders of the asset, funds are held by the vault of the distribution"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "assetMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "docs": [
+              "id chosen by the authority, unique per asset"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payoutMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "snapshot",
+            "type": {
+              "defined": {
+                "name": "distributionSnapshot"
+              }
+            }
+          },
+          {
+            "name": "snapshotId",
+            "docs": [
+              "policy engine snapshot of the tracker balances, 0 for merkle snapshots"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "snapshotSupply",
+            "docs": [
+              "sum of the balances at the record date"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recordTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "claims are closed after the expiry, the unclaimed funds can then be reclaimed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "amount funded into the vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reclaimed",
+            "type": "bool"
+          },
+          {
+            "name": "taxVault",
+            "docs": [
+              "token account receiving the withheld amounts, default when nothing is withheld"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "withholdingRates",
+            "docs": [
+              "groups without a rate are paid in full"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "withholdingRate"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "extensionMetadataEvent",
-      "discriminator": [
-        22,
-        198,
-        253,
-        69,
-        234,
-        122,
-        248,
-        117
-      ]
+      "name": "distributionClaimAccount",
+      "docs": [
+        "receipt of the claim of an identity, prevents claiming twice"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "distribution",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "gross amount of the claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withheldAmount",
+            "docs": [
+              "part of the amount sent to the tax vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "issueEvent",
-      "discriminator": [
-        220,
-        74,
-        136,
-        189,
-        186,
-        247,
-        253,
-        140
-      ]
+      "name": "distributionClaimedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "distribution",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          },
+          {
+            "name": "country",
+            "type": "u8"
+          },
+          {
+            "name": "grossAmount",
+            "type": "u64"
+          },
+          {
+            "name": "withheldAmount",
+            "type": "u64"
+          },
+          {
+            "name": "netAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "revokeEvent",
-      "discriminator": [
-        87,
-        202,
-        67,
-        213,
-        43,
-        84,
-        177,
-        3
-      ]
+      "name": "distributionCreatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "distribution",
+            "type": "pubkey"
+          },
+          {
+            "name": "payoutMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "snapshotId",
+            "type": "u64"
+          },
+          {
+            "name": "snapshotSupply",
+            "type": "u64"
+          },
+          {
+            "name": "recordTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "taxVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "withholdingRates",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "withholdingRate"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "seizeEvent",
-      "discriminator": [
-        100,
-        186,
-        127,
-        43,
-        145,
-        98,
-        208,
-        78
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "transferMintNotApproved",
-      "msg": "Transfer hasnt been approved for the asset mint"
+      "name": "distributionReclaimedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "distribution",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "transferFromNotApproved",
-      "msg": "Transfer hasnt been approved for from account"
+      "name": "distributionSnapshot",
+      "docs": [
+        "source of the record date balances a distribution is paid against"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "tracker"
+          },
+          {
+            "name": "merkle",
+            "fields": [
+              {
+                "name": "root",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "totalBalance",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "transferToNotApproved",
-      "msg": "Transfer hasnt been approved for to account"
+      "name": "enforcementMode",
+      "docs": [
+        "how a policy or counter limit reacts when it is violated"
+      ],
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "enforce"
+          },
+          {
+            "name": "monitor"
+          },
+          {
+            "name": "disabled"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "transferAmountNotApproved",
-      "msg": "Transfer hasnt been approved for the specified amount"
+      "name": "extensionMetadataEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "string"
+          },
+          {
+            "name": "extensionType",
+            "type": "u8"
+          },
+          {
+            "name": "metadata",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "invalidPolicyAccount",
-      "msg": "Invalid policy account passed"
+      "name": "filterComparison",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "or"
+          },
+          {
+            "name": "and"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "transferSlotNotApproved",
-      "msg": "Invalid slot for approve account"
+      "name": "filterData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": {
+              "defined": {
+                "name": "filterLevel"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": {
+              "defined": {
+                "name": "filterTarget"
+              }
+            }
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": {
+                "name": "filterMode"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "transferHistoryFull",
-      "msg": "Transfer history is full"
+      "name": "filterInner",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "single",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterData"
+                }
+              }
+            ]
+          },
+          {
+            "name": "tuple",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterData"
+                }
+              },
+              {
+                "defined": {
+                  "name": "filterComparison"
+                }
+              },
+              {
+                "defined": {
+                  "name": "filterData"
+                }
+              }
+            ]
+          },
+          {
+            "name": "multiple",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterComparison"
+                }
+              },
+              {
+                "vec": {
+                  "defined": {
+                    "name": "filterData"
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "unauthorized",
-      "msg": "unauthorized"
+      "name": "filterLevel",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "level",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "levelMappingAny",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "levelMapping",
+            "fields": [
+              {
+                "name": "source",
+                "type": "u8"
+              },
+              {
+                "name": "target",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "country",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "countryMapping",
+            "fields": [
+              "u8"
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidPdaPassedIn",
-      "msg": "Pda passed in for transfer is wrong"
+      "name": "filterMode",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "include"
+          },
+          {
+            "name": "exclude"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "tokenAccountNotInitialized",
-      "msg": "Token account is not initialized"
+      "name": "filterTarget",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "sender"
+          },
+          {
+            "name": "receiver"
+          },
+          {
+            "name": "bothAnd"
+          },
+          {
+            "name": "bothOr"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "tokenAccountAlreadyInitialized",
-      "msg": "Token account is already initialized"
+      "name": "identityAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "version of the account"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "identityRegistry",
+            "docs": [
+              "identity registry to which the account belongs"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "owner of the identity account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "numWallets",
+            "docs": [
+              "number of wallets attached to this identity account"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "country",
+            "docs": [
+              "country code of the user"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "beneficialOwners",
+            "docs": [
+              "number of beneficial owners behind this identity, used by look-through counters"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "levels",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "identityLevel"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "invalidIdentityAccounts",
-      "msg": "Invalid identity accounts"
+      "name": "identityFilter",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "simple",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterInner"
+                }
+              }
+            ]
+          },
+          {
+            "name": "ifThen",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterInner"
+                }
+              },
+              {
+                "defined": {
+                  "name": "filterInner"
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "invalidAuthority",
-      "msg": "Invalid authority"
-    }
-  ],
-  "types": [
+      "name": "identityLevel",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
-      "name": "assetControllerAccount",
+      "name": "identityRegistryAccount",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1183,257 +6705,411 @@ export type AssetController = {
             "name": "version",
             "type": "u8"
           },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
           {
             "name": "assetMint",
             "docs": [
-              "mint pubkey"
+              "corresponding asset mint"
             ],
             "type": "pubkey"
           },
           {
             "name": "authority",
             "docs": [
-              "authority has the ability to seize, freeze token accounts, etc."
+              "authority to manage the registry"
             ],
             "type": "pubkey"
+          },
+          {
+            "name": "identityCount",
+            "docs": [
+              "number of identity accounts, checked by the counter reconciliations of the policy engine"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "assetMetadataEvent",
+      "name": "issuance",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "issueTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "issuancePolicies",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "disallowBackdating",
+            "type": "bool"
+          },
+          {
+            "name": "maxSupply",
+            "docs": [
+              "in base units, like the supply, before the scaled ui amount multiplier of the mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "usLockPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "nonUsLockPeriod",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "issueEvent",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "issuanceTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
           {
             "name": "mint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lock",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "releaseTime",
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "type": "u64"
+          },
+          {
+            "name": "reasonString",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboundVolume",
+      "docs": [
+        "outbound volume of the identity, kept in weekly buckets so the tracker does not grow in the transfer hook"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastBucket",
+            "docs": [
+              "week of the most recently written bucket"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "buckets",
+            "type": {
+              "array": [
+                "u64",
+                13
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "policy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hash",
             "type": "string"
           },
           {
-            "name": "name",
+            "name": "identityFilter",
             "type": {
-              "option": "string"
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
             }
           },
           {
-            "name": "symbol",
-            "type": {
-              "option": "string"
-            }
+            "name": "customError",
+            "type": "u8"
           },
           {
-            "name": "uri",
+            "name": "enforcementMode",
             "type": {
-              "option": "string"
+              "defined": {
+                "name": "enforcementMode"
+              }
             }
           },
           {
-            "name": "decimals",
+            "name": "applicability",
             "type": {
-              "option": "u8"
+              "defined": {
+                "name": "policyApplicability"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "burnEvent",
+      "name": "policyApplicability",
+      "docs": [
+        "lifecycle events a policy is evaluated on"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "transfer",
+            "type": "bool"
           },
           {
-            "name": "reason",
-            "type": "string"
+            "name": "issuance",
+            "type": "bool"
           },
           {
-            "name": "wallet",
-            "type": "pubkey"
+            "name": "burn",
+            "docs": [
+              "burn and revoke"
+            ],
+            "type": "bool"
           },
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "seize",
+            "docs": [
+              "transfers made by the permanent delegate"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "levelsChange",
+            "docs": [
+              "levels, country and beneficial owners changes"
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "counter",
+      "name": "policyEngineAccount",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "value",
-            "type": "u64"
+            "name": "version",
+            "docs": [
+              "version"
+            ],
+            "type": "u8"
           },
           {
-            "name": "id",
-            "type": "u8"
+            "name": "assetMint",
+            "docs": [
+              "asset mint"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "identityFilter",
+            "name": "authority",
+            "docs": [
+              "authority of the registry"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mapping",
+            "docs": [
+              "generic mapping for levels"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                256
+              ]
+            }
+          },
+          {
+            "name": "issuancePolicies",
+            "docs": [
+              "policies to apply on issuance",
+              "these are partially for storage only"
+            ],
             "type": {
               "defined": {
-                "name": "identityFilter"
+                "name": "issuancePolicies"
               }
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "counterLimit",
-      "type": {
-        "kind": "enum",
-        "variants": [
+          },
           {
-            "name": "holdersLimit",
-            "fields": [
-              {
-                "name": "max",
-                "type": "u64"
-              },
-              {
-                "name": "min",
-                "type": "u64"
-              },
-              {
-                "name": "counterId",
-                "type": "u8"
+            "name": "timelock",
+            "docs": [
+              "delay applied to proposed configuration changes"
+            ],
+            "type": {
+              "defined": {
+                "name": "timelock"
               }
-            ]
+            }
           },
           {
-            "name": "groupedHoldersLimit",
-            "fields": [
-              {
-                "name": "max",
-                "type": "u64"
-              },
-              {
-                "name": "min",
-                "type": "u64"
-              },
-              {
-                "name": "counters",
-                "type": "bytes"
-              }
-            ]
+            "name": "nextChangeId",
+            "docs": [
+              "id assigned to the next proposed configuration change"
+            ],
+            "type": "u64"
           },
           {
-            "name": "percentageLimit",
-            "fields": [
-              {
-                "name": "higherCounterId",
-                "type": "u8"
-              },
-              {
-                "name": "lowerCounterId",
-                "type": "u8"
-              },
-              {
-                "name": "minPercentage",
-                "type": "u8"
-              },
-              {
-                "name": "maxPercentage",
-                "type": "u8"
+            "name": "snapshot",
+            "docs": [
+              "record date snapshot checkpointed by the trackers"
+            ],
+            "type": {
+              "defined": {
+                "name": "snapshot"
               }
-            ]
-          }
-        ]
-      }
-    },
-    {
-      "name": "createAssetControllerArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "decimals",
-            "type": "u8"
+            }
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "holdersSequence",
+            "docs": [
+              "incremented when an identity starts or stops holding or the levels of a holder change, outdates running reconciliations"
+            ],
+            "type": "u64"
           },
           {
-            "name": "symbol",
-            "type": "string"
+            "name": "policies",
+            "docs": [
+              "policies to check on transfers or balance changes"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "policy"
+                }
+              }
+            }
           },
           {
-            "name": "uri",
-            "type": "string"
-          }
-        ]
-      }
-    },
-    {
-      "name": "extensionMetadataEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "string"
+            "name": "counters",
+            "docs": [
+              "counters to track the number of holders depending on filters"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "counter"
+                }
+              }
+            }
           },
           {
-            "name": "extensionType",
-            "type": "u8"
+            "name": "counterLimits",
+            "docs": [
+              "limits to apply on existing counters"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "counterLimit"
+                }
+              }
+            }
           },
           {
-            "name": "metadata",
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "filterComparison",
-      "repr": {
-        "kind": "rust"
-      },
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "or"
+            "name": "countryCounters",
+            "docs": [
+              "counters keeping one holder count per country group"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "countryCounter"
+                }
+              }
+            }
           },
           {
-            "name": "and"
+            "name": "policyPages",
+            "docs": [
+              "overflow pages holding additional policies"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "policyPageRef"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "filterData",
+      "name": "policyPageRef",
+      "docs": [
+        "reference to a policy page, kept on the policy engine"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "level",
-            "type": {
-              "defined": {
-                "name": "filterLevel"
-              }
-            }
-          },
-          {
-            "name": "target",
-            "type": {
-              "defined": {
-                "name": "filterTarget"
-              }
-            }
+            "name": "index",
+            "type": "u8"
           },
           {
-            "name": "mode",
+            "name": "scope",
             "type": {
               "defined": {
-                "name": "filterMode"
+                "name": "policyPageScope"
               }
             }
           }
@@ -1441,7 +7117,10 @@ export type AssetController = {
       }
     },
     {
-      "name": "filterInner",
+      "name": "policyPageScope",
+      "docs": [
+        "operation evaluating the policies of a page, so each instruction only loads the pages it needs"
+      ],
       "repr": {
         "kind": "rust"
       },
@@ -1449,604 +7128,829 @@ export type AssetController = {
         "kind": "enum",
         "variants": [
           {
-            "name": "single",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterData"
-                }
-              }
-            ]
+            "name": "transfer"
           },
           {
-            "name": "tuple",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterData"
-                }
-              },
-              {
-                "defined": {
-                  "name": "filterComparison"
-                }
-              },
-              {
-                "defined": {
-                  "name": "filterData"
-                }
-              }
-            ]
+            "name": "issuance"
           },
           {
-            "name": "multiple",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterComparison"
-                }
-              },
-              {
-                "vec": {
-                  "defined": {
-                    "name": "filterData"
-                  }
-                }
-              }
-            ]
+            "name": "levelsChange"
           }
         ]
       }
     },
     {
-      "name": "filterLevel",
+      "name": "policyType",
+      "docs": [
+        "amounts and limits are in base units, before the scaled ui amount multiplier of the mint.",
+        "splits do not change the base units held, so the limits keep applying to the same holdings."
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "level",
+            "name": "identityApproval"
+          },
+          {
+            "name": "transactionAmountLimit",
             "fields": [
-              "u8"
+              {
+                "name": "limit",
+                "type": "u64"
+              }
             ]
           },
           {
-            "name": "levelMappingAny",
+            "name": "maxBalance",
             "fields": [
-              "u8"
+              {
+                "name": "limit",
+                "type": "u64"
+              }
             ]
           },
           {
-            "name": "levelMapping",
+            "name": "minBalance",
             "fields": [
               {
-                "name": "source",
-                "type": "u8"
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "minMaxBalance",
+            "fields": [
+              {
+                "name": "min",
+                "type": "u64"
               },
               {
-                "name": "target",
-                "type": "u8"
+                "name": "max",
+                "type": "u64"
               }
             ]
           },
           {
-            "name": "country",
+            "name": "transferPause"
+          },
+          {
+            "name": "forbiddenIdentityGroup"
+          },
+          {
+            "name": "forceFullTransfer"
+          },
+          {
+            "name": "blockFlowbackEndTime",
             "fields": [
-              "u8"
+              {
+                "name": "time",
+                "type": "i64"
+              }
             ]
           },
           {
-            "name": "countryMapping",
+            "name": "affiliateVolumeLimit",
             "fields": [
-              "u8"
+              {
+                "name": "amount",
+                "type": "u64"
+              }
             ]
           }
         ]
       }
     },
     {
-      "name": "filterMode",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "redemptionApprovedEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "include"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "exclude"
+            "name": "request",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "filterTarget",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "redemptionCancelledEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "sender"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "receiver"
+            "name": "request",
+            "type": "pubkey"
           },
           {
-            "name": "bothAnd"
+            "name": "wallet",
+            "type": "pubkey"
           },
           {
-            "name": "bothOr"
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "identityAccount",
+      "name": "redemptionRejectedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "request",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "redemptionRequestAccount",
+      "docs": [
+        "redemption requested by a holder, the amount stays locked on the tracker while pending"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "version",
-            "docs": [
-              "version of the account"
-            ],
             "type": "u8"
           },
           {
-            "name": "identityRegistry",
+            "name": "assetMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
             "docs": [
-              "identity registry to which the account belongs"
+              "identity account of the holder"
             ],
             "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "wallet",
             "docs": [
-              "owner of the identity account"
+              "wallet holding the tokens to redeem"
             ],
             "type": "pubkey"
           },
           {
-            "name": "numWallets",
+            "name": "payer",
             "docs": [
-              "number of wallets attached to this identity account"
+              "receives the rent back when the request is closed"
             ],
-            "type": "u16"
+            "type": "pubkey"
           },
           {
-            "name": "country",
+            "name": "id",
             "docs": [
-              "country code of the user"
+              "id chosen by the holder, unique per wallet"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "levels",
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "state",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "identityLevel"
-                }
+              "defined": {
+                "name": "redemptionState"
               }
             }
+          },
+          {
+            "name": "requestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "resolvedAt",
+            "docs": [
+              "time of approval, rejection or cancellation"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "redemptionRequestedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "request",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "identityFilter",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "redemptionState",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "simple",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterInner"
-                }
-              }
-            ]
+            "name": "pending"
           },
           {
-            "name": "ifThen",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterInner"
-                }
-              },
-              {
-                "defined": {
-                  "name": "filterInner"
-                }
-              }
-            ]
+            "name": "approved"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "cancelled"
           }
         ]
       }
     },
     {
-      "name": "identityLevel",
+      "name": "revokeEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "level",
-            "type": "u8"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "expiry",
-            "type": "i64"
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "identityRegistryAccount",
+      "name": "seizeEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
-            "type": "u8"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "reason",
+            "type": "string"
           },
           {
-            "name": "assetMint",
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "toWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enforceLimits",
+            "type": "bool"
+          },
+          {
+            "name": "decreasedCounters",
             "docs": [
-              "corresponding asset mint"
+              "holder counters the seize removed the source identity from"
             ],
-            "type": "pubkey"
+            "type": "bytes"
           },
           {
-            "name": "authority",
+            "name": "increasedCounters",
             "docs": [
-              "authority to manage the registry"
+              "holder counters the seize added the destination identity to"
             ],
-            "type": "pubkey"
+            "type": "bytes"
+          },
+          {
+            "name": "movedIssuancesAmount",
+            "docs": [
+              "issuances moved to the destination, newest lots of the source first. they keep their issue time, so the",
+              "holding periods of the seized shares run from their issuance and not from the seize"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "issuance",
+      "name": "seizeLegEvent",
+      "docs": [
+        "emitted for every leg of a multi destination seize, legs of one seize share the case reference"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "caseReference",
+            "type": "string"
+          },
+          {
+            "name": "leg",
+            "type": "u8"
+          },
+          {
+            "name": "legs",
+            "type": "u8"
+          },
           {
             "name": "amount",
             "type": "u64"
           },
           {
-            "name": "issueTime",
-            "type": "i64"
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "toWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enforceLimits",
+            "type": "bool"
+          },
+          {
+            "name": "decreasedCounters",
+            "type": "bytes"
+          },
+          {
+            "name": "increasedCounters",
+            "type": "bytes"
+          },
+          {
+            "name": "movedIssuancesAmount",
+            "docs": [
+              "issuances moved to the destination, newest lots of the source first. they keep their issue time, so the",
+              "holding periods of the seized shares run from their issuance and not from the seize"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "issuancePolicies",
+      "name": "shareConversionEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "disallowBackdating",
-            "type": "bool"
+            "name": "sourceMint",
+            "type": "pubkey"
           },
           {
-            "name": "maxSupply",
+            "name": "destinationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "sourceAmount",
             "type": "u64"
           },
           {
-            "name": "usLockPeriod",
-            "type": "i64"
+            "name": "destinationAmount",
+            "type": "u64"
           },
           {
-            "name": "nonUsLockPeriod",
-            "type": "i64"
+            "name": "preserveHoldingPeriods",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "issueEvent",
+      "name": "snapshot",
+      "docs": [
+        "latest record date snapshot of the holder balances, id 0 when none was opened"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
+            "name": "id",
             "type": "u64"
           },
           {
-            "name": "issuanceTimestamp",
+            "name": "recordTimestamp",
             "type": "i64"
           },
           {
-            "name": "wallet",
-            "type": "pubkey"
+            "name": "supply",
+            "docs": [
+              "supply of the asset at the record date"
+            ],
+            "type": "u64"
           },
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "reservedUntil",
+            "docs": [
+              "time until which the readable snapshots are in use by proposals or distributions, slot `id % MAX_CHECKPOINTS`"
+            ],
+            "type": {
+              "array": [
+                "i64",
+                4
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "lock",
+      "name": "subscriptionOfferAccount",
+      "docs": [
+        "offer to subscribe to the asset by depositing a payment token, deposits are held by the vault of the offer"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "assetMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "docs": [
+              "id chosen by the authority, unique per asset"
+            ],
             "type": "u64"
           },
           {
-            "name": "releaseTime",
+            "name": "paymentMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "token account receiving the payments of settled subscriptions"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "payment base units per whole share"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cutoff",
+            "docs": [
+              "deposits are closed after the cutoff, pending subscriptions can then be settled by anyone"
+            ],
             "type": "i64"
           },
           {
-            "name": "reason",
+            "name": "pendingAmount",
+            "docs": [
+              "payments held by the vault for pending subscriptions"
+            ],
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "subscriptionOfferEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "reasonString",
-            "type": "string"
+            "name": "offer",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "cutoff",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "policy",
+      "name": "subscriptionRefundedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "hash",
-            "type": "string"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "identityFilter",
-            "type": {
-              "defined": {
-                "name": "identityFilter"
-              }
-            }
+            "name": "offer",
+            "type": "pubkey"
           },
           {
-            "name": "policyType",
-            "type": {
-              "defined": {
-                "name": "policyType"
-              }
-            }
+            "name": "request",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
           },
           {
-            "name": "customError",
-            "type": "u8"
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "refundedBy",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "policyEngineAccount",
+      "name": "subscriptionRequestAccount",
+      "docs": [
+        "payment deposited by an investor, the shares are issued when the subscription is settled"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "version",
-            "docs": [
-              "version"
-            ],
             "type": "u8"
           },
           {
             "name": "assetMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "offer",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
             "docs": [
-              "asset mint"
+              "identity account of the investor"
             ],
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "wallet",
             "docs": [
-              "authority of the registry"
+              "wallet receiving the shares, and the payment back if refunded"
             ],
             "type": "pubkey"
           },
           {
-            "name": "mapping",
+            "name": "payer",
             "docs": [
-              "generic mapping for levels"
+              "receives the rent back when the request is closed"
             ],
-            "type": {
-              "array": [
-                "u8",
-                256
-              ]
-            }
+            "type": "pubkey"
           },
           {
-            "name": "issuancePolicies",
+            "name": "id",
             "docs": [
-              "policies to apply on issuance",
-              "these are partially for storage only"
+              "id chosen by the investor, unique per wallet and offer"
             ],
-            "type": {
-              "defined": {
-                "name": "issuancePolicies"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "policies",
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "price",
             "docs": [
-              "policies to check on transfers or balance changes"
+              "price of the offer when the payment was deposited"
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "policy"
-                }
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "counters",
+            "name": "shares",
             "docs": [
-              "counters to track the number of holders depending on filters"
+              "shares issued on settlement"
             ],
+            "type": "u64"
+          },
+          {
+            "name": "state",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "counter"
-                }
+              "defined": {
+                "name": "subscriptionState"
               }
             }
           },
           {
-            "name": "counterLimits",
+            "name": "requestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "resolvedAt",
             "docs": [
-              "limits to apply on existing counters"
+              "time of settlement or refund"
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "counterLimit"
-                }
-              }
-            }
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "policyType",
+      "name": "subscriptionRequestedEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "identityApproval"
-          },
-          {
-            "name": "transactionAmountLimit",
-            "fields": [
-              {
-                "name": "limit",
-                "type": "u64"
-              }
-            ]
-          },
-          {
-            "name": "maxBalance",
-            "fields": [
-              {
-                "name": "limit",
-                "type": "u64"
-              }
-            ]
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "minBalance",
-            "fields": [
-              {
-                "name": "limit",
-                "type": "u64"
-              }
-            ]
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "minMaxBalance",
-            "fields": [
-              {
-                "name": "min",
-                "type": "u64"
-              },
-              {
-                "name": "max",
-                "type": "u64"
-              }
-            ]
+            "name": "offer",
+            "type": "pubkey"
           },
           {
-            "name": "transferPause"
+            "name": "request",
+            "type": "pubkey"
           },
           {
-            "name": "forbiddenIdentityGroup"
+            "name": "wallet",
+            "type": "pubkey"
           },
           {
-            "name": "forceFullTransfer"
+            "name": "paymentAmount",
+            "type": "u64"
           },
           {
-            "name": "blockFlowbackEndTime",
-            "fields": [
-              {
-                "name": "time",
-                "type": "i64"
-              }
-            ]
+            "name": "price",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "revokeEvent",
+      "name": "subscriptionSettledEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "reason",
-            "type": "string"
+            "name": "offer",
+            "type": "pubkey"
+          },
+          {
+            "name": "request",
+            "type": "pubkey"
           },
           {
             "name": "wallet",
             "type": "pubkey"
           },
           {
-            "name": "mint",
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "settledBy",
             "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "seizeEvent",
+      "name": "subscriptionState",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "pending"
           },
           {
-            "name": "reason",
-            "type": "string"
+            "name": "settled"
           },
           {
-            "name": "wallet",
-            "type": "pubkey"
-          },
+            "name": "refunded"
+          }
+        ]
+      }
+    },
+    {
+      "name": "timelock",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "toWallet",
-            "type": "pubkey"
+            "name": "delay",
+            "docs": [
+              "seconds between proposing a change and it becoming executable"
+            ],
+            "type": "i64"
           },
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "directChangesEnabled",
+            "docs": [
+              "whether the authority can still change the engine without going through the queue"
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -2091,6 +7995,30 @@ export type AssetController = {
                 }
               }
             }
+          },
+          {
+            "name": "outboundVolume",
+            "type": {
+              "defined": {
+                "name": "outboundVolume"
+              }
+            }
+          },
+          {
+            "name": "checkpoints",
+            "docs": [
+              "balances at the record dates of the recent snapshots, slot `snapshot_id % MAX_CHECKPOINTS`"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "checkpoint"
+                  }
+                },
+                4
+              ]
+            }
           }
         ]
       }
@@ -2136,6 +8064,64 @@ export type AssetController = {
           }
         ]
       }
+    },
+    {
+      "name": "walletRecoveredEvent",
+      "docs": [
+        "emitted when the tokens and identity of a lost wallet are moved to a new wallet"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "caseReference",
+            "type": "string"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "identity",
+            "type": "pubkey"
+          },
+          {
+            "name": "lostWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "newWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withholdingRate",
+      "docs": [
+        "share of the claims withheld for the identities of a country group, the group of an identity being `mapping[country]` of the policy engine"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "type": "u8"
+          },
+          {
+            "name": "rateBps",
+            "type": "u16"
+          }
+        ]
+      }
     }
   ]
 };
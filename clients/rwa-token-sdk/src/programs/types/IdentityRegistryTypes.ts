@@ -40,24 +40,14 @@ export type IdentityRegistry = {
           "signer": true
         },
         {
-          "name": "identityRegistry"
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
         },
         {
           "name": "identityAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "identityRegistry"
-              },
-              {
-                "kind": "account",
-                "path": "identity_account.owner",
-                "account": "identityAccount"
-              }
-            ]
-          }
+          "writable": true
         },
         {
           "name": "systemProgram",
@@ -191,6 +181,85 @@ export type IdentityRegistry = {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         },
+        {
+          "name": "assetControllerProgram",
+          "optional": true,
+          "address": "7tXjmbkZVY3Gmg9kDBebcNXT1yC5pyoxxXVLwdbv9tvP"
+        },
+        {
+          "name": "assetController",
+          "optional": true
+        },
+        {
+          "name": "tokenAccountOwner",
+          "optional": true
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true
+        },
+        {
+          "name": "assetControllerSigner",
+          "docs": [
+            "signs when the asset controller thaws or freezes the token account itself, as during a wallet recovery"
+          ],
+          "signer": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                102,
+                89,
+                127,
+                105,
+                153,
+                104,
+                231,
+                6,
+                81,
+                85,
+                192,
+                80,
+                35,
+                31,
+                89,
+                115,
+                246,
+                148,
+                12,
+                38,
+                237,
+                44,
+                189,
+                34,
+                196,
+                160,
+                89,
+                167,
+                15,
+                131,
+                146,
+                20
+              ]
+            }
+          }
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -231,6 +300,101 @@ export type IdentityRegistry = {
         }
       ]
     },
+    {
+      "name": "changeBeneficialOwners",
+      "docs": [
+        "set the number of beneficial owners used by look-through counters"
+      ],
+      "discriminator": [
+        46,
+        183,
+        206,
+        231,
+        208,
+        111,
+        132,
+        33
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "writable": true
+        },
+        {
+          "name": "policyEngineProgram",
+          "address": "FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2"
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "trackerAccount"
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "beneficialOwners",
+          "type": "u32"
+        },
+        {
+          "name": "enforceLimits",
+          "type": "bool"
+        }
+      ]
+    },
     {
       "name": "changeCountry",
       "discriminator": [
@@ -253,24 +417,14 @@ export type IdentityRegistry = {
           "signer": true
         },
         {
-          "name": "identityRegistry"
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
         },
         {
           "name": "identityAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "identityRegistry"
-              },
-              {
-                "kind": "account",
-                "path": "identity_account.owner",
-                "account": "identityAccount"
-              }
-            ]
-          }
+          "writable": true
         },
         {
           "name": "policyEngineProgram",
@@ -333,6 +487,110 @@ export type IdentityRegistry = {
         }
       ]
     },
+    {
+      "name": "changeIdentityOwner",
+      "docs": [
+        "hand the identity over to another of its wallets, used when the owner wallet is recovered"
+      ],
+      "discriminator": [
+        2,
+        96,
+        179,
+        108,
+        3,
+        68,
+        112,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "docs": [
+            "the identity keeps the address derived from its first owner"
+          ],
+          "writable": true,
+          "relations": [
+            "walletIdentity"
+          ]
+        },
+        {
+          "name": "walletIdentity",
+          "docs": [
+            "the new owner must be a wallet of the identity"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "arg",
+                "path": "newOwner"
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "newOwner",
+          "type": "pubkey"
+        }
+      ]
+    },
     {
       "name": "createIdentityAccount",
       "docs": [
@@ -360,7 +618,8 @@ export type IdentityRegistry = {
           "signer": true
         },
         {
-          "name": "identityRegistry"
+          "name": "identityRegistry",
+          "writable": true
         },
         {
           "name": "identityAccount",
@@ -410,6 +669,32 @@ export type IdentityRegistry = {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         },
+        {
+          "name": "assetControllerProgram",
+          "optional": true,
+          "address": "7tXjmbkZVY3Gmg9kDBebcNXT1yC5pyoxxXVLwdbv9tvP"
+        },
+        {
+          "name": "assetController",
+          "optional": true
+        },
+        {
+          "name": "tokenAccountOwner",
+          "optional": true
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -654,6 +939,72 @@ export type IdentityRegistry = {
             "identityRegistry"
           ]
         },
+        {
+          "name": "assetControllerProgram",
+          "optional": true,
+          "address": "7tXjmbkZVY3Gmg9kDBebcNXT1yC5pyoxxXVLwdbv9tvP"
+        },
+        {
+          "name": "assetController",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "assetControllerSigner",
+          "docs": [
+            "signs when the asset controller thaws or freezes the token account itself, as during a wallet recovery"
+          ],
+          "signer": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                102,
+                89,
+                127,
+                105,
+                153,
+                104,
+                231,
+                6,
+                81,
+                85,
+                192,
+                80,
+                35,
+                31,
+                89,
+                115,
+                246,
+                148,
+                12,
+                38,
+                237,
+                44,
+                189,
+                34,
+                196,
+                160,
+                89,
+                167,
+                15,
+                131,
+                146,
+                20
+              ]
+            }
+          }
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -689,6 +1040,88 @@ export type IdentityRegistry = {
       ],
       "args": []
     },
+    {
+      "name": "migrateIdentityAccount",
+      "docs": [
+        "migrate identity account"
+      ],
+      "discriminator": [
+        214,
+        136,
+        6,
+        95,
+        215,
+        230,
+        6,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "identityRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "identity_registry.asset_mint",
+                "account": "identityRegistryAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "identityAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateIdentityRegistry",
+      "docs": [
+        "migrations of the accounts created by version 1 of the program",
+        "migrate identity registry, before any of its identities"
+      ],
+      "discriminator": [
+        251,
+        158,
+        85,
+        40,
+        193,
+        85,
+        140,
+        134
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "identityRegistry",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
     {
       "name": "removeLevelFromIdentityAccount",
       "docs": [
@@ -715,24 +1148,14 @@ export type IdentityRegistry = {
           "signer": true
         },
         {
-          "name": "identityRegistry"
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
         },
         {
           "name": "identityAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "identityRegistry"
-              },
-              {
-                "kind": "account",
-                "path": "identity_account.owner",
-                "account": "identityAccount"
-              }
-            ]
-          }
+          "writable": true
         },
         {
           "name": "systemProgram",
@@ -823,6 +1246,7 @@ export type IdentityRegistry = {
         },
         {
           "name": "identityRegistry",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -831,24 +1255,14 @@ export type IdentityRegistry = {
                 "account": "identityRegistryAccount"
               }
             ]
-          }
+          },
+          "relations": [
+            "identityAccount"
+          ]
         },
         {
           "name": "identityAccount",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "identityRegistry"
-              },
-              {
-                "kind": "account",
-                "path": "identity_account.owner",
-                "account": "identityAccount"
-              }
-            ]
-          },
           "relations": [
             "walletIdentity"
           ]
@@ -881,6 +1295,24 @@ export type IdentityRegistry = {
         {
           "name": "assetMint"
         },
+        {
+          "name": "assetControllerProgram",
+          "optional": true,
+          "address": "7tXjmbkZVY3Gmg9kDBebcNXT1yC5pyoxxXVLwdbv9tvP"
+        },
+        {
+          "name": "assetController",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "optional": true
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -990,6 +1422,19 @@ export type IdentityRegistry = {
         72
       ]
     },
+    {
+      "name": "changeBeneficialOwnersEvent",
+      "discriminator": [
+        153,
+        10,
+        98,
+        16,
+        169,
+        106,
+        171,
+        147
+      ]
+    },
     {
       "name": "changeCountryEvent",
       "discriminator": [
@@ -1003,6 +1448,19 @@ export type IdentityRegistry = {
         18
       ]
     },
+    {
+      "name": "changeIdentityOwnerEvent",
+      "discriminator": [
+        228,
+        83,
+        84,
+        39,
+        145,
+        160,
+        160,
+        149
+      ]
+    },
     {
       "name": "createdIdentityEvent",
       "discriminator": [
@@ -1111,6 +1569,21 @@ export type IdentityRegistry = {
       "code": 6010,
       "name": "tokenAccountNotEmpty",
       "msg": "Token account is not empty"
+    },
+    {
+      "code": 6011,
+      "name": "invalidBeneficialOwners",
+      "msg": "Beneficial owners count must be at least 1"
+    },
+    {
+      "code": 6012,
+      "name": "walletActivationAccountsMissing",
+      "msg": "Accounts to thaw or freeze the token account of the wallet are missing"
+    },
+    {
+      "code": 6013,
+      "name": "invalidAccountVersion",
+      "msg": "Account is not at the version migrated by this instruction"
     }
   ],
   "types": [
@@ -1168,6 +1641,30 @@ export type IdentityRegistry = {
         ]
       }
     },
+    {
+      "name": "changeBeneficialOwnersEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "identity",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficialOwners",
+            "type": "u32"
+          },
+          {
+            "name": "sender",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
       "name": "changeCountryEvent",
       "type": {
@@ -1192,6 +1689,34 @@ export type IdentityRegistry = {
         ]
       }
     },
+    {
+      "name": "changeIdentityOwnerEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "identity",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "sender",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
       "name": "createdIdentityEvent",
       "type": {
@@ -1284,6 +1809,13 @@ export type IdentityRegistry = {
             ],
             "type": "u8"
           },
+          {
+            "name": "beneficialOwners",
+            "docs": [
+              "number of beneficial owners behind this identity, used by look-through counters"
+            ],
+            "type": "u32"
+          },
           {
             "name": "levels",
             "type": {
@@ -1339,6 +1871,13 @@ export type IdentityRegistry = {
               "authority to manage the registry"
             ],
             "type": "pubkey"
+          },
+          {
+            "name": "identityCount",
+            "docs": [
+              "number of identity accounts, checked by the counter reconciliations of the policy engine"
+            ],
+            "type": "u64"
           }
         ]
       }
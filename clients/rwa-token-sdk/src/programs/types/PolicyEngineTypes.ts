@@ -205,20 +205,41 @@ export type PolicyEngine = {
         {
           "name": "customError",
           "type": "u8"
+        },
+        {
+          "name": "enforcementMode",
+          "type": {
+            "defined": {
+              "name": "enforcementMode"
+            }
+          }
+        },
+        {
+          "name": "applicability",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "policyApplicability"
+              }
+            }
+          }
         }
       ]
     },
     {
-      "name": "changeCounterLimits",
+      "name": "attachToPolicyPage",
+      "docs": [
+        "attach a policy to a policy page"
+      ],
       "discriminator": [
-        200,
-        2,
-        8,
+        184,
+        114,
+        159,
+        193,
+        173,
+        69,
         102,
-        43,
-        168,
-        141,
-        139
+        229
       ],
       "accounts": [
         {
@@ -232,6 +253,12 @@ export type PolicyEngine = {
         },
         {
           "name": "policyEngine",
+          "relations": [
+            "policyPage"
+          ]
+        },
+        {
+          "name": "policyPage",
           "writable": true
         },
         {
@@ -273,15 +300,39 @@ export type PolicyEngine = {
       ],
       "args": [
         {
-          "name": "removedCounterLimits",
-          "type": "bytes"
+          "name": "identityFilter",
+          "type": {
+            "defined": {
+              "name": "identityFilter"
+            }
+          }
         },
         {
-          "name": "addedCounterLimits",
+          "name": "policyType",
           "type": {
-            "vec": {
+            "defined": {
+              "name": "policyType"
+            }
+          }
+        },
+        {
+          "name": "customError",
+          "type": "u8"
+        },
+        {
+          "name": "enforcementMode",
+          "type": {
+            "defined": {
+              "name": "enforcementMode"
+            }
+          }
+        },
+        {
+          "name": "applicability",
+          "type": {
+            "option": {
               "defined": {
-                "name": "counterLimit"
+                "name": "policyApplicability"
               }
             }
           }
@@ -289,22 +340,24 @@ export type PolicyEngine = {
       ]
     },
     {
-      "name": "changeCounters",
+      "name": "cancelChange",
+      "docs": [
+        "drop a queued configuration change"
+      ],
       "discriminator": [
-        156,
-        107,
-        88,
-        204,
-        113,
-        131,
-        241,
-        192
+        100,
+        30,
+        4,
+        148,
+        3,
+        244,
+        243,
+        168
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "rentReceiver",
+          "writable": true
         },
         {
           "name": "signer",
@@ -312,11 +365,13 @@ export type PolicyEngine = {
         },
         {
           "name": "policyEngine",
-          "writable": true
+          "relations": [
+            "pendingChange"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "pendingChange",
+          "writable": true
         },
         {
           "name": "eventAuthority",
@@ -351,40 +406,24 @@ export type PolicyEngine = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "removedCounters",
-          "type": "bytes"
-        },
-        {
-          "name": "addedCounters",
-          "type": {
-            "vec": {
-              "defined": {
-                "name": "counter"
-              }
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "changeIssuancePolicies",
+      "name": "cancelReconciliation",
       "discriminator": [
-        186,
-        201,
-        163,
-        157,
-        32,
-        250,
-        166,
-        37
+        112,
+        173,
+        1,
+        102,
+        19,
+        189,
+        79,
+        118
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "rentReceiver",
+          "writable": true
         },
         {
           "name": "signer",
@@ -392,6 +431,12 @@ export type PolicyEngine = {
         },
         {
           "name": "policyEngine",
+          "relations": [
+            "reconciliation"
+          ]
+        },
+        {
+          "name": "reconciliation",
           "writable": true
         },
         {
@@ -427,28 +472,22 @@ export type PolicyEngine = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "issuancePolicies",
-          "type": {
-            "defined": {
-              "name": "issuancePolicies"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "changeMapping",
+      "name": "castVote",
+      "docs": [
+        "vote the record date balance of an identity, owned or delegated to the voter"
+      ],
       "discriminator": [
-        103,
-        1,
-        52,
         20,
-        160,
-        194,
-        113,
-        125
+        212,
+        15,
+        189,
+        69,
+        180,
+        69,
+        151
       ],
       "accounts": [
         {
@@ -458,12 +497,110 @@ export type PolicyEngine = {
         },
         {
           "name": "signer",
+          "docs": [
+            "owner of the voter identity"
+          ],
           "signer": true
         },
         {
           "name": "policyEngine",
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "proposal",
           "writable": true
         },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "voterIdentityAccount",
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "voterIdentityAccount"
+        },
+        {
+          "name": "identityAccount",
+          "docs": [
+            "identity whose balance is voted, the voter identity itself or an identity delegating to it"
+          ],
+          "relations": [
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount"
+        },
+        {
+          "name": "delegation",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "policyEngine"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -499,164 +636,95 @@ export type PolicyEngine = {
       ],
       "args": [
         {
-          "name": "mappingSource",
-          "type": "bytes"
-        },
-        {
-          "name": "mappingValue",
-          "type": "bytes"
+          "name": "option",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "closeTrackerAccount",
-      "docs": [
-        "close tracker account"
-      ],
+      "name": "changeCounterLimitEnforcementMode",
       "discriminator": [
-        191,
-        131,
-        63,
-        182,
-        65,
-        217,
-        37,
-        166
+        36,
+        136,
+        115,
+        162,
+        60,
+        189,
+        129,
+        255
       ],
       "accounts": [
         {
           "name": "payer",
-          "writable": true
+          "signer": true
         },
         {
-          "name": "identityRegistry",
-          "signer": true,
-          "relations": [
-            "identityAccount"
-          ]
+          "name": "signer",
+          "signer": true
         },
         {
-          "name": "assetMint",
-          "relations": [
-            "identityRegistry",
-            "trackerAccount"
-          ]
-        },
-        {
-          "name": "identityAccount",
-          "relations": [
-            "trackerAccount"
-          ]
-        },
-        {
-          "name": "trackerAccount",
+          "name": "policyEngine",
           "writable": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createPolicyEngine",
-      "docs": [
-        "create a policy registry"
-      ],
-      "discriminator": [
-        85,
-        105,
-        207,
-        153,
-        73,
-        125,
-        225,
-        54
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "signer",
-          "signer": true
-        },
-        {
-          "name": "assetMint"
-        },
-        {
-          "name": "policyEngineAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "assetMint"
-              }
-            ]
-          }
         },
         {
-          "name": "extraMetasAccount",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  95,
+                  95,
                   101,
-                  120,
+                  118,
+                  101,
+                  110,
                   116,
-                  114,
-                  97,
-                  45,
+                  95,
                   97,
-                  99,
-                  99,
-                  111,
                   117,
-                  110,
                   116,
-                  45,
-                  109,
-                  101,
+                  104,
+                  111,
+                  114,
+                  105,
                   116,
-                  97,
-                  115
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "assetMint"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "authority",
-          "type": "pubkey"
+          "name": "index",
+          "type": "u8"
+        },
+        {
+          "name": "enforcementMode",
+          "type": {
+            "defined": {
+              "name": "enforcementMode"
+            }
+          }
         }
       ]
     },
     {
-      "name": "createTrackerAccount",
-      "docs": [
-        "create tracker account"
-      ],
+      "name": "changeCounterLimits",
       "discriminator": [
-        40,
-        16,
-        40,
-        191,
-        109,
-        177,
-        83,
-        190
+        200,
+        2,
+        8,
+        102,
+        43,
+        168,
+        141,
+        139
       ],
       "accounts": [
         {
@@ -665,109 +733,82 @@ export type PolicyEngine = {
           "signer": true
         },
         {
-          "name": "identityAccount",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "identityRegistry"
-              },
-              {
-                "kind": "arg",
-                "path": "owner"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                231,
-                75,
-                81,
-                14,
-                232,
-                84,
-                45,
-                52,
-                3,
-                211,
-                48,
-                13,
-                45,
-                218,
-                249,
-                1,
-                6,
-                163,
-                235,
-                112,
-                36,
-                214,
-                213,
-                157,
-                141,
-                10,
-                56,
-                4,
-                197,
-                233,
-                153,
-                177
-              ]
-            }
-          }
+          "name": "signer",
+          "signer": true
         },
         {
-          "name": "identityRegistry",
-          "signer": true
+          "name": "policyEngine",
+          "writable": true
         },
         {
-          "name": "assetMint",
-          "relations": [
-            "identityRegistry"
-          ]
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "trackerAccount",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "assetMint"
-              },
-              {
-                "kind": "account",
-                "path": "identityAccount"
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "owner",
-          "type": "pubkey"
+          "name": "removedCounterLimits",
+          "type": "bytes"
+        },
+        {
+          "name": "addedCounterLimits",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "counterLimit"
+              }
+            }
+          }
+        },
+        {
+          "name": "skipLimitValidation",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "detachFromPolicyEngine",
-      "docs": [
-        "remove policy"
-      ],
+      "name": "changeCounters",
       "discriminator": [
         156,
-        137,
-        67,
-        121,
-        46,
-        207,
-        45,
-        12
+        107,
+        88,
+        204,
+        113,
+        131,
+        241,
+        192
       ],
       "accounts": [
         {
@@ -822,349 +863,284 @@ export type PolicyEngine = {
       ],
       "args": [
         {
-          "name": "hash",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "enforcePolicyIssuance",
-      "discriminator": [
-        204,
-        149,
-        175,
-        224,
-        136,
-        255,
-        219,
-        75
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "removedCounters",
+          "type": "bytes"
         },
         {
-          "name": "assetController",
-          "signer": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "assetMint"
+          "name": "addedCounters",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "counter"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                102,
-                89,
-                127,
-                105,
-                153,
-                104,
-                231,
-                6,
-                81,
-                85,
-                192,
-                80,
-                35,
-                31,
-                89,
-                115,
-                246,
-                148,
-                12,
-                38,
-                237,
-                44,
-                189,
-                34,
-                196,
-                160,
-                89,
-                167,
-                15,
-                131,
-                146,
-                20
-              ]
             }
           }
         },
         {
-          "name": "assetMint",
-          "relations": [
-            "policyEngine",
-            "identityRegistry"
-          ]
-        },
-        {
-          "name": "policyEngine",
-          "writable": true
-        },
-        {
-          "name": "destinationAccount"
-        },
-        {
-          "name": "identityRegistry",
-          "relations": [
-            "identityAccount"
-          ]
-        },
-        {
-          "name": "identityAccount",
-          "relations": [
-            "destinationTrackerAccount"
-          ]
-        },
-        {
-          "name": "destinationTrackerAccount",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "issuanceTimestamp",
-          "type": "i64"
+          "name": "skipLimitValidation",
+          "type": "bool"
         }
-      ],
-      "returns": "i64"
+      ]
     },
     {
-      "name": "enforcePolicyOnLevelsChange",
+      "name": "changeCountryCounters",
+      "docs": [
+        "add or remove counters keeping one holder count per country group"
+      ],
       "discriminator": [
-        236,
-        18,
-        123,
-        137,
-        253,
-        244,
-        32,
-        248
+        173,
+        33,
+        93,
+        170,
+        245,
+        189,
+        120,
+        121
       ],
       "accounts": [
         {
-          "name": "identityAccount"
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "identityRegistry",
-          "signer": true,
-          "relations": [
-            "identityAccount"
-          ]
+          "name": "signer",
+          "signer": true
         },
         {
-          "name": "assetMint",
-          "relations": [
-            "identityRegistry",
-            "policyEngine"
-          ]
+          "name": "policyEngine",
+          "writable": true
         },
         {
-          "name": "trackerAccount",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "path": "assetMint"
-              },
-              {
-                "kind": "account",
-                "path": "identityAccount"
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
               }
             ]
           }
         },
         {
-          "name": "policyEngine",
-          "writable": true
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "newLevels",
+          "name": "removedCountryCounters",
+          "type": "bytes"
+        },
+        {
+          "name": "addedCountryCounters",
           "type": {
-            "defined": {
-              "name": "newLevelsArgs"
+            "vec": {
+              "defined": {
+                "name": "countryCounter"
+              }
             }
           }
         },
         {
-          "name": "newCountry",
-          "type": "u8"
-        },
-        {
-          "name": "enforceLimits",
+          "name": "skipLimitValidation",
           "type": "bool"
         }
       ]
     },
     {
-      "name": "executeTransaction",
-      "docs": [
-        "execute transfer hook"
-      ],
+      "name": "changeIssuancePolicies",
       "discriminator": [
-        105,
-        37,
-        101,
-        197,
-        75,
-        251,
-        102,
-        26
+        186,
+        201,
+        163,
+        157,
+        32,
+        250,
+        166,
+        37
       ],
       "accounts": [
         {
-          "name": "sourceAccount"
-        },
-        {
-          "name": "assetMint"
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "destinationAccount"
+          "name": "signer",
+          "signer": true
         },
         {
-          "name": "ownerDelegate"
+          "name": "policyEngine",
+          "writable": true
         },
         {
-          "name": "extraMetasAccount",
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  95,
+                  95,
                   101,
-                  120,
+                  118,
+                  101,
+                  110,
                   116,
-                  114,
-                  97,
-                  45,
+                  95,
                   97,
-                  99,
-                  99,
-                  111,
                   117,
-                  110,
                   116,
-                  45,
-                  109,
-                  101,
+                  104,
+                  111,
+                  114,
+                  105,
                   116,
-                  97,
-                  115
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "assetMint"
               }
             ]
           }
         },
         {
-          "name": "policyEngineAccount",
-          "writable": true
-        },
-        {
-          "name": "identityRegistry",
-          "address": "GZsnjqT3c5zbHqsctrJ4EG4rbEfo7ZXyyUG7aDJNmxfA"
-        },
+          "name": "program"
+        }
+      ],
+      "args": [
         {
-          "name": "identityRegistryAccount"
-        },
+          "name": "issuancePolicies",
+          "type": {
+            "defined": {
+              "name": "issuancePolicies"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeMapping",
+      "discriminator": [
+        103,
+        1,
+        52,
+        20,
+        160,
+        194,
+        113,
+        125
+      ],
+      "accounts": [
         {
-          "name": "sourceWalletIdentity"
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "destinationWalletIdentity"
+          "name": "signer",
+          "signer": true
         },
         {
-          "name": "sourceIdentityAccount"
+          "name": "policyEngine",
+          "writable": true
         },
         {
-          "name": "destinationIdentityAccount"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "sourceTrackerAccount",
-          "writable": true
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "destinationTrackerAccount",
-          "writable": true
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "mappingSource",
+          "type": "bytes"
+        },
+        {
+          "name": "mappingValue",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "removeLock",
+      "name": "changePolicyApplicability",
+      "docs": [
+        "choose the lifecycle events a policy is evaluated on"
+      ],
       "discriminator": [
-        1,
-        17,
-        121,
-        74,
-        62,
-        241,
-        127,
-        120
+        181,
+        56,
+        89,
+        228,
+        113,
+        117,
+        37,
+        245
       ],
       "accounts": [
         {
           "name": "payer",
-          "writable": true,
           "signer": true
         },
         {
           "name": "signer",
           "signer": true
         },
-        {
-          "name": "assetMint",
-          "relations": [
-            "policyEngine",
-            "identityRegistry"
-          ]
-        },
         {
           "name": "policyEngine",
           "writable": true
         },
-        {
-          "name": "identityRegistry",
-          "relations": [
-            "identityAccount"
-          ]
-        },
-        {
-          "name": "identityAccount",
-          "relations": [
-            "trackerAccount"
-          ]
-        },
-        {
-          "name": "trackerAccount",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -1200,22 +1176,33 @@ export type PolicyEngine = {
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u8"
+          "name": "hash",
+          "type": "string"
+        },
+        {
+          "name": "applicability",
+          "type": {
+            "defined": {
+              "name": "policyApplicability"
+            }
+          }
         }
       ]
     },
     {
-      "name": "setCounters",
+      "name": "changePolicyEnforcementMode",
+      "docs": [
+        "switch a policy between enforce, monitor and disabled"
+      ],
       "discriminator": [
-        127,
-        151,
-        147,
-        141,
-        171,
-        53,
-        28,
-        135
+        111,
+        99,
+        183,
+        187,
+        45,
+        190,
+        250,
+        231
       ],
       "accounts": [
         {
@@ -1265,518 +1252,5942 @@ export type PolicyEngine = {
       ],
       "args": [
         {
-          "name": "changedCounters",
-          "type": "bytes"
+          "name": "hash",
+          "type": "string"
         },
         {
-          "name": "values",
+          "name": "enforcementMode",
           "type": {
-            "vec": "u64"
+            "defined": {
+              "name": "enforcementMode"
+            }
           }
         }
       ]
     },
     {
-      "name": "updateCountersOnBurn",
+      "name": "changeTimelock",
+      "docs": [
+        "change the delay of queued changes and whether direct changes are allowed"
+      ],
       "discriminator": [
-        22,
-        151,
-        139,
-        67,
-        21,
-        61,
-        191,
-        236
+        136,
+        159,
+        14,
+        110,
+        193,
+        243,
+        243,
+        243
       ],
       "accounts": [
         {
-          "name": "assetController",
+          "name": "payer",
+          "writable": true,
           "signer": true
         },
         {
-          "name": "assetMint",
-          "relations": [
-            "policyEngine",
-            "identityRegistry"
-          ]
+          "name": "signer",
+          "signer": true
         },
         {
           "name": "policyEngine",
           "writable": true
         },
         {
-          "name": "destinationAccount"
-        },
-        {
-          "name": "identityRegistry",
-          "relations": [
-            "identityAccount"
-          ]
-        },
-        {
-          "name": "identityAccount",
-          "relations": [
-            "destinationTrackerAccount"
-          ]
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "destinationTrackerAccount",
-          "writable": true
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "timelock",
+          "type": {
+            "defined": {
+              "name": "timelock"
+            }
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "identityAccount",
-      "discriminator": [
-        194,
-        90,
-        181,
-        160,
-        182,
-        206,
-        116,
-        158
-      ]
     },
     {
-      "name": "identityRegistryAccount",
+      "name": "changeTranche",
+      "docs": [
+        "change the window, caps, eligibility and lock period of a tranche"
+      ],
       "discriminator": [
-        154,
-        254,
-        118,
-        4,
-        115,
-        36,
-        125,
-        78
-      ]
-    },
-    {
-      "name": "policyEngineAccount",
-      "discriminator": [
-        124,
-        85,
-        205,
-        80,
-        2,
+        113,
+        173,
+        162,
+        109,
+        181,
         18,
-        26,
-        45
+        142,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "relations": [
+            "tranche"
+          ]
+        },
+        {
+          "name": "tranche",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "trancheConfig"
+            }
+          }
+        }
       ]
     },
     {
-      "name": "trackerAccount",
-      "discriminator": [
-        83,
-        95,
-        166,
-        148,
-        57,
-        30,
-        90,
-        210
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "attachPolicyEvent",
+      "name": "closePolicyPage",
+      "docs": [
+        "close an empty policy page"
+      ],
       "discriminator": [
+        34,
+        126,
+        188,
+        128,
+        198,
         104,
-        248,
-        239,
-        158,
         181,
-        172,
-        130,
-        47
-      ]
-    },
-    {
-      "name": "changedCounterLimitsEvent",
-      "discriminator": [
-        91,
-        102,
-        100,
-        27,
-        124,
-        137,
-        26,
-        180
-      ]
+        15
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true,
+          "relations": [
+            "policyPage"
+          ]
+        },
+        {
+          "name": "policyPage",
+          "writable": true
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "changedCountersEvent",
+      "name": "closeProposal",
+      "docs": [
+        "close a finalized proposal"
+      ],
       "discriminator": [
-        111,
-        121,
-        17,
-        44,
-        125,
-        218,
         213,
-        226
-      ]
+        178,
+        139,
+        19,
+        50,
+        191,
+        82,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "changedIssuancePoliciesEvent",
+      "name": "closeTrackerAccount",
+      "docs": [
+        "close tracker account"
+      ],
       "discriminator": [
-        225,
-        149,
-        140,
-        234,
-        143,
-        91,
-        92,
-        243
-      ]
-    },
-    {
-      "name": "changedMappingEvent",
-      "discriminator": [
-        199,
-        174,
-        161,
-        68,
-        233,
-        180,
-        13,
-        199
-      ]
-    },
-    {
-      "name": "detachPolicyEvent",
-      "discriminator": [
-        166,
-        167,
-        46,
-        102,
-        163,
-        198,
-        55,
-        44
-      ]
-    },
-    {
-      "name": "lockEvent",
-      "discriminator": [
-        76,
+        191,
+        131,
+        63,
+        182,
+        65,
+        217,
         37,
-        6,
-        186,
-        14,
-        42,
-        253,
-        15
-      ]
+        166
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "signer": true,
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "setCounterValueEvent",
+      "name": "closeTranche",
+      "docs": [
+        "close a tranche"
+      ],
       "discriminator": [
+        59,
+        61,
+        189,
+        199,
         124,
-        109,
-        69,
-        140,
-        144,
-        62,
-        60,
-        75
-      ]
+        115,
+        232,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "relations": [
+            "tranche"
+          ]
+        },
+        {
+          "name": "tranche",
+          "writable": true
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "transferEvent",
+      "name": "commitReconciliation",
+      "docs": [
+        "overwrite the counters with the reconciled values"
+      ],
       "discriminator": [
-        100,
+        74,
+        87,
+        71,
+        57,
         10,
-        46,
-        113,
-        8,
-        28,
-        179,
-        125
-      ]
+        154,
+        159,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true,
+          "relations": [
+            "reconciliation"
+          ]
+        },
+        {
+          "name": "identityRegistry"
+        },
+        {
+          "name": "reconciliation",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "unlockEvent",
+      "name": "createPolicyEngine",
+      "docs": [
+        "create a policy registry"
+      ],
       "discriminator": [
+        85,
         105,
-        1,
-        235,
-        144,
-        68,
-        123,
-        75,
-        123
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidPolicy",
-      "msg": "Invalid policy passed"
-    },
-    {
-      "code": 6001,
-      "name": "transactionAmountLimitExceeded",
-      "msg": "Transaction amount limit exceeded"
-    },
-    {
-      "code": 6002,
-      "name": "transactionAmountVelocityExceeded",
-      "msg": "Transaction amount velocity exceeded"
-    },
-    {
-      "code": 6003,
-      "name": "transactionCountVelocityExceeded",
+        207,
+        153,
+        73,
+        125,
+        225,
+        54
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "assetMint"
+        },
+        {
+          "name": "policyEngineAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "extraMetasAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "authority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "createPolicyPage",
+      "docs": [
+        "create an overflow page holding policies evaluated by the given scope"
+      ],
+      "discriminator": [
+        118,
+        53,
+        247,
+        179,
+        14,
+        93,
+        50,
+        195
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "policyPage",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u8"
+        },
+        {
+          "name": "scope",
+          "type": {
+            "defined": {
+              "name": "policyPageScope"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createProposal",
+      "docs": [
+        "create a proposal voted with the balances at the record date of the latest snapshot"
+      ],
+      "discriminator": [
+        132,
+        116,
+        68,
+        174,
+        216,
+        160,
+        198,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "policyEngine"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "proposalConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTrackerAccount",
+      "docs": [
+        "create tracker account"
+      ],
+      "discriminator": [
+        40,
+        16,
+        40,
+        191,
+        109,
+        177,
+        83,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "identityAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "identityRegistry"
+              },
+              {
+                "kind": "arg",
+                "path": "owner"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                231,
+                75,
+                81,
+                14,
+                232,
+                84,
+                45,
+                52,
+                3,
+                211,
+                48,
+                13,
+                45,
+                218,
+                249,
+                1,
+                6,
+                163,
+                235,
+                112,
+                36,
+                214,
+                213,
+                157,
+                141,
+                10,
+                56,
+                4,
+                197,
+                233,
+                153,
+                177
+              ]
+            }
+          }
+        },
+        {
+          "name": "identityRegistry",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "owner",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "createTranche",
+      "docs": [
+        "create an offering tranche issuances can be made against"
+      ],
+      "discriminator": [
+        36,
+        77,
+        222,
+        19,
+        226,
+        34,
+        56,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "tranche",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  99,
+                  104,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "policyEngine"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "trancheConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "delegateVotes",
+      "docs": [
+        "delegate the voting power of an identity to another identity"
+      ],
+      "discriminator": [
+        134,
+        214,
+        180,
+        254,
+        134,
+        143,
+        185,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "docs": [
+            "owner of the delegating identity"
+          ],
+          "signer": true
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount",
+            "delegateIdentityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount"
+        },
+        {
+          "name": "delegateIdentityAccount"
+        },
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "policyEngine"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "detachFromPolicyEngine",
+      "docs": [
+        "remove policy"
+      ],
+      "discriminator": [
+        156,
+        137,
+        67,
+        121,
+        46,
+        207,
+        45,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "hash",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "detachFromPolicyPage",
+      "docs": [
+        "remove a policy from a policy page"
+      ],
+      "discriminator": [
+        215,
+        181,
+        155,
+        105,
+        193,
+        147,
+        67,
+        76
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "relations": [
+            "policyPage"
+          ]
+        },
+        {
+          "name": "policyPage",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "hash",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "enforcePolicyIssuance",
+      "discriminator": [
+        204,
+        149,
+        175,
+        224,
+        136,
+        255,
+        219,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                102,
+                89,
+                127,
+                105,
+                153,
+                104,
+                231,
+                6,
+                81,
+                85,
+                192,
+                80,
+                35,
+                31,
+                89,
+                115,
+                246,
+                148,
+                12,
+                38,
+                237,
+                44,
+                189,
+                34,
+                196,
+                160,
+                89,
+                167,
+                15,
+                131,
+                146,
+                20
+              ]
+            }
+          }
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "policyEngine",
+          "writable": true,
+          "relations": [
+            "tranche"
+          ]
+        },
+        {
+          "name": "destinationAccount"
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "destinationTrackerAccount"
+          ]
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tranche",
+          "docs": [
+            "tranche the issuance is made against, if any"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "trancheSubscription",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  99,
+                  104,
+                  101,
+                  45,
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tranche"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "issuanceTimestamp",
+          "type": "i64"
+        }
+      ],
+      "returns": "i64"
+    },
+    {
+      "name": "enforcePolicyOnLevelsChange",
+      "discriminator": [
+        236,
+        18,
+        123,
+        137,
+        253,
+        244,
+        32,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "identityAccount"
+        },
+        {
+          "name": "identityRegistry",
+          "signer": true,
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry",
+            "policyEngine"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "identityAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newLevels",
+          "type": {
+            "defined": {
+              "name": "newLevelsArgs"
+            }
+          }
+        },
+        {
+          "name": "newCountry",
+          "type": "u8"
+        },
+        {
+          "name": "newBeneficialOwners",
+          "type": "u32"
+        },
+        {
+          "name": "enforceLimits",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "executeChange",
+      "docs": [
+        "apply a queued configuration change"
+      ],
+      "discriminator": [
+        104,
+        53,
+        136,
+        238,
+        82,
+        222,
+        200,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true,
+          "relations": [
+            "pendingChange"
+          ]
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "pendingChange",
+          "writable": true
+        },
+        {
+          "name": "policyPage",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "policyPageRentReceiver",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "execute transfer hook"
+      ],
+      "discriminator": [
+        105,
+        37,
+        101,
+        197,
+        75,
+        251,
+        102,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "sourceAccount"
+        },
+        {
+          "name": "assetMint"
+        },
+        {
+          "name": "destinationAccount"
+        },
+        {
+          "name": "ownerDelegate"
+        },
+        {
+          "name": "extraMetasAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "policyEngineAccount",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "address": "GZsnjqT3c5zbHqsctrJ4EG4rbEfo7ZXyyUG7aDJNmxfA"
+        },
+        {
+          "name": "identityRegistryAccount"
+        },
+        {
+          "name": "sourceWalletIdentity"
+        },
+        {
+          "name": "destinationWalletIdentity"
+        },
+        {
+          "name": "sourceIdentityAccount"
+        },
+        {
+          "name": "destinationIdentityAccount"
+        },
+        {
+          "name": "sourceTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeProposal",
+      "docs": [
+        "end a proposal after its voting window and emit the final tally"
+      ],
+      "discriminator": [
+        23,
+        68,
+        51,
+        167,
+        109,
+        173,
+        187,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "policyEngine",
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getSnapshotBalance",
+      "docs": [
+        "balance of a tracker at the record date of a snapshot"
+      ],
+      "discriminator": [
+        0,
+        84,
+        12,
+        101,
+        155,
+        195,
+        94,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount"
+        }
+      ],
+      "args": [
+        {
+          "name": "snapshotId",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "lockOnRedemption",
+      "discriminator": [
+        210,
+        123,
+        113,
+        11,
+        239,
+        81,
+        31,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                102,
+                89,
+                127,
+                105,
+                153,
+                104,
+                231,
+                6,
+                81,
+                85,
+                192,
+                80,
+                35,
+                31,
+                89,
+                115,
+                246,
+                148,
+                12,
+                38,
+                237,
+                44,
+                189,
+                34,
+                196,
+                160,
+                89,
+                167,
+                15,
+                131,
+                146,
+                20
+              ]
+            }
+          }
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "request",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "migratePolicyEngine",
+      "docs": [
+        "migrations of the accounts created by version 1 of the program"
+      ],
+      "discriminator": [
+        218,
+        180,
+        194,
+        43,
+        149,
+        108,
+        112,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateTrackerAccount",
+      "discriminator": [
+        74,
+        201,
+        17,
+        206,
+        19,
+        80,
+        104,
+        2
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "moveIssuancesOnSeize",
+      "discriminator": [
+        60,
+        105,
+        113,
+        56,
+        5,
+        57,
+        138,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "sourceTrackerAccount",
+            "destinationTrackerAccount"
+          ]
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "sourceTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "openSnapshot",
+      "docs": [
+        "open a record date snapshot of the holder balances at the current time"
+      ],
+      "discriminator": [
+        40,
+        146,
+        134,
+        77,
+        162,
+        196,
+        95,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openSnapshotOnDistribution",
+      "discriminator": [
+        91,
+        59,
+        123,
+        220,
+        62,
+        19,
+        20,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "assetController",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                102,
+                89,
+                127,
+                105,
+                153,
+                104,
+                231,
+                6,
+                81,
+                85,
+                192,
+                80,
+                35,
+                31,
+                89,
+                115,
+                246,
+                148,
+                12,
+                38,
+                237,
+                44,
+                189,
+                34,
+                196,
+                160,
+                89,
+                167,
+                15,
+                131,
+                146,
+                20
+              ]
+            }
+          }
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine"
+          ]
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "expiry",
+          "type": "i64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "proposeChange",
+      "docs": [
+        "queue a configuration change, executable once the timelock delay has passed"
+      ],
+      "discriminator": [
+        167,
+        211,
+        18,
+        222,
+        93,
+        215,
+        74,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "pendingChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  45,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "policyEngine"
+              },
+              {
+                "kind": "account",
+                "path": "policy_engine.next_change_id",
+                "account": "policyEngineAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": {
+              "name": "policyEngineChange"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "reconcileCounters",
+      "docs": [
+        "count a batch of (identity account, tracker account) pairs passed as remaining accounts"
+      ],
+      "discriminator": [
+        206,
+        71,
+        237,
+        21,
+        233,
+        204,
+        33,
+        14
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "relations": [
+            "reconciliation"
+          ]
+        },
+        {
+          "name": "identityRegistry"
+        },
+        {
+          "name": "reconciliation",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeExpiredTrancheLocks",
+      "discriminator": [
+        132,
+        150,
+        15,
+        244,
+        214,
+        4,
+        58,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeLock",
+      "discriminator": [
+        1,
+        17,
+        121,
+        74,
+        62,
+        241,
+        127,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setCounters",
+      "discriminator": [
+        127,
+        151,
+        147,
+        141,
+        171,
+        53,
+        28,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "changedCounters",
+          "type": "bytes"
+        },
+        {
+          "name": "values",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "skipLimitValidation",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "startReconciliation",
+      "docs": [
+        "start recomputing the counters from the tracker accounts"
+      ],
+      "discriminator": [
+        64,
+        101,
+        178,
+        194,
+        87,
+        146,
+        45,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "reconciliation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  111,
+                  110,
+                  99,
+                  105,
+                  108,
+                  105,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "policyEngine"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "takeIssuancesOnConversion",
+      "discriminator": [
+        158,
+        145,
+        135,
+        109,
+        2,
+        93,
+        25,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "assetController",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": {
+            "name": "issuance"
+          }
+        }
+      }
+    },
+    {
+      "name": "undelegateVotes",
+      "docs": [
+        "revoke the delegation of the voting power of an identity"
+      ],
+      "discriminator": [
+        3,
+        16,
+        78,
+        162,
+        64,
+        40,
+        201,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "docs": [
+            "owner of the delegating identity"
+          ],
+          "signer": true
+        },
+        {
+          "name": "policyEngine",
+          "relations": [
+            "delegation"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "delegation"
+          ]
+        },
+        {
+          "name": "delegation",
+          "writable": true
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unlockOnRedemption",
+      "discriminator": [
+        176,
+        81,
+        126,
+        208,
+        249,
+        129,
+        121,
+        167
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "assetController",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                102,
+                89,
+                127,
+                105,
+                153,
+                104,
+                231,
+                6,
+                81,
+                85,
+                192,
+                80,
+                35,
+                31,
+                89,
+                115,
+                246,
+                148,
+                12,
+                38,
+                237,
+                44,
+                189,
+                34,
+                196,
+                160,
+                89,
+                167,
+                15,
+                131,
+                146,
+                20
+              ]
+            }
+          }
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "trackerAccount"
+          ]
+        },
+        {
+          "name": "trackerAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "request",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateCountersOnBurn",
+      "discriminator": [
+        22,
+        151,
+        139,
+        67,
+        21,
+        61,
+        191,
+        236
+      ],
+      "accounts": [
+        {
+          "name": "assetController",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "destinationAccount"
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "identityAccount"
+          ]
+        },
+        {
+          "name": "identityAccount",
+          "relations": [
+            "destinationTrackerAccount"
+          ]
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateCountersOnSeize",
+      "discriminator": [
+        135,
+        94,
+        153,
+        116,
+        124,
+        172,
+        201,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "assetController",
+          "signer": true
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine",
+            "identityRegistry"
+          ]
+        },
+        {
+          "name": "policyEngine",
+          "writable": true
+        },
+        {
+          "name": "identityRegistry",
+          "relations": [
+            "sourceIdentityAccount",
+            "destinationIdentityAccount"
+          ]
+        },
+        {
+          "name": "sourceIdentityAccount"
+        },
+        {
+          "name": "destinationIdentityAccount"
+        },
+        {
+          "name": "sourceTrackerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "sourceIdentityAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "destinationTrackerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "assetMint"
+              },
+              {
+                "kind": "account",
+                "path": "destinationIdentityAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "enforceLimits",
+          "type": "bool"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "seizeCounterChanges"
+        }
+      }
+    },
+    {
+      "name": "updateExtraMetas",
+      "docs": [
+        "rewrite the extra metas of an existing mint to the current account list"
+      ],
+      "discriminator": [
+        123,
+        51,
+        189,
+        224,
+        34,
+        225,
+        56,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "policyEngine"
+        },
+        {
+          "name": "assetMint",
+          "relations": [
+            "policyEngine"
+          ]
+        },
+        {
+          "name": "extraMetasAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "delegationAccount",
+      "discriminator": [
+        84,
+        214,
+        213,
+        219,
+        161,
+        8,
+        165,
+        130
+      ]
+    },
+    {
+      "name": "identityAccount",
+      "discriminator": [
+        194,
+        90,
+        181,
+        160,
+        182,
+        206,
+        116,
+        158
+      ]
+    },
+    {
+      "name": "identityRegistryAccount",
+      "discriminator": [
+        154,
+        254,
+        118,
+        4,
+        115,
+        36,
+        125,
+        78
+      ]
+    },
+    {
+      "name": "pendingChangeAccount",
+      "discriminator": [
+        109,
+        101,
+        166,
+        254,
+        71,
+        150,
+        223,
+        115
+      ]
+    },
+    {
+      "name": "policyEngineAccount",
+      "discriminator": [
+        124,
+        85,
+        205,
+        80,
+        2,
+        18,
+        26,
+        45
+      ]
+    },
+    {
+      "name": "policyPageAccount",
+      "discriminator": [
+        104,
+        1,
+        252,
+        214,
+        137,
+        183,
+        22,
+        98
+      ]
+    },
+    {
+      "name": "proposalAccount",
+      "discriminator": [
+        164,
+        190,
+        4,
+        248,
+        203,
+        124,
+        243,
+        64
+      ]
+    },
+    {
+      "name": "reconciliationAccount",
+      "discriminator": [
+        91,
+        16,
+        194,
+        20,
+        121,
+        141,
+        179,
+        182
+      ]
+    },
+    {
+      "name": "trackerAccount",
+      "discriminator": [
+        83,
+        95,
+        166,
+        148,
+        57,
+        30,
+        90,
+        210
+      ]
+    },
+    {
+      "name": "trancheAccount",
+      "discriminator": [
+        62,
+        166,
+        190,
+        123,
+        218,
+        176,
+        48,
+        171
+      ]
+    },
+    {
+      "name": "trancheSubscriptionAccount",
+      "discriminator": [
+        206,
+        74,
+        124,
+        217,
+        250,
+        221,
+        139,
+        97
+      ]
+    },
+    {
+      "name": "voteAccount",
+      "discriminator": [
+        203,
+        238,
+        154,
+        106,
+        200,
+        131,
+        0,
+        41
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "attachPolicyEvent",
+      "discriminator": [
+        104,
+        248,
+        239,
+        158,
+        181,
+        172,
+        130,
+        47
+      ]
+    },
+    {
+      "name": "attachPolicyToPageEvent",
+      "discriminator": [
+        138,
+        248,
+        136,
+        158,
+        52,
+        129,
+        6,
+        217
+      ]
+    },
+    {
+      "name": "cancelledChangeEvent",
+      "discriminator": [
+        220,
+        0,
+        130,
+        51,
+        131,
+        107,
+        204,
+        252
+      ]
+    },
+    {
+      "name": "cancelledReconciliationEvent",
+      "discriminator": [
+        190,
+        194,
+        134,
+        228,
+        83,
+        208,
+        34,
+        124
+      ]
+    },
+    {
+      "name": "ChangedCounterLimitEnforcementModeEvent",
+      "discriminator": [
+        205,
+        31,
+        142,
+        7,
+        144,
+        62,
+        59,
+        88
+      ]
+    },
+    {
+      "name": "changedCounterLimitsEvent",
+      "discriminator": [
+        91,
+        102,
+        100,
+        27,
+        124,
+        137,
+        26,
+        180
+      ]
+    },
+    {
+      "name": "changedCountersEvent",
+      "discriminator": [
+        111,
+        121,
+        17,
+        44,
+        125,
+        218,
+        213,
+        226
+      ]
+    },
+    {
+      "name": "changedCountryCountersEvent",
+      "discriminator": [
+        101,
+        249,
+        39,
+        205,
+        128,
+        128,
+        166,
+        31
+      ]
+    },
+    {
+      "name": "changedIssuancePoliciesEvent",
+      "discriminator": [
+        225,
+        149,
+        140,
+        234,
+        143,
+        91,
+        92,
+        243
+      ]
+    },
+    {
+      "name": "changedMappingEvent",
+      "discriminator": [
+        199,
+        174,
+        161,
+        68,
+        233,
+        180,
+        13,
+        199
+      ]
+    },
+    {
+      "name": "changedPolicyApplicabilityEvent",
+      "discriminator": [
+        60,
+        229,
+        224,
+        93,
+        230,
+        151,
+        42,
+        253
+      ]
+    },
+    {
+      "name": "changedPolicyEnforcementModeEvent",
+      "discriminator": [
+        252,
+        48,
+        183,
+        88,
+        151,
+        212,
+        124,
+        73
+      ]
+    },
+    {
+      "name": "changedTimelockEvent",
+      "discriminator": [
+        46,
+        199,
+        62,
+        211,
+        184,
+        56,
+        122,
+        5
+      ]
+    },
+    {
+      "name": "changedTrancheEvent",
+      "discriminator": [
+        74,
+        250,
+        82,
+        143,
+        108,
+        77,
+        77,
+        5
+      ]
+    },
+    {
+      "name": "closedPolicyPageEvent",
+      "discriminator": [
+        203,
+        91,
+        201,
+        35,
+        61,
+        20,
+        116,
+        9
+      ]
+    },
+    {
+      "name": "closedProposalEvent",
+      "discriminator": [
+        66,
+        172,
+        36,
+        158,
+        156,
+        155,
+        46,
+        215
+      ]
+    },
+    {
+      "name": "closedTrancheEvent",
+      "discriminator": [
+        145,
+        165,
+        118,
+        99,
+        57,
+        219,
+        102,
+        54
+      ]
+    },
+    {
+      "name": "committedReconciliationEvent",
+      "discriminator": [
+        211,
+        201,
+        186,
+        225,
+        233,
+        32,
+        145,
+        151
+      ]
+    },
+    {
+      "name": "counterLimitViolationEvent",
+      "discriminator": [
+        187,
+        108,
+        40,
+        91,
+        48,
+        168,
+        251,
+        209
+      ]
+    },
+    {
+      "name": "counterLimitWarningEvent",
+      "discriminator": [
+        129,
+        230,
+        169,
+        108,
+        163,
+        99,
+        253,
+        137
+      ]
+    },
+    {
+      "name": "counterMismatchEvent",
+      "discriminator": [
+        83,
+        248,
+        63,
+        12,
+        65,
+        68,
+        53,
+        5
+      ]
+    },
+    {
+      "name": "createdPolicyPageEvent",
+      "discriminator": [
+        139,
+        177,
+        17,
+        84,
+        205,
+        167,
+        190,
+        183
+      ]
+    },
+    {
+      "name": "createdProposalEvent",
+      "discriminator": [
+        124,
+        96,
+        196,
+        220,
+        166,
+        51,
+        111,
+        174
+      ]
+    },
+    {
+      "name": "createdTrancheEvent",
+      "discriminator": [
+        177,
+        98,
+        22,
+        117,
+        113,
+        41,
+        85,
+        7
+      ]
+    },
+    {
+      "name": "delegatedVotesEvent",
+      "discriminator": [
+        185,
+        204,
+        87,
+        95,
+        145,
+        130,
+        158,
+        104
+      ]
+    },
+    {
+      "name": "detachPolicyEvent",
+      "discriminator": [
+        166,
+        167,
+        46,
+        102,
+        163,
+        198,
+        55,
+        44
+      ]
+    },
+    {
+      "name": "detachPolicyFromPageEvent",
+      "discriminator": [
+        17,
+        52,
+        66,
+        38,
+        82,
+        194,
+        125,
+        159
+      ]
+    },
+    {
+      "name": "executedChangeEvent",
+      "discriminator": [
+        195,
+        189,
+        26,
+        231,
+        193,
+        82,
+        64,
+        37
+      ]
+    },
+    {
+      "name": "finalizedProposalEvent",
+      "discriminator": [
+        253,
+        196,
+        127,
+        196,
+        29,
+        240,
+        36,
+        14
+      ]
+    },
+    {
+      "name": "lockEvent",
+      "discriminator": [
+        76,
+        37,
+        6,
+        186,
+        14,
+        42,
+        253,
+        15
+      ]
+    },
+    {
+      "name": "openedSnapshotEvent",
+      "discriminator": [
+        39,
+        10,
+        97,
+        250,
+        229,
+        197,
+        184,
+        116
+      ]
+    },
+    {
+      "name": "policyViolationEvent",
+      "discriminator": [
+        28,
+        36,
+        36,
+        84,
+        195,
+        133,
+        160,
+        248
+      ]
+    },
+    {
+      "name": "proposedChangeEvent",
+      "discriminator": [
+        238,
+        145,
+        16,
+        49,
+        19,
+        90,
+        35,
+        194
+      ]
+    },
+    {
+      "name": "setCounterValueEvent",
+      "discriminator": [
+        124,
+        109,
+        69,
+        140,
+        144,
+        62,
+        60,
+        75
+      ]
+    },
+    {
+      "name": "startedReconciliationEvent",
+      "discriminator": [
+        40,
+        196,
+        167,
+        115,
+        121,
+        202,
+        198,
+        31
+      ]
+    },
+    {
+      "name": "transferEvent",
+      "discriminator": [
+        100,
+        10,
+        46,
+        113,
+        8,
+        28,
+        179,
+        125
+      ]
+    },
+    {
+      "name": "undelegatedVotesEvent",
+      "discriminator": [
+        189,
+        70,
+        202,
+        116,
+        48,
+        186,
+        109,
+        229
+      ]
+    },
+    {
+      "name": "unlockEvent",
+      "discriminator": [
+        105,
+        1,
+        235,
+        144,
+        68,
+        123,
+        75,
+        123
+      ]
+    },
+    {
+      "name": "updatedExtraMetasEvent",
+      "discriminator": [
+        159,
+        24,
+        190,
+        86,
+        195,
+        21,
+        113,
+        233
+      ]
+    },
+    {
+      "name": "voteCastEvent",
+      "discriminator": [
+        241,
+        151,
+        159,
+        134,
+        250,
+        234,
+        71,
+        234
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidPolicy",
+      "msg": "Invalid policy passed"
+    },
+    {
+      "code": 6001,
+      "name": "transactionAmountLimitExceeded",
+      "msg": "Transaction amount limit exceeded"
+    },
+    {
+      "code": 6002,
+      "name": "transactionAmountVelocityExceeded",
+      "msg": "Transaction amount velocity exceeded"
+    },
+    {
+      "code": 6003,
+      "name": "transactionCountVelocityExceeded",
       "msg": "Transaction count velocity exceeded"
     },
     {
-      "code": 6004,
-      "name": "identityLevelLimitExceeded",
-      "msg": "Identity level limit exceeded"
+      "code": 6004,
+      "name": "identityLevelLimitExceeded",
+      "msg": "Identity level limit exceeded"
+    },
+    {
+      "code": 6005,
+      "name": "policyEngineFull",
+      "msg": "Policy registry is full, cannot add more policies"
+    },
+    {
+      "code": 6006,
+      "name": "policyNotFound",
+      "msg": "Policy not found"
+    },
+    {
+      "code": 6007,
+      "name": "identityFilterFailed",
+      "msg": "Identity filter failed"
+    },
+    {
+      "code": 6008,
+      "name": "unauthorizedSigner",
+      "msg": "Unauthorized signer"
+    },
+    {
+      "code": 6009,
+      "name": "policyAlreadyExists",
+      "msg": "Policy already exists"
+    },
+    {
+      "code": 6010,
+      "name": "maxBalanceExceeded",
+      "msg": "Max balance exceeded"
+    },
+    {
+      "code": 6011,
+      "name": "minBalanceExceeded",
+      "msg": "Min balance exceeded"
+    },
+    {
+      "code": 6012,
+      "name": "invalidCpiTransferAmount",
+      "msg": "Invalid CPI transfer amount"
+    },
+    {
+      "code": 6013,
+      "name": "invalidCpiTransferMint",
+      "msg": "Invalid CPI transfer mint"
+    },
+    {
+      "code": 6014,
+      "name": "invalidCpiTransferProgram",
+      "msg": "Invalid CPI transfer program"
+    },
+    {
+      "code": 6015,
+      "name": "invalidPdaPassedIn",
+      "msg": "Invalid PDA passed in"
+    },
+    {
+      "code": 6016,
+      "name": "transferHistoryFull",
+      "msg": "Transfer history full"
+    },
+    {
+      "code": 6017,
+      "name": "transferPaused",
+      "msg": "All Transfers have been paused"
+    },
+    {
+      "code": 6018,
+      "name": "forceFullTransfer",
+      "msg": "Expected source account to transfer full amount"
+    },
+    {
+      "code": 6019,
+      "name": "holderLimitExceeded",
+      "msg": "Holder limit exceeded"
+    },
+    {
+      "code": 6020,
+      "name": "balanceLimitExceeded",
+      "msg": "Balance limit exceeded"
+    },
+    {
+      "code": 6021,
+      "name": "trackerAccountOwnerMismatch",
+      "msg": "Tracker account owner mismatch"
+    },
+    {
+      "code": 6022,
+      "name": "forbiddenIdentityGroup",
+      "msg": "Forbidden identity group"
+    },
+    {
+      "code": 6023,
+      "name": "invalidIdentityAccount",
+      "msg": "Invalid identity account"
+    },
+    {
+      "code": 6024,
+      "name": "holdersLimitExceeded",
+      "msg": "Holders limit exceeded"
+    },
+    {
+      "code": 6025,
+      "name": "minMaxBalanceExceeded",
+      "msg": "Min max balance exceeded"
+    },
+    {
+      "code": 6026,
+      "name": "invalidPolicyEngineAccount",
+      "msg": "Invalid policy engine account"
+    },
+    {
+      "code": 6027,
+      "name": "percentageLimitExceeded",
+      "msg": "Percentage limit exceeded"
+    },
+    {
+      "code": 6028,
+      "name": "flowback",
+      "msg": "flowback"
+    },
+    {
+      "code": 6029,
+      "name": "invalidInstructionData",
+      "msg": "Invalid instruction data"
+    },
+    {
+      "code": 6030,
+      "name": "backdatingNotAllowed",
+      "msg": "Backdating not allowed"
+    },
+    {
+      "code": 6031,
+      "name": "maxSupplyExceeded",
+      "msg": "Max supply exceeded"
+    },
+    {
+      "code": 6032,
+      "name": "counterNotFound",
+      "msg": "Counter not found"
+    },
+    {
+      "code": 6033,
+      "name": "dataIsNotEmpty",
+      "msg": "Data is not empty"
+    },
+    {
+      "code": 6034,
+      "name": "holdUp",
+      "msg": "Tokens are held up"
+    },
+    {
+      "code": 6035,
+      "name": "tokensLocked",
+      "msg": "Tokens are locked"
+    },
+    {
+      "code": 6050,
+      "name": "forceAccredited"
+    },
+    {
+      "code": 6051,
+      "name": "forceAccreditedUs"
+    },
+    {
+      "code": 6052,
+      "name": "tokenPaused"
+    },
+    {
+      "code": 6053,
+      "name": "investorFullyLocked"
+    },
+    {
+      "code": 6054,
+      "name": "trackerAccountNotEmpty"
+    },
+    {
+      "code": 6055,
+      "name": "counterIdAlreadyExists",
+      "msg": "Counter id already exists"
+    },
+    {
+      "code": 6056,
+      "name": "counterIdNotFound",
+      "msg": "Counter id not found"
+    },
+    {
+      "code": 6057,
+      "name": "counterUnderflow",
+      "msg": "Counter underflow, please manually override the counter value"
+    },
+    {
+      "code": 6058,
+      "name": "counterOverflow",
+      "msg": "Counter overflow, please manually override the counter value"
+    },
+    {
+      "code": 6059,
+      "name": "balanceUnderflow",
+      "msg": "Balance underflow error"
+    },
+    {
+      "code": 6060,
+      "name": "balanceOverflow",
+      "msg": "Balance overflow error"
+    },
+    {
+      "code": 6061,
+      "name": "lockIndexNotFound",
+      "msg": "Lock index not found"
+    },
+    {
+      "code": 6062,
+      "name": "counterLimitIndexNotFound",
+      "msg": "Counter Limit index not found"
+    },
+    {
+      "code": 6063,
+      "name": "directChangesDisabled",
+      "msg": "Direct changes are disabled, changes must be proposed"
+    },
+    {
+      "code": 6064,
+      "name": "changeNotExecutable",
+      "msg": "Pending change is not executable yet"
+    },
+    {
+      "code": 6065,
+      "name": "invalidTimelockDelay",
+      "msg": "Timelock delay cannot be negative"
+    },
+    {
+      "code": 6066,
+      "name": "counterLimitViolated",
+      "msg": "Counter limit is already violated by the current counter values"
+    },
+    {
+      "code": 6067,
+      "name": "identityAccountsNotSorted",
+      "msg": "Identity accounts must be reconciled in ascending order"
+    },
+    {
+      "code": 6068,
+      "name": "reconciliationOutdated",
+      "msg": "Counters were added or removed since the reconciliation started"
+    },
+    {
+      "code": 6069,
+      "name": "invalidPolicyPageIndex",
+      "msg": "Transfer policy pages must use one of the reserved extra account meta slots"
+    },
+    {
+      "code": 6070,
+      "name": "invalidPolicyPage",
+      "msg": "Invalid policy page account"
+    },
+    {
+      "code": 6071,
+      "name": "policyPageMissing",
+      "msg": "A policy page referenced by the policy engine was not provided"
+    },
+    {
+      "code": 6072,
+      "name": "policyPageNotEmpty",
+      "msg": "Policy page still has policies attached"
+    },
+    {
+      "code": 6073,
+      "name": "invalidPolicyApplicability",
+      "msg": "Policy cannot be evaluated on one of the selected events"
+    },
+    {
+      "code": 6074,
+      "name": "affiliateVolumeLimitExceeded",
+      "msg": "Affiliate outbound volume over the rolling 3 month window exceeds the allowance"
+    },
+    {
+      "code": 6075,
+      "name": "invalidTrancheConfig",
+      "msg": "Invalid tranche configuration"
+    },
+    {
+      "code": 6076,
+      "name": "trancheNotOpen",
+      "msg": "Tranche is not open for issuance"
+    },
+    {
+      "code": 6077,
+      "name": "trancheCapExceeded",
+      "msg": "Tranche cap exceeded"
+    },
+    {
+      "code": 6078,
+      "name": "trancheSubscriptionOutOfBounds",
+      "msg": "Subscribed amount is outside the tranche per-investor limits"
+    },
+    {
+      "code": 6079,
+      "name": "trancheIdentityNotEligible",
+      "msg": "Identity is not eligible for the tranche"
+    },
+    {
+      "code": 6080,
+      "name": "trancheSubscriptionMissing",
+      "msg": "Tranche subscription account is required when issuing against a tranche"
+    },
+    {
+      "code": 6081,
+      "name": "snapshotUnavailable",
+      "msg": "Snapshot balance is not available for this tracker"
+    },
+    {
+      "code": 6082,
+      "name": "invalidProposalConfig",
+      "msg": "Invalid proposal configuration"
+    },
+    {
+      "code": 6083,
+      "name": "proposalNotOpen",
+      "msg": "Proposal is not open for voting"
+    },
+    {
+      "code": 6084,
+      "name": "invalidVoteOption",
+      "msg": "Invalid vote option"
+    },
+    {
+      "code": 6085,
+      "name": "voteNotDelegated",
+      "msg": "Voting power of the identity is not delegated to the voter"
+    },
+    {
+      "code": 6086,
+      "name": "identityNotEligibleToVote",
+      "msg": "Identity is not eligible to vote on the proposal"
+    },
+    {
+      "code": 6087,
+      "name": "noVotingPower",
+      "msg": "Identity has no voting power at the record date"
+    },
+    {
+      "code": 6088,
+      "name": "proposalNotFinalizable",
+      "msg": "Proposal can only be finalized once after its end"
+    },
+    {
+      "code": 6089,
+      "name": "proposalNotFinalized",
+      "msg": "Proposal must be finalized first"
+    },
+    {
+      "code": 6090,
+      "name": "invalidDelegation",
+      "msg": "Voting power cannot be delegated to the same identity"
+    },
+    {
+      "code": 6091,
+      "name": "snapshotInUse",
+      "msg": "Snapshot is still in use by a proposal or a distribution"
+    },
+    {
+      "code": 6092,
+      "name": "countryGroupNotEmpty",
+      "msg": "Country group still counts holders in a country counter"
+    },
+    {
+      "code": 6093,
+      "name": "reconciliationStale",
+      "msg": "Balances or identities changed since the reconciliation started"
+    },
+    {
+      "code": 6094,
+      "name": "reconciliationIncomplete",
+      "msg": "Reconciliation did not process every identity account of the registry"
+    },
+    {
+      "code": 6095,
+      "name": "invalidAccountVersion",
+      "msg": "Account is not at the version migrated by this instruction"
+    }
+  ],
+  "types": [
+    {
+      "name": "attachPolicyEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
+            }
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "customError",
+            "type": "u8"
+          },
+          {
+            "name": "enforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          },
+          {
+            "name": "applicability",
+            "type": {
+              "defined": {
+                "name": "policyApplicability"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "attachPolicyToPageEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "pageIndex",
+            "type": "u8"
+          },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
+            }
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "customError",
+            "type": "u8"
+          },
+          {
+            "name": "enforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          },
+          {
+            "name": "applicability",
+            "type": {
+              "defined": {
+                "name": "policyApplicability"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "cancelledChangeEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "policyEngineChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "cancelledReconciliationEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "processedIdentities",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChangedCounterLimitEnforcementModeEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          },
+          {
+            "name": "enforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          },
+          {
+            "name": "previousEnforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "changedCounterLimitsEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "removedCounterLimits",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "counterLimit"
+                }
+              }
+            }
+          },
+          {
+            "name": "addedCounterLimits",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "counterLimit"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "changedCountersEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "removedCounters",
+            "type": "bytes"
+          },
+          {
+            "name": "addedCounters",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "changedCountryCountersEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "removedCountryCounters",
+            "type": "bytes"
+          },
+          {
+            "name": "addedCountryCounters",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "changedIssuancePoliciesEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "issuancePolicies",
+            "type": {
+              "defined": {
+                "name": "issuancePolicies"
+              }
+            }
+          },
+          {
+            "name": "previousIssuancePolicies",
+            "type": {
+              "defined": {
+                "name": "issuancePolicies"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "policyEngineFull",
-      "msg": "Policy registry is full, cannot add more policies"
+      "name": "changedMappingEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "mappingSource",
+            "type": "bytes"
+          },
+          {
+            "name": "mappingValue",
+            "type": "bytes"
+          },
+          {
+            "name": "previousMapping",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "policyNotFound",
-      "msg": "Policy not found"
+      "name": "changedPolicyApplicabilityEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "hash",
+            "type": "string"
+          },
+          {
+            "name": "applicability",
+            "type": {
+              "defined": {
+                "name": "policyApplicability"
+              }
+            }
+          },
+          {
+            "name": "previousApplicability",
+            "type": {
+              "defined": {
+                "name": "policyApplicability"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "identityFilterFailed",
-      "msg": "Identity filter failed"
+      "name": "changedPolicyEnforcementModeEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "hash",
+            "type": "string"
+          },
+          {
+            "name": "enforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          },
+          {
+            "name": "previousEnforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "unauthorizedSigner",
-      "msg": "Unauthorized signer"
+      "name": "changedTimelockEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "timelock",
+            "type": {
+              "defined": {
+                "name": "timelock"
+              }
+            }
+          },
+          {
+            "name": "previousTimelock",
+            "type": {
+              "defined": {
+                "name": "timelock"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "policyAlreadyExists",
-      "msg": "Policy already exists"
+      "name": "changedTrancheEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tranche",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "trancheConfig"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "maxBalanceExceeded",
-      "msg": "Max balance exceeded"
+      "name": "checkpoint",
+      "docs": [
+        "balance of a tracker before its first change after a snapshot was opened"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "snapshotId",
+            "type": "u64"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "minBalanceExceeded",
-      "msg": "Min balance exceeded"
+      "name": "closedPolicyPageEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "invalidCpiTransferAmount",
-      "msg": "Invalid CPI transfer amount"
+      "name": "closedProposalEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "invalidCpiTransferMint",
-      "msg": "Invalid CPI transfer mint"
+      "name": "closedTrancheEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tranche",
+            "type": "pubkey"
+          },
+          {
+            "name": "issued",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "invalidCpiTransferProgram",
-      "msg": "Invalid CPI transfer program"
+      "name": "committedReconciliationEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "processedIdentities",
+            "type": "u64"
+          },
+          {
+            "name": "mismatches",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "counter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "countBeneficialOwners",
+            "docs": [
+              "counts every beneficial owner of an identity instead of the identity once"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "invalidPdaPassedIn",
-      "msg": "Invalid PDA passed in"
+      "name": "counterLimit",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "holdersLimit",
+            "fields": [
+              {
+                "name": "max",
+                "type": "u64"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "counterId",
+                "type": "u8"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningThreshold",
+                "docs": [
+                  "emits a warning event when the counter crosses this value, 0 to disable"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "groupedHoldersLimit",
+            "fields": [
+              {
+                "name": "max",
+                "type": "u64"
+              },
+              {
+                "name": "min",
+                "type": "u64"
+              },
+              {
+                "name": "counters",
+                "type": "bytes"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningThreshold",
+                "docs": [
+                  "emits a warning event when any of the counters crosses this value, 0 to disable"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "percentageLimit",
+            "fields": [
+              {
+                "name": "higherCounterId",
+                "type": "u8"
+              },
+              {
+                "name": "lowerCounterId",
+                "type": "u8"
+              },
+              {
+                "name": "minPercentage",
+                "type": "u8"
+              },
+              {
+                "name": "maxPercentage",
+                "type": "u8"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningPercentage",
+                "docs": [
+                  "emits a warning event when the percentage crosses this value, 0 to disable"
+                ],
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "countryHoldersLimit",
+            "fields": [
+              {
+                "name": "max",
+                "type": "u64"
+              },
+              {
+                "name": "counterId",
+                "type": "u8"
+              },
+              {
+                "name": "groups",
+                "docs": [
+                  "country groups the limit applies to, all groups if empty"
+                ],
+                "type": "bytes"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "warningThreshold",
+                "docs": [
+                  "emits a warning event when any of the groups crosses this value, 0 to disable"
+                ],
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "transferHistoryFull",
-      "msg": "Transfer history full"
+      "name": "counterLimitViolationEvent",
+      "docs": [
+        "emitted instead of failing when a monitored counter limit is violated"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "counterLimit",
+            "type": {
+              "defined": {
+                "name": "counterLimit"
+              }
+            }
+          },
+          {
+            "name": "errorCode",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "transferPaused",
-      "msg": "All Transfers have been paused"
+      "name": "counterLimitWarningEvent",
+      "docs": [
+        "emitted when a counter limit's warning threshold is crossed, value is a percentage for percentage limits"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "counterLimit",
+            "type": {
+              "defined": {
+                "name": "counterLimit"
+              }
+            }
+          },
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "aboveThreshold",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "forceFullTransfer",
-      "msg": "Expected source account to transfer full amount"
+      "name": "counterMismatchEvent",
+      "docs": [
+        "emitted on commit for every counter, or country group, whose value differs from the reconciled one"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "counterId",
+            "type": "u8"
+          },
+          {
+            "name": "group",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "reconciledValue",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "holderLimitExceeded",
-      "msg": "Holder limit exceeded"
+      "name": "countryCounter",
+      "docs": [
+        "holders counter keeping one value per country group, the group of an identity being `mapping[country]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "docs": [
+              "shares the id space of `counters`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "countBeneficialOwners",
+            "docs": [
+              "counts every beneficial owner of an identity instead of the identity once"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "values",
+            "docs": [
+              "value of each country group in use, padded with zeros when added and when the mapping uses new groups"
+            ],
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "balanceLimitExceeded",
-      "msg": "Balance limit exceeded"
+      "name": "createdPolicyPageEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "page",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "policyPageScope"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "trackerAccountOwnerMismatch",
-      "msg": "Tracker account owner mismatch"
+      "name": "createdProposalEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "snapshotId",
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "proposalConfig"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "forbiddenIdentityGroup",
-      "msg": "Forbidden identity group"
+      "name": "createdTrancheEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tranche",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "trancheConfig"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidIdentityAccount",
-      "msg": "Invalid identity account"
+      "name": "delegatedVotesEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "holdersLimitExceeded",
-      "msg": "Holders limit exceeded"
+      "name": "delegationAccount",
+      "docs": [
+        "voting power of an identity delegated to another identity, for all the proposals of the asset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "policyEngine",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "minMaxBalanceExceeded",
-      "msg": "Min max balance exceeded"
+      "name": "detachPolicyEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
+            }
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "customError",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "invalidPolicyEngineAccount",
-      "msg": "Invalid policy engine account"
+      "name": "detachPolicyFromPageEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "pageIndex",
+            "type": "u8"
+          },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
+            }
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "customError",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "percentageLimitExceeded",
-      "msg": "Percentage limit exceeded"
+      "name": "enforcementMode",
+      "docs": [
+        "how a policy or counter limit reacts when it is violated"
+      ],
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "enforce"
+          },
+          {
+            "name": "monitor"
+          },
+          {
+            "name": "disabled"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "flowback",
-      "msg": "flowback"
+      "name": "executedChangeEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "policyEngineChange"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6029,
-      "name": "invalidInstructionData",
-      "msg": "Invalid instruction data"
+      "name": "filterComparison",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "or"
+          },
+          {
+            "name": "and"
+          }
+        ]
+      }
     },
     {
-      "code": 6030,
-      "name": "backdatingNotAllowed",
-      "msg": "Backdating not allowed"
+      "name": "filterData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": {
+              "defined": {
+                "name": "filterLevel"
+              }
+            }
+          },
+          {
+            "name": "target",
+            "type": {
+              "defined": {
+                "name": "filterTarget"
+              }
+            }
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": {
+                "name": "filterMode"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6031,
-      "name": "maxSupplyExceeded",
-      "msg": "Max supply exceeded"
+      "name": "filterInner",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "single",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterData"
+                }
+              }
+            ]
+          },
+          {
+            "name": "tuple",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterData"
+                }
+              },
+              {
+                "defined": {
+                  "name": "filterComparison"
+                }
+              },
+              {
+                "defined": {
+                  "name": "filterData"
+                }
+              }
+            ]
+          },
+          {
+            "name": "multiple",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterComparison"
+                }
+              },
+              {
+                "vec": {
+                  "defined": {
+                    "name": "filterData"
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6032,
-      "name": "counterNotFound",
-      "msg": "Counter not found"
+      "name": "filterLevel",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "level",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "levelMappingAny",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "levelMapping",
+            "fields": [
+              {
+                "name": "source",
+                "type": "u8"
+              },
+              {
+                "name": "target",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "country",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "countryMapping",
+            "fields": [
+              "u8"
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6033,
-      "name": "dataIsNotEmpty",
-      "msg": "Data is not empty"
+      "name": "filterMode",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "include"
+          },
+          {
+            "name": "exclude"
+          }
+        ]
+      }
     },
     {
-      "code": 6034,
-      "name": "holdUp",
-      "msg": "Tokens are held up"
+      "name": "filterTarget",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "sender"
+          },
+          {
+            "name": "receiver"
+          },
+          {
+            "name": "bothAnd"
+          },
+          {
+            "name": "bothOr"
+          }
+        ]
+      }
     },
     {
-      "code": 6035,
-      "name": "tokensLocked",
-      "msg": "Tokens are locked"
+      "name": "finalizedProposalEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "tallies",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "totalVotes",
+            "type": "u64"
+          },
+          {
+            "name": "snapshotSupply",
+            "type": "u64"
+          },
+          {
+            "name": "quorumReached",
+            "type": "bool"
+          },
+          {
+            "name": "winningOption",
+            "docs": [
+              "none without votes or on a tie"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6050,
-      "name": "forceAccredited"
+      "name": "identityAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "version of the account"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "identityRegistry",
+            "docs": [
+              "identity registry to which the account belongs"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "owner of the identity account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "numWallets",
+            "docs": [
+              "number of wallets attached to this identity account"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "country",
+            "docs": [
+              "country code of the user"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "beneficialOwners",
+            "docs": [
+              "number of beneficial owners behind this identity, used by look-through counters"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "levels",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "identityLevel"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6051,
-      "name": "forceAccreditedUs"
+      "name": "identityFilter",
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "simple",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterInner"
+                }
+              }
+            ]
+          },
+          {
+            "name": "ifThen",
+            "fields": [
+              {
+                "defined": {
+                  "name": "filterInner"
+                }
+              },
+              {
+                "defined": {
+                  "name": "filterInner"
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6052,
-      "name": "tokenPaused"
+      "name": "identityLevel",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6053,
-      "name": "investorFullyLocked"
+      "name": "identityRegistryAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "assetMint",
+            "docs": [
+              "corresponding asset mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "authority to manage the registry"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "identityCount",
+            "docs": [
+              "number of identity accounts, checked by the counter reconciliations of the policy engine"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6054,
-      "name": "trackerAccountNotEmpty"
+      "name": "issuance",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "issueTime",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6055,
-      "name": "counterIdAlreadyExists",
-      "msg": "Counter id already exists"
+      "name": "issuancePolicies",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "disallowBackdating",
+            "type": "bool"
+          },
+          {
+            "name": "maxSupply",
+            "docs": [
+              "in base units, like the supply, before the scaled ui amount multiplier of the mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "usLockPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "nonUsLockPeriod",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6056,
-      "name": "counterIdNotFound",
-      "msg": "Counter id not found"
+      "name": "levelExpiry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6057,
-      "name": "counterUnderflow",
-      "msg": "Counter underflow, please manually override the counter value"
+      "name": "lock",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "releaseTime",
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "type": "u64"
+          },
+          {
+            "name": "reasonString",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6058,
-      "name": "counterOverflow",
-      "msg": "Counter overflow, please manually override the counter value"
+      "name": "lockEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "type": "u64"
+          },
+          {
+            "name": "reasonString",
+            "type": "string"
+          },
+          {
+            "name": "identity",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6059,
-      "name": "balanceUnderflow",
-      "msg": "Balance underflow error"
+      "name": "newLevelsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "levels",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "levelExpiry"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6060,
-      "name": "balanceOverflow",
-      "msg": "Balance overflow error"
+      "name": "openedSnapshotEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "recordTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "supply",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6061,
-      "name": "lockIndexNotFound",
-      "msg": "Lock index not found"
+      "name": "outboundVolume",
+      "docs": [
+        "outbound volume of the identity, kept in weekly buckets so the tracker does not grow in the transfer hook"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastBucket",
+            "docs": [
+              "week of the most recently written bucket"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "buckets",
+            "type": {
+              "array": [
+                "u64",
+                13
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6062,
-      "name": "counterLimitIndexNotFound",
-      "msg": "Counter Limit index not found"
-    }
-  ],
-  "types": [
-    {
-      "name": "attachPolicyEvent",
+      "name": "pendingChangeAccount",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "policyEngine",
+            "docs": [
+              "policy engine the change applies to"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "policyType",
+            "name": "id",
+            "docs": [
+              "sequential id of the change, part of the pda seeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "receives the rent back once the change is executed or cancelled"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "proposedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "docs": [
+              "earliest execution time at the delay in force when proposed, the delay at execution time applies"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "change",
             "type": {
               "defined": {
-                "name": "policyType"
+                "name": "policyEngineChange"
               }
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "policy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "hash",
+            "type": "string"
           },
           {
             "name": "identityFilter",
@@ -1786,34 +7197,194 @@ export type PolicyEngine = {
               }
             }
           },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
+            }
+          },
           {
             "name": "customError",
             "type": "u8"
+          },
+          {
+            "name": "enforcementMode",
+            "type": {
+              "defined": {
+                "name": "enforcementMode"
+              }
+            }
+          },
+          {
+            "name": "applicability",
+            "type": {
+              "defined": {
+                "name": "policyApplicability"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "changedCounterLimitsEvent",
+      "name": "policyApplicability",
+      "docs": [
+        "lifecycle events a policy is evaluated on"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
+            "name": "transfer",
+            "type": "bool"
+          },
+          {
+            "name": "issuance",
+            "type": "bool"
+          },
+          {
+            "name": "burn",
+            "docs": [
+              "burn and revoke"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "seize",
+            "docs": [
+              "transfers made by the permanent delegate"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "levelsChange",
+            "docs": [
+              "levels, country and beneficial owners changes"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "policyEngineAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "version"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "assetMint",
+            "docs": [
+              "asset mint"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "removedCounterLimits",
+            "name": "authority",
+            "docs": [
+              "authority of the registry"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mapping",
+            "docs": [
+              "generic mapping for levels"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                256
+              ]
+            }
+          },
+          {
+            "name": "issuancePolicies",
+            "docs": [
+              "policies to apply on issuance",
+              "these are partially for storage only"
+            ],
+            "type": {
+              "defined": {
+                "name": "issuancePolicies"
+              }
+            }
+          },
+          {
+            "name": "timelock",
+            "docs": [
+              "delay applied to proposed configuration changes"
+            ],
+            "type": {
+              "defined": {
+                "name": "timelock"
+              }
+            }
+          },
+          {
+            "name": "nextChangeId",
+            "docs": [
+              "id assigned to the next proposed configuration change"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "snapshot",
+            "docs": [
+              "record date snapshot checkpointed by the trackers"
+            ],
+            "type": {
+              "defined": {
+                "name": "snapshot"
+              }
+            }
+          },
+          {
+            "name": "holdersSequence",
+            "docs": [
+              "incremented when an identity starts or stops holding or the levels of a holder change, outdates running reconciliations"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "policies",
+            "docs": [
+              "policies to check on transfers or balance changes"
+            ],
             "type": {
               "vec": {
                 "defined": {
-                  "name": "counterLimit"
+                  "name": "policy"
                 }
               }
             }
           },
           {
-            "name": "addedCounterLimits",
+            "name": "counters",
+            "docs": [
+              "counters to track the number of holders depending on filters"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "counter"
+                }
+              }
+            }
+          },
+          {
+            "name": "counterLimits",
+            "docs": [
+              "limits to apply on existing counters"
+            ],
             "type": {
               "vec": {
                 "defined": {
@@ -1821,162 +7392,356 @@ export type PolicyEngine = {
                 }
               }
             }
+          },
+          {
+            "name": "countryCounters",
+            "docs": [
+              "counters keeping one holder count per country group"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "countryCounter"
+                }
+              }
+            }
+          },
+          {
+            "name": "policyPages",
+            "docs": [
+              "overflow pages holding additional policies"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "policyPageRef"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "changedCountersEvent",
+      "name": "policyEngineChange",
+      "docs": [
+        "configuration changes that can be queued on a policy engine"
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "attachPolicy",
+            "fields": [
+              {
+                "name": "identityFilter",
+                "type": {
+                  "defined": {
+                    "name": "identityFilter"
+                  }
+                }
+              },
+              {
+                "name": "policyType",
+                "type": {
+                  "defined": {
+                    "name": "policyType"
+                  }
+                }
+              },
+              {
+                "name": "customError",
+                "type": "u8"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "applicability",
+                "type": {
+                  "option": {
+                    "defined": {
+                      "name": "policyApplicability"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "detachPolicy",
+            "fields": [
+              {
+                "name": "hash",
+                "type": "string"
+              }
+            ]
           },
           {
-            "name": "removedCounters",
-            "type": "bytes"
+            "name": "changePolicyEnforcementMode",
+            "fields": [
+              {
+                "name": "hash",
+                "type": "string"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "addedCounters",
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "changedIssuancePoliciesEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "changeCounters",
+            "fields": [
+              {
+                "name": "removedCounters",
+                "type": "bytes"
+              },
+              {
+                "name": "addedCounters",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "counter"
+                    }
+                  }
+                }
+              },
+              {
+                "name": "skipLimitValidation",
+                "type": "bool"
+              }
+            ]
           },
           {
-            "name": "issuancePolicies",
-            "type": {
-              "defined": {
-                "name": "issuancePolicies"
+            "name": "changeCounterLimits",
+            "fields": [
+              {
+                "name": "removedCounterLimits",
+                "type": "bytes"
+              },
+              {
+                "name": "addedCounterLimits",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "counterLimit"
+                    }
+                  }
+                }
+              },
+              {
+                "name": "skipLimitValidation",
+                "type": "bool"
               }
-            }
+            ]
           },
           {
-            "name": "previousIssuancePolicies",
-            "type": {
-              "defined": {
-                "name": "issuancePolicies"
+            "name": "ChangeCounterLimitEnforcementMode",
+            "fields": [
+              {
+                "name": "index",
+                "type": "u8"
+              },
+              {
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
               }
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "changedMappingEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "pubkey"
+            ]
           },
           {
-            "name": "mappingSource",
-            "type": "bytes"
+            "name": "changeMapping",
+            "fields": [
+              {
+                "name": "mappingSource",
+                "type": "bytes"
+              },
+              {
+                "name": "mappingValue",
+                "type": "bytes"
+              }
+            ]
           },
           {
-            "name": "mappingValue",
-            "type": "bytes"
+            "name": "changeIssuancePolicies",
+            "fields": [
+              {
+                "name": "issuancePolicies",
+                "type": {
+                  "defined": {
+                    "name": "issuancePolicies"
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "previousMapping",
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "counter",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "value",
-            "type": "u64"
+            "name": "changeTimelock",
+            "fields": [
+              {
+                "name": "timelock",
+                "type": {
+                  "defined": {
+                    "name": "timelock"
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "id",
-            "type": "u8"
+            "name": "changeCountryCounters",
+            "fields": [
+              {
+                "name": "removedCountryCounters",
+                "type": "bytes"
+              },
+              {
+                "name": "addedCountryCounters",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "countryCounter"
+                    }
+                  }
+                }
+              },
+              {
+                "name": "skipLimitValidation",
+                "type": "bool"
+              }
+            ]
           },
           {
-            "name": "identityFilter",
-            "type": {
-              "defined": {
-                "name": "identityFilter"
+            "name": "changePolicyApplicability",
+            "fields": [
+              {
+                "name": "hash",
+                "type": "string"
+              },
+              {
+                "name": "applicability",
+                "type": {
+                  "defined": {
+                    "name": "policyApplicability"
+                  }
+                }
               }
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "counterLimit",
-      "type": {
-        "kind": "enum",
-        "variants": [
+            ]
+          },
           {
-            "name": "holdersLimit",
+            "name": "setCounters",
             "fields": [
               {
-                "name": "max",
-                "type": "u64"
+                "name": "changedCounters",
+                "type": "bytes"
               },
               {
-                "name": "min",
-                "type": "u64"
+                "name": "values",
+                "type": {
+                  "vec": "u64"
+                }
               },
               {
-                "name": "counterId",
-                "type": "u8"
+                "name": "skipLimitValidation",
+                "type": "bool"
               }
             ]
           },
           {
-            "name": "groupedHoldersLimit",
+            "name": "createPolicyPage",
             "fields": [
               {
-                "name": "max",
-                "type": "u64"
-              },
-              {
-                "name": "min",
-                "type": "u64"
+                "name": "index",
+                "type": "u8"
               },
               {
-                "name": "counters",
-                "type": "bytes"
+                "name": "scope",
+                "type": {
+                  "defined": {
+                    "name": "policyPageScope"
+                  }
+                }
               }
             ]
           },
           {
-            "name": "percentageLimit",
+            "name": "attachToPolicyPage",
             "fields": [
               {
-                "name": "higherCounterId",
+                "name": "index",
                 "type": "u8"
               },
               {
-                "name": "lowerCounterId",
+                "name": "identityFilter",
+                "type": {
+                  "defined": {
+                    "name": "identityFilter"
+                  }
+                }
+              },
+              {
+                "name": "policyType",
+                "type": {
+                  "defined": {
+                    "name": "policyType"
+                  }
+                }
+              },
+              {
+                "name": "customError",
                 "type": "u8"
               },
               {
-                "name": "minPercentage",
+                "name": "enforcementMode",
+                "type": {
+                  "defined": {
+                    "name": "enforcementMode"
+                  }
+                }
+              },
+              {
+                "name": "applicability",
+                "type": {
+                  "option": {
+                    "defined": {
+                      "name": "policyApplicability"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "detachFromPolicyPage",
+            "fields": [
+              {
+                "name": "index",
                 "type": "u8"
               },
               {
-                "name": "maxPercentage",
+                "name": "hash",
+                "type": "string"
+              }
+            ]
+          },
+          {
+            "name": "closePolicyPage",
+            "fields": [
+              {
+                "name": "index",
                 "type": "u8"
               }
             ]
@@ -1985,80 +7750,67 @@ export type PolicyEngine = {
       }
     },
     {
-      "name": "detachPolicyEvent",
+      "name": "policyPageAccount",
+      "docs": [
+        "overflow storage for policies that do not fit in the policy engine account"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "version",
+            "type": "u8"
           },
           {
-            "name": "policyType",
-            "type": {
-              "defined": {
-                "name": "policyType"
-              }
-            }
+            "name": "policyEngine",
+            "type": "pubkey"
           },
           {
-            "name": "identityFilter",
-            "type": {
-              "defined": {
-                "name": "identityFilter"
-              }
-            }
+            "name": "payer",
+            "type": "pubkey"
           },
           {
-            "name": "customError",
+            "name": "index",
             "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "filterComparison",
-      "repr": {
-        "kind": "rust"
-      },
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "or"
           },
           {
-            "name": "and"
-          }
-        ]
-      }
-    },
-    {
-      "name": "filterData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "level",
+            "name": "scope",
             "type": {
               "defined": {
-                "name": "filterLevel"
+                "name": "policyPageScope"
               }
             }
           },
           {
-            "name": "target",
+            "name": "policies",
             "type": {
-              "defined": {
-                "name": "filterTarget"
+              "vec": {
+                "defined": {
+                  "name": "policy"
+                }
               }
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "policyPageRef",
+      "docs": [
+        "reference to a policy page, kept on the policy engine"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "index",
+            "type": "u8"
           },
           {
-            "name": "mode",
+            "name": "scope",
             "type": {
               "defined": {
-                "name": "filterMode"
+                "name": "policyPageScope"
               }
             }
           }
@@ -2066,7 +7818,10 @@ export type PolicyEngine = {
       }
     },
     {
-      "name": "filterInner",
+      "name": "policyPageScope",
+      "docs": [
+        "operation evaluating the policies of a page, so each instruction only loads the pages it needs"
+      ],
       "repr": {
         "kind": "rust"
       },
@@ -2074,247 +7829,328 @@ export type PolicyEngine = {
         "kind": "enum",
         "variants": [
           {
-            "name": "single",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterData"
-                }
-              }
-            ]
+            "name": "transfer"
           },
           {
-            "name": "tuple",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterData"
-                }
-              },
-              {
-                "defined": {
-                  "name": "filterComparison"
-                }
-              },
-              {
-                "defined": {
-                  "name": "filterData"
-                }
-              }
-            ]
+            "name": "issuance"
           },
           {
-            "name": "multiple",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterComparison"
-                }
-              },
-              {
-                "vec": {
-                  "defined": {
-                    "name": "filterData"
-                  }
-                }
-              }
-            ]
+            "name": "levelsChange"
           }
         ]
       }
     },
     {
-      "name": "filterLevel",
+      "name": "policyType",
+      "docs": [
+        "amounts and limits are in base units, before the scaled ui amount multiplier of the mint.",
+        "splits do not change the base units held, so the limits keep applying to the same holdings."
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "level",
+            "name": "identityApproval"
+          },
+          {
+            "name": "transactionAmountLimit",
             "fields": [
-              "u8"
+              {
+                "name": "limit",
+                "type": "u64"
+              }
             ]
           },
           {
-            "name": "levelMappingAny",
+            "name": "maxBalance",
             "fields": [
-              "u8"
+              {
+                "name": "limit",
+                "type": "u64"
+              }
             ]
           },
           {
-            "name": "levelMapping",
+            "name": "minBalance",
             "fields": [
               {
-                "name": "source",
-                "type": "u8"
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "minMaxBalance",
+            "fields": [
+              {
+                "name": "min",
+                "type": "u64"
               },
               {
-                "name": "target",
-                "type": "u8"
+                "name": "max",
+                "type": "u64"
               }
             ]
           },
           {
-            "name": "country",
+            "name": "transferPause"
+          },
+          {
+            "name": "forbiddenIdentityGroup"
+          },
+          {
+            "name": "forceFullTransfer"
+          },
+          {
+            "name": "blockFlowbackEndTime",
             "fields": [
-              "u8"
+              {
+                "name": "time",
+                "type": "i64"
+              }
             ]
           },
           {
-            "name": "countryMapping",
+            "name": "affiliateVolumeLimit",
             "fields": [
-              "u8"
+              {
+                "name": "amount",
+                "type": "u64"
+              }
             ]
           }
         ]
       }
     },
     {
-      "name": "filterMode",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "policyViolationEvent",
+      "docs": [
+        "emitted instead of failing when a monitored policy is violated"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "include"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "exclude"
+            "name": "hash",
+            "type": "string"
+          },
+          {
+            "name": "policyType",
+            "type": {
+              "defined": {
+                "name": "policyType"
+              }
+            }
+          },
+          {
+            "name": "errorCode",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "filterTarget",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "proposalAccount",
+      "docs": [
+        "vote of the holders, weighted by their balances at the record date of a snapshot"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "sender"
+            "name": "version",
+            "type": "u8"
           },
           {
-            "name": "receiver"
+            "name": "policyEngine",
+            "type": "pubkey"
           },
           {
-            "name": "bothAnd"
+            "name": "payer",
+            "type": "pubkey"
           },
           {
-            "name": "bothOr"
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "snapshotId",
+            "docs": [
+              "snapshot the balances are read from"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "snapshotSupply",
+            "docs": [
+              "supply at the record date, the quorum is computed against it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "quorumBps",
+            "type": "u16"
+          },
+          {
+            "name": "finalized",
+            "type": "bool"
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          },
+          {
+            "name": "question",
+            "type": "string"
+          },
+          {
+            "name": "options",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "tallies",
+            "docs": [
+              "votes per option, in the order of the options"
+            ],
+            "type": {
+              "vec": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "identityAccount",
+      "name": "proposalConfig",
+      "docs": [
+        "question put to the holders, set by the authority"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
-            "docs": [
-              "version of the account"
-            ],
-            "type": "u8"
+            "name": "question",
+            "type": "string"
           },
           {
-            "name": "identityRegistry",
+            "name": "options",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "startTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "quorumBps",
             "docs": [
-              "identity registry to which the account belongs"
+              "share of the supply at the record date that must vote for the result to be valid"
             ],
-            "type": "pubkey"
+            "type": "u16"
           },
           {
-            "name": "owner",
+            "name": "identityFilter",
             "docs": [
-              "owner of the identity account"
+              "identities whose balances can be voted"
             ],
-            "type": "pubkey"
-          },
+            "type": {
+              "defined": {
+                "name": "identityFilter"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposedChangeEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "numWallets",
-            "docs": [
-              "number of wallets attached to this identity account"
-            ],
-            "type": "u16"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "country",
-            "docs": [
-              "country code of the user"
-            ],
-            "type": "u8"
+            "name": "id",
+            "type": "u64"
           },
           {
-            "name": "levels",
+            "name": "change",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "identityLevel"
-                }
+              "defined": {
+                "name": "policyEngineChange"
               }
             }
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "identityFilter",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "reconciledCounter",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "simple",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterInner"
-                }
-              }
-            ]
+            "name": "id",
+            "type": "u8"
           },
           {
-            "name": "ifThen",
-            "fields": [
-              {
-                "defined": {
-                  "name": "filterInner"
-                }
-              },
-              {
-                "defined": {
-                  "name": "filterInner"
-                }
-              }
-            ]
+            "name": "value",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "identityLevel",
+      "name": "reconciledCountryCounter",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "level",
+            "name": "id",
             "type": "u8"
           },
           {
-            "name": "expiry",
-            "type": "i64"
+            "name": "values",
+            "type": {
+              "vec": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "identityRegistryAccount",
+      "name": "reconciliationAccount",
+      "docs": [
+        "scratch account recomputing the counters of a policy engine from its tracker accounts"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -2323,369 +8159,374 @@ export type PolicyEngine = {
             "type": "u8"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "policyEngine",
+            "docs": [
+              "policy engine being reconciled"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "assetMint",
+            "name": "payer",
             "docs": [
-              "corresponding asset mint"
+              "receives the rent back once the reconciliation is committed or cancelled"
             ],
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "lastIdentityAccount",
             "docs": [
-              "authority to manage the registry"
+              "identity accounts are processed in ascending order so none is counted twice"
             ],
             "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "issuance",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
           },
           {
-            "name": "issueTime",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "issuancePolicies",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "disallowBackdating",
-            "type": "bool"
+            "name": "processedIdentities",
+            "type": "u64"
           },
           {
-            "name": "maxSupply",
+            "name": "holdersSequence",
+            "docs": [
+              "holders sequence of the policy engine when the reconciliation started"
+            ],
             "type": "u64"
           },
           {
-            "name": "usLockPeriod",
-            "type": "i64"
+            "name": "counters",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "reconciledCounter"
+                }
+              }
+            }
           },
           {
-            "name": "nonUsLockPeriod",
-            "type": "i64"
+            "name": "countryCounters",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "reconciledCountryCounter"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "levelExpiry",
+      "name": "seizeCounterChanges",
+      "docs": [
+        "holder counters changed by a seize"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "level",
-            "type": "u8"
+            "name": "decreasedCounters",
+            "type": "bytes"
           },
           {
-            "name": "expiry",
-            "type": "i64"
+            "name": "increasedCounters",
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "lock",
+      "name": "setCounterValueEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "releaseTime",
-            "type": "i64"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "reason",
-            "type": "u64"
+            "name": "counters",
+            "type": "bytes"
           },
           {
-            "name": "reasonString",
-            "type": "string"
+            "name": "values",
+            "type": {
+              "vec": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "lockEvent",
+      "name": "snapshot",
+      "docs": [
+        "latest record date snapshot of the holder balances, id 0 when none was opened"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
+            "name": "id",
             "type": "u64"
           },
           {
-            "name": "releaseTimestamp",
+            "name": "recordTimestamp",
             "type": "i64"
           },
           {
-            "name": "reason",
+            "name": "supply",
+            "docs": [
+              "supply of the asset at the record date"
+            ],
             "type": "u64"
           },
           {
-            "name": "reasonString",
-            "type": "string"
-          },
-          {
-            "name": "identity",
-            "type": "pubkey"
+            "name": "reservedUntil",
+            "docs": [
+              "time until which the readable snapshots are in use by proposals or distributions, slot `id % MAX_CHECKPOINTS`"
+            ],
+            "type": {
+              "array": [
+                "i64",
+                4
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "newLevelsArgs",
+      "name": "startedReconciliationEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "levels",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "levelExpiry"
-                }
-              }
-            }
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reconciliation",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "policy",
+      "name": "timelock",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "hash",
-            "type": "string"
-          },
-          {
-            "name": "identityFilter",
-            "type": {
-              "defined": {
-                "name": "identityFilter"
-              }
-            }
-          },
-          {
-            "name": "policyType",
-            "type": {
-              "defined": {
-                "name": "policyType"
-              }
-            }
+            "name": "delay",
+            "docs": [
+              "seconds between proposing a change and it becoming executable"
+            ],
+            "type": "i64"
           },
           {
-            "name": "customError",
-            "type": "u8"
+            "name": "directChangesEnabled",
+            "docs": [
+              "whether the authority can still change the engine without going through the queue"
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "policyEngineAccount",
+      "name": "trackerAccount",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "version",
-            "docs": [
-              "version"
-            ],
             "type": "u8"
           },
           {
             "name": "assetMint",
-            "docs": [
-              "asset mint"
-            ],
             "type": "pubkey"
           },
           {
-            "name": "authority",
-            "docs": [
-              "authority of the registry"
-            ],
+            "name": "identityAccount",
             "type": "pubkey"
           },
           {
-            "name": "mapping",
-            "docs": [
-              "generic mapping for levels"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                256
-              ]
-            }
+            "name": "totalAmount",
+            "type": "u64"
           },
           {
-            "name": "issuancePolicies",
-            "docs": [
-              "policies to apply on issuance",
-              "these are partially for storage only"
-            ],
+            "name": "issuances",
             "type": {
-              "defined": {
-                "name": "issuancePolicies"
+              "vec": {
+                "defined": {
+                  "name": "issuance"
+                }
               }
             }
           },
           {
-            "name": "policies",
-            "docs": [
-              "policies to check on transfers or balance changes"
-            ],
+            "name": "locks",
             "type": {
               "vec": {
                 "defined": {
-                  "name": "policy"
+                  "name": "lock"
                 }
               }
             }
           },
           {
-            "name": "counters",
-            "docs": [
-              "counters to track the number of holders depending on filters"
-            ],
+            "name": "outboundVolume",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "counter"
-                }
+              "defined": {
+                "name": "outboundVolume"
               }
             }
           },
           {
-            "name": "counterLimits",
+            "name": "checkpoints",
             "docs": [
-              "limits to apply on existing counters"
+              "balances at the record dates of the recent snapshots, slot `snapshot_id % MAX_CHECKPOINTS`"
             ],
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "counterLimit"
-                }
-              }
+              "array": [
+                {
+                  "defined": {
+                    "name": "checkpoint"
+                  }
+                },
+                4
+              ]
             }
           }
         ]
       }
     },
     {
-      "name": "policyType",
+      "name": "trancheAccount",
+      "docs": [
+        "offering tranche issuances can be made against"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "identityApproval"
+            "name": "version",
+            "type": "u8"
           },
           {
-            "name": "transactionAmountLimit",
-            "fields": [
-              {
-                "name": "limit",
-                "type": "u64"
-              }
-            ]
+            "name": "policyEngine",
+            "type": "pubkey"
           },
           {
-            "name": "maxBalance",
-            "fields": [
-              {
-                "name": "limit",
-                "type": "u64"
-              }
-            ]
+            "name": "payer",
+            "type": "pubkey"
           },
           {
-            "name": "minBalance",
-            "fields": [
-              {
-                "name": "limit",
-                "type": "u64"
-              }
-            ]
+            "name": "id",
+            "type": "u64"
           },
           {
-            "name": "minMaxBalance",
-            "fields": [
-              {
-                "name": "min",
-                "type": "u64"
-              },
-              {
-                "name": "max",
-                "type": "u64"
-              }
-            ]
+            "name": "openTimestamp",
+            "type": "i64"
           },
           {
-            "name": "transferPause"
+            "name": "closeTimestamp",
+            "type": "i64"
           },
           {
-            "name": "forbiddenIdentityGroup"
+            "name": "cap",
+            "type": "u64"
           },
           {
-            "name": "forceFullTransfer"
+            "name": "minSubscription",
+            "type": "u64"
           },
           {
-            "name": "blockFlowbackEndTime",
-            "fields": [
-              {
-                "name": "time",
-                "type": "i64"
+            "name": "maxSubscription",
+            "type": "u64"
+          },
+          {
+            "name": "lockPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "issued",
+            "docs": [
+              "amount issued through the tranche so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "identityFilter",
+            "type": {
+              "defined": {
+                "name": "identityFilter"
               }
-            ]
+            }
           }
         ]
       }
     },
     {
-      "name": "setCounterValueEvent",
+      "name": "trancheConfig",
+      "docs": [
+        "rules of a tranche, set by the authority"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "openTimestamp",
+            "type": "i64"
           },
           {
-            "name": "counters",
-            "type": "bytes"
+            "name": "closeTimestamp",
+            "type": "i64"
           },
           {
-            "name": "values",
+            "name": "cap",
+            "docs": [
+              "max amount issued through the tranche, 0 for no cap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minSubscription",
+            "docs": [
+              "min amount subscribed by an investor across the issuances of the tranche"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxSubscription",
+            "docs": [
+              "max amount subscribed by an investor across the issuances of the tranche, 0 for no max"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockPeriod",
+            "docs": [
+              "seconds the tokens issued through the tranche stay locked, 0 for no lock"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "identityFilter",
+            "docs": [
+              "identities allowed to subscribe"
+            ],
             "type": {
-              "vec": "u64"
+              "defined": {
+                "name": "identityFilter"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "trackerAccount",
+      "name": "trancheSubscriptionAccount",
+      "docs": [
+        "amount subscribed by an investor to a tranche"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -2694,7 +8535,7 @@ export type PolicyEngine = {
             "type": "u8"
           },
           {
-            "name": "assetMint",
+            "name": "tranche",
             "type": "pubkey"
           },
           {
@@ -2702,28 +8543,8 @@ export type PolicyEngine = {
             "type": "pubkey"
           },
           {
-            "name": "totalAmount",
+            "name": "subscribed",
             "type": "u64"
-          },
-          {
-            "name": "issuances",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "issuance"
-                }
-              }
-            }
-          },
-          {
-            "name": "locks",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "lock"
-                }
-              }
-            }
           }
         ]
       }
@@ -2748,6 +8569,26 @@ export type PolicyEngine = {
         ]
       }
     },
+    {
+      "name": "undelegatedVotesEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
       "name": "unlockEvent",
       "type": {
@@ -2775,6 +8616,95 @@ export type PolicyEngine = {
           }
         ]
       }
+    },
+    {
+      "name": "updatedExtraMetasEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteAccount",
+      "docs": [
+        "vote cast with the balance of an identity, prevents voting a balance twice"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "docs": [
+              "identity whose balance was voted"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "voter",
+            "docs": [
+              "identity that cast the vote, the delegate when the voting power was delegated"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "option",
+            "type": "u8"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "votedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteCastEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          },
+          {
+            "name": "identityAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "name": "option",
+            "type": "u8"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          }
+        ]
+      }
     }
   ]
 };
@@ -0,0 +1,203 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getIdentityAccountPda,
+	getIdentityRegistryEventAuthority,
+	getIdentityRegistryPda,
+	getIdentityRegistryProgram,
+	getRevokeIdentityAccountIx,
+	getWalletIdentityAccountPda,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, PublicKey, SystemProgram, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+	createAssociatedTokenAccountInstruction,
+	getAccount,
+	getAssociatedTokenAddressSync,
+	TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test kyc-gated token account activation", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	const getTokenAccount = (wallet: PublicKey) =>
+		getAssociatedTokenAddressSync(new PublicKey(mint), wallet, false, TOKEN_2022_PROGRAM_ID);
+	const isFrozen = async (wallet: PublicKey) => {
+		const tokenAccount = await getAccount(
+			setup.provider.connection,
+			getTokenAccount(wallet),
+			undefined,
+			TOKEN_2022_PROGRAM_ID
+		);
+		return tokenAccount.isFrozen;
+	};
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller whose token accounts are frozen by default
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry({
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+			defaultAccountStateFrozen: true,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+	});
+
+	test("token accounts created without an identity are frozen", async () => {
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(createAssociatedTokenAccountInstruction(
+				setup.payer,
+				getTokenAccount(setup.user2),
+				setup.user2,
+				new PublicKey(mint),
+				TOKEN_2022_PROGRAM_ID
+			)),
+			[setup.payerKp]
+		);
+		expect(await isFrozen(setup.user2)).toBe(true);
+	});
+
+	test("creating an identity thaws the token account of the owner", async () => {
+		for (const user of [setup.user1, setup.user3]) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				levels: [1],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+			expect(await isFrozen(user)).toBe(false);
+		}
+
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000),
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+	});
+
+	test("attaching a wallet without the activation accounts is rejected", async () => {
+		const identityProgram = getIdentityRegistryProgram(rwaClient.provider);
+		const attachWallet = await identityProgram.methods
+			.attachWalletToIdentity(setup.user2)
+			.accountsStrict({
+				payer: setup.payer,
+				authority: setup.user1,
+				assetMint: mint,
+				identityAccount: getIdentityAccountPda(mint, setup.user1.toString()),
+				identityRegistry: getIdentityRegistryPda(mint),
+				walletIdentity: getWalletIdentityAccountPda(mint, setup.user2.toString()),
+				systemProgram: SystemProgram.programId,
+				eventAuthority: getIdentityRegistryEventAuthority(),
+				program: identityProgram.programId,
+				assetControllerProgram: null,
+				assetController: null,
+				tokenAccountOwner: null,
+				tokenAccount: null,
+				tokenProgram: null,
+				associatedTokenProgram: null,
+				assetControllerSigner: null,
+			})
+			.instruction();
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(attachWallet),
+			[setup.payerKp, setup.user1Kp]
+		)).rejects.toThrowError(/custom program error: 0x177c/); // WalletActivationAccountsMissing error
+	});
+
+	test("attaching a wallet thaws its token account", async () => {
+		const attachWallet = await rwaClient.identityRegistry.attachWalletToIdentity({
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			payer: setup.payer.toString(),
+			wallet: setup.user2.toString(),
+			authority: setup.user1.toString(),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(attachWallet),
+			[setup.payerKp, setup.user1Kp]
+		);
+		expect(await isFrozen(setup.user2)).toBe(false);
+	});
+
+	test("detaching a wallet freezes its token account", async () => {
+		const detachWallet = await rwaClient.identityRegistry.detachWalletFromIdentity({
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			payer: setup.payer.toString(),
+			wallet: setup.user2.toString(),
+			authority: setup.authority.toString(),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(detachWallet),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(await isFrozen(setup.user2)).toBe(true);
+	});
+
+	test("revoking an identity freezes the token account of the owner", async () => {
+		const revokeIx = await getRevokeIdentityAccountIx({
+			owner: setup.user3.toString(),
+			assetMint: mint,
+			signer: setup.authority.toString(),
+			payer: setup.payer.toString(),
+		}, rwaClient.provider);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(revokeIx),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(await isFrozen(setup.user3)).toBe(true);
+	});
+});
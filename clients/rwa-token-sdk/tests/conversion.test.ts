@@ -0,0 +1,215 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	ConversionConfig,
+	getCloseConversionIx,
+	getConversionAccount,
+	getConvertSharesIx,
+	getCreateConversionIx,
+	getTrackerAccount,
+	getUpdateConversionIx,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test share-class conversion", async () => {
+	let rwaClient: RwaClient;
+	let classA: string;
+	let classB: string;
+	const setup = await setupTests();
+	const decimals = 2;
+	const issueTimestamp = Math.floor(Date.now() / 1000) - 30 * 24 * 60 * 60;
+
+	const getBalance = async (mint: string) => {
+		const tokenAccount = getAssociatedTokenAddressSync(new PublicKey(mint), setup.user1, false, TOKEN_2022_PROGRAM_ID);
+		const balance = await setup.provider.connection.getTokenAccountBalance(tokenAccount);
+		return balance.value.amount;
+	};
+	const convert = async (amount: number) => {
+		const convertShares = await getConvertSharesIx({
+			payer: setup.payer.toString(),
+			wallet: setup.user1.toString(),
+			sourceMint: classA,
+			destinationMint: classB,
+			amount: new BN(amount),
+		}, rwaClient.provider);
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(convertShares),
+			[setup.payerKp, setup.user1Kp]
+		);
+	};
+	const updateConversion = async (config: ConversionConfig) => {
+		const updateConversionIx = await getUpdateConversionIx({
+			sourceAuthority: setup.authority.toString(),
+			destinationAuthority: setup.authority.toString(),
+			sourceMint: classA,
+			destinationMint: classB,
+			config,
+		}, rwaClient.provider);
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(updateConversionIx),
+			[setup.payerKp, setup.authorityKp]
+		);
+	};
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create an asset controller per share class, user1 has an identity in both
+		const mints: string[] = [];
+		for (const symbol of ["TSTA", "TSTB"]) {
+			const setupAssetController = await rwaClient.assetController.setupNewRegistry({
+				decimals,
+				payer: setup.payer.toString(),
+				authority: setup.authority.toString(),
+				name: `Test Asset ${symbol}`,
+				uri: "https://test.com",
+				symbol,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupAssetController.ixs),
+				[setup.payerKp, ...setupAssetController.signers]
+			);
+			const mint = setupAssetController.signers[0].publicKey.toString();
+			mints.push(mint);
+
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: setup.user1.toString(),
+				assetMint: mint,
+				levels: [1],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+		}
+		[classA, classB] = mints;
+
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: classA,
+			amount: new BN(1000),
+			timestamp: new BN(issueTimestamp),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	test("fail to create a conversion with a zero ratio", async () => {
+		const createConversion = await getCreateConversionIx({
+			payer: setup.payer.toString(),
+			sourceAuthority: setup.authority.toString(),
+			destinationAuthority: setup.authority.toString(),
+			sourceMint: classA,
+			destinationMint: classB,
+			config: { ratioNumerator: new BN(3), ratioDenominator: new BN(0), preserveHoldingPeriods: true },
+		}, rwaClient.provider);
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(createConversion),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x178e/); // InvalidConversionRatio error
+	});
+
+	test("create a conversion at 3 class B shares per 2 class A shares", async () => {
+		const createConversion = await getCreateConversionIx({
+			payer: setup.payer.toString(),
+			sourceAuthority: setup.authority.toString(),
+			destinationAuthority: setup.authority.toString(),
+			sourceMint: classA,
+			destinationMint: classB,
+			config: { ratioNumerator: new BN(3), ratioDenominator: new BN(2), preserveHoldingPeriods: true },
+		}, rwaClient.provider);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(createConversion),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		const conversion = await getConversionAccount(classA, classB, rwaClient.provider);
+		expect(conversion?.ratioNumerator.toNumber()).toBe(3);
+		expect(conversion?.preserveHoldingPeriods).toBe(true);
+	});
+
+	test("fail to convert an amount worth less than a destination base unit", async () => {
+		await updateConversion({ ratioNumerator: new BN(1), ratioDenominator: new BN(3), preserveHoldingPeriods: true });
+		await expect(convert(2)).rejects.toThrowError(/custom program error: 0x178f/); // InvalidConversionAmount error
+		await updateConversion({ ratioNumerator: new BN(3), ratioDenominator: new BN(2), preserveHoldingPeriods: true });
+	});
+
+	test("convert shares preserving the holding period", async () => {
+		expect(await convert(400)).toBeTruthy();
+		expect(await getBalance(classA)).toBe("600");
+		expect(await getBalance(classB)).toBe("600");
+
+		const sourceTracker = await getTrackerAccount(classA, setup.user1.toString(), rwaClient.provider);
+		expect(sourceTracker?.totalAmount.toNumber()).toBe(600);
+		// the lots of the converted shares left the source tracker
+		expect(sourceTracker?.issuances.map((issuance) => issuance.amount.toNumber())).toEqual([600]);
+		const destinationTracker = await getTrackerAccount(classB, setup.user1.toString(), rwaClient.provider);
+		expect(destinationTracker?.totalAmount.toNumber()).toBe(600);
+		expect(destinationTracker?.issuances.map((issuance) => issuance.issueTime.toNumber())).toEqual([issueTimestamp]);
+	});
+
+	test("convert shares resetting the holding period", async () => {
+		await updateConversion({ ratioNumerator: new BN(3), ratioDenominator: new BN(2), preserveHoldingPeriods: false });
+		expect(await convert(100)).toBeTruthy();
+		expect(await getBalance(classB)).toBe("750");
+
+		const sourceTracker = await getTrackerAccount(classA, setup.user1.toString(), rwaClient.provider);
+		expect(sourceTracker?.issuances.map((issuance) => issuance.amount.toNumber())).toEqual([500]);
+
+		const destinationTracker = await getTrackerAccount(classB, setup.user1.toString(), rwaClient.provider);
+		expect(destinationTracker?.issuances.length).toBe(2);
+		expect(destinationTracker?.issuances[1].issueTime.toNumber()).toBeGreaterThan(issueTimestamp);
+	});
+
+	test("close the conversion", async () => {
+		const closeConversion = await getCloseConversionIx({
+			sourceAuthority: setup.authority.toString(),
+			destinationAuthority: setup.authority.toString(),
+			sourceMint: classA,
+			destinationMint: classB,
+			rentReceiver: setup.payer.toString(),
+		}, rwaClient.provider);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(closeConversion),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(await getConversionAccount(classA, classB, rwaClient.provider)).toBeUndefined();
+		await expect(convert(100)).rejects.toThrowError();
+	});
+});
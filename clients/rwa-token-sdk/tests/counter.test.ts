@@ -138,6 +138,7 @@ describe("counter tests", async () => {
 			assetMint: mint,
 			addedCounters: [{
 				id: 0,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -150,6 +151,7 @@ describe("counter tests", async () => {
 				value: new BN(0),
 			}, {
 				id: 1,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -162,6 +164,7 @@ describe("counter tests", async () => {
 				value: new BN(0),
 			}, {
 				id: 2,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -174,6 +177,7 @@ describe("counter tests", async () => {
 				value: new BN(0),
 			}, {
 				id: 3,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						tuple: [{
@@ -194,6 +198,7 @@ describe("counter tests", async () => {
 				value: new BN(0),
 			}, {
 				id: 4,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						tuple: [{
@@ -214,6 +219,7 @@ describe("counter tests", async () => {
 				value: new BN(0),
 			}, {
 				id: 5,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -396,6 +402,84 @@ describe("counter tests", async () => {
 		expect(policyAccount?.counters[5].value.toNumber()).toBe(3);
 	});
 
+
+	test("fail to add a counter limit already violated by the counter value", async () => {
+		const changeCounterLimitsIx = await rwaClient.policyEngine.changeCounterLimits({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounterLimits: Buffer.from([]),
+			addedCounterLimits: [{
+				holdersLimit: {
+					min: new BN(0),
+					max: new BN(10),
+					counterId: 1,
+					enforcementMode: { enforce: {} },
+					warningThreshold: new BN(0),
+				}
+			}],
+		});
+
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...changeCounterLimitsIx.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrow(/CounterLimitViolated/);
+	});
+
+	test("add a violated counter limit when skipping validation", async () => {
+		const changeCounterLimitsIx = await rwaClient.policyEngine.changeCounterLimits({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounterLimits: Buffer.from([]),
+			addedCounterLimits: [{
+				holdersLimit: {
+					min: new BN(0),
+					max: new BN(10),
+					counterId: 1,
+					enforcementMode: { enforce: {} },
+					warningThreshold: new BN(0),
+				}
+			}],
+			skipLimitValidation: true,
+		});
+
+		let txnId = await sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...changeCounterLimitsIx.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const setCountersIx = await rwaClient.policyEngine.setCounters({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			changedCounters: [1],
+			values: [new BN(2000)],
+		});
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...setCountersIx.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrow(/CounterLimitViolated/);
+
+		const removeCounterLimitsIx = await rwaClient.policyEngine.changeCounterLimits({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounterLimits: Buffer.from([0]),
+			addedCounterLimits: [],
+		});
+		txnId = await sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...removeCounterLimitsIx.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+	});
+
 	
 	test("remove counter", async () => {
 		const policyAccountBefore = await getPolicyEngineAccount(mint, rwaClient.provider);
@@ -430,6 +514,7 @@ describe("counter tests", async () => {
 			assetMint: mint,
 			addedCounters: [{
 				id: 0,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -458,6 +543,7 @@ describe("counter tests", async () => {
 			assetMint: mint,
 			addedCounters: [{
 				id: 20,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -470,6 +556,7 @@ describe("counter tests", async () => {
 				value: new BN(0),
 			}, {
 				id: 20,
+				countBeneficialOwners: false,
 				identityFilter: {
 					simple: [{
 						single: [{
@@ -0,0 +1,308 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getBalanceMerkleTree,
+	getClaimDistributionIx,
+	getCreateDistributionIx,
+	getDistributionAccount,
+	getDistributionClaimAccount,
+	getIdentityAccountPda,
+	getReclaimDistributionIx,
+	DistributionSnapshot,
+	RwaClient,
+	WithholdingRate,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Keypair, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+	createMint,
+	getAccount,
+	getOrCreateAssociatedTokenAccount,
+	mintTo,
+} from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test pro-rata distributions", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	let payoutMint: string;
+	let fundingAccount: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	const distributionArgs = (id: number) => ({
+		distributionId: new BN(id),
+		assetMint: mint,
+		payoutMint,
+	});
+	const createDistribution = async (
+		id: number,
+		snapshot: DistributionSnapshot,
+		expiry: number,
+		withholding?: { withholdingRates: WithholdingRate[]; taxVault: string }
+	) => sendAndConfirmTransaction(
+		setup.provider.connection,
+		new Transaction().add(await getCreateDistributionIx({
+			...distributionArgs(id),
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			fundingAccount,
+			snapshot,
+			amount: new BN(1000),
+			expiry: new BN(expiry),
+			...withholding,
+		}, rwaClient.provider)),
+		[setup.payerKp, setup.authorityKp]
+	);
+	const claim = async (id: number, owner: PublicKey, proof?: { balance: BN; proof: number[][] }) => sendAndConfirmTransaction(
+		setup.provider.connection,
+		new Transaction().add(await getClaimDistributionIx({
+			...distributionArgs(id),
+			payer: setup.payer.toString(),
+			owner: owner.toString(),
+			proof,
+		}, rwaClient.provider)),
+		[setup.payerKp]
+	);
+	const transfer = async (from: Keypair, to: PublicKey, amount: number) => sendAndConfirmTransaction(
+		setup.provider.connection,
+		new Transaction().add(...await rwaClient.assetController.transfer({
+			from: from.publicKey.toString(),
+			to: to.toString(),
+			assetMint: mint,
+			amount: new BN(amount),
+			decimals,
+			createTa: true,
+		})),
+		[from]
+	);
+	const getPayoutBalance = async (owner: PublicKey) => {
+		const account = await getOrCreateAssociatedTokenAccount(
+			setup.provider.connection, setup.payerKp, new PublicKey(payoutMint), owner
+		);
+		return Number((await getAccount(setup.provider.connection, account.address)).amount);
+	};
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup holders, user1 holds 3/4 of the supply and user2 1/4 from another country
+		for (const [user, amount, country] of [[setup.user1, 300, 0], [setup.user2, 100, 1]] as const) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				levels: [1],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+			const issueTokens = await rwaClient.assetController.issueTokenIxns({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				amount: new BN(amount),
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueTokens),
+				[setup.payerKp, setup.authorityKp]
+			);
+		}
+
+		// Create the payout token and fund the authority
+		const payoutMintKey = await createMint(setup.provider.connection, setup.payerKp, setup.authority, null, 6);
+		payoutMint = payoutMintKey.toString();
+		const authorityAccount = await getOrCreateAssociatedTokenAccount(
+			setup.provider.connection, setup.payerKp, payoutMintKey, setup.authority
+		);
+		await mintTo(setup.provider.connection, setup.payerKp, payoutMintKey, authorityAccount.address, setup.authorityKp, 10_000);
+		fundingAccount = authorityAccount.address.toString();
+	});
+
+	describe("tracker snapshot", () => {
+		test("create distribution against the supply", async () => {
+			expect(await createDistribution(0, { tracker: {} }, Math.floor(Date.now() / 1000) + 24 * 60 * 60)).toBeTruthy();
+			const distribution = await getDistributionAccount(mint, new BN(0), rwaClient.provider);
+			expect(distribution?.snapshotSupply.toNumber()).toBe(400);
+			expect(distribution?.totalAmount.toNumber()).toBe(1000);
+		});
+
+		test("holders claim their pro-rata share", async () => {
+			expect(await claim(0, setup.user1)).toBeTruthy();
+			expect(await claim(0, setup.user2)).toBeTruthy();
+			expect(await getPayoutBalance(setup.user1)).toBe(750);
+			expect(await getPayoutBalance(setup.user2)).toBe(250);
+			const receipt = await getDistributionClaimAccount(
+				mint, new BN(0), getIdentityAccountPda(mint, setup.user1.toString()).toString(), rwaClient.provider
+			);
+			expect(receipt?.amount.toNumber()).toBe(750);
+		});
+
+		test("fail to claim twice", async () => {
+			await expect(claim(0, setup.user1)).rejects.toThrowError(/already in use/);
+		});
+
+		test("tokens transferred after the record date are not claimed again", async () => {
+			expect(await createDistribution(5, { tracker: {} }, Math.floor(Date.now() / 1000) + 24 * 60 * 60)).toBeTruthy();
+			const distribution = await getDistributionAccount(mint, new BN(5), rwaClient.provider);
+			expect(distribution?.snapshotId.toNumber()).toBe(2);
+
+			const user1Before = await getPayoutBalance(setup.user1);
+			const user2Before = await getPayoutBalance(setup.user2);
+			expect(await claim(5, setup.user1)).toBeTruthy();
+			expect(await transfer(setup.user1Kp, setup.user2, 300)).toBeTruthy();
+			expect(await claim(5, setup.user2)).toBeTruthy();
+			expect(await getPayoutBalance(setup.user1) - user1Before).toBe(750);
+			expect(await getPayoutBalance(setup.user2) - user2Before).toBe(250);
+			expect((await getDistributionAccount(mint, new BN(5), rwaClient.provider))?.claimedAmount.toNumber()).toBe(1000);
+
+			expect(await transfer(setup.user2Kp, setup.user1, 300)).toBeTruthy();
+		});
+	});
+
+	describe("merkle snapshot", () => {
+		const getTree = () => getBalanceMerkleTree([
+			{ identityAccount: getIdentityAccountPda(mint, setup.user1.toString()), balance: new BN(300) },
+			{ identityAccount: getIdentityAccountPda(mint, setup.user2.toString()), balance: new BN(100) },
+		]);
+
+		test("create distribution against a committed root", async () => {
+			const tree = getTree();
+			expect(await createDistribution(1, {
+				merkle: { root: tree.root, totalBalance: tree.totalBalance },
+			}, Math.floor(Date.now() / 1000) + 24 * 60 * 60)).toBeTruthy();
+		});
+
+		test("fail to claim with a wrong balance", async () => {
+			const proof = getTree().getProof(getIdentityAccountPda(mint, setup.user1.toString()));
+			await expect(claim(1, setup.user1, { ...proof, balance: new BN(400) })).rejects.toThrowError(/custom program error: 0x1789/); // InvalidBalanceProof error
+		});
+
+		test("claim with a proof", async () => {
+			const proof = getTree().getProof(getIdentityAccountPda(mint, setup.user1.toString()));
+			expect(await claim(1, setup.user1, proof)).toBeTruthy();
+			expect(await getPayoutBalance(setup.user1)).toBe(1500);
+		});
+	});
+
+	describe("withholding", () => {
+		test("claims are split by the rate of the country group", async () => {
+			const changeMapping = await rwaClient.policyEngine.changeMapping({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				mappingSource: [1],
+				mappingValue: [5],
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...changeMapping.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			const taxVault = await getOrCreateAssociatedTokenAccount(
+				setup.provider.connection, setup.payerKp, new PublicKey(payoutMint), setup.user4
+			);
+			expect(await createDistribution(3, { tracker: {} }, Math.floor(Date.now() / 1000) + 24 * 60 * 60, {
+				withholdingRates: [{ group: 5, rateBps: 3000 }],
+				taxVault: taxVault.address.toString(),
+			})).toBeTruthy();
+
+			const user1Before = await getPayoutBalance(setup.user1);
+			const user2Before = await getPayoutBalance(setup.user2);
+			expect(await claim(3, setup.user1)).toBeTruthy();
+			expect(await claim(3, setup.user2)).toBeTruthy();
+			expect(await getPayoutBalance(setup.user1) - user1Before).toBe(750);
+			expect(await getPayoutBalance(setup.user2) - user2Before).toBe(175);
+			expect(await getPayoutBalance(setup.user4)).toBe(75);
+			const receipt = await getDistributionClaimAccount(
+				mint, new BN(3), getIdentityAccountPda(mint, setup.user2.toString()).toString(), rwaClient.provider
+			);
+			expect(receipt?.amount.toNumber()).toBe(250);
+			expect(receipt?.withheldAmount.toNumber()).toBe(75);
+		});
+
+		test("fail to create withholding rates without a tax vault", async () => {
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getCreateDistributionIx({
+					...distributionArgs(4),
+					authority: setup.authority.toString(),
+					payer: setup.payer.toString(),
+					fundingAccount,
+					snapshot: { tracker: {} },
+					amount: new BN(1000),
+					expiry: new BN(Math.floor(Date.now() / 1000) + 60),
+					withholdingRates: [{ group: 5, rateBps: 3000 }],
+				}, rwaClient.provider)),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x178d/); // TaxVaultMissing error
+		});
+	});
+
+	describe("expiry", () => {
+		test("unclaimed funds are reclaimable after expiry", async () => {
+			expect(await createDistribution(2, { tracker: {} }, Math.floor(Date.now() / 1000) + 3)).toBeTruthy();
+			const reclaim = async () => sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getReclaimDistributionIx({
+					...distributionArgs(2),
+					authority: setup.authority.toString(),
+					destination: fundingAccount,
+				}, rwaClient.provider)),
+				[setup.authorityKp]
+			);
+			await expect(reclaim()).rejects.toThrowError(/custom program error: 0x178b/); // DistributionNotReclaimable error
+			expect(await claim(2, setup.user2)).toBeTruthy();
+
+			await new Promise((resolve) => setTimeout(resolve, 5000));
+			await expect(claim(2, setup.user1)).rejects.toThrowError(/custom program error: 0x178a/); // DistributionExpired error
+			expect(await reclaim()).toBeTruthy();
+			const distribution = await getDistributionAccount(mint, new BN(2), rwaClient.provider);
+			expect(distribution?.reclaimed).toBe(true);
+			expect(distribution?.claimedAmount.toNumber()).toBe(250);
+		});
+	});
+});
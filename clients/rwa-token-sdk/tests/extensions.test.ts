@@ -2,7 +2,10 @@ import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
 import {
 	getDisableMemoTransferIx,
 	getEnableMemoTransferIx,
+	getPauseAssetIx,
+	getResumeAssetIx,
 	getSetupUserIxs,
+	getUpdateInterestRateIx,
 } from "../src";
 import { setupTests } from "./setup";
 import {
@@ -10,12 +13,24 @@ import {
 	Connection,
 	PublicKey,
 	Transaction,
+	type TransactionInstruction,
 	sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import { expect, test, describe } from "vitest";
 import { type Config } from "../src/classes/types";
 import { RwaClient } from "../src/classes";
-import { getAccount, getAssociatedTokenAddressSync, getInterestBearingMintConfigState, getMemoTransfer, getMint, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { getAssetControllerPda } from "../src";
+import {
+	AccountState,
+	getAccount,
+	getAssociatedTokenAddressSync,
+	getDefaultAccountState,
+	getGroupPointerState,
+	getInterestBearingMintConfigState,
+	getMemoTransfer,
+	getMint,
+	TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 
 describe("extension tests", async () => {
 	let rwaClient: RwaClient;
@@ -142,4 +157,121 @@ describe("extension tests", async () => {
 		expect(memoMintConfig?.requireIncomingTransferMemos).toEqual(false);
 	});
 
+	test("initialize asset controller with optional mint extensions", async () => {
+		const group = new PublicKey(mint);
+		const setupIx = await rwaClient.assetController.setupNewRegistry({
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Extension Asset",
+			uri: "https://test.com",
+			symbol: "TXT",
+			defaultAccountStateFrozen: true,
+			interestRate: 500,
+			groupPointer: group.toString(),
+		});
+		const txnId = await sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...setupIx.ixs),
+			[setup.payerKp, ...setupIx.signers]
+		);
+		expect(txnId).toBeTruthy();
+		const extensionMint = setupIx.signers[0].publicKey;
+		const assetController = getAssetControllerPda(extensionMint.toString());
+		const mintData = await getMint(
+			rwaClient.provider.connection,
+			extensionMint,
+			undefined,
+			TOKEN_2022_PROGRAM_ID,
+		);
+		expect(getDefaultAccountState(mintData)?.state).toEqual(AccountState.Frozen);
+		const interestConfig = getInterestBearingMintConfigState(mintData);
+		expect(interestConfig?.currentRate).toEqual(500);
+		expect(interestConfig?.rateAuthority.equals(assetController)).toBe(true);
+		const groupPointer = getGroupPointerState(mintData);
+		expect(groupPointer?.groupAddress?.equals(group)).toBe(true);
+		expect(groupPointer?.authority?.equals(assetController)).toBe(true);
+	});
+
+	test("update the interest rate and pause the asset", async () => {
+		const setupIx = await rwaClient.assetController.setupNewRegistry({
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Pausable Asset",
+			uri: "https://test.com",
+			symbol: "TPA",
+			interestRate: 500,
+			pausable: true,
+		});
+		await sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...setupIx.ixs),
+			[setup.payerKp, ...setupIx.signers]
+		);
+		const pausableMint = setupIx.signers[0].publicKey.toString();
+		const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: pausableMint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authority.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...setupUser.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+		);
+		const send = async (ix: TransactionInstruction) => sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(ix),
+			[setup.payerKp, setup.authorityKp]
+		);
+		const issue = async () => sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...await rwaClient.assetController.issueTokenIxns({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				owner: setup.user1.toString(),
+				assetMint: pausableMint,
+				amount: new BN(100),
+			})),
+			[setup.payerKp, setup.authorityKp]
+		);
+		const args = { authority: setup.authority.toString(), assetMint: pausableMint };
+
+		expect(await send(await getUpdateInterestRateIx({ ...args, rate: 250 }, rwaClient.provider))).toBeTruthy();
+		const mintData = await getMint(
+			rwaClient.provider.connection,
+			new PublicKey(pausableMint),
+			undefined,
+			TOKEN_2022_PROGRAM_ID,
+		);
+		expect(getInterestBearingMintConfigState(mintData)?.currentRate).toEqual(250);
+
+		expect(await send(await getPauseAssetIx(args, rwaClient.provider))).toBeTruthy();
+		await expect(issue()).rejects.toThrowError();
+		expect(await send(await getResumeAssetIx(args, rwaClient.provider))).toBeTruthy();
+		expect(await issue()).toBeTruthy();
+	});
+
+	test("fail to combine the scaled ui amount and interest-bearing extensions", async () => {
+		const setupIx = await rwaClient.assetController.setupNewRegistry({
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Extension Asset",
+			uri: "https://test.com",
+			symbol: "TXT",
+			interestRate: 500,
+			multiplier: 1,
+		});
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...setupIx.ixs),
+			[setup.payerKp, ...setupIx.signers]
+		)).rejects.toThrowError(/custom program error: 0x1790/); // IncompatibleExtensions error
+	});
 });
@@ -0,0 +1,300 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getDelegationAccount,
+	getProposalAccount,
+	getVoteAccount,
+	ProposalConfig,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Keypair, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test token-holder voting", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+	const proposalId = new BN(1);
+	const votingPeriod = 20;
+	let proposalConfig: ProposalConfig;
+
+	const castVote = async (voterKp: Keypair, owner: string, option: number) => {
+		const castVote = await rwaClient.policyEngine.castVote({
+			assetMint: mint,
+			payer: setup.payer.toString(),
+			id: proposalId,
+			option,
+			voter: voterKp.publicKey.toString(),
+			owner,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...castVote.ixs),
+			[setup.payerKp, voterKp]
+		);
+	};
+	const openSnapshot = async () => {
+		const openSnapshot = await rwaClient.policyEngine.openSnapshot({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...openSnapshot.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+	};
+	const finalize = async () => {
+		const finalizeProposal = await rwaClient.policyEngine.finalizeProposal({
+			assetMint: mint,
+			id: proposalId,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...finalizeProposal.ixs),
+			[setup.payerKp]
+		);
+	};
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users, user3 does not have the level eligible to vote
+		for (const [user, level, amount] of [[setup.user1, 1, 600], [setup.user2, 1, 300], [setup.user3, 2, 100]] as const) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				levels: [level],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+			const issueTokens = await rwaClient.assetController.issueTokenIxns({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				amount: new BN(amount),
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueTokens),
+				[setup.payerKp, setup.authorityKp]
+			);
+		}
+	});
+
+	test("fail to create a proposal without a snapshot", async () => {
+		const now = Math.floor(Date.now() / 1000);
+		proposalConfig = {
+			question: "Approve the annual accounts?",
+			options: ["For", "Against"],
+			startTimestamp: new BN(now - 60),
+			endTimestamp: new BN(now + votingPeriod),
+			quorumBps: 5000,
+			identityFilter: {
+				simple: [{
+					single: [
+						{
+							target: {bothOr: {}},
+							mode: {include: {}},
+							level: {level: [1]},
+						}
+					]
+				}]
+			},
+		};
+		const createProposal = await rwaClient.policyEngine.createProposal({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			id: proposalId,
+			config: proposalConfig,
+		});
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...createProposal.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x17c1/); // SnapshotUnavailable error
+	});
+
+	test("create proposal at the record date", async () => {
+		await openSnapshot();
+		const createProposal = await rwaClient.policyEngine.createProposal({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			id: proposalId,
+			config: proposalConfig,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...createProposal.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		const proposal = await getProposalAccount(mint, proposalId, rwaClient.provider);
+		expect(proposal?.snapshotId.toNumber()).toBe(1);
+		expect(proposal?.snapshotSupply.toNumber()).toBe(1000);
+		expect(proposal?.options).toEqual(["For", "Against"]);
+
+		// balances moved after the record date do not change the voting power
+		const transferIxs = await rwaClient.assetController.transfer({
+			from: setup.user1.toString(),
+			to: setup.user2.toString(),
+			assetMint: mint,
+			amount: new BN(200),
+			decimals,
+			createTa: true,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...transferIxs),
+			[setup.user1Kp]
+		);
+	});
+
+	test("open later snapshots while the proposal is open", async () => {
+		expect(await openSnapshot()).toBeTruthy();
+		// user1 checkpoints the second snapshot, the record date of the proposal stays readable
+		const transferIxs = await rwaClient.assetController.transfer({
+			from: setup.user1.toString(),
+			to: setup.user2.toString(),
+			assetMint: mint,
+			amount: new BN(100),
+			decimals,
+			createTa: true,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...transferIxs),
+			[setup.user1Kp]
+		);
+		expect(await openSnapshot()).toBeTruthy();
+		expect(await openSnapshot()).toBeTruthy();
+		// a fifth snapshot would push the record date of the proposal out of the readable window
+		await expect(openSnapshot()).rejects.toThrowError(/custom program error: 0x17cb/); // SnapshotInUse error
+	});
+
+	test("delegate voting power", async () => {
+		const delegateVotes = await rwaClient.policyEngine.delegateVotes({
+			assetMint: mint,
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			delegate: setup.user1.toString(),
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...delegateVotes.ixs),
+			[setup.payerKp, setup.user2Kp]
+		);
+		expect(txnId).toBeTruthy();
+		const delegation = await getDelegationAccount(mint, setup.user2.toString(), rwaClient.provider);
+		expect(delegation).toBeTruthy();
+	});
+
+	test("fail to vote a delegated balance directly", async () => {
+		await expect(castVote(setup.user2Kp, setup.user2.toString(), 1)).rejects.toThrowError(/custom program error: 0x17c5/); // VoteNotDelegated error
+	});
+
+	test("fail to vote as an identity outside the proposal filter", async () => {
+		await expect(castVote(setup.user3Kp, setup.user3.toString(), 1)).rejects.toThrowError(/custom program error: 0x17c6/); // IdentityNotEligibleToVote error
+	});
+
+	test("fail to vote an option that does not exist", async () => {
+		await expect(castVote(setup.user1Kp, setup.user1.toString(), 2)).rejects.toThrowError(/custom program error: 0x17c4/); // InvalidVoteOption error
+	});
+
+	test("vote own and delegated balances at the record date", async () => {
+		expect(await castVote(setup.user1Kp, setup.user1.toString(), 0)).toBeTruthy();
+		expect(await castVote(setup.user1Kp, setup.user2.toString(), 0)).toBeTruthy();
+
+		const vote = await getVoteAccount(mint, proposalId, setup.user2.toString(), rwaClient.provider);
+		expect(vote?.weight.toNumber()).toBe(300);
+		const proposal = await getProposalAccount(mint, proposalId, rwaClient.provider);
+		expect(proposal?.tallies.map((tally) => tally.toNumber())).toEqual([900, 0]);
+	});
+
+	test("fail to vote a balance twice", async () => {
+		await expect(castVote(setup.user1Kp, setup.user1.toString(), 1)).rejects.toThrowError(/already in use/);
+	});
+
+	test("finalize proposal after the voting window", async () => {
+		await expect(finalize()).rejects.toThrowError(/custom program error: 0x17c8/); // ProposalNotFinalizable error
+		await new Promise((resolve) => setTimeout(resolve, (votingPeriod + 2) * 1000));
+		expect(await finalize()).toBeTruthy();
+		const proposal = await getProposalAccount(mint, proposalId, rwaClient.provider);
+		expect(proposal?.finalized).toBe(true);
+	});
+
+	test("close proposal and revoke the delegation", async () => {
+		const closeProposal = await rwaClient.policyEngine.closeProposal({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+			id: proposalId,
+			rentReceiver: setup.payer.toString(),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...closeProposal.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(await getProposalAccount(mint, proposalId, rwaClient.provider)).toBeUndefined();
+
+		const undelegateVotes = await rwaClient.policyEngine.undelegateVotes({
+			assetMint: mint,
+			owner: setup.user2.toString(),
+			rentReceiver: setup.payer.toString(),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...undelegateVotes.ixs),
+			[setup.payerKp, setup.user2Kp]
+		);
+		expect(await getDelegationAccount(mint, setup.user2.toString(), rwaClient.provider)).toBeUndefined();
+	});
+});
@@ -0,0 +1,166 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getIssueTokensBatchIx,
+	getTrackerAccount,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, getAccount, getAssociatedTokenAddressSync, getMint } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test batch issuance", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		for (const user of [setup.user1, setup.user2, setup.user3]) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				levels: [1],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+		}
+	});
+
+	describe("test IssueTokensBatch", async () => {
+		const getTokenAccount = (wallet: PublicKey) => getAccount(
+			setup.provider.connection,
+			getAssociatedTokenAddressSync(new PublicKey(mint), wallet, true, TOKEN_2022_PROGRAM_ID),
+			undefined,
+			TOKEN_2022_PROGRAM_ID
+		);
+
+		test("issue tokens to several recipients", async () => {
+			const issueIxs = await getIssueTokensBatchIx({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				recipients: [
+					{ owner: setup.user1.toString(), amount: new BN(1000) },
+					{ owner: setup.user2.toString(), amount: new BN(2000) },
+					{ owner: setup.user3.toString(), amount: new BN(3000) },
+				],
+				timestamp: new BN(0),
+			}, rwaClient.provider);
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueIxs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+
+			expect((await getTokenAccount(setup.user1)).amount).toBe(BigInt(1000));
+			expect((await getTokenAccount(setup.user2)).amount).toBe(BigInt(2000));
+			expect((await getTokenAccount(setup.user3)).amount).toBe(BigInt(3000));
+			const tracker = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+			expect(tracker?.totalAmount.toNumber()).toBe(2000);
+			expect(tracker?.issuances.length).toBe(1);
+		});
+
+		test("fail when a recipient has no wallet identity", async () => {
+			const issueIxs = await getIssueTokensBatchIx({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				recipients: [
+					{ owner: setup.user1.toString(), amount: new BN(1000) },
+					{ owner: setup.user2.toString(), wallet: setup.user3.toString(), amount: new BN(1000) },
+				],
+			}, rwaClient.provider);
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueIxs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError();
+		});
+
+		test("enforce max supply against the batch total", async () => {
+			const supply = (await getMint(setup.provider.connection, new PublicKey(mint), undefined, TOKEN_2022_PROGRAM_ID)).supply;
+			const changeIssuancePolicies = await rwaClient.policyEngine.changeIssuancePolicies({
+				payer: setup.payer.toString(),
+				authority: setup.authority.toString(),
+				assetMint: mint,
+				issuancePolicies: {
+					disallowBackdating: false,
+					maxSupply: new BN((supply + BigInt(1500)).toString()),
+					usLockPeriod: new BN(0),
+					nonUsLockPeriod: new BN(0),
+				},
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...changeIssuancePolicies.ixs),
+				[setup.payerKp, setup.authorityKp, ...changeIssuancePolicies.signers]
+			);
+
+			// each amount fits under the max supply on its own, the total does not
+			const issueIxs = await getIssueTokensBatchIx({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				recipients: [
+					{ owner: setup.user1.toString(), amount: new BN(1000) },
+					{ owner: setup.user2.toString(), amount: new BN(1000) },
+				],
+			}, rwaClient.provider);
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueIxs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError();
+			expect((await getTokenAccount(setup.user1)).amount).toBe(BigInt(1000));
+		});
+	});
+});
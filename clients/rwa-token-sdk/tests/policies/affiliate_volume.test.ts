@@ -0,0 +1,169 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getAffiliateVolumeAllowance,
+	getTransferTokensIxs,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test affiliate volume policy", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	describe("test AffiliateVolumeLimit policy", async () => {
+		test("attach AffiliateVolumeLimit policy", async () => {
+			const attachPolicy = await rwaClient.policyEngine.attachPolicy({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				identityFilter: {
+					simple: [ {
+						single: [
+							{
+								target: {bothOr: {}},
+								mode: {include: {}},
+								level: {level: [1]},
+							}
+						]
+					}]
+				},
+				policyType: { affiliateVolumeLimit: { amount: new BN(5000) } },
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...attachPolicy.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+		});
+
+		test("sell within 1% of the supply", async () => {
+			const transferTokensIxs = await getTransferTokensIxs({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(8000),
+				decimals,
+				createTa: true,
+			}, rwaClient.provider);
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...transferTokensIxs),
+				[setup.user1Kp],
+			);
+			expect(txnId).toBeTruthy();
+
+			const allowance = await getAffiliateVolumeAllowance(mint, setup.user1.toString(), rwaClient.provider);
+			expect(allowance?.allowance.toNumber()).toBe(10000);
+			expect(allowance?.volume.toNumber()).toBe(8000);
+			expect(allowance?.remaining.toNumber()).toBe(2000);
+		});
+
+		test("sell over the allowance", async () => {
+			const transferTokensIxs = await getTransferTokensIxs({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(3000),
+				decimals,
+				createTa: true,
+			}, rwaClient.provider);
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...transferTokensIxs),
+				[setup.user1Kp],
+			)).rejects.toThrowError(/custom program error: 0x17ba/); // AffiliateVolumeLimitExceeded error
+		});
+	});
+});
@@ -0,0 +1,188 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getPolicyEngineAccount,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test beneficial owner look-through counting", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const changeCounters = await rwaClient.policyEngine.changeCounters({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounters: Buffer.from([]),
+			addedCounters: [
+				{
+					value: new BN(0),
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+				{
+					value: new BN(0),
+					id: 1,
+					countBeneficialOwners: true,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+			],
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.counters.find((c) => c.id === 0)?.value.toNumber()).toBe(1);
+		expect(policyEngine?.counters.find((c) => c.id === 1)?.value.toNumber()).toBe(1);
+	});
+
+	test("increase beneficial owners updates look-through counters only", async () => {
+		const changeBeneficialOwnersIx = await rwaClient.identityRegistry.changeBeneficialOwners({
+			payer: setup.payer.toString(),
+			signer: setup.authority.toString(),
+			assetMint: mint,
+			owner: setup.user1.toString(),
+			beneficialOwners: 5,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(changeBeneficialOwnersIx),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.counters.find((c) => c.id === 0)?.value.toNumber()).toBe(1);
+		expect(policyEngine?.counters.find((c) => c.id === 1)?.value.toNumber()).toBe(5);
+	});
+
+	test("decrease beneficial owners", async () => {
+		const changeBeneficialOwnersIx = await rwaClient.identityRegistry.changeBeneficialOwners({
+			payer: setup.payer.toString(),
+			signer: setup.authority.toString(),
+			assetMint: mint,
+			owner: setup.user1.toString(),
+			beneficialOwners: 2,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(changeBeneficialOwnersIx),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.counters.find((c) => c.id === 1)?.value.toNumber()).toBe(2);
+	});
+
+	test("reject zero beneficial owners", async () => {
+		const changeBeneficialOwnersIx = await rwaClient.identityRegistry.changeBeneficialOwners({
+			payer: setup.payer.toString(),
+			signer: setup.authority.toString(),
+			assetMint: mint,
+			owner: setup.user1.toString(),
+			beneficialOwners: 0,
+		});
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(changeBeneficialOwnersIx),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x177b/); // InvalidBeneficialOwners error
+	});
+});
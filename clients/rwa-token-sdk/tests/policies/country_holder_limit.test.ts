@@ -0,0 +1,302 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getPolicyEngineAccount,
+	getTransferTokensIxs,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test country holder counters", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 1,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 2,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		const setupUser3 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user3.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 1,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser3.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser3.signers]
+		);
+
+		const changeMapping = await rwaClient.policyEngine.changeMapping({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			mappingSource: [1, 2],
+			mappingValue: [5, 6],
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeMapping.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	test("add country counter and limit", async () => {
+		const changeCountryCounters = await rwaClient.policyEngine.changeCountryCounters({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCountryCounters: Buffer.from([]),
+			addedCountryCounters: [
+				{
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+					values: [],
+				},
+			],
+		});
+		let txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCountryCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const changeCounterLimits = await rwaClient.policyEngine.changeCounterLimits({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			removedCounterLimits: Buffer.from([]),
+			addedCounterLimits: [{
+				countryHoldersLimit: {
+					max: new BN(1),
+					counterId: 0,
+					groups: Buffer.from([5]),
+					enforcementMode: { enforce: {} },
+					warningThreshold: new BN(0)
+				}
+			}]
+		});
+		txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounterLimits.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.countryCounters[0].values.length).toBe(7);
+	});
+
+	test("issue tokens counts holder in its country group", async () => {
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.countryCounters[0].values[5].toNumber()).toBe(1);
+	});
+
+	test("attempt transfer to new holder in a full group", async () => {
+		const transferTokensIxs = await getTransferTokensIxs({
+			from: setup.user1.toString(),
+			to: setup.user3.toString(),
+			assetMint: mint,
+			amount: new BN(10),
+			decimals,
+			createTa: true,
+		}, rwaClient.provider);
+
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...transferTokensIxs),
+			[setup.user1Kp],
+		)).rejects.toThrowError(/custom program error: 0x1788/); // HolderLimitExceeded error
+	});
+
+	test("transfer to new holder in another group", async () => {
+		const transferTokensIxs = await getTransferTokensIxs({
+			from: setup.user1.toString(),
+			to: setup.user2.toString(),
+			assetMint: mint,
+			amount: new BN(10),
+			decimals,
+			createTa: true,
+		}, rwaClient.provider);
+
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...transferTokensIxs),
+			[setup.user1Kp],
+		);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.countryCounters[0].values[5].toNumber()).toBe(1);
+		expect(policyEngine?.countryCounters[0].values[6].toNumber()).toBe(1);
+	});
+
+	test("attempt to move holder into a full group", async () => {
+		const changeCountryIx = await rwaClient.identityRegistry.changeCountry({
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			payer: setup.payer.toString(),
+			country: 1,
+			signer: setup.authority.toString(),
+		});
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(changeCountryIx),
+			[setup.payerKp, setup.authorityKp],
+			{ skipPreflight: true }
+		)).rejects.toThrowError(/"Custom":6024/); // HolderLimitExceeded error
+	});
+
+	test("move holder to an unlimited group", async () => {
+		const changeCountryIx = await rwaClient.identityRegistry.changeCountry({
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			payer: setup.payer.toString(),
+			country: 3,
+			signer: setup.authority.toString(),
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(changeCountryIx),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.countryCounters[0].values[6].toNumber()).toBe(0);
+		expect(policyEngine?.countryCounters[0].values[0].toNumber()).toBe(1);
+	});
+
+	test("attempt to remap a country whose group counts holders", async () => {
+		const changeMapping = await rwaClient.policyEngine.changeMapping({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			mappingSource: [1],
+			mappingValue: [7],
+		});
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeMapping.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x17cc/); // CountryGroupNotEmpty error
+	});
+
+	test("remap a country of an empty group to a new group", async () => {
+		const changeMapping = await rwaClient.policyEngine.changeMapping({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			mappingSource: [2],
+			mappingValue: [8],
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeMapping.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.countryCounters[0].values.length).toBe(9);
+		expect(policyEngine?.countryCounters[0].values[5].toNumber()).toBe(1);
+	});
+});
@@ -122,6 +122,7 @@ describe("test additional policies", async () => {
 					{
 						value: new BN(0),
 						id: 0,
+						countBeneficialOwners: false,
 						identityFilter: {
 							simple: [ {
 								single: [
@@ -137,6 +138,7 @@ describe("test additional policies", async () => {
 					{
 						value: new BN(0),
 						id: 1,
+						countBeneficialOwners: false,
 						identityFilter: {
 							simple: [ {
 								single: [
@@ -174,6 +176,8 @@ describe("test additional policies", async () => {
 							min: new BN(2),
 							max: new BN(holderLimit),
 							counters: Buffer.from([0, 1]),
+							enforcementMode: { enforce: {} },
+							warningThreshold: new BN(0),
 						},
 					},
 				],
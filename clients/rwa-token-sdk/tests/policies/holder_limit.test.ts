@@ -110,6 +110,7 @@ describe("test additional policies", async () => {
 				{
 					value: new BN(0),
 					id: 0,
+					countBeneficialOwners: false,
 					identityFilter: {
 						simple: [ {
 							single: [
@@ -125,6 +126,7 @@ describe("test additional policies", async () => {
 				{
 					value: new BN(0),
 					id: 1,
+					countBeneficialOwners: false,
 					identityFilter: {
 						simple: [ {
 							single: [
@@ -174,13 +176,17 @@ describe("test additional policies", async () => {
 					holdersLimit: {
 						min: new BN(2),
 						max: new BN(holderLimit),
-						counterId: 0
+						counterId: 0,
+						enforcementMode: { enforce: {} },
+						warningThreshold: new BN(0)
 					}
 				},{
 					holdersLimit: {
 						min: new BN(0),
 						max: new BN(holderLimit),
-						counterId: 1
+						counterId: 1,
+						enforcementMode: { enforce: {} },
+						warningThreshold: new BN(0)
 					}
 				},
 				]
@@ -0,0 +1,186 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	EnforcementMode,
+	getPolicyEngineAccount,
+	getTransferTokensIxs,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test additional policies", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	describe("test policy enforcement modes", async () => {
+		let hash: string;
+
+		const transfer = async () => {
+			const transferTokensIxs = await getTransferTokensIxs({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(10),
+				decimals,
+				createTa: true,
+			}, rwaClient.provider);
+			return sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...transferTokensIxs),
+				[setup.user1Kp],
+			);
+		};
+
+		const changeMode = async (enforcementMode: EnforcementMode) => {
+			const changeModeIx = await rwaClient.policyEngine.changePolicyEnforcementMode({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				hash,
+				enforcementMode,
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...changeModeIx.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+		};
+
+		test("attach TransferPause policy in monitor mode", async () => {
+			const attachPolicy = await rwaClient.policyEngine.attachPolicy({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				identityFilter: {
+					simple: [ {
+						single: [
+							{
+								target: {bothOr: {}},
+								mode: {include: {}},
+								level: {level: [1]}	,
+							}
+						]
+					}]
+				},
+				policyType: { transferPause: {} },
+				enforcementMode: { monitor: {} },
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...attachPolicy.ixs),
+				[setup.payerKp, setup.authorityKp, ...attachPolicy.signers]
+			);
+			expect(txnId).toBeTruthy();
+
+			const policyAccount = await getPolicyEngineAccount(mint, rwaClient.provider);
+			expect(policyAccount?.policies.length).toBe(1);
+			expect(policyAccount?.policies[0].enforcementMode).toEqual({ monitor: {} });
+			hash = policyAccount!.policies[0].hash;
+		});
+
+		test("transfer succeeds while policy is monitored", async () => {
+			expect(await transfer()).toBeTruthy();
+		});
+
+		test("transfer fails once policy is enforced", async () => {
+			await changeMode({ enforce: {} });
+			await expect(transfer()).rejects.toThrowError(/custom program error: 0x1781/); // TransferPaused error
+		});
+
+		test("transfer succeeds while policy is disabled", async () => {
+			await changeMode({ disabled: {} });
+			expect(await transfer()).toBeTruthy();
+		});
+	});
+});
@@ -0,0 +1,329 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getPolicyEngineAccount,
+	getPolicyPageAccount,
+	getTransferTokensIxs,
+	PolicyEngineChange,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test policy pages", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	describe("test transfer policy page", async () => {
+		test("create transfer policy page", async () => {
+			const createPage = await rwaClient.policyEngine.createPolicyPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 0,
+				scope: { transfer: {} },
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...createPage.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+		});
+
+		test("reject transfer page outside the reserved slots", async () => {
+			const createPage = await rwaClient.policyEngine.createPolicyPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 4,
+				scope: { transfer: {} },
+			});
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...createPage.ixs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x17b5/); // InvalidPolicyPageIndex error
+		});
+
+		test("attach TransferPause policy to the page", async () => {
+			const attachPolicy = await rwaClient.policyEngine.attachPolicyToPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 0,
+				identityFilter: {
+					simple: [ {
+						single: [
+							{
+								target: {bothOr: {}},
+								mode: {include: {}},
+								level: {level: [1]},
+							}
+						]
+					}]
+				},
+				policyType: { transferPause: {} },
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...attachPolicy.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			const page = await getPolicyPageAccount(mint, 0, rwaClient.provider);
+			expect(page?.policies.length).toBe(1);
+		});
+
+		test("attempt transfer while paused by the page", async () => {
+			const transferTokensIxs = await getTransferTokensIxs({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(10),
+				decimals,
+				createTa: true,
+			}, rwaClient.provider);
+
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...transferTokensIxs),
+				[setup.user1Kp],
+			)).rejects.toThrowError(/custom program error: 0x1781/); // TransferPaused error
+		});
+
+		test("page with policies cannot be closed", async () => {
+			const closePage = await rwaClient.policyEngine.closePolicyPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 0,
+				rentReceiver: setup.payer.toString(),
+			});
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...closePage.ixs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x17b8/); // PolicyPageNotEmpty error
+		});
+
+		test("detach policy from the page and transfer", async () => {
+			const page = await getPolicyPageAccount(mint, 0, rwaClient.provider);
+			const detachPolicy = await rwaClient.policyEngine.detachPolicyFromPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 0,
+				hash: page!.policies[0].hash,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...detachPolicy.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+
+			const transferTokensIxs = await getTransferTokensIxs({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(10),
+				decimals,
+				createTa: true,
+			}, rwaClient.provider);
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...transferTokensIxs),
+				[setup.user1Kp],
+			);
+			expect(txnId).toBeTruthy();
+		});
+
+		test("close the empty page", async () => {
+			const closePage = await rwaClient.policyEngine.closePolicyPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 0,
+				rentReceiver: setup.payer.toString(),
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...closePage.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			expect(await getPolicyPageAccount(mint, 0, rwaClient.provider)).toBeUndefined();
+		});
+	});
+
+	describe("test queued policy page changes", async () => {
+		const proposeAndExecute = async (change: PolicyEngineChange, id: number) => {
+			const proposeIx = await rwaClient.policyEngine.proposeChange({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				change,
+			});
+			await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...proposeIx.ixs), [setup.payerKp, setup.authorityKp]);
+			const executeIx = await rwaClient.policyEngine.executeChange({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				id: new BN(id),
+			});
+			return sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...executeIx.ixs), [setup.payerKp, setup.authorityKp]);
+		};
+
+		test("disable direct changes", async () => {
+			const changeTimelockIx = await rwaClient.policyEngine.changeTimelock({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				timelock: { delay: new BN(0), directChangesEnabled: false },
+			});
+			await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...changeTimelockIx.ixs), [setup.payerKp, setup.authorityKp]);
+
+			const createPage = await rwaClient.policyEngine.createPolicyPage({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				index: 1,
+				scope: { transfer: {} },
+			});
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...createPage.ixs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x17af/); // DirectChangesDisabled error
+		});
+
+		test("create a page and attach a policy through the queue", async () => {
+			expect(await proposeAndExecute({ createPolicyPage: { index: 1, scope: { transfer: {} } } }, 0)).toBeTruthy();
+			expect(await proposeAndExecute({
+				attachToPolicyPage: {
+					index: 1,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+					policyType: { transferPause: {} },
+					customError: 0,
+					enforcementMode: { enforce: {} },
+					applicability: null,
+				},
+			}, 1)).toBeTruthy();
+
+			const page = await getPolicyPageAccount(mint, 1, rwaClient.provider);
+			expect(page?.policies.length).toBe(1);
+			const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+			expect(policyEngine?.policyPages.map((ref) => ref.index)).toContain(1);
+		});
+
+		test("detach the policy and close the page through the queue", async () => {
+			const page = await getPolicyPageAccount(mint, 1, rwaClient.provider);
+			expect(await proposeAndExecute({ detachFromPolicyPage: { index: 1, hash: page!.policies[0].hash } }, 2)).toBeTruthy();
+			expect((await getPolicyPageAccount(mint, 1, rwaClient.provider))?.policies.length).toBe(0);
+
+			expect(await proposeAndExecute({ closePolicyPage: { index: 1 } }, 3)).toBeTruthy();
+			expect(await getPolicyPageAccount(mint, 1, rwaClient.provider)).toBeUndefined();
+			const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+			expect(policyEngine?.policyPages.map((ref) => ref.index)).not.toContain(1);
+		});
+	});
+});
@@ -0,0 +1,244 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	ChangeCountersArgs,
+	getPolicyEngineAccount,
+	getSeizeTokensIx,
+	getTrackerAccount,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, createAssociatedTokenAccountIdempotentInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test seize holder counters", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		const changeCounterArgs: ChangeCountersArgs = {
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounters: Buffer.from([]),
+			addedCounters: [
+				{
+					value: new BN(0),
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+			],
+		};
+		const changeCounters = await rwaClient.policyEngine.changeCounters(changeCounterArgs);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+	describe("test seize counter updates", async () => {
+		const getCounterValue = async () => {
+			const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+			return policyEngine?.counters.find((c) => c.id === 0)?.value.toNumber();
+		};
+
+		test("attach HoldersLimit", async () => {
+			const attachPolicy = await rwaClient.policyEngine.changeCounterLimits({
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				removedCounterLimits: Buffer.from([]),
+				addedCounterLimits: [{
+					holdersLimit: {
+						min: new BN(0),
+						max: new BN(1),
+						counterId: 0,
+						enforcementMode: { enforce: {} },
+						warningThreshold: new BN(0)
+					}
+				}]
+			});
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...attachPolicy.ixs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			expect(await getCounterValue()).toBe(1);
+		});
+
+		test("attempt seize to a new holder exceeding limit with limits enforced", async () => {
+			// seizes do not create the destination token account
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(createAssociatedTokenAccountIdempotentInstruction(
+					setup.payer,
+					getAssociatedTokenAddressSync(new PublicKey(mint), setup.user2, true, TOKEN_2022_PROGRAM_ID),
+					setup.user2,
+					new PublicKey(mint),
+					TOKEN_2022_PROGRAM_ID
+				)),
+				[setup.payerKp]
+			);
+
+			const seizeIxs = await getSeizeTokensIx({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(10),
+				authority: setup.authority.toString(),
+				reason: "TEST",
+				enforceLimits: true,
+			}, rwaClient.provider);
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...seizeIxs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x1788/); // HolderLimitExceeded error
+		});
+
+		test("seize to a new holder increases counter without enforcing limits", async () => {
+			const seizeIxs = await getSeizeTokensIx({
+				from: setup.user1.toString(),
+				to: setup.user2.toString(),
+				assetMint: mint,
+				amount: new BN(10),
+				authority: setup.authority.toString(),
+				reason: "TEST",
+			}, rwaClient.provider);
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...seizeIxs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			expect(await getCounterValue()).toBe(2);
+
+			// the seized amount keeps its issue time on the destination tracker
+			const sourceTracker = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+			const destinationTracker = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+			expect(sourceTracker?.issuances.map((issuance) => issuance.amount.toNumber())).toEqual([999990]);
+			expect(destinationTracker?.issuances.map((issuance) => issuance.amount.toNumber())).toEqual([10]);
+			expect(destinationTracker?.issuances[0].issueTime.toNumber()).toBe(sourceTracker?.issuances[0].issueTime.toNumber());
+		});
+
+		test("seize emptying a holder decreases counter", async () => {
+			const seizeIxs = await getSeizeTokensIx({
+				from: setup.user2.toString(),
+				to: setup.user1.toString(),
+				assetMint: mint,
+				amount: new BN(10),
+				authority: setup.authority.toString(),
+				reason: "TEST",
+				enforceLimits: true,
+			}, rwaClient.provider);
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...seizeIxs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			expect(await getCounterValue()).toBe(1);
+			const sourceTracker = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+			expect(sourceTracker?.issuances.length).toBe(0);
+		});
+	});
+});
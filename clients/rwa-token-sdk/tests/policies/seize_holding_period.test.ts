@@ -0,0 +1,167 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getSeizeTokensIx,
+	getTrackerAccount,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, createAssociatedTokenAccountIdempotentInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test holding periods of seized lots", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+	const lockPeriod = 1000;
+	const oldIssueTime = Math.floor(Date.now() / 1000) - 2 * lockPeriod;
+
+	const seize = async (amount: number) => sendAndConfirmTransaction(
+		setup.provider.connection,
+		new Transaction().add(...await getSeizeTokensIx({
+			from: setup.user1.toString(),
+			to: setup.user2.toString(),
+			assetMint: mint,
+			amount: new BN(amount),
+			authority: setup.authority.toString(),
+			reason: "TEST",
+		}, rwaClient.provider)),
+		[setup.payerKp, setup.authorityKp]
+	);
+	const transferToUser3 = async (amount: number) => sendAndConfirmTransaction(
+		setup.provider.connection,
+		new Transaction().add(...await rwaClient.assetController.transfer({
+			from: setup.user2.toString(),
+			to: setup.user3.toString(),
+			assetMint: mint,
+			amount: new BN(amount),
+			decimals,
+			createTa: true,
+		})),
+		[setup.user2Kp]
+	);
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry({
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+
+		for (const owner of [setup.user1, setup.user2, setup.user3]) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: owner.toString(),
+				assetMint: mint,
+				levels: [1],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+		}
+
+		// seizes do not create the destination token account
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(createAssociatedTokenAccountIdempotentInstruction(
+				setup.payer,
+				getAssociatedTokenAddressSync(new PublicKey(mint), setup.user2, true, TOKEN_2022_PROGRAM_ID),
+				setup.user2,
+				new PublicKey(mint),
+				TOKEN_2022_PROGRAM_ID
+			)),
+			[setup.payerKp]
+		);
+	});
+
+	test("issue a lot past the holding period and a new lot", async () => {
+		for (const [amount, timestamp] of [[1000, oldIssueTime], [500, Math.floor(Date.now() / 1000)]]) {
+			const issueTokens = await rwaClient.assetController.issueTokenIxns({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				owner: setup.user1.toString(),
+				assetMint: mint,
+				amount: new BN(amount),
+				timestamp: new BN(timestamp),
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueTokens),
+				[setup.payerKp, setup.authorityKp]
+			);
+		}
+
+		const changeIssuancePolicies = await rwaClient.policyEngine.changeIssuancePolicies({
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			assetMint: mint,
+			issuancePolicies: {
+				disallowBackdating: false,
+				maxSupply: new BN(0),
+				usLockPeriod: new BN(lockPeriod),
+				nonUsLockPeriod: new BN(lockPeriod),
+			},
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeIssuancePolicies.ixs),
+			[setup.payerKp, setup.authorityKp, ...changeIssuancePolicies.signers]
+		);
+		expect(txnId).toBeTruthy();
+	});
+
+	test("seize takes the newest lot, which is held up on the destination", async () => {
+		expect(await seize(500)).toBeTruthy();
+		const destinationTracker = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+		expect(destinationTracker?.issuances.map((issuance) => issuance.amount.toNumber())).toEqual([500]);
+		expect(destinationTracker?.issuances[0].issueTime.toNumber()).toBeGreaterThan(oldIssueTime + lockPeriod);
+
+		await expect(transferToUser3(100)).rejects.toThrowError(/custom program error: 0x1792/); // HoldUp error
+	});
+
+	test("seize of the older lot can be transferred on by the destination", async () => {
+		expect(await seize(400)).toBeTruthy();
+		const sourceTracker = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+		expect(sourceTracker?.issuances.map((issuance) => issuance.amount.toNumber())).toEqual([600]);
+		const destinationTracker = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+		expect(destinationTracker?.issuances.find((issuance) => issuance.amount.toNumber() === 400)?.issueTime.toNumber()).toBe(oldIssueTime);
+
+		// only the 400 seized from the older lot are past the holding period
+		await expect(transferToUser3(500)).rejects.toThrowError(/custom program error: 0x1792/); // HoldUp error
+		expect(await transferToUser3(400)).toBeTruthy();
+	});
+});
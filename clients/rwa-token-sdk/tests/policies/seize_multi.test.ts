@@ -0,0 +1,216 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	ChangeCountersArgs,
+	getPolicyEngineAccount,
+	getSeizeTokensMultiIx,
+	getTrackerAccount,
+	RwaClient,
+} from "../../src";
+import { setupTests } from "../setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, createAssociatedTokenAccountIdempotentInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../../src/classes/types";
+
+describe("test multi destination seize", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		const setupUser3 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user3.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser3.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser3.signers]
+		);
+
+		const changeCounterArgs: ChangeCountersArgs = {
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounters: Buffer.from([]),
+			addedCounters: [
+				{
+					value: new BN(0),
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+			],
+		};
+		const changeCounters = await rwaClient.policyEngine.changeCounters(changeCounterArgs);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+	describe("test SeizeTokensMulti", async () => {
+		const getSeizeIxs = (amounts: number[]) => getSeizeTokensMultiIx({
+			from: setup.user1.toString(),
+			legs: [
+				{ to: setup.user2.toString(), amount: new BN(amounts[0]) },
+				{ to: setup.user3.toString(), amount: new BN(amounts[1]) },
+			],
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			reason: "court order",
+			caseReference: "CASE-2026-001",
+		}, rwaClient.provider);
+
+		test("create destination token accounts", async () => {
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...[setup.user2, setup.user3].map((owner) => createAssociatedTokenAccountIdempotentInstruction(
+					setup.payer,
+					getAssociatedTokenAddressSync(new PublicKey(mint), owner, true, TOKEN_2022_PROGRAM_ID),
+					owner,
+					new PublicKey(mint),
+					TOKEN_2022_PROGRAM_ID
+				))),
+				[setup.payerKp]
+			);
+			expect(txnId).toBeTruthy();
+		});
+
+		test("attempt seize with an empty leg", async () => {
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...await getSeizeIxs([300, 0])),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x177d/); // InvalidSeizeLegs error
+		});
+
+		test("seize to several destinations", async () => {
+			const sourceIssuances = (await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider))!.issuances;
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...await getSeizeIxs([300, 200])),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+
+			const source = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+			const user2 = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+			const user3 = await getTrackerAccount(mint, setup.user3.toString(), rwaClient.provider);
+			expect(source?.totalAmount.toNumber()).toBe(1000000 - 500);
+			expect(user2?.totalAmount.toNumber()).toBe(300);
+			expect(user3?.totalAmount.toNumber()).toBe(200);
+
+			// issuances keep their issue time at the destination
+			expect(user2?.issuances.length).toBe(1);
+			expect(user2?.issuances[0].amount.toNumber()).toBe(300);
+			expect(user2?.issuances[0].issueTime.toNumber()).toBe(sourceIssuances[0].issueTime.toNumber());
+			expect(user3?.issuances[0].amount.toNumber()).toBe(200);
+			expect(source?.issuances[0].amount.toNumber()).toBe(1000000 - 500);
+
+			const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+			expect(policyEngine?.counters.find((c) => c.id === 0)?.value.toNumber()).toBe(3);
+		});
+	});
+});
@@ -0,0 +1,298 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getPolicyEngineAccount,
+	getReconciliationAccount,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Keypair, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test counter reconciliation", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		const changeCounters = await rwaClient.policyEngine.changeCounters({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounters: Buffer.from([]),
+			addedCounters: [
+				{
+					value: new BN(0),
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+			],
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		for (const owner of [setup.user1, setup.user2]) {
+			const issueTokens = await rwaClient.assetController.issueTokenIxns({
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				owner: owner.toString(),
+				assetMint: mint,
+				amount: new BN(1000),
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...issueTokens),
+				[setup.payerKp, setup.authorityKp]
+			);
+		}
+
+		// simulate drift
+		const setCounters = await rwaClient.policyEngine.setCounters({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			changedCounters: [0],
+			values: [new BN(5)],
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	const reconciliationArgs = () => ({
+		authority: setup.authority.toString(),
+		payer: setup.payer.toString(),
+		assetMint: mint,
+	});
+	const transfer = async (from: string, to: string, amount: number, signer: Keypair) => {
+		const transferIxs = await rwaClient.assetController.transfer({
+			from,
+			to,
+			assetMint: mint,
+			amount: new BN(amount),
+			decimals,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...transferIxs),
+			[setup.payerKp, signer]
+		);
+	};
+
+	test("attempt to commit a reconciliation missing identities", async () => {
+		const startReconciliation = await rwaClient.policyEngine.startReconciliation(reconciliationArgs());
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...startReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		const commitReconciliation = await rwaClient.policyEngine.commitReconciliation(reconciliationArgs());
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...commitReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x17ce/); // ReconciliationIncomplete error
+
+		const cancelReconciliation = await rwaClient.policyEngine.cancelReconciliation(reconciliationArgs());
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...cancelReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(await getReconciliationAccount(mint, rwaClient.provider)).toBeUndefined();
+	});
+
+	test("start reconciliation", async () => {
+		const startReconciliation = await rwaClient.policyEngine.startReconciliation({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...startReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const reconciliation = await getReconciliationAccount(mint, rwaClient.provider);
+		expect(reconciliation?.counters[0].value.toNumber()).toBe(0);
+	});
+
+	test("reconcile identities in batches", async () => {
+		const reconcileCounters = await rwaClient.policyEngine.reconcileCounters({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+			owners: [setup.user1.toString(), setup.user2.toString()],
+			batchSize: 1,
+		});
+		expect(reconcileCounters.ixs.length).toBe(2);
+		for (const ix of reconcileCounters.ixs) {
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(ix),
+				[setup.payerKp, setup.authorityKp]
+			);
+		}
+
+		const reconciliation = await getReconciliationAccount(mint, rwaClient.provider);
+		expect(reconciliation?.processedIdentities.toNumber()).toBe(2);
+		expect(reconciliation?.counters[0].value.toNumber()).toBe(2);
+	});
+
+	test("attempt to reconcile an identity twice", async () => {
+		const reconcileCounters = await rwaClient.policyEngine.reconcileCounters({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+			owners: [setup.user1.toString()],
+		});
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...reconcileCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x17b3/); // IdentityAccountsNotSorted error
+	});
+
+	test("transfer between holders keeps the reconciliation current", async () => {
+		const holdersSequence = (await getPolicyEngineAccount(mint, rwaClient.provider))!.holdersSequence.toNumber();
+		expect(await transfer(setup.user1.toString(), setup.user2.toString(), 100, setup.user1Kp)).toBeTruthy();
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.holdersSequence.toNumber()).toBe(holdersSequence);
+	});
+
+	test("commit reconciliation", async () => {
+		const commitReconciliation = await rwaClient.policyEngine.commitReconciliation({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...commitReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.counters[0].value.toNumber()).toBe(2);
+		expect(await getReconciliationAccount(mint, rwaClient.provider)).toBeUndefined();
+	});
+
+	test("attempt to commit a reconciliation outdated by a holder change", async () => {
+		const startReconciliation = await rwaClient.policyEngine.startReconciliation(reconciliationArgs());
+		const reconcileCounters = await rwaClient.policyEngine.reconcileCounters({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+			owners: [setup.user1.toString(), setup.user2.toString()],
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...startReconciliation.ixs, ...reconcileCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// user2 stops holding
+		expect(await transfer(setup.user2.toString(), setup.user1.toString(), 1100, setup.user2Kp)).toBeTruthy();
+		const commitReconciliation = await rwaClient.policyEngine.commitReconciliation(reconciliationArgs());
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...commitReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x17cd/); // ReconciliationStale error
+
+		const cancelReconciliation = await rwaClient.policyEngine.cancelReconciliation(reconciliationArgs());
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...cancelReconciliation.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect((await getPolicyEngineAccount(mint, rwaClient.provider))?.counters[0].value.toNumber()).toBe(1);
+	});
+});
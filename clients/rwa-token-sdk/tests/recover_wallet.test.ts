@@ -0,0 +1,224 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	ChangeCountersArgs,
+	getAttachWalletToIdentityIx,
+	getIdentityAccount,
+	getIdentityAccountPda,
+	getPolicyEngineAccount,
+	getRecoverWalletIx,
+	getTrackerAccount,
+	getWalletIdentityAccount,
+	getWalletIdentityAccountPda,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Keypair, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test wallet recovery", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		const changeCounterArgs: ChangeCountersArgs = {
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounters: Buffer.from([]),
+			addedCounters: [
+				{
+					value: new BN(0),
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+			],
+		};
+		const changeCounters = await rwaClient.policyEngine.changeCounters(changeCounterArgs);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+	describe("test RecoverWallet", async () => {
+		const newWallet = Keypair.generate().publicKey;
+		const getTokenAccount = (wallet: PublicKey) => getAccount(
+			setup.provider.connection,
+			getAssociatedTokenAddressSync(new PublicKey(mint), wallet, true, TOKEN_2022_PROGRAM_ID),
+			undefined,
+			TOKEN_2022_PROGRAM_ID
+		);
+
+		test("attempt recovery to the lost wallet", async () => {
+			const recoverIxs = await getRecoverWalletIx({
+				owner: setup.user1.toString(),
+				newWallet: setup.user1.toString(),
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				caseReference: "CASE-2026-002",
+			}, rwaClient.provider);
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...recoverIxs),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x177e/); // InvalidRecoveryWallet error
+		});
+
+		test("recover lost wallet", async () => {
+			const trackerBefore = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+			const recoverIxs = await getRecoverWalletIx({
+				owner: setup.user1.toString(),
+				newWallet: newWallet.toString(),
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				assetMint: mint,
+				caseReference: "CASE-2026-002",
+			}, rwaClient.provider);
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...recoverIxs),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+
+			const lostTokenAccount = await getTokenAccount(setup.user1);
+			const newTokenAccount = await getTokenAccount(newWallet);
+			expect(lostTokenAccount.amount).toBe(BigInt(0));
+			expect(lostTokenAccount.isFrozen).toBe(true);
+			expect(newTokenAccount.amount).toBe(BigInt(1000000));
+
+			// wallet mapping is swapped
+			expect(await getWalletIdentityAccount(getWalletIdentityAccountPda(mint, setup.user1.toString()), rwaClient.provider)).toBeUndefined();
+			const newWalletIdentity = await getWalletIdentityAccount(getWalletIdentityAccountPda(mint, newWallet.toString()), rwaClient.provider);
+			expect(newWalletIdentity?.identityAccount.toString()).toBe(getIdentityAccountPda(mint, setup.user1.toString()).toString());
+
+			// the identity follows the recovered owner wallet and keeps its address
+			const identityAccount = await getIdentityAccount(getIdentityAccountPda(mint, setup.user1.toString()), rwaClient.provider);
+			expect(identityAccount?.owner.toString()).toBe(newWallet.toString());
+			expect(identityAccount?.numWallets).toBe(1);
+
+			// tracker, holding periods and holder counters are left untouched
+			const trackerAfter = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+			expect(trackerAfter?.totalAmount.toNumber()).toBe(trackerBefore?.totalAmount.toNumber());
+			expect(trackerAfter?.issuances[0].issueTime.toNumber()).toBe(trackerBefore?.issuances[0].issueTime.toNumber());
+			const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+			expect(policyEngine?.counters.find((c) => c.id === 0)?.value.toNumber()).toBe(1);
+		});
+
+		test("lost owner key can no longer attach wallets", async () => {
+			const attachIx = await getAttachWalletToIdentityIx({
+				wallet: Keypair.generate().publicKey.toString(),
+				owner: setup.user1.toString(),
+				payer: setup.payer.toString(),
+				authority: setup.user1.toString(),
+				assetMint: mint,
+				signer: setup.user1.toString(),
+			}, rwaClient.provider);
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(attachIx),
+				[setup.payerKp, setup.user1Kp]
+			)).rejects.toThrowError(/custom program error: 0x1773/); // UnauthorizedSigner error
+		});
+	});
+});
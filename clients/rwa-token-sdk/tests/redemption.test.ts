@@ -0,0 +1,289 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	ChangeCountersArgs,
+	getApproveRedemptionIx,
+	getCancelRedemptionIx,
+	getCloseRedemptionRequestIx,
+	getRedemptionRequestAccount,
+	getRejectRedemptionIx,
+	getRequestRedemptionIx,
+	getTrackerAccount,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, createAssociatedTokenAccountIdempotentInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test redemption requests", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users
+		const setupUser1 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser1.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser1.signers]
+		);
+
+		const setupUser2 = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser2.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser2.signers]
+		);
+
+		const changeCounterArgs: ChangeCountersArgs = {
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			removedCounters: Buffer.from([]),
+			addedCounters: [
+				{
+					value: new BN(0),
+					id: 0,
+					countBeneficialOwners: false,
+					identityFilter: {
+						simple: [ {
+							single: [
+								{
+									target: {bothOr: {}},
+									mode: {include: {}},
+									level: {level: [1]},
+								}
+							]
+						}]
+					},
+				},
+			],
+		};
+		const changeCounters = await rwaClient.policyEngine.changeCounters(changeCounterArgs);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeCounters.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// Issue tokens to user1
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000000),
+			timestamp: new BN(0)
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+	describe("test redemption workflow", async () => {
+		const requestArgs = (id: number) => ({
+			wallet: setup.user1.toString(),
+			id: new BN(id),
+			assetMint: mint,
+			payer: setup.payer.toString(),
+		});
+		const request = async (id: number, amount: number) => sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getRequestRedemptionIx({ ...requestArgs(id), amount: new BN(amount) }, rwaClient.provider)),
+			[setup.payerKp, setup.user1Kp]
+		);
+		const getLocks = async () => (await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider))!.locks;
+
+		test("request redemption locks the amount", async () => {
+			expect(await request(0, 100)).toBeTruthy();
+			const locks = await getLocks();
+			expect(locks.length).toBe(1);
+			expect(locks[0].amount.toNumber()).toBe(100);
+			const redemptionRequest = await getRedemptionRequestAccount(mint, setup.user1.toString(), new BN(0), rwaClient.provider);
+			expect(redemptionRequest?.state).toEqual({ pending: {} });
+		});
+
+		test("attempt redemption exceeding balance", async () => {
+			await expect(request(1, 2000000)).rejects.toThrowError(/custom program error: 0x177f/); // InvalidRedemptionAmount error
+		});
+
+		test("cancel redemption releases the lock", async () => {
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getCancelRedemptionIx(requestArgs(0), rwaClient.provider)),
+				[setup.payerKp, setup.user1Kp]
+			);
+			expect(txnId).toBeTruthy();
+			expect((await getLocks()).length).toBe(0);
+			const redemptionRequest = await getRedemptionRequestAccount(mint, setup.user1.toString(), new BN(0), rwaClient.provider);
+			expect(redemptionRequest?.state).toEqual({ cancelled: {} });
+
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getCancelRedemptionIx(requestArgs(0), rwaClient.provider)),
+				[setup.payerKp, setup.user1Kp]
+			)).rejects.toThrowError(/custom program error: 0x1780/); // InvalidRedemptionState error
+		});
+
+		test("reject redemption releases the lock", async () => {
+			expect(await request(1, 100)).toBeTruthy();
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getRejectRedemptionIx({
+					...requestArgs(1),
+					authority: setup.authority.toString(),
+					reason: "outside redemption window",
+				}, rwaClient.provider)),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			expect((await getLocks()).length).toBe(0);
+			const redemptionRequest = await getRedemptionRequestAccount(mint, setup.user1.toString(), new BN(1), rwaClient.provider);
+			expect(redemptionRequest?.state).toEqual({ rejected: {} });
+		});
+
+		test("approve redemption burns the amount", async () => {
+			expect(await request(2, 100)).toBeTruthy();
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getApproveRedemptionIx({
+					...requestArgs(2),
+					authority: setup.authority.toString(),
+				}, rwaClient.provider)),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			const tracker = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+			expect(tracker?.locks.length).toBe(0);
+			expect(tracker?.totalAmount.toNumber()).toBe(1000000 - 100);
+			const redemptionRequest = await getRedemptionRequestAccount(mint, setup.user1.toString(), new BN(2), rwaClient.provider);
+			expect(redemptionRequest?.state).toEqual({ approved: {} });
+		});
+
+		test("attempt approval once the tokens moved to another wallet of the identity", async () => {
+			expect(await request(3, 999900)).toBeTruthy();
+			const attachWallet = await rwaClient.identityRegistry.attachWalletToIdentity({
+				owner: setup.user1.toString(),
+				assetMint: mint,
+				payer: setup.payer.toString(),
+				wallet: setup.user4.toString(),
+				authority: setup.user1.toString(),
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(attachWallet),
+				[setup.payerKp, setup.user1Kp]
+			);
+			const transfer = await rwaClient.assetController.transfer({
+				from: setup.user1.toString(),
+				to: setup.user4.toString(),
+				assetMint: mint,
+				amount: new BN(999900),
+				decimals,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(
+					createAssociatedTokenAccountIdempotentInstruction(
+						setup.payer,
+						getAssociatedTokenAddressSync(new PublicKey(mint), setup.user4, true, TOKEN_2022_PROGRAM_ID),
+						setup.user4,
+						new PublicKey(mint),
+						TOKEN_2022_PROGRAM_ID
+					),
+					...transfer
+				),
+				[setup.payerKp, setup.user1Kp]
+			);
+
+			await expect(sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getApproveRedemptionIx({
+					...requestArgs(3),
+					authority: setup.authority.toString(),
+				}, rwaClient.provider)),
+				[setup.payerKp, setup.authorityKp]
+			)).rejects.toThrowError(/custom program error: 0x1791/); // RedemptionBalanceInsufficient error
+
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getRejectRedemptionIx({
+					...requestArgs(3),
+					authority: setup.authority.toString(),
+					reason: "balance moved",
+				}, rwaClient.provider)),
+				[setup.payerKp, setup.authorityKp]
+			);
+			expect(txnId).toBeTruthy();
+			expect((await getLocks()).length).toBe(0);
+		});
+
+		test("close settled redemption request", async () => {
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getCloseRedemptionRequestIx(requestArgs(0), rwaClient.provider)),
+				[setup.payerKp, setup.user1Kp]
+			);
+			expect(txnId).toBeTruthy();
+			expect(await getRedemptionRequestAccount(mint, setup.user1.toString(), new BN(0), rwaClient.provider)).toBeUndefined();
+		});
+	});
+});
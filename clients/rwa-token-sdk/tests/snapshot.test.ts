@@ -0,0 +1,159 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getPolicyEngineAccount,
+	getSnapshotBalance,
+	getTrackerAccount,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test record date snapshots", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	const openSnapshot = async () => {
+		const openSnapshot = await rwaClient.policyEngine.openSnapshot({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...openSnapshot.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+	};
+	const transfer = async (amount: number) => {
+		const transferIxs = await rwaClient.assetController.transfer({
+			from: setup.user1.toString(),
+			to: setup.user2.toString(),
+			assetMint: mint,
+			amount: new BN(amount),
+			decimals,
+			createTa: true,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...transferIxs),
+			[setup.user1Kp]
+		);
+	};
+	const getBalance = async (user: string, snapshotId: number) =>
+		(await getSnapshotBalance(mint, user, new BN(snapshotId), rwaClient.provider))?.toNumber();
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		for (const user of [setup.user1, setup.user2]) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				levels: [1],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+		}
+
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	test("open snapshot records the supply", async () => {
+		expect(await openSnapshot()).toBeTruthy();
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.snapshot.id.toNumber()).toBe(1);
+		expect(policyEngine?.snapshot.supply.toNumber()).toBe(1000);
+	});
+
+	test("transfers checkpoint the balances at the record date", async () => {
+		expect(await transfer(400)).toBeTruthy();
+		expect(await transfer(100)).toBeTruthy();
+
+		const tracker = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+		expect(tracker?.totalAmount.toNumber()).toBe(500);
+		expect(tracker?.checkpoints[1].snapshotId.toNumber()).toBe(1);
+		expect(await getBalance(setup.user1.toString(), 1)).toBe(1000);
+		expect(await getBalance(setup.user2.toString(), 1)).toBe(0);
+	});
+
+	test("a later snapshot reads unchanged balances from the trackers", async () => {
+		expect(await openSnapshot()).toBeTruthy();
+		expect(await getBalance(setup.user1.toString(), 2)).toBe(500);
+		expect(await getBalance(setup.user2.toString(), 2)).toBe(500);
+		// user1 has not changed since the first checkpoint, both snapshots are still readable
+		expect(await getBalance(setup.user1.toString(), 1)).toBe(1000);
+
+		expect(await transfer(50)).toBeTruthy();
+		expect(await getBalance(setup.user1.toString(), 2)).toBe(500);
+		// the checkpoint of the first snapshot is kept in its own slot
+		expect(await getBalance(setup.user1.toString(), 1)).toBe(1000);
+		expect(await getBalance(setup.user1.toString(), 3)).toBeUndefined();
+	});
+
+	test("snapshots leave the readable window after 4 newer ones", async () => {
+		for (let i = 0; i < 3; i++) {
+			expect(await openSnapshot()).toBeTruthy();
+			expect(await transfer(10)).toBeTruthy();
+		}
+		expect(await getBalance(setup.user1.toString(), 2)).toBe(500);
+		expect(await getBalance(setup.user1.toString(), 5)).toBe(430);
+		expect(await getBalance(setup.user1.toString(), 1)).toBeUndefined();
+	});
+});
@@ -0,0 +1,125 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getScaledUiAmountMultiplier,
+	getTrackerAccount,
+	getUpdateMultiplierIx,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test splits through the scaled ui amount multiplier", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+
+	const updateMultiplier = async (multiplier: number, effectiveTimestamp: number, authority = setup.authorityKp) => {
+		const updateMultiplierIx = await getUpdateMultiplierIx({
+			authority: authority.publicKey.toString(),
+			assetMint: mint,
+			multiplier,
+			effectiveTimestamp: new BN(effectiveTimestamp),
+		}, rwaClient.provider);
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(updateMultiplierIx),
+			[setup.payerKp, authority]
+		);
+	};
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller with the scaled ui amount extension
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+			multiplier: 1,
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+		expect(await getScaledUiAmountMultiplier(mint, rwaClient.provider)).toBe(1);
+
+		const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+		);
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			amount: new BN(1000),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	});
+
+	test("fail to update the multiplier without the authority", async () => {
+		await expect(updateMultiplier(2, 0, setup.user1Kp)).rejects.toThrowError();
+	});
+
+	test("2:1 split doubles the ui amounts without moving balances", async () => {
+		expect(await updateMultiplier(2, Math.floor(Date.now() / 1000) - 60)).toBeTruthy();
+		expect(await getScaledUiAmountMultiplier(mint, rwaClient.provider)).toBe(2);
+
+		// balances, and the limits enforced on them, stay in base units
+		const tracker = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+		expect(tracker?.totalAmount.toNumber()).toBe(1000);
+		const tokenAccount = getAssociatedTokenAddressSync(new PublicKey(mint), setup.user1, false, TOKEN_2022_PROGRAM_ID);
+		const balance = await setup.provider.connection.getTokenAccountBalance(tokenAccount);
+		expect(balance.value.amount).toBe("1000");
+	});
+
+	test("schedule a reverse split", async () => {
+		const effectiveTimestamp = Math.floor(Date.now() / 1000) + 24 * 60 * 60;
+		expect(await updateMultiplier(1, effectiveTimestamp)).toBeTruthy();
+		expect(await getScaledUiAmountMultiplier(mint, rwaClient.provider)).toBe(2);
+		expect(await getScaledUiAmountMultiplier(mint, rwaClient.provider, effectiveTimestamp)).toBe(1);
+	});
+});
@@ -0,0 +1,374 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getCloseSubscriptionRequestIx,
+	getCreateSubscriptionOfferIx,
+	getRefundSubscriptionIx,
+	getSettleSubscriptionIx,
+	getSubscribeIx,
+	getSubscriptionOfferAccount,
+	getSubscriptionRequestAccount,
+	getTrancheAccount,
+	getTrancheSubscriptionAccount,
+	getUpdateSubscriptionOfferIx,
+	RwaClient,
+	TrancheConfig,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+	TOKEN_2022_PROGRAM_ID,
+	createMint,
+	getAccount,
+	getAssociatedTokenAddressSync,
+	getOrCreateAssociatedTokenAccount,
+	mintTo,
+} from "@solana/spl-token";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test stablecoin subscriptions", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	let paymentMint: string;
+	let treasury: string;
+	const setup = await setupTests();
+	const decimals = 2;
+	const offerId = new BN(1);
+	// 1.50 payment tokens (6 decimals) per whole share
+	const price = new BN(1_500_000);
+	const cutoff = new BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60);
+
+	const offerArgs = () => ({
+		offerId,
+		assetMint: mint,
+		paymentMint,
+	});
+	const requestArgs = (wallet: string, id: number) => ({
+		...offerArgs(),
+		wallet,
+		id: new BN(id),
+		payer: setup.payer.toString(),
+	});
+	const subscribe = async (id: number, paymentAmount: number) => sendAndConfirmTransaction(
+		setup.provider.connection,
+		new Transaction().add(await getSubscribeIx({
+			...requestArgs(setup.user1.toString(), id),
+			paymentAmount: new BN(paymentAmount),
+		}, rwaClient.provider)),
+		[setup.payerKp, setup.user1Kp]
+	);
+	const getPaymentBalance = async (account: string) =>
+		Number((await getAccount(setup.provider.connection, new PublicKey(account))).amount);
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup user1 as a verified investor
+		const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user1.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+		);
+
+		// Create the payment stablecoin and fund user1
+		const paymentMintKey = await createMint(setup.provider.connection, setup.payerKp, setup.authority, null, 6);
+		paymentMint = paymentMintKey.toString();
+		const investorAccount = await getOrCreateAssociatedTokenAccount(
+			setup.provider.connection, setup.payerKp, paymentMintKey, setup.user1
+		);
+		await mintTo(setup.provider.connection, setup.payerKp, paymentMintKey, investorAccount.address, setup.authorityKp, 10_000_000);
+		treasury = (await getOrCreateAssociatedTokenAccount(
+			setup.provider.connection, setup.payerKp, paymentMintKey, setup.authority
+		)).address.toString();
+	});
+
+	test("create subscription offer", async () => {
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getCreateSubscriptionOfferIx({
+				...offerArgs(),
+				authority: setup.authority.toString(),
+				payer: setup.payer.toString(),
+				treasury,
+				price,
+				cutoff,
+			}, rwaClient.provider)),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		const offer = await getSubscriptionOfferAccount(mint, offerId, rwaClient.provider);
+		expect(offer?.price.toString()).toBe(price.toString());
+	});
+
+	test("subscribe deposits the payment", async () => {
+		expect(await subscribe(0, 3_000_000)).toBeTruthy();
+		const request = await getSubscriptionRequestAccount(mint, offerId, setup.user1.toString(), new BN(0), rwaClient.provider);
+		expect(request?.state).toEqual({ pending: {} });
+		expect(request?.price.toString()).toBe(price.toString());
+		const offer = await getSubscriptionOfferAccount(mint, offerId, rwaClient.provider);
+		expect(offer?.pendingAmount.toNumber()).toBe(3_000_000);
+	});
+
+	test("fail to subscribe below one share unit", async () => {
+		await expect(subscribe(1, 1000)).rejects.toThrowError(/custom program error: 0x1783/); // InvalidSubscriptionAmount error
+	});
+
+	test("fail to settle without the authority before the cutoff", async () => {
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getSettleSubscriptionIx({
+				...requestArgs(setup.user1.toString(), 0),
+				signer: setup.user1.toString(),
+				treasury,
+			}, rwaClient.provider)),
+			[setup.payerKp, setup.user1Kp]
+		)).rejects.toThrowError(/custom program error: 0x1786/); // SubscriptionSettlementNotAllowed error
+	});
+
+	test("new price only applies to new subscriptions", async () => {
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getUpdateSubscriptionOfferIx({
+				offerId,
+				assetMint: mint,
+				authority: setup.authority.toString(),
+				treasury,
+				price: new BN(2_000_000),
+				cutoff,
+			}, rwaClient.provider)),
+			[setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		expect(await subscribe(1, 1_000_000)).toBeTruthy();
+	});
+
+	test("settle subscription issues shares at the subscription price", async () => {
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getSettleSubscriptionIx({
+				...requestArgs(setup.user1.toString(), 0),
+				signer: setup.authority.toString(),
+				treasury,
+			}, rwaClient.provider)),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+
+		// 3.00 / 1.50 = 2 whole shares
+		const shares = await getAccount(
+			setup.provider.connection,
+			getAssociatedTokenAddressSync(new PublicKey(mint), setup.user1, true, TOKEN_2022_PROGRAM_ID),
+			undefined,
+			TOKEN_2022_PROGRAM_ID
+		);
+		expect(Number(shares.amount)).toBe(200);
+		expect(await getPaymentBalance(treasury)).toBe(3_000_000);
+		const request = await getSubscriptionRequestAccount(mint, offerId, setup.user1.toString(), new BN(0), rwaClient.provider);
+		expect(request?.state).toEqual({ settled: {} });
+		expect(request?.shares.toNumber()).toBe(200);
+	});
+
+	test("settle subscription against a tranche", async () => {
+		const trancheId = new BN(1);
+		const now = Math.floor(Date.now() / 1000);
+		const trancheConfig: TrancheConfig = {
+			openTimestamp: new BN(now - 60),
+			closeTimestamp: new BN(now + 24 * 60 * 60),
+			cap: new BN(100),
+			minSubscription: new BN(1),
+			maxSubscription: new BN(100),
+			lockPeriod: new BN(24 * 60 * 60),
+			identityFilter: {
+				simple: [{
+					single: [
+						{
+							target: {bothOr: {}},
+							mode: {include: {}},
+							level: {level: [1]},
+						}
+					]
+				}]
+			},
+		};
+		const createTranche = await rwaClient.policyEngine.createTranche({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			id: trancheId,
+			config: trancheConfig,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...createTranche.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// 1.50 / 2.00 = 0.75 shares
+		expect(await subscribe(2, 1_500_000)).toBeTruthy();
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getSettleSubscriptionIx({
+				...requestArgs(setup.user1.toString(), 2),
+				signer: setup.authority.toString(),
+				treasury,
+				trancheId,
+			}, rwaClient.provider)),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		const tranche = await getTrancheAccount(mint, trancheId, rwaClient.provider);
+		expect(tranche?.issued.toNumber()).toBe(75);
+		const subscription = await getTrancheSubscriptionAccount(mint, trancheId, setup.user1.toString(), rwaClient.provider);
+		expect(subscription?.subscribed.toNumber()).toBe(75);
+	});
+
+	test("refund subscription returns the payment", async () => {
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getRefundSubscriptionIx({
+				...offerArgs(),
+				wallet: setup.user1.toString(),
+				id: new BN(1),
+				signer: setup.authority.toString(),
+				reason: "offering cancelled",
+			}, rwaClient.provider)),
+			[setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		const investorAccount = getAssociatedTokenAddressSync(
+			new PublicKey(paymentMint), setup.user1
+		);
+		expect(await getPaymentBalance(investorAccount.toString())).toBe(5_500_000);
+		const offer = await getSubscriptionOfferAccount(mint, offerId, rwaClient.provider);
+		expect(offer?.pendingAmount.toNumber()).toBe(0);
+	});
+
+	test("wallet refunds a subscription once its identity is revoked", async () => {
+		const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			levels: [1],
+			expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+			signer: setup.authorityKp.publicKey.toString(),
+			country: 0,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupUser.ixs),
+			[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+		);
+		const investorAccount = await getOrCreateAssociatedTokenAccount(
+			setup.provider.connection, setup.payerKp, new PublicKey(paymentMint), setup.user2
+		);
+		await mintTo(setup.provider.connection, setup.payerKp, new PublicKey(paymentMint), investorAccount.address, setup.authorityKp, 1_000_000);
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await getSubscribeIx({
+				...requestArgs(setup.user2.toString(), 0),
+				paymentAmount: new BN(1_000_000),
+			}, rwaClient.provider)),
+			[setup.payerKp, setup.user2Kp]
+		);
+
+		const refund = async (signer: string) => getRefundSubscriptionIx({
+			...offerArgs(),
+			wallet: setup.user2.toString(),
+			id: new BN(0),
+			signer,
+			reason: "identity revoked",
+		}, rwaClient.provider);
+		// the request can still be settled, only the authority refunds it
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await refund(setup.user2.toString())),
+			[setup.user2Kp]
+		)).rejects.toThrowError(/custom program error: 0x1792/); // SubscriptionRefundNotAllowed error
+
+		const revokeIx = await rwaClient.identityRegistry.revokeIdentityAccount({
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			signer: setup.authority.toString(),
+			payer: setup.payer.toString(),
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(revokeIx),
+			[setup.payerKp, setup.authorityKp]
+		);
+
+		// anyone else has to wait for the cutoff
+		await expect(sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await refund(setup.user1.toString())),
+			[setup.user1Kp]
+		)).rejects.toThrowError(/custom program error: 0x1792/); // SubscriptionRefundNotAllowed error
+
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(await refund(setup.user2.toString())),
+			[setup.user2Kp]
+		);
+		expect(txnId).toBeTruthy();
+		expect(await getPaymentBalance(investorAccount.address.toString())).toBe(1_000_000);
+		const request = await getSubscriptionRequestAccount(mint, offerId, setup.user2.toString(), new BN(0), rwaClient.provider);
+		expect(request?.state).toEqual({ refunded: {} });
+	});
+
+	test("close resolved subscription requests", async () => {
+		for (const id of [0, 1, 2]) {
+			const txnId = await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(await getCloseSubscriptionRequestIx(requestArgs(setup.user1.toString(), id), rwaClient.provider)),
+				[setup.payerKp, setup.user1Kp]
+			);
+			expect(txnId).toBeTruthy();
+			expect(await getSubscriptionRequestAccount(mint, offerId, setup.user1.toString(), new BN(id), rwaClient.provider)).toBeUndefined();
+		}
+	});
+});
@@ -0,0 +1,192 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getPendingChangeAccount,
+	getPolicyEngineAccount,
+	RwaClient,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("timelocked policy engine changes", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+
+	const decimals = 2;
+	const delay = 3;
+
+	test("setup provider", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+
+		rwaClient = new RwaClient(config, provider);
+	});
+
+	test("setup registries", async () => {
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+
+		const txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...setupAssetController.ixs), [setup.payerKp, ...setupAssetController.signers]);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+	});
+
+	test("enable timelock and disable direct changes", async () => {
+		const changeTimelockIx = await rwaClient.policyEngine.changeTimelock({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			timelock: { delay: new BN(delay), directChangesEnabled: false },
+		});
+		const txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...changeTimelockIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.timelock.delay.toNumber()).toBe(delay);
+		expect(policyEngine?.timelock.directChangesEnabled).toBe(false);
+	});
+
+	test("direct changes are rejected", async () => {
+		const changeMappingIx = await rwaClient.policyEngine.changeMapping({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			mappingSource: [1],
+			mappingValue: [2],
+		});
+		await expect(sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...changeMappingIx.ixs), [setup.payerKp, setup.authorityKp]))
+			.rejects.toThrowError(/custom program error: 0x17af/); // DirectChangesDisabled
+	});
+
+	test("propose and cancel a change", async () => {
+		const proposeIx = await rwaClient.policyEngine.proposeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			change: { changeMapping: { mappingSource: Buffer.from([1]), mappingValue: Buffer.from([3]) } },
+		});
+		let txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...proposeIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		const cancelIx = await rwaClient.policyEngine.cancelChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			id: new BN(0),
+		});
+		txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...cancelIx.ixs), [setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+		expect(await getPendingChangeAccount(mint, new BN(0), rwaClient.provider)).toBeUndefined();
+	});
+
+	test("propose a change and execute it after the delay", async () => {
+		const proposeIx = await rwaClient.policyEngine.proposeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			change: { changeMapping: { mappingSource: Buffer.from([1]), mappingValue: Buffer.from([2]) } },
+		});
+		let txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...proposeIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		const pendingChange = await getPendingChangeAccount(mint, new BN(1), rwaClient.provider);
+		expect(pendingChange?.executableAt.sub(pendingChange.proposedAt).toNumber()).toBe(delay);
+
+		const executeIx = await rwaClient.policyEngine.executeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			id: new BN(1),
+		});
+		await expect(sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...executeIx.ixs), [setup.payerKp, setup.authorityKp]))
+			.rejects.toThrowError(/custom program error: 0x17b0/); // ChangeNotExecutable
+
+		await new Promise((resolve) => setTimeout(resolve, (delay + 2) * 1000));
+
+		txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...executeIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.mapping[1]).toBe(2);
+		expect(await getPendingChangeAccount(mint, new BN(1), rwaClient.provider)).toBeUndefined();
+	});
+
+	test("a raised delay applies to changes proposed before it", async () => {
+		const proposeIx = await rwaClient.policyEngine.proposeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			change: { changeMapping: { mappingSource: Buffer.from([1]), mappingValue: Buffer.from([3]) } },
+		});
+		let txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...proposeIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		const proposeTimelockIx = await rwaClient.policyEngine.proposeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			change: { changeTimelock: { timelock: { delay: new BN(3600), directChangesEnabled: false } } },
+		});
+		txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...proposeTimelockIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		await new Promise((resolve) => setTimeout(resolve, (delay + 2) * 1000));
+
+		const executeTimelockIx = await rwaClient.policyEngine.executeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			id: new BN(3),
+		});
+		txnId = await sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...executeTimelockIx.ixs), [setup.payerKp, setup.authorityKp]);
+		expect(txnId).toBeTruthy();
+
+		// proposed under the old delay, but the one in force when executing applies
+		const executeIx = await rwaClient.policyEngine.executeChange({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			id: new BN(2),
+		});
+		await expect(sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...executeIx.ixs), [setup.payerKp, setup.authorityKp]))
+			.rejects.toThrowError(/custom program error: 0x17b0/); // ChangeNotExecutable
+		const policyEngine = await getPolicyEngineAccount(mint, rwaClient.provider);
+		expect(policyEngine?.mapping[1]).toBe(2);
+	});
+
+	test("counter values are rejected without going through the queue", async () => {
+		const setCountersIx = await rwaClient.policyEngine.setCounters({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			authority: setup.authority.toString(),
+			changedCounters: [0],
+			values: [new BN(1)],
+		});
+		await expect(sendAndConfirmTransaction(setup.provider.connection, new Transaction().add(...setCountersIx.ixs), [setup.payerKp, setup.authorityKp]))
+			.rejects.toThrowError(/custom program error: 0x17af/); // DirectChangesDisabled
+	});
+});
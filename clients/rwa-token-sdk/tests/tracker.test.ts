@@ -152,4 +152,50 @@ describe("test suite to test tracker account is being updated correctly on trans
 		);
 		expect(txnId).toBeTruthy();
 	});
+
+	test("attempt to migrate accounts already at the current version", async () => {
+		const migrateTrackerIx = await rwaClient.policyEngine.migrateTrackerAccount({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			owner: setup.user1.toString(),
+		});
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...migrateTrackerIx.ixs),
+			[setup.payerKp]
+		)).rejects.toThrowError(/custom program error: 0x17cf/); // InvalidAccountVersion error
+
+		const migratePolicyEngineIx = await rwaClient.policyEngine.migratePolicyEngine({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+		});
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(...migratePolicyEngineIx.ixs),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x17cf/); // InvalidAccountVersion error
+
+		const migrateRegistryIx = await rwaClient.identityRegistry.migrateIdentityRegistry({
+			payer: setup.payer.toString(),
+			signer: setup.authority.toString(),
+			assetMint: mint,
+		});
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(migrateRegistryIx),
+			[setup.payerKp, setup.authorityKp]
+		)).rejects.toThrowError(/custom program error: 0x177d/); // InvalidAccountVersion error
+
+		const migrateIdentityIx = await rwaClient.identityRegistry.migrateIdentityAccount({
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			owner: setup.user1.toString(),
+		});
+		await expect(sendAndConfirmTransaction(
+			rwaClient.provider.connection,
+			new Transaction().add(migrateIdentityIx),
+			[setup.payerKp]
+		)).rejects.toThrowError(/custom program error: 0x177d/); // InvalidAccountVersion error
+	});
 });
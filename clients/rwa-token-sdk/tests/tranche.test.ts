@@ -0,0 +1,243 @@
+import { AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
+import {
+	getTrackerAccount,
+	getTrancheAccount,
+	getTrancheSubscriptionAccount,
+	RwaClient,
+	TrancheConfig,
+} from "../src";
+import { setupTests } from "./setup";
+import { ConfirmOptions, Connection, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { expect, test, describe } from "vitest";
+import { Config } from "../src/classes/types";
+
+describe("test offering tranches", async () => {
+	let rwaClient: RwaClient;
+	let mint: string;
+	const setup = await setupTests();
+	const decimals = 2;
+	const trancheId = new BN(1);
+	const now = Math.floor(Date.now() / 1000);
+	const trancheConfig: TrancheConfig = {
+		openTimestamp: new BN(now - 60),
+		closeTimestamp: new BN(now + 24 * 60 * 60),
+		cap: new BN(5000),
+		minSubscription: new BN(1000),
+		maxSubscription: new BN(3000),
+		lockPeriod: new BN(24 * 60 * 60),
+		identityFilter: {
+			simple: [{
+				single: [
+					{
+						target: {bothOr: {}},
+						mode: {include: {}},
+						level: {level: [1]},
+					}
+				]
+			}]
+		},
+	};
+
+	const issue = async (owner: string, amount: number) => {
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner,
+			assetMint: mint,
+			amount: new BN(amount),
+			trancheId,
+		});
+		return sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+	};
+
+	test("setup environment", async () => {
+		const connectionUrl = process.env.RPC_URL ?? "http://localhost:8899";
+		const connection = new Connection(connectionUrl, "processed");
+
+		const confirmationOptions: ConfirmOptions = {
+			skipPreflight: false,
+			maxRetries: 3,
+			commitment: "processed",
+		};
+
+		const config: Config = {
+			connection,
+			rpcUrl: connectionUrl,
+			confirmationOptions,
+		};
+
+		const provider = new AnchorProvider(connection, new Wallet(setup.payerKp), confirmationOptions);
+		rwaClient = new RwaClient(config, provider);
+
+		// Create asset controller
+		const createAssetControllerArgs = {
+			decimals,
+			payer: setup.payer.toString(),
+			authority: setup.authority.toString(),
+			name: "Test Asset",
+			uri: "https://test.com",
+			symbol: "TST",
+		};
+		const setupAssetController = await rwaClient.assetController.setupNewRegistry(
+			createAssetControllerArgs
+		);
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...setupAssetController.ixs),
+			[setup.payerKp, ...setupAssetController.signers]
+		);
+		mint = setupAssetController.signers[0].publicKey.toString();
+		expect(txnId).toBeTruthy();
+
+		// Setup users, user3 does not have the level eligible for the tranche
+		for (const [user, level] of [[setup.user1, 1], [setup.user2, 1], [setup.user3, 2]] as const) {
+			const setupUser = await rwaClient.identityRegistry.setupUserIxns({
+				payer: setup.payer.toString(),
+				owner: user.toString(),
+				assetMint: mint,
+				levels: [level],
+				expiry: [new BN(Date.now() / 1000 + 24 * 60 * 60)],
+				signer: setup.authorityKp.publicKey.toString(),
+				country: 0,
+			});
+			await sendAndConfirmTransaction(
+				setup.provider.connection,
+				new Transaction().add(...setupUser.ixs),
+				[setup.payerKp, setup.authorityKp, ...setupUser.signers]
+			);
+		}
+	});
+
+	test("create tranche", async () => {
+		const createTranche = await rwaClient.policyEngine.createTranche({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			id: trancheId,
+			config: trancheConfig,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...createTranche.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		const tranche = await getTrancheAccount(mint, trancheId, rwaClient.provider);
+		expect(tranche?.cap.toNumber()).toBe(5000);
+		expect(tranche?.issued.toNumber()).toBe(0);
+	});
+
+	test("fail to issue below the per-investor minimum", async () => {
+		await expect(issue(setup.user1.toString(), 500)).rejects.toThrowError(/custom program error: 0x17be/); // TrancheSubscriptionOutOfBounds error
+	});
+
+	test("fail to issue to an identity outside the tranche filter", async () => {
+		await expect(issue(setup.user3.toString(), 1000)).rejects.toThrowError(/custom program error: 0x17bf/); // TrancheIdentityNotEligible error
+	});
+
+	test("issue against the tranche", async () => {
+		expect(await issue(setup.user1.toString(), 1000)).toBeTruthy();
+		// top ups count towards the subscribed total, so they can be under the minimum
+		expect(await issue(setup.user1.toString(), 500)).toBeTruthy();
+
+		const subscription = await getTrancheSubscriptionAccount(mint, trancheId, setup.user1.toString(), rwaClient.provider);
+		expect(subscription?.subscribed.toNumber()).toBe(1500);
+		const tranche = await getTrancheAccount(mint, trancheId, rwaClient.provider);
+		expect(tranche?.issued.toNumber()).toBe(1500);
+
+		// issued tokens are locked for the tranche lock period
+		const tracker = await getTrackerAccount(mint, setup.user1.toString(), rwaClient.provider);
+		expect(tracker?.locks.length).toBe(2);
+		expect(tracker?.locks.every((lock) => lock.releaseTime.gt(new BN(now)))).toBe(true);
+	});
+
+	test("fail to issue over the per-investor maximum", async () => {
+		await expect(issue(setup.user1.toString(), 2000)).rejects.toThrowError(/custom program error: 0x17be/); // TrancheSubscriptionOutOfBounds error
+	});
+
+	test("fail to issue over the tranche cap", async () => {
+		expect(await issue(setup.user2.toString(), 3000)).toBeTruthy();
+		await expect(issue(setup.user1.toString(), 1000)).rejects.toThrowError(/custom program error: 0x17bd/); // TrancheCapExceeded error
+	});
+
+	test("remove expired tranche locks", async () => {
+		const shortTrancheId = new BN(2);
+		const createTranche = await rwaClient.policyEngine.createTranche({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			id: shortTrancheId,
+			config: { ...trancheConfig, lockPeriod: new BN(1) },
+		});
+		const issueTokens = await rwaClient.assetController.issueTokenIxns({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			owner: setup.user2.toString(),
+			assetMint: mint,
+			amount: new BN(1000),
+			trancheId: shortTrancheId,
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...createTranche.ixs, ...issueTokens),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect((await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider))?.locks.length).toBe(2);
+
+		await new Promise((resolve) => setTimeout(resolve, 3000));
+		const removeExpiredTrancheLocks = await rwaClient.policyEngine.removeExpiredTrancheLocks({
+			owner: setup.user2.toString(),
+			assetMint: mint,
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...removeExpiredTrancheLocks.ixs),
+			[setup.payerKp]
+		);
+		expect(txnId).toBeTruthy();
+		// only the lock of the first tranche is still running
+		const tracker = await getTrackerAccount(mint, setup.user2.toString(), rwaClient.provider);
+		expect(tracker?.locks.length).toBe(1);
+		expect(tracker?.locks[0].releaseTime.gt(new BN(now))).toBe(true);
+	});
+
+	test("fail to issue once the tranche is closed", async () => {
+		const changeTranche = await rwaClient.policyEngine.changeTranche({
+			authority: setup.authority.toString(),
+			payer: setup.payer.toString(),
+			assetMint: mint,
+			id: trancheId,
+			config: {
+				...trancheConfig,
+				openTimestamp: new BN(now - 120),
+				closeTimestamp: new BN(now - 60),
+			},
+		});
+		await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...changeTranche.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		await expect(issue(setup.user1.toString(), 100)).rejects.toThrowError(/custom program error: 0x17bc/); // TrancheNotOpen error
+	});
+
+	test("close tranche", async () => {
+		const closeTranche = await rwaClient.policyEngine.closeTranche({
+			authority: setup.authority.toString(),
+			assetMint: mint,
+			id: trancheId,
+			rentReceiver: setup.payer.toString(),
+		});
+		const txnId = await sendAndConfirmTransaction(
+			setup.provider.connection,
+			new Transaction().add(...closeTranche.ixs),
+			[setup.payerKp, setup.authorityKp]
+		);
+		expect(txnId).toBeTruthy();
+		expect(await getTrancheAccount(mint, trancheId, rwaClient.provider)).toBeUndefined();
+	});
+});
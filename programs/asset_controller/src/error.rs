@@ -28,4 +28,48 @@ pub enum AssetControllerErrors {
     InvalidIdentityAccounts,
     #[msg("Invalid authority")]
     InvalidAuthority,
+    #[msg("Invalid seize legs")]
+    InvalidSeizeLegs,
+    #[msg("Recovery wallet must differ from the lost wallet")]
+    InvalidRecoveryWallet,
+    #[msg("Redemption amount must be positive and covered by the token account balance")]
+    InvalidRedemptionAmount,
+    #[msg("Redemption request is not in a valid state for this operation")]
+    InvalidRedemptionState,
+    #[msg("Invalid issuance batch")]
+    InvalidIssuanceBatch,
+    #[msg("Subscription price must be positive")]
+    InvalidSubscriptionPrice,
+    #[msg("Subscription amount must be positive and buy at least one share unit")]
+    InvalidSubscriptionAmount,
+    #[msg("Subscription request is not in a valid state for this operation")]
+    InvalidSubscriptionState,
+    #[msg("Subscription offer is closed for deposits")]
+    SubscriptionOfferClosed,
+    #[msg("Subscriptions can only be settled by the authority before the cutoff")]
+    SubscriptionSettlementNotAllowed,
+    #[msg("Distribution must be funded against a non empty snapshot and expire after its record date")]
+    InvalidDistribution,
+    #[msg("Distribution claim is empty or exceeds the funded amount")]
+    InvalidDistributionClaim,
+    #[msg("Balance proof does not match the distribution snapshot")]
+    InvalidBalanceProof,
+    #[msg("Distribution has expired")]
+    DistributionExpired,
+    #[msg("Distribution can only be reclaimed once after its expiry")]
+    DistributionNotReclaimable,
+    #[msg("Withholding rates must be at most 10000 bps with one rate per country group")]
+    InvalidWithholdingRates,
+    #[msg("Tax vault of the distribution is missing")]
+    TaxVaultMissing,
+    #[msg("Conversion ratio must have a non-zero numerator and denominator")]
+    InvalidConversionRatio,
+    #[msg("Converted amount is zero or overflows")]
+    InvalidConversionAmount,
+    #[msg("Scaled ui amount and interest-bearing extensions cannot be combined")]
+    IncompatibleExtensions,
+    #[msg("Token account no longer holds the amount of the redemption request")]
+    RedemptionBalanceInsufficient,
+    #[msg("Subscriptions can only be refunded by the authority, or by the wallet or after the cutoff once they cannot be settled")]
+    SubscriptionRefundNotAllowed,
 }
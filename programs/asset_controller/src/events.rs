@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::WithholdingRate;
+
 #[event]
 pub struct BurnEvent {
     pub amount: u64,
@@ -16,6 +18,46 @@ pub struct SeizeEvent {
     pub wallet: Pubkey,
     pub to_wallet: Pubkey,
     pub mint: Pubkey,
+    pub enforce_limits: bool,
+    /// holder counters the seize removed the source identity from
+    pub decreased_counters: Vec<u8>,
+    /// holder counters the seize added the destination identity to
+    pub increased_counters: Vec<u8>,
+    /// issuances moved to the destination, newest lots of the source first. they keep their issue time, so the
+    /// holding periods of the seized shares run from their issuance and not from the seize
+    pub moved_issuances_amount: u64,
+}
+
+/// emitted for every leg of a multi destination seize, legs of one seize share the case reference
+#[event]
+pub struct SeizeLegEvent {
+    pub case_reference: String,
+    pub leg: u8,
+    pub legs: u8,
+    pub amount: u64,
+    pub reason: String,
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+    pub to_wallet: Pubkey,
+    pub mint: Pubkey,
+    pub enforce_limits: bool,
+    pub decreased_counters: Vec<u8>,
+    pub increased_counters: Vec<u8>,
+    /// issuances moved to the destination, newest lots of the source first. they keep their issue time, so the
+    /// holding periods of the seized shares run from their issuance and not from the seize
+    pub moved_issuances_amount: u64,
+}
+
+/// emitted when the tokens and identity of a lost wallet are moved to a new wallet
+#[event]
+pub struct WalletRecoveredEvent {
+    pub case_reference: String,
+    pub mint: Pubkey,
+    pub identity: Pubkey,
+    pub lost_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
 }
 
 #[event]
@@ -33,3 +75,135 @@ pub struct IssueEvent {
     pub wallet: Pubkey,
     pub mint: Pubkey,
 }
+
+#[event]
+pub struct RedemptionRequestedEvent {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RedemptionApprovedEvent {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RedemptionRejectedEvent {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RedemptionCancelledEvent {
+    pub mint: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SubscriptionOfferEvent {
+    pub mint: Pubkey,
+    pub offer: Pubkey,
+    pub payment_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub price: u64,
+    pub cutoff: i64,
+}
+
+#[event]
+pub struct SubscriptionRequestedEvent {
+    pub mint: Pubkey,
+    pub offer: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub payment_amount: u64,
+    pub price: u64,
+}
+
+#[event]
+pub struct SubscriptionSettledEvent {
+    pub mint: Pubkey,
+    pub offer: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub payment_amount: u64,
+    pub shares: u64,
+    pub settled_by: Pubkey,
+}
+
+#[event]
+pub struct SubscriptionRefundedEvent {
+    pub mint: Pubkey,
+    pub offer: Pubkey,
+    pub request: Pubkey,
+    pub wallet: Pubkey,
+    pub payment_amount: u64,
+    pub reason: String,
+    pub refunded_by: Pubkey,
+}
+
+#[event]
+pub struct DistributionCreatedEvent {
+    pub mint: Pubkey,
+    pub distribution: Pubkey,
+    pub payout_mint: Pubkey,
+    pub total_amount: u64,
+    pub snapshot_id: u64,
+    pub snapshot_supply: u64,
+    pub record_timestamp: i64,
+    pub expiry: i64,
+    pub tax_vault: Pubkey,
+    pub withholding_rates: Vec<WithholdingRate>,
+}
+
+#[event]
+pub struct DistributionClaimedEvent {
+    pub mint: Pubkey,
+    pub distribution: Pubkey,
+    pub identity_account: Pubkey,
+    pub destination: Pubkey,
+    pub balance: u64,
+    pub country: u8,
+    pub gross_amount: u64,
+    pub withheld_amount: u64,
+    pub net_amount: u64,
+}
+
+#[event]
+pub struct DistributionReclaimedEvent {
+    pub mint: Pubkey,
+    pub distribution: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ConversionEvent {
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub conversion: Pubkey,
+    pub ratio_numerator: u64,
+    pub ratio_denominator: u64,
+    pub preserve_holding_periods: bool,
+}
+
+#[event]
+pub struct ShareConversionEvent {
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub wallet: Pubkey,
+    pub source_amount: u64,
+    pub destination_amount: u64,
+    pub preserve_holding_periods: bool,
+}
@@ -0,0 +1,70 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{thaw_account, ThawAccount},
+    token_interface::{Mint, Token2022, TokenAccount},
+};
+use identity_registry::IdentityRegistryAccount;
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ActivateWallet<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// signs when a wallet is attached to an identity
+    #[account(has_one = asset_mint, signer)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    /// CHECK: wallet attached to the identity
+    pub wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = asset_mint,
+        associated_token::authority = wallet,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ActivateWallet<'_> {
+    fn thaw_tokens(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = ThawAccount {
+            mint: self.asset_mint.to_account_info(),
+            authority: self.asset_controller.to_account_info(),
+            account: self.token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        thaw_account(cpi_ctx)?;
+        Ok(())
+    }
+}
+
+/// creates the associated token account of the wallet and thaws it, token accounts of assets
+/// with the default account state extension are frozen until the wallet is attached to an identity
+pub fn handler(ctx: Context<ActivateWallet>) -> Result<()> {
+    if !ctx.accounts.token_account.is_frozen() {
+        return Ok(());
+    }
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    ctx.accounts.thaw_tokens(&[&signer_seeds])?;
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{freeze_account, FreezeAccount},
+    token_interface::{Mint, Token2022, TokenAccount},
+};
+use identity_registry::IdentityRegistryAccount;
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct DeactivateWallet<'info> {
+    /// signs when a wallet is detached from an identity or the identity is revoked
+    #[account(has_one = asset_mint, signer)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl DeactivateWallet<'_> {
+    fn freeze_tokens(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = FreezeAccount {
+            mint: self.asset_mint.to_account_info(),
+            authority: self.asset_controller.to_account_info(),
+            account: self.token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        freeze_account(cpi_ctx)?;
+        Ok(())
+    }
+}
+
+/// freezes the token account of a wallet that no longer belongs to an identity
+pub fn handler(ctx: Context<DeactivateWallet>) -> Result<()> {
+    if ctx.accounts.token_account.is_frozen() {
+        return Ok(());
+    }
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    ctx.accounts.freeze_tokens(&[&signer_seeds])?;
+    Ok(())
+}
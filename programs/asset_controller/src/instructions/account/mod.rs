@@ -1,5 +1,11 @@
+pub mod activate_wallet;
+pub mod deactivate_wallet;
 pub mod freeze;
+pub mod recover_wallet;
 pub mod thaw;
 
+pub use activate_wallet::*;
+pub use deactivate_wallet::*;
 pub use freeze::*;
+pub use recover_wallet::*;
 pub use thaw::*;
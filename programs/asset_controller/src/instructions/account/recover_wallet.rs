@@ -0,0 +1,276 @@
+use crate::{state::*, AssetControllerErrors, WalletRecoveredEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{freeze_account, thaw_account, FreezeAccount, ThawAccount},
+    token_interface::{Mint, Token2022, TokenAccount},
+};
+use identity_registry::{
+    program::IdentityRegistry, IdentityAccount, IdentityRegistryAccount, WalletIdentity,
+};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount};
+use rwa_utils::get_bump_in_seed_form;
+use spl_token_2022::instruction::transfer_checked;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct RecoverWallet<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = asset_mint,
+        associated_token::authority = lost_wallet_identity.wallet,
+    )]
+    pub lost_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: new wallet of the identity
+    #[account(
+        constraint = new_wallet.key() != lost_wallet_identity.wallet @ AssetControllerErrors::InvalidRecoveryWallet
+    )]
+    pub new_wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = asset_mint,
+        associated_token::authority = new_wallet,
+    )]
+    pub new_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(mut, has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut, has_one = identity_account)]
+    pub lost_wallet_identity: Box<Account<'info, WalletIdentity>>,
+    /// CHECK: initialized by the identity registry
+    #[account(mut)]
+    pub new_wallet_identity: UncheckedAccount<'info>,
+    /// CHECK: checked by the transfer hook, left untouched as both sides share the identity
+    #[account(mut)]
+    pub tracker_account: UncheckedAccount<'info>,
+    /// CHECK: checked by the transfer hook
+    pub extra_metas_account: UncheckedAccount<'info>,
+    pub identity_registry_program: Program<'info, IdentityRegistry>,
+    /// CHECK: checked by the identity registry
+    pub identity_registry_event_authority: UncheckedAccount<'info>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RecoverWallet<'info> {
+    fn attach_new_wallet(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = identity_registry::cpi::accounts::AttachWalletToIdentity {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            wallet_identity: self.new_wallet_identity.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            // the new token account is thawed here, the registry cannot call back into the asset controller
+            asset_controller_program: None,
+            asset_controller: None,
+            token_account_owner: None,
+            token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            asset_controller_signer: Some(self.asset_controller.to_account_info()),
+            event_authority: self.identity_registry_event_authority.to_account_info(),
+            program: self.identity_registry_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.identity_registry_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        identity_registry::cpi::attach_wallet_to_identity(cpi_ctx, self.new_wallet.key())
+    }
+
+    fn change_identity_owner(&self) -> Result<()> {
+        let accounts = identity_registry::cpi::accounts::ChangeIdentityOwner {
+            payer: self.payer.to_account_info(),
+            signer: self.authority.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            wallet_identity: self.new_wallet_identity.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            event_authority: self.identity_registry_event_authority.to_account_info(),
+            program: self.identity_registry_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.identity_registry_program.to_account_info(), accounts);
+        identity_registry::cpi::change_identity_owner(cpi_ctx, self.new_wallet.key())
+    }
+
+    fn detach_lost_wallet(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = identity_registry::cpi::accounts::DetachWalletFromIdentity {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            wallet_identity: self.lost_wallet_identity.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            token_account: self.lost_token_account.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            // the lost token account is frozen here
+            asset_controller_program: None,
+            asset_controller: None,
+            token_program: None,
+            asset_controller_signer: Some(self.asset_controller.to_account_info()),
+            event_authority: self.identity_registry_event_authority.to_account_info(),
+            program: self.identity_registry_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.identity_registry_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        identity_registry::cpi::detach_wallet_from_identity(cpi_ctx)
+    }
+
+    fn thaw_token_account(
+        &self,
+        token_account: &InterfaceAccount<'info, TokenAccount>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let accounts = ThawAccount {
+            mint: self.asset_mint.to_account_info(),
+            authority: self.asset_controller.to_account_info(),
+            account: token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        thaw_account(cpi_ctx)
+    }
+
+    fn freeze_lost_token_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = FreezeAccount {
+            mint: self.asset_mint.to_account_info(),
+            authority: self.asset_controller.to_account_info(),
+            account: self.lost_token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        freeze_account(cpi_ctx)
+    }
+
+    fn transfer_tokens(
+        &self,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+        policy_pages: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let mut ix = transfer_checked(
+            self.token_program.key,
+            &self.lost_token_account.key(),
+            &self.asset_mint.key(),
+            &self.new_token_account.key(),
+            &self.asset_controller.key(),
+            &[],
+            amount,
+            self.asset_mint.decimals,
+        )?;
+
+        let mut account_infos = vec![
+            self.lost_token_account.to_account_info(),
+            self.asset_mint.to_account_info(),
+            self.new_token_account.to_account_info(),
+            self.asset_controller.to_account_info(),
+        ];
+
+        // transfer hook accounts, in the order of the extra metas
+        let hook_accounts = [
+            self.extra_metas_account.to_account_info(),
+            self.policy_engine_program.to_account_info(),
+            self.policy_engine.to_account_info(),
+            self.identity_registry_program.to_account_info(),
+            self.identity_registry.to_account_info(),
+            self.lost_wallet_identity.to_account_info(),
+            self.new_wallet_identity.to_account_info(),
+            self.identity_account.to_account_info(),
+            self.identity_account.to_account_info(),
+            self.tracker_account.to_account_info(),
+            self.tracker_account.to_account_info(),
+        ];
+
+        for account in hook_accounts.iter().chain(policy_pages.iter()) {
+            ix.accounts.push(AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            });
+            account_infos.push(account.clone());
+        }
+
+        anchor_lang::solana_program::program::invoke_signed(&ix, &account_infos, signer_seeds)
+            .map_err(Into::into)
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RecoverWallet<'info>>,
+    case_reference: String,
+) -> Result<()> {
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    let amount = ctx.accounts.lost_token_account.amount;
+
+    ctx.accounts.attach_new_wallet(&[&signer_seeds])?;
+    // the identity follows its owner wallet, otherwise the lost key would keep control over it
+    if ctx.accounts.identity_account.owner == ctx.accounts.lost_wallet_identity.wallet {
+        ctx.accounts.change_identity_owner()?;
+    }
+
+    if ctx.accounts.lost_token_account.is_frozen() {
+        ctx.accounts
+            .thaw_token_account(&ctx.accounts.lost_token_account, &[&signer_seeds])?;
+    }
+    // token accounts of assets with the default account state extension are created frozen
+    if ctx.accounts.new_token_account.is_frozen() {
+        ctx.accounts
+            .thaw_token_account(&ctx.accounts.new_token_account, &[&signer_seeds])?;
+    }
+    // both wallets belong to the same identity, the transfer hook treats it as a self transfer
+    // so the tracker, holder counters and holding periods are left as they are
+    if amount > 0 {
+        // remaining accounts are the transfer policy pages
+        ctx.accounts
+            .transfer_tokens(amount, &[&signer_seeds], ctx.remaining_accounts)?;
+    }
+    ctx.accounts.freeze_lost_token_account(&[&signer_seeds])?;
+
+    ctx.accounts.detach_lost_wallet(&[&signer_seeds])?;
+
+    emit_cpi!(WalletRecoveredEvent {
+        case_reference,
+        mint: asset_mint,
+        identity: ctx.accounts.identity_account.key(),
+        lost_wallet: ctx.accounts.lost_wallet_identity.wallet,
+        new_wallet: ctx.accounts.new_wallet.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseConversion<'info> {
+    pub source_authority: Signer<'info>,
+    pub destination_authority: Signer<'info>,
+    #[account(
+        seeds = [conversion.source_mint.as_ref()],
+        bump,
+        constraint = source_asset_controller.authority == source_authority.key()
+    )]
+    pub source_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        seeds = [conversion.destination_mint.as_ref()],
+        bump,
+        constraint = destination_asset_controller.authority == destination_authority.key()
+    )]
+    pub destination_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(mut, close = rent_receiver)]
+    pub conversion: Box<Account<'info, ConversionAccount>>,
+    /// CHECK: payer of the conversion
+    #[account(mut, address = conversion.payer)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
@@ -0,0 +1,246 @@
+use crate::{state::*, AssetControllerErrors, ShareConversionEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{burn, Burn},
+    token_interface::{mint_to, Mint, MintTo, Token2022, TokenAccount},
+};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+use policy_engine::{program::PolicyEngine, Issuance, PolicyEngineAccount, TrackerAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct ConvertShares<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub wallet: Signer<'info>,
+    #[account(
+        has_one = source_mint,
+        has_one = destination_mint,
+    )]
+    pub conversion: Box<Account<'info, ConversionAccount>>,
+    #[account(mut)]
+    pub source_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub destination_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(seeds = [source_mint.key().as_ref()], bump)]
+    pub source_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(seeds = [destination_mint.key().as_ref()], bump)]
+    pub destination_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = source_mint,
+        associated_token::authority = wallet,
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = destination_mint,
+        associated_token::authority = wallet,
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = source_identity_registry.asset_mint == source_mint.key())]
+    pub source_identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(
+        constraint = source_identity_account.identity_registry == source_identity_registry.key(),
+        constraint = source_identity_account.owner == wallet.key() @ AssetControllerErrors::InvalidIdentityAccounts,
+    )]
+    pub source_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        mut,
+        constraint = source_tracker_account.asset_mint == source_mint.key(),
+        constraint = source_tracker_account.identity_account == source_identity_account.key(),
+    )]
+    pub source_tracker_account: Box<Account<'info, TrackerAccount>>,
+    #[account(mut, constraint = source_policy_engine.asset_mint == source_mint.key())]
+    pub source_policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: authority of the source policy engine, checked by the policy engine, receives the rent freed by the source tracker
+    #[account(mut)]
+    pub source_rent_receiver: UncheckedAccount<'info>,
+    #[account(constraint = destination_identity_registry.asset_mint == destination_mint.key())]
+    pub destination_identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(
+        constraint = destination_identity_account.identity_registry == destination_identity_registry.key(),
+        constraint = destination_identity_account.owner == wallet.key() @ AssetControllerErrors::InvalidIdentityAccounts,
+    )]
+    pub destination_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        mut,
+        constraint = destination_tracker_account.asset_mint == destination_mint.key(),
+        constraint = destination_tracker_account.identity_account == destination_identity_account.key(),
+    )]
+    pub destination_tracker_account: Box<Account<'info, TrackerAccount>>,
+    #[account(mut, constraint = destination_policy_engine.asset_mint == destination_mint.key())]
+    pub destination_policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConvertShares<'info> {
+    fn burn_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = Burn {
+            mint: self.source_mint.to_account_info(),
+            authority: self.source_asset_controller.to_account_info(),
+            from: self.source_token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        burn(cpi_ctx, amount)?;
+        Ok(())
+    }
+
+    fn update_counters_on_burn(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = policy_engine::cpi::accounts::UpdateCountersOnBurnAccounts {
+            asset_mint: self.source_mint.to_account_info(),
+            policy_engine: self.source_policy_engine.to_account_info(),
+            destination_account: self.source_token_account.to_account_info(),
+            identity_registry: self.source_identity_registry.to_account_info(),
+            identity_account: self.source_identity_account.to_account_info(),
+            destination_tracker_account: self.source_tracker_account.to_account_info(),
+            asset_controller: self.source_asset_controller.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        policy_engine::cpi::update_counters_on_burn(cpi_ctx, amount)?;
+        Ok(())
+    }
+
+    fn take_issuances(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<Vec<Issuance>> {
+        let accounts = policy_engine::cpi::accounts::TakeIssuancesOnConversionAccounts {
+            asset_controller: self.source_asset_controller.to_account_info(),
+            asset_mint: self.source_mint.to_account_info(),
+            policy_engine: self.source_policy_engine.to_account_info(),
+            rent_receiver: self.source_rent_receiver.to_account_info(),
+            tracker_account: self.source_tracker_account.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        let res = policy_engine::cpi::take_issuances_on_conversion(cpi_ctx, amount)?;
+        Ok(res.get())
+    }
+
+    fn issue_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = MintTo {
+            mint: self.destination_mint.to_account_info(),
+            to: self.destination_token_account.to_account_info(),
+            authority: self.destination_asset_controller.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        mint_to(cpi_ctx, amount)?;
+        Ok(())
+    }
+
+    fn enforce_policy_issuance(
+        &self,
+        amount: u64,
+        issuance_timestamp: i64,
+        policy_pages: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<i64> {
+        let accounts = policy_engine::cpi::accounts::EnforcePolicyIssuanceAccounts {
+            asset_mint: self.destination_mint.to_account_info(),
+            policy_engine: self.destination_policy_engine.to_account_info(),
+            destination_account: self.destination_token_account.to_account_info(),
+            identity_registry: self.destination_identity_registry.to_account_info(),
+            identity_account: self.destination_identity_account.to_account_info(),
+            destination_tracker_account: self.destination_tracker_account.to_account_info(),
+            asset_controller: self.destination_asset_controller.to_account_info(),
+            payer: self.payer.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            tranche: None,
+            tranche_subscription: None,
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        )
+        .with_remaining_accounts(policy_pages.to_vec());
+        let res = policy_engine::cpi::enforce_policy_issuance(cpi_ctx, amount, issuance_timestamp)?;
+        Ok(res.get())
+    }
+}
+
+/// burns the source shares of the wallet and issues the destination shares at the conversion ratio,
+/// the issuance policy pages of the destination are passed in the remaining accounts
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ConvertShares<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let conversion = &ctx.accounts.conversion;
+    let destination_amount = conversion.convert(amount)?;
+    require!(
+        destination_amount > 0,
+        AssetControllerErrors::InvalidConversionAmount
+    );
+
+    let source_mint = ctx.accounts.source_mint.key();
+    let source_signer_seeds = [
+        source_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.source_asset_controller),
+    ];
+    // the lots backing the burnt shares leave the source tracker, newest first like a seize takes them
+    let source_lots = ctx
+        .accounts
+        .take_issuances(amount, &[&source_signer_seeds])?;
+    let timestamp = Clock::get()?.unix_timestamp;
+    let lots = if conversion.preserve_holding_periods {
+        conversion.convert_lots(&source_lots, amount, timestamp)?
+    } else {
+        conversion.convert_lots(&[], amount, timestamp)?
+    };
+
+    ctx.accounts.burn_tokens(amount, &[&source_signer_seeds])?;
+    ctx.accounts
+        .update_counters_on_burn(amount, &[&source_signer_seeds])?;
+
+    let destination_mint = ctx.accounts.destination_mint.key();
+    let destination_signer_seeds = [
+        destination_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.destination_asset_controller),
+    ];
+    ctx.accounts
+        .issue_tokens(destination_amount, &[&destination_signer_seeds])?;
+    for lot in lots {
+        ctx.accounts.enforce_policy_issuance(
+            lot.amount,
+            lot.issue_time,
+            ctx.remaining_accounts,
+            &[&destination_signer_seeds],
+        )?;
+    }
+
+    emit_cpi!(ShareConversionEvent {
+        source_mint,
+        destination_mint,
+        wallet: ctx.accounts.wallet.key(),
+        source_amount: amount,
+        destination_amount,
+        preserve_holding_periods: ctx.accounts.conversion.preserve_holding_periods,
+    });
+
+    Ok(())
+}
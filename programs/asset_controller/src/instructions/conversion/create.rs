@@ -0,0 +1,60 @@
+use crate::{state::*, ConversionEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct CreateConversion<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub source_authority: Signer<'info>,
+    pub destination_authority: Signer<'info>,
+    pub source_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub destination_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [source_mint.key().as_ref()],
+        bump,
+        constraint = source_asset_controller.authority == source_authority.key()
+    )]
+    pub source_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        seeds = [destination_mint.key().as_ref()],
+        bump,
+        constraint = destination_asset_controller.authority == destination_authority.key()
+    )]
+    pub destination_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ConversionAccount::INIT_SPACE,
+        seeds = [CONVERSION_SEED, source_mint.key().as_ref(), destination_mint.key().as_ref()],
+        bump,
+    )]
+    pub conversion: Box<Account<'info, ConversionAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateConversion>, config: ConversionConfig) -> Result<()> {
+    config.validate()?;
+
+    let source_mint = ctx.accounts.source_mint.key();
+    let destination_mint = ctx.accounts.destination_mint.key();
+    ctx.accounts.conversion.set_inner(ConversionAccount::new(
+        source_mint,
+        destination_mint,
+        ctx.accounts.payer.key(),
+        config.clone(),
+    ));
+
+    emit_cpi!(ConversionEvent {
+        source_mint,
+        destination_mint,
+        conversion: ctx.accounts.conversion.key(),
+        ratio_numerator: config.ratio_numerator,
+        ratio_denominator: config.ratio_denominator,
+        preserve_holding_periods: config.preserve_holding_periods,
+    });
+
+    Ok(())
+}
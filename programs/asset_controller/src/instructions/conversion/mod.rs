@@ -0,0 +1,9 @@
+pub mod close;
+pub mod convert;
+pub mod create;
+pub mod update;
+
+pub use close::*;
+pub use convert::*;
+pub use create::*;
+pub use update::*;
@@ -0,0 +1,42 @@
+use crate::{state::*, ConversionEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct UpdateConversion<'info> {
+    pub source_authority: Signer<'info>,
+    pub destination_authority: Signer<'info>,
+    #[account(
+        seeds = [conversion.source_mint.as_ref()],
+        bump,
+        constraint = source_asset_controller.authority == source_authority.key()
+    )]
+    pub source_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        seeds = [conversion.destination_mint.as_ref()],
+        bump,
+        constraint = destination_asset_controller.authority == destination_authority.key()
+    )]
+    pub destination_asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(mut)]
+    pub conversion: Box<Account<'info, ConversionAccount>>,
+}
+
+/// changes the terms of the conversion, applies to the conversions made after the update
+pub fn handler(ctx: Context<UpdateConversion>, config: ConversionConfig) -> Result<()> {
+    config.validate()?;
+
+    ctx.accounts.conversion.set_config(config.clone());
+
+    emit_cpi!(ConversionEvent {
+        source_mint: ctx.accounts.conversion.source_mint,
+        destination_mint: ctx.accounts.conversion.destination_mint,
+        conversion: ctx.accounts.conversion.key(),
+        ratio_numerator: config.ratio_numerator,
+        ratio_denominator: config.ratio_denominator,
+        preserve_holding_periods: config.preserve_holding_periods,
+    });
+
+    Ok(())
+}
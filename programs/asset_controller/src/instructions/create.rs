@@ -1,9 +1,21 @@
 /// creates a mint a new asset
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-        token_metadata_initialize, Mint, Token2022,
-        TokenMetadataInitialize,
-    };
+use anchor_lang::{
+    solana_program::program::invoke,
+    system_program::{create_account, CreateAccount},
+};
+use anchor_spl::{
+    token_2022::spl_token_2022::state::AccountState,
+    token_interface::{
+        default_account_state_initialize, group_member_pointer_initialize,
+        group_pointer_initialize, initialize_mint2, interest_bearing_mint_initialize,
+        metadata_pointer_initialize, mint_close_authority_initialize,
+        permanent_delegate_initialize, token_metadata_initialize, transfer_hook_initialize, DefaultAccountStateInitialize, GroupMemberPointerInitialize,
+        GroupPointerInitialize, InitializeMint2, InterestBearingMintInitialize,
+        MetadataPointerInitialize, MintCloseAuthorityInitialize, PermanentDelegateInitialize,
+        Token2022, TokenMetadataInitialize, TransferHookInitialize,
+    },
+};
 use identity_registry::{
     cpi::{accounts::CreateIdentityRegistry, create_identity_registry},
     program::IdentityRegistry,
@@ -13,8 +25,15 @@ use policy_engine::{
     program::PolicyEngine,
 };
 use rwa_utils::{get_bump_in_seed_form, ASSET_ACCESS_CONTROLLER_ID};
+use spl_token_2022::{
+    extension::{pausable, scaled_ui_amount, ExtensionType},
+    state::Mint,
+};
 
-use crate::{state::*, update_account_lamports_to_minimum_balance, AssetControllerErrors};
+use crate::{
+    get_multiplier_metadata, state::*, update_account_lamports_to_minimum_balance,
+    AssetControllerErrors, ExtensionMetadataEvent,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateAssetControllerArgs {
@@ -22,6 +41,53 @@ pub struct CreateAssetControllerArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// initial multiplier of the scaled ui amount extension used for splits, none to create the asset without it
+    pub multiplier: Option<f64>,
+    /// new token accounts are frozen until the asset controller thaws them
+    pub default_account_state_frozen: bool,
+    /// interest rate in basis points of the interest-bearing extension, cannot be combined with a multiplier
+    pub interest_rate: Option<i16>,
+    /// address of the token group the asset describes
+    pub group_pointer: Option<Pubkey>,
+    /// address of the token group member the asset describes
+    pub group_member_pointer: Option<Pubkey>,
+    /// lets the asset controller pause mints, burns and transfers
+    pub pausable: bool,
+}
+
+impl CreateAssetControllerArgs {
+    /// optional extensions of the mint with the metadata of their events
+    fn get_extensions(&self) -> Vec<(ExtensionType, Vec<u8>)> {
+        let mut extensions = Vec::new();
+        if let Some(multiplier) = self.multiplier {
+            extensions.push((
+                ExtensionType::ScaledUiAmount,
+                get_multiplier_metadata(multiplier, 0),
+            ));
+        }
+        if self.default_account_state_frozen {
+            extensions.push((
+                ExtensionType::DefaultAccountState,
+                vec![AccountState::Frozen as u8],
+            ));
+        }
+        if let Some(interest_rate) = self.interest_rate {
+            extensions.push((
+                ExtensionType::InterestBearingConfig,
+                interest_rate.to_le_bytes().to_vec(),
+            ));
+        }
+        if let Some(group) = self.group_pointer {
+            extensions.push((ExtensionType::GroupPointer, group.to_bytes().to_vec()));
+        }
+        if let Some(member) = self.group_member_pointer {
+            extensions.push((ExtensionType::GroupMemberPointer, member.to_bytes().to_vec()));
+        }
+        if self.pausable {
+            extensions.push((ExtensionType::Pausable, vec![0]));
+        }
+        extensions
+    }
 }
 
 #[derive(Accounts)]
@@ -41,22 +107,9 @@ pub struct CreateAssetController<'info> {
         bump,
     )]
     pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
-    #[account(
-        init,
-        signer,
-        payer = payer,
-        mint::token_program = token_program,
-        mint::decimals = args.decimals,
-        mint::authority = asset_controller.key(),
-        mint::freeze_authority = asset_controller.key(),
-        extensions::permanent_delegate::delegate = asset_controller.key(),
-        extensions::transfer_hook::authority = asset_controller.key(),
-        extensions::transfer_hook::program_id = policy_engine::id(),
-        extensions::metadata_pointer::authority = asset_controller.key(),
-        extensions::metadata_pointer::metadata_address = asset_mint.key(),
-        extensions::close_authority::authority = asset_controller.key(),
-    )]
-    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// created with its extensions by the handler, the scaled ui amount extension cannot be set through the mint constraints
+    #[account(mut)]
+    pub asset_mint: Signer<'info>,
     #[account(mut)]
     /// CHECK: cpi checks
     pub extra_metas_account: UncheckedAccount<'info>,
@@ -74,6 +127,159 @@ pub struct CreateAssetController<'info> {
 }
 
 impl<'info> CreateAssetController<'info> {
+    /// creates the mint account, initializes its extensions then the mint itself
+    fn create_asset_mint(&self, args: &CreateAssetControllerArgs) -> Result<()> {
+        let asset_controller = self.asset_controller.key();
+        let mut extensions = vec![
+            ExtensionType::MetadataPointer,
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::TransferHook,
+            ExtensionType::PermanentDelegate,
+        ];
+        extensions.extend(
+            args.get_extensions()
+                .into_iter()
+                .map(|(extension, _)| extension),
+        );
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&extensions)?;
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.payer.to_account_info(),
+                    to: self.asset_mint.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &self.token_program.key(),
+        )?;
+
+        metadata_pointer_initialize(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: self.asset_mint.to_account_info(),
+                },
+            ),
+            Some(asset_controller),
+            Some(self.asset_mint.key()),
+        )?;
+        mint_close_authority_initialize(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                MintCloseAuthorityInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: self.asset_mint.to_account_info(),
+                },
+            ),
+            Some(&asset_controller),
+        )?;
+        transfer_hook_initialize(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferHookInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: self.asset_mint.to_account_info(),
+                },
+            ),
+            Some(asset_controller),
+            Some(policy_engine::id()),
+        )?;
+        permanent_delegate_initialize(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                PermanentDelegateInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: self.asset_mint.to_account_info(),
+                },
+            ),
+            &asset_controller,
+        )?;
+        if let Some(multiplier) = args.multiplier {
+            let ix = scaled_ui_amount::instruction::initialize(
+                &self.token_program.key(),
+                &self.asset_mint.key(),
+                Some(asset_controller),
+                multiplier,
+            )?;
+            invoke(&ix, &[self.asset_mint.to_account_info()])?;
+        }
+        if args.default_account_state_frozen {
+            default_account_state_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    DefaultAccountStateInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.asset_mint.to_account_info(),
+                    },
+                ),
+                &AccountState::Frozen,
+            )?;
+        }
+        if let Some(interest_rate) = args.interest_rate {
+            interest_bearing_mint_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    InterestBearingMintInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.asset_mint.to_account_info(),
+                    },
+                ),
+                Some(asset_controller),
+                interest_rate,
+            )?;
+        }
+        if let Some(group) = args.group_pointer {
+            group_pointer_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    GroupPointerInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.asset_mint.to_account_info(),
+                    },
+                ),
+                Some(asset_controller),
+                Some(group),
+            )?;
+        }
+        if let Some(member) = args.group_member_pointer {
+            group_member_pointer_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    GroupMemberPointerInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.asset_mint.to_account_info(),
+                    },
+                ),
+                Some(asset_controller),
+                Some(member),
+            )?;
+        }
+        if args.pausable {
+            let ix = pausable::instruction::initialize(
+                &self.token_program.key(),
+                &self.asset_mint.key(),
+                &asset_controller,
+            )?;
+            invoke(&ix, &[self.asset_mint.to_account_info()])?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.asset_mint.to_account_info(),
+                },
+            ),
+            args.decimals,
+            &asset_controller,
+            Some(&asset_controller),
+        )?;
+        Ok(())
+    }
+
     fn initialize_token_metadata(
         &self,
         name: String,
@@ -171,6 +377,14 @@ pub fn handler(ctx: Context<CreateAssetController>, args: CreateAssetControllerA
     ));
     let asset_mint = ctx.accounts.asset_mint.key();
 
+    // the scaled ui amount and interest-bearing extensions both rescale the ui amount
+    require!(
+        args.multiplier.is_none() || args.interest_rate.is_none(),
+        AssetControllerErrors::IncompatibleExtensions
+    );
+    ctx.accounts.create_asset_mint(&args)?;
+    let extensions = args.get_extensions();
+
     let signer_seeds = [
         asset_mint.as_ref(),
         &get_bump_in_seed_form(&ctx.bumps.asset_controller),
@@ -199,6 +413,14 @@ pub fn handler(ctx: Context<CreateAssetController>, args: CreateAssetControllerA
         decimals: Some(args.decimals),
     });
 
+    for (extension_type, metadata) in extensions {
+        emit_cpi!(ExtensionMetadataEvent {
+            address: asset_mint.to_string(),
+            extension_type: extension_type as u8,
+            metadata,
+        });
+    }
+
     // create policy registry
     ctx.accounts.create_policy_engine(
         &[&signer_seeds],
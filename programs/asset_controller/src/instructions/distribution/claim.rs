@@ -0,0 +1,162 @@
+use crate::{state::*, AssetControllerErrors, DistributionClaimedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+use policy_engine::{PolicyEngineAccount, TrackerAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct ClaimDistribution<'info> {
+    /// holder or crank claiming on its behalf
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        has_one = payout_mint,
+        seeds = [DISTRIBUTION_SEED, asset_mint.key().as_ref(), &distribution.id.to_le_bytes()],
+        bump,
+    )]
+    pub distribution: Box<Account<'info, DistributionAccount>>,
+    pub payout_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = payout_token_program,
+        associated_token::mint = payout_mint,
+        associated_token::authority = distribution,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    /// required for tracker snapshots, read at the snapshot of the distribution
+    #[account(has_one = asset_mint, has_one = identity_account)]
+    pub tracker_account: Option<Box<Account<'info, TrackerAccount>>>,
+    /// token account of the identity owner receiving the payout
+    #[account(
+        mut,
+        token::mint = payout_mint,
+        token::authority = identity_account.owner,
+        token::token_program = payout_token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// maps the country of the identity to the group of its withholding rate and holds the snapshot
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// required when the distribution has a tax vault
+    #[account(mut, address = distribution.tax_vault)]
+    pub tax_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DistributionClaimAccount::INIT_SPACE,
+        seeds = [DISTRIBUTION_CLAIM_SEED, distribution.key().as_ref(), identity_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_claim: Box<Account<'info, DistributionClaimAccount>>,
+    pub payout_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimDistribution<'info> {
+    fn pay(&self, to: AccountInfo<'info>, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.payout_mint.to_account_info(),
+            to,
+            authority: self.distribution.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.payout_token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        transfer_checked(cpi_ctx, amount, self.payout_mint.decimals)
+    }
+}
+
+pub fn handler(ctx: Context<ClaimDistribution>, proof: Option<BalanceProof>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        timestamp < ctx.accounts.distribution.expiry,
+        AssetControllerErrors::DistributionExpired
+    );
+
+    let identity_account = ctx.accounts.identity_account.key();
+    let tracker_balance = ctx
+        .accounts
+        .tracker_account
+        .as_ref()
+        .map(|tracker| {
+            tracker.get_snapshot_balance(
+                &ctx.accounts.policy_engine.snapshot,
+                ctx.accounts.distribution.snapshot_id,
+            )
+        })
+        .transpose()?;
+    let balance = ctx
+        .accounts
+        .distribution
+        .get_balance(identity_account, tracker_balance, proof)?;
+    let amount = ctx.accounts.distribution.claim(balance)?;
+    let country = ctx.accounts.identity_account.country;
+    let (withheld_amount, net_amount) = ctx
+        .accounts
+        .distribution
+        .withhold(ctx.accounts.policy_engine.mapping[country as usize], amount);
+
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let distribution_id = ctx.accounts.distribution.id.to_le_bytes();
+    let distribution_seeds = [
+        DISTRIBUTION_SEED,
+        asset_mint.as_ref(),
+        distribution_id.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.distribution),
+    ];
+    ctx.accounts.pay(
+        ctx.accounts.destination.to_account_info(),
+        net_amount,
+        &[&distribution_seeds],
+    )?;
+    if withheld_amount > 0 {
+        let tax_vault = ctx
+            .accounts
+            .tax_vault
+            .as_ref()
+            .ok_or(AssetControllerErrors::TaxVaultMissing)?
+            .to_account_info();
+        ctx.accounts
+            .pay(tax_vault, withheld_amount, &[&distribution_seeds])?;
+    }
+
+    ctx.accounts
+        .distribution_claim
+        .set_inner(DistributionClaimAccount::new(
+            ctx.accounts.distribution.key(),
+            identity_account,
+            balance,
+            amount,
+            withheld_amount,
+            timestamp,
+        ));
+
+    emit_cpi!(DistributionClaimedEvent {
+        mint: asset_mint,
+        distribution: ctx.accounts.distribution.key(),
+        identity_account,
+        destination: ctx.accounts.destination.key(),
+        balance,
+        country,
+        gross_amount: amount,
+        withheld_amount,
+        net_amount,
+    });
+
+    Ok(())
+}
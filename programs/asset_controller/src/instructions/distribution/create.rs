@@ -0,0 +1,166 @@
+use crate::{state::*, AssetControllerErrors, DistributionCreatedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction(id: u64, snapshot: DistributionSnapshot, amount: u64, expiry: i64, withholding_rates: Vec<WithholdingRate>)]
+#[event_cpi]
+pub struct CreateDistribution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    /// holds the snapshot the tracker balances are read from
+    #[account(mut, has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mint::token_program = payout_token_program)]
+    pub payout_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = payout_mint,
+        token::authority = authority,
+        token::token_program = payout_token_program,
+    )]
+    pub funding_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DistributionAccount::get_space(&withholding_rates),
+        seeds = [DISTRIBUTION_SEED, asset_mint.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub distribution: Box<Account<'info, DistributionAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::token_program = payout_token_program,
+        associated_token::mint = payout_mint,
+        associated_token::authority = distribution,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// receives the withheld amounts, required with withholding rates
+    #[account(
+        token::mint = payout_mint,
+        token::token_program = payout_token_program,
+    )]
+    pub tax_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payout_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateDistribution<'_> {
+    fn fund(&self, amount: u64) -> Result<()> {
+        let accounts = TransferChecked {
+            from: self.funding_account.to_account_info(),
+            mint: self.payout_mint.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.payout_token_program.to_account_info(), accounts);
+        transfer_checked(cpi_ctx, amount, self.payout_mint.decimals)
+    }
+
+    /// opens a policy engine snapshot kept readable until the expiry, returns its id
+    fn open_snapshot(&self, expiry: i64, signer_seeds: &[&[&[u8]]]) -> Result<u64> {
+        let accounts = policy_engine::cpi::accounts::OpenSnapshotOnDistributionAccounts {
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        Ok(policy_engine::cpi::open_snapshot_on_distribution(cpi_ctx, expiry)?.get())
+    }
+}
+
+/// funds the distribution and takes the record date snapshot, the record date being the current time.
+/// tracker snapshots stay readable until the expiry, later snapshots cannot replace them before.
+pub fn handler(
+    ctx: Context<CreateDistribution>,
+    id: u64,
+    snapshot: DistributionSnapshot,
+    amount: u64,
+    expiry: i64,
+    withholding_rates: Vec<WithholdingRate>,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let snapshot_supply = match &snapshot {
+        DistributionSnapshot::Tracker => ctx.accounts.asset_mint.supply,
+        DistributionSnapshot::Merkle { total_balance, .. } => *total_balance,
+    };
+    require!(
+        amount > 0 && snapshot_supply > 0 && expiry > timestamp,
+        AssetControllerErrors::InvalidDistribution
+    );
+
+    DistributionAccount::validate_withholding_rates(&withholding_rates)?;
+    let tax_vault = ctx
+        .accounts
+        .tax_vault
+        .as_ref()
+        .map_or(Pubkey::default(), |tax_vault| tax_vault.key());
+    require!(
+        withholding_rates.is_empty() || tax_vault != Pubkey::default(),
+        AssetControllerErrors::TaxVaultMissing
+    );
+
+    let snapshot_id = match &snapshot {
+        DistributionSnapshot::Tracker => {
+            let asset_mint = ctx.accounts.asset_mint.key();
+            let signer_seeds = [
+                asset_mint.as_ref(),
+                &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+            ];
+            ctx.accounts.open_snapshot(expiry, &[&signer_seeds])?
+        }
+        DistributionSnapshot::Merkle { .. } => 0,
+    };
+
+    ctx.accounts.fund(amount)?;
+    ctx.accounts
+        .distribution
+        .set_inner(DistributionAccount::new(
+            ctx.accounts.asset_mint.key(),
+            id,
+            ctx.accounts.payout_mint.key(),
+            snapshot,
+            snapshot_id,
+            snapshot_supply,
+            timestamp,
+            expiry,
+            amount,
+            tax_vault,
+            withholding_rates.clone(),
+        ));
+
+    emit_cpi!(DistributionCreatedEvent {
+        mint: ctx.accounts.asset_mint.key(),
+        distribution: ctx.accounts.distribution.key(),
+        payout_mint: ctx.accounts.payout_mint.key(),
+        total_amount: amount,
+        snapshot_id,
+        snapshot_supply,
+        record_timestamp: timestamp,
+        expiry,
+        tax_vault,
+        withholding_rates,
+    });
+
+    Ok(())
+}
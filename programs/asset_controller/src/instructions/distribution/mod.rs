@@ -0,0 +1,7 @@
+pub mod claim;
+pub mod create;
+pub mod reclaim;
+
+pub use claim::*;
+pub use create::*;
+pub use reclaim::*;
@@ -0,0 +1,87 @@
+use crate::{state::*, AssetControllerErrors, DistributionReclaimedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct ReclaimDistribution<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [distribution.asset_mint.as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        has_one = payout_mint,
+        seeds = [DISTRIBUTION_SEED, distribution.asset_mint.as_ref(), &distribution.id.to_le_bytes()],
+        bump,
+    )]
+    pub distribution: Box<Account<'info, DistributionAccount>>,
+    pub payout_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = payout_token_program,
+        associated_token::mint = payout_mint,
+        associated_token::authority = distribution,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payout_mint,
+        token::token_program = payout_token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub payout_token_program: Interface<'info, TokenInterface>,
+}
+
+impl ReclaimDistribution<'_> {
+    fn reclaim(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.payout_mint.to_account_info(),
+            to: self.destination.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.payout_token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        transfer_checked(cpi_ctx, amount, self.payout_mint.decimals)
+    }
+}
+
+pub fn handler(ctx: Context<ReclaimDistribution>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.distribution.expiry
+            && !ctx.accounts.distribution.reclaimed,
+        AssetControllerErrors::DistributionNotReclaimable
+    );
+
+    let amount = ctx.accounts.distribution.get_unclaimed_amount();
+    let asset_mint = ctx.accounts.distribution.asset_mint;
+    let distribution_id = ctx.accounts.distribution.id.to_le_bytes();
+    let distribution_seeds = [
+        DISTRIBUTION_SEED,
+        asset_mint.as_ref(),
+        distribution_id.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.distribution),
+    ];
+    ctx.accounts.reclaim(amount, &[&distribution_seeds])?;
+    ctx.accounts.distribution.reclaimed = true;
+
+    emit_cpi!(DistributionReclaimedEvent {
+        mint: asset_mint,
+        distribution: ctx.accounts.distribution.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
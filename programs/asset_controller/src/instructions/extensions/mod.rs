@@ -1,5 +1,13 @@
 pub mod disable_memo;
 pub mod enable_memo;
+pub mod pause;
+pub mod resume;
+pub mod update_interest_rate;
+pub mod update_multiplier;
 
 pub use disable_memo::*;
 pub use enable_memo::*;
+pub use pause::*;
+pub use resume::*;
+pub use update_interest_rate::*;
+pub use update_multiplier::*;
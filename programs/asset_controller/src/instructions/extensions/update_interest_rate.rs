@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token_interface::{Mint, Token2022};
+use rwa_utils::get_bump_in_seed_form;
+use spl_token_2022::extension::{interest_bearing_mint::instruction::update_rate, ExtensionType};
+
+use crate::{AssetControllerAccount, ExtensionMetadataEvent};
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct UpdateInterestRate<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// changes the rate of the interest-bearing extension, in basis points.
+/// only the ui amount accrues, raw balances and the policy limits are unchanged.
+pub fn handler(ctx: Context<UpdateInterestRate>, rate: i16) -> Result<()> {
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let asset_controller = ctx.accounts.asset_controller.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    let ix = update_rate(
+        &ctx.accounts.token_program.key(),
+        &asset_mint,
+        &asset_controller,
+        &[],
+        rate,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.asset_mint.to_account_info(),
+            ctx.accounts.asset_controller.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+
+    emit_cpi!(ExtensionMetadataEvent {
+        address: asset_mint.to_string(),
+        extension_type: ExtensionType::InterestBearingConfig as u8,
+        metadata: rate.to_le_bytes().to_vec(),
+    });
+
+    Ok(())
+}
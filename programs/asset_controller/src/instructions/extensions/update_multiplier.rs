@@ -0,0 +1,61 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token_interface::{Mint, Token2022};
+use rwa_utils::get_bump_in_seed_form;
+use spl_token_2022::extension::{scaled_ui_amount::instruction::update_multiplier, ExtensionType};
+
+use crate::{get_multiplier_metadata, AssetControllerAccount, ExtensionMetadataEvent};
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct UpdateMultiplier<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// schedules the ui multiplier applied from the effective timestamp, 2.0 for a 2:1 split.
+/// raw balances are unchanged, so the policy limits keep applying to the same holdings.
+pub fn handler(
+    ctx: Context<UpdateMultiplier>,
+    multiplier: f64,
+    effective_timestamp: i64,
+) -> Result<()> {
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let asset_controller = ctx.accounts.asset_controller.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    let ix = update_multiplier(
+        &ctx.accounts.token_program.key(),
+        &asset_mint,
+        &asset_controller,
+        &[],
+        multiplier,
+        effective_timestamp,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.asset_mint.to_account_info(),
+            ctx.accounts.asset_controller.to_account_info(),
+        ],
+        &[&signer_seeds],
+    )?;
+
+    emit_cpi!(ExtensionMetadataEvent {
+        address: asset_mint.to_string(),
+        extension_type: ExtensionType::ScaledUiAmount as u8,
+        metadata: get_multiplier_metadata(multiplier, effective_timestamp),
+    });
+
+    Ok(())
+}
@@ -1,11 +1,19 @@
 pub mod account;
+pub mod conversion;
 pub mod create;
+pub mod distribution;
 pub mod extensions;
+pub mod redemption;
+pub mod subscription;
 pub mod token;
 pub mod update;
 
 pub use account::*;
+pub use conversion::*;
 pub use create::*;
+pub use distribution::*;
 pub use extensions::*;
+pub use redemption::*;
+pub use subscription::*;
 pub use token::*;
 pub use update::*;
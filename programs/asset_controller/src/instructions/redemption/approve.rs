@@ -0,0 +1,138 @@
+use crate::{state::*, AssetControllerErrors, RedemptionApprovedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{burn, Burn},
+    token_interface::{Mint, Token2022, TokenAccount},
+};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount, TrackerAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct ApproveRedemption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        has_one = identity_account,
+        constraint = redemption_request.state == RedemptionState::Pending @ AssetControllerErrors::InvalidRedemptionState
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequestAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = asset_mint,
+        associated_token::authority = redemption_request.wallet,
+        // the lock is kept on the identity, the wallet could have moved the tokens to another wallet of the identity
+        constraint = token_account.amount >= redemption_request.amount @ AssetControllerErrors::RedemptionBalanceInsufficient,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut, has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ApproveRedemption<'_> {
+    fn unlock_on_redemption(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = policy_engine::cpi::accounts::UnlockOnRedemptionAccounts {
+            payer: self.payer.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            tracker_account: self.tracker_account.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        policy_engine::cpi::unlock_on_redemption(cpi_ctx, self.redemption_request.key())
+    }
+
+    fn burn_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = Burn {
+            mint: self.asset_mint.to_account_info(),
+            authority: self.asset_controller.to_account_info(),
+            from: self.token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        burn(cpi_ctx, amount)?;
+        Ok(())
+    }
+
+    fn update_counters_on_burn(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = policy_engine::cpi::accounts::UpdateCountersOnBurnAccounts {
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            destination_account: self.token_account.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            destination_tracker_account: self.tracker_account.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        policy_engine::cpi::update_counters_on_burn(cpi_ctx, amount)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ApproveRedemption>) -> Result<()> {
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    let amount = ctx.accounts.redemption_request.amount;
+
+    // the lock is released first, the burn then goes through the same path as revoke
+    ctx.accounts.unlock_on_redemption(&[&signer_seeds])?;
+    ctx.accounts.burn_tokens(amount, &[&signer_seeds])?;
+    ctx.accounts
+        .update_counters_on_burn(amount, &[&signer_seeds])?;
+
+    ctx.accounts
+        .redemption_request
+        .resolve(RedemptionState::Approved, Clock::get()?.unix_timestamp);
+
+    emit_cpi!(RedemptionApprovedEvent {
+        mint: asset_mint,
+        request: ctx.accounts.redemption_request.key(),
+        wallet: ctx.accounts.redemption_request.wallet,
+        amount,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
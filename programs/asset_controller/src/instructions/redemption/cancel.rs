@@ -0,0 +1,80 @@
+use crate::{state::*, AssetControllerErrors, RedemptionCancelledEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+use policy_engine::{program::PolicyEngine, TrackerAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct CancelRedemption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub wallet: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        has_one = identity_account,
+        has_one = wallet,
+        constraint = redemption_request.state == RedemptionState::Pending @ AssetControllerErrors::InvalidRedemptionState
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequestAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut, has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CancelRedemption<'_> {
+    fn unlock_on_redemption(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = policy_engine::cpi::accounts::UnlockOnRedemptionAccounts {
+            payer: self.payer.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            tracker_account: self.tracker_account.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        policy_engine::cpi::unlock_on_redemption(cpi_ctx, self.redemption_request.key())
+    }
+}
+
+pub fn handler(ctx: Context<CancelRedemption>) -> Result<()> {
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    ctx.accounts.unlock_on_redemption(&[&signer_seeds])?;
+
+    ctx.accounts
+        .redemption_request
+        .resolve(RedemptionState::Cancelled, Clock::get()?.unix_timestamp);
+
+    emit_cpi!(RedemptionCancelledEvent {
+        mint: asset_mint,
+        request: ctx.accounts.redemption_request.key(),
+        wallet: ctx.accounts.wallet.key(),
+        amount: ctx.accounts.redemption_request.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use crate::{state::*, AssetControllerErrors};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseRedemptionRequest<'info> {
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet,
+        constraint = redemption_request.state != RedemptionState::Pending @ AssetControllerErrors::InvalidRedemptionState,
+        close = rent_receiver,
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequestAccount>>,
+    /// CHECK: payer of the request
+    #[account(mut, address = redemption_request.payer)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
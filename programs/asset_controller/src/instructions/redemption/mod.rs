@@ -0,0 +1,11 @@
+pub mod approve;
+pub mod cancel;
+pub mod close;
+pub mod reject;
+pub mod request;
+
+pub use approve::*;
+pub use cancel::*;
+pub use close::*;
+pub use reject::*;
+pub use request::*;
@@ -0,0 +1,106 @@
+use crate::{state::*, AssetControllerErrors, RedemptionRequestedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount, WalletIdentity};
+use policy_engine::{program::PolicyEngine, TrackerAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+#[event_cpi]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub wallet: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        associated_token::token_program = token_program,
+        associated_token::mint = asset_mint,
+        associated_token::authority = wallet,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        has_one = identity_account,
+        constraint = wallet_identity_account.wallet == wallet.key() @ AssetControllerErrors::InvalidIdentityAccounts
+    )]
+    pub wallet_identity_account: Box<Account<'info, WalletIdentity>>,
+    #[account(mut, has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RedemptionRequestAccount::INIT_SPACE,
+        seeds = [REDEMPTION_REQUEST_SEED, asset_mint.key().as_ref(), wallet.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequestAccount>>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RequestRedemption<'_> {
+    fn lock_on_redemption(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = policy_engine::cpi::accounts::LockOnRedemptionAccounts {
+            payer: self.payer.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            tracker_account: self.tracker_account.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        policy_engine::cpi::lock_on_redemption(cpi_ctx, amount, self.redemption_request.key())
+    }
+}
+
+pub fn handler(ctx: Context<RequestRedemption>, id: u64, amount: u64) -> Result<()> {
+    require!(
+        amount > 0 && ctx.accounts.token_account.amount >= amount,
+        AssetControllerErrors::InvalidRedemptionAmount
+    );
+
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    ctx.accounts.lock_on_redemption(amount, &[&signer_seeds])?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .redemption_request
+        .set_inner(RedemptionRequestAccount::new(
+            asset_mint,
+            ctx.accounts.identity_account.key(),
+            ctx.accounts.wallet.key(),
+            ctx.accounts.payer.key(),
+            id,
+            amount,
+            timestamp,
+        ));
+
+    emit_cpi!(RedemptionRequestedEvent {
+        mint: asset_mint,
+        request: ctx.accounts.redemption_request.key(),
+        wallet: ctx.accounts.wallet.key(),
+        amount,
+    });
+
+    Ok(())
+}
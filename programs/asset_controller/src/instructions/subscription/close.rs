@@ -0,0 +1,18 @@
+use crate::{state::*, AssetControllerErrors};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CloseSubscriptionRequest<'info> {
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet,
+        constraint = subscription_request.state != SubscriptionState::Pending @ AssetControllerErrors::InvalidSubscriptionState,
+        close = rent_receiver,
+    )]
+    pub subscription_request: Box<Account<'info, SubscriptionRequestAccount>>,
+    /// CHECK: payer of the request
+    #[account(mut, address = subscription_request.payer)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
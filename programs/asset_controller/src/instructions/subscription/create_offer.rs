@@ -0,0 +1,79 @@
+use crate::{state::*, AssetControllerErrors, SubscriptionOfferEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+#[event_cpi]
+pub struct CreateSubscriptionOffer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        token::mint = payment_mint,
+        token::token_program = payment_token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SubscriptionOfferAccount::INIT_SPACE,
+        seeds = [SUBSCRIPTION_OFFER_SEED, asset_mint.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub subscription_offer: Box<Account<'info, SubscriptionOfferAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::token_program = payment_token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = subscription_offer,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSubscriptionOffer>,
+    id: u64,
+    price: u64,
+    cutoff: i64,
+) -> Result<()> {
+    require!(price > 0, AssetControllerErrors::InvalidSubscriptionPrice);
+
+    ctx.accounts
+        .subscription_offer
+        .set_inner(SubscriptionOfferAccount::new(
+            ctx.accounts.asset_mint.key(),
+            id,
+            ctx.accounts.payment_mint.key(),
+            ctx.accounts.treasury.key(),
+            price,
+            cutoff,
+        ));
+
+    emit_cpi!(SubscriptionOfferEvent {
+        mint: ctx.accounts.asset_mint.key(),
+        offer: ctx.accounts.subscription_offer.key(),
+        payment_mint: ctx.accounts.payment_mint.key(),
+        treasury: ctx.accounts.treasury.key(),
+        price,
+        cutoff,
+    });
+
+    Ok(())
+}
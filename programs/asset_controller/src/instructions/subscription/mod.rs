@@ -0,0 +1,13 @@
+pub mod close;
+pub mod create_offer;
+pub mod refund;
+pub mod settle;
+pub mod subscribe;
+pub mod update_offer;
+
+pub use close::*;
+pub use create_offer::*;
+pub use refund::*;
+pub use settle::*;
+pub use subscribe::*;
+pub use update_offer::*;
@@ -0,0 +1,116 @@
+use crate::{state::*, AssetControllerErrors, SubscriptionRefundedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct RefundSubscription<'info> {
+    /// authority of the asset, or the wallet or anyone after the cutoff when the request cannot be settled
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [subscription_offer.asset_mint.as_ref()],
+        bump,
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        has_one = payment_mint,
+        seeds = [SUBSCRIPTION_OFFER_SEED, subscription_offer.asset_mint.as_ref(), &subscription_offer.id.to_le_bytes()],
+        bump,
+    )]
+    pub subscription_offer: Box<Account<'info, SubscriptionOfferAccount>>,
+    #[account(
+        mut,
+        constraint = subscription_request.offer == subscription_offer.key() @ AssetControllerErrors::InvalidSubscriptionState,
+        constraint = subscription_request.state == SubscriptionState::Pending @ AssetControllerErrors::InvalidSubscriptionState,
+    )]
+    pub subscription_request: Box<Account<'info, SubscriptionRequestAccount>>,
+    /// CHECK: identity of the subscription request, closed once the identity is revoked
+    #[account(address = subscription_request.identity_account)]
+    pub identity_account: UncheckedAccount<'info>,
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = payment_token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = subscription_offer,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = subscription_request.wallet,
+        token::token_program = payment_token_program,
+    )]
+    pub payment_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+}
+
+impl RefundSubscription<'_> {
+    /// a request whose identity was revoked can no longer be settled, so its payment would stay locked in the vault
+    fn is_settleable(&self) -> bool {
+        !self.identity_account.data_is_empty()
+    }
+
+    fn refund_payment(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.payment_mint.to_account_info(),
+            to: self.payment_account.to_account_info(),
+            authority: self.subscription_offer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.payment_token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        transfer_checked(cpi_ctx, amount, self.payment_mint.decimals)
+    }
+}
+
+/// refunds the payment of a pending subscription, at any time by the authority. a request that cannot be settled
+/// is also refunded by its wallet, or by anyone once the cutoff of the offer has passed
+pub fn handler(ctx: Context<RefundSubscription>, reason: String) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        signer == ctx.accounts.asset_controller.authority
+            || (!ctx.accounts.is_settleable()
+                && (signer == ctx.accounts.subscription_request.wallet
+                    || timestamp >= ctx.accounts.subscription_offer.cutoff)),
+        AssetControllerErrors::SubscriptionRefundNotAllowed
+    );
+
+    let payment_amount = ctx.accounts.subscription_request.payment_amount;
+    let asset_mint = ctx.accounts.subscription_offer.asset_mint;
+    let offer_id = ctx.accounts.subscription_offer.id.to_le_bytes();
+    let offer_seeds = [
+        SUBSCRIPTION_OFFER_SEED,
+        asset_mint.as_ref(),
+        offer_id.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.subscription_offer),
+    ];
+    ctx.accounts
+        .refund_payment(payment_amount, &[&offer_seeds])?;
+    ctx.accounts.subscription_offer.release(payment_amount)?;
+
+    ctx.accounts
+        .subscription_request
+        .resolve(SubscriptionState::Refunded, timestamp);
+
+    emit_cpi!(SubscriptionRefundedEvent {
+        mint: asset_mint,
+        offer: ctx.accounts.subscription_offer.key(),
+        request: ctx.accounts.subscription_request.key(),
+        wallet: ctx.accounts.subscription_request.wallet,
+        payment_amount,
+        reason,
+        refunded_by: signer,
+    });
+
+    Ok(())
+}
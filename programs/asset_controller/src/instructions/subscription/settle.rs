@@ -0,0 +1,184 @@
+use crate::{state::*, AssetControllerErrors, IssuanceCpiAccounts, IssueEvent, SubscriptionSettledEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        transfer_checked, Mint, Token2022, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount, TrackerAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct SettleSubscription<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// authority of the asset, or anyone once the cutoff of the offer has passed
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        has_one = payment_mint,
+        has_one = treasury,
+        seeds = [SUBSCRIPTION_OFFER_SEED, asset_mint.key().as_ref(), &subscription_offer.id.to_le_bytes()],
+        bump,
+    )]
+    pub subscription_offer: Box<Account<'info, SubscriptionOfferAccount>>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        has_one = wallet,
+        has_one = identity_account,
+        constraint = subscription_request.offer == subscription_offer.key() @ AssetControllerErrors::InvalidSubscriptionState,
+        constraint = subscription_request.state == SubscriptionState::Pending @ AssetControllerErrors::InvalidSubscriptionState,
+    )]
+    pub subscription_request: Box<Account<'info, SubscriptionRequestAccount>>,
+    /// CHECK: wallet of the subscription request
+    pub wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = asset_mint,
+        associated_token::authority = wallet,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = payment_token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = subscription_offer,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut, has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    /// CHECK: tranche the shares are issued from, checked by the policy engine
+    #[account(mut)]
+    pub tranche: Option<UncheckedAccount<'info>>,
+    /// CHECK: subscription of the identity to the tranche, created by the policy engine
+    #[account(mut)]
+    pub tranche_subscription: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> SettleSubscription<'info> {
+    fn issuance_accounts(&self) -> IssuanceCpiAccounts<'info> {
+        IssuanceCpiAccounts {
+            payer: self.payer.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            token_account: self.token_account.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: self.identity_account.to_account_info(),
+            tracker_account: self.tracker_account.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            policy_engine_program: self.policy_engine_program.to_account_info(),
+            tranche: self.tranche.as_ref().map(|tranche| tranche.to_account_info()),
+            tranche_subscription: self
+                .tranche_subscription
+                .as_ref()
+                .map(|subscription| subscription.to_account_info()),
+        }
+    }
+
+    fn release_payment(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.payment_mint.to_account_info(),
+            to: self.treasury.to_account_info(),
+            authority: self.subscription_offer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.payment_token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        transfer_checked(cpi_ctx, amount, self.payment_mint.decimals)
+    }
+}
+
+/// issues the shares bought by a pending subscription and moves its payment to the treasury
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SettleSubscription<'info>>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.signer.key() == ctx.accounts.asset_controller.authority
+            || timestamp >= ctx.accounts.subscription_offer.cutoff,
+        AssetControllerErrors::SubscriptionSettlementNotAllowed
+    );
+
+    let shares = ctx
+        .accounts
+        .subscription_request
+        .get_shares(ctx.accounts.asset_mint.decimals)?;
+    let payment_amount = ctx.accounts.subscription_request.payment_amount;
+
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+    let issuance_timestamp = ctx.accounts.issuance_accounts().issue(
+        shares,
+        timestamp,
+        ctx.remaining_accounts,
+        &[&signer_seeds],
+    )?;
+
+    let offer_id = ctx.accounts.subscription_offer.id.to_le_bytes();
+    let offer_seeds = [
+        SUBSCRIPTION_OFFER_SEED,
+        asset_mint.as_ref(),
+        offer_id.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.subscription_offer),
+    ];
+    ctx.accounts
+        .release_payment(payment_amount, &[&offer_seeds])?;
+    ctx.accounts.subscription_offer.release(payment_amount)?;
+
+    let request = &mut ctx.accounts.subscription_request;
+    request.shares = shares;
+    request.resolve(SubscriptionState::Settled, timestamp);
+
+    emit_cpi!(IssueEvent {
+        amount: shares,
+        issuance_timestamp,
+        wallet: ctx.accounts.wallet.key(),
+        mint: asset_mint,
+    });
+    emit_cpi!(SubscriptionSettledEvent {
+        mint: asset_mint,
+        offer: ctx.accounts.subscription_offer.key(),
+        request: ctx.accounts.subscription_request.key(),
+        wallet: ctx.accounts.wallet.key(),
+        payment_amount,
+        shares,
+        settled_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
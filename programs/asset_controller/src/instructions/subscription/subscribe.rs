@@ -0,0 +1,109 @@
+use crate::{state::*, AssetControllerErrors, SubscriptionRequestedEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount, WalletIdentity};
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+#[event_cpi]
+pub struct Subscribe<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        has_one = payment_mint,
+    )]
+    pub subscription_offer: Box<Account<'info, SubscriptionOfferAccount>>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = wallet,
+        token::token_program = payment_token_program,
+    )]
+    pub payment_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::token_program = payment_token_program,
+        associated_token::mint = payment_mint,
+        associated_token::authority = subscription_offer,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        has_one = identity_account,
+        constraint = wallet_identity_account.wallet == wallet.key() @ AssetControllerErrors::InvalidIdentityAccounts
+    )]
+    pub wallet_identity_account: Box<Account<'info, WalletIdentity>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SubscriptionRequestAccount::INIT_SPACE,
+        seeds = [SUBSCRIPTION_REQUEST_SEED, subscription_offer.key().as_ref(), wallet.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub subscription_request: Box<Account<'info, SubscriptionRequestAccount>>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl Subscribe<'_> {
+    fn deposit_payment(&self, amount: u64) -> Result<()> {
+        let accounts = TransferChecked {
+            from: self.payment_account.to_account_info(),
+            mint: self.payment_mint.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.wallet.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.payment_token_program.to_account_info(), accounts);
+        transfer_checked(cpi_ctx, amount, self.payment_mint.decimals)
+    }
+}
+
+/// deposits the payment of an identity verified investor at the current price of the offer
+pub fn handler(ctx: Context<Subscribe>, id: u64, payment_amount: u64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        timestamp < ctx.accounts.subscription_offer.cutoff,
+        AssetControllerErrors::SubscriptionOfferClosed
+    );
+
+    let request = SubscriptionRequestAccount::new(
+        ctx.accounts.asset_mint.key(),
+        ctx.accounts.subscription_offer.key(),
+        ctx.accounts.identity_account.key(),
+        ctx.accounts.wallet.key(),
+        ctx.accounts.payer.key(),
+        id,
+        payment_amount,
+        ctx.accounts.subscription_offer.price,
+        timestamp,
+    );
+    require!(
+        request.get_shares(ctx.accounts.asset_mint.decimals)? > 0,
+        AssetControllerErrors::InvalidSubscriptionAmount
+    );
+
+    ctx.accounts.deposit_payment(payment_amount)?;
+    ctx.accounts.subscription_offer.deposit(payment_amount)?;
+    ctx.accounts.subscription_request.set_inner(request);
+
+    emit_cpi!(SubscriptionRequestedEvent {
+        mint: ctx.accounts.asset_mint.key(),
+        offer: ctx.accounts.subscription_offer.key(),
+        request: ctx.accounts.subscription_request.key(),
+        wallet: ctx.accounts.wallet.key(),
+        payment_amount,
+        price: ctx.accounts.subscription_offer.price,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{state::*, AssetControllerErrors, SubscriptionOfferEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct UpdateSubscriptionOffer<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [subscription_offer.asset_mint.as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(mut)]
+    pub subscription_offer: Box<Account<'info, SubscriptionOfferAccount>>,
+    #[account(token::mint = subscription_offer.payment_mint)]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// publishes a new price, pending subscriptions keep the price they were made at
+pub fn handler(ctx: Context<UpdateSubscriptionOffer>, price: u64, cutoff: i64) -> Result<()> {
+    require!(price > 0, AssetControllerErrors::InvalidSubscriptionPrice);
+
+    let treasury = ctx.accounts.treasury.key();
+    ctx.accounts
+        .subscription_offer
+        .update(treasury, price, cutoff);
+
+    emit_cpi!(SubscriptionOfferEvent {
+        mint: ctx.accounts.subscription_offer.asset_mint,
+        offer: ctx.accounts.subscription_offer.key(),
+        payment_mint: ctx.accounts.subscription_offer.payment_mint,
+        treasury,
+        price,
+        cutoff,
+    });
+
+    Ok(())
+}
@@ -52,53 +52,97 @@ pub struct IssueTokens<'info> {
     pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
     #[account(has_one = identity_account)]
     pub wallet_identity_account: Account<'info, WalletIdentity>,
+    /// CHECK: tranche the issuance is made against, checked by the policy engine
+    #[account(mut)]
+    pub tranche: Option<UncheckedAccount<'info>>,
+    /// CHECK: subscription of the identity to the tranche, created by the policy engine
+    #[account(mut)]
+    pub tranche_subscription: Option<UncheckedAccount<'info>>,
 }
 
-impl<'info> IssueTokens<'info> {
-    fn issue_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
-        let accounts = MintTo {
-            mint: self.asset_mint.to_account_info(),
-            to: self.token_account.to_account_info(),
-            authority: self.asset_controller.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            accounts,
-            signer_seeds,
-        );
-        mint_to(cpi_ctx, amount)?;
-        Ok(())
-    }
+/// accounts of an issuance to a single identity, shared by the instructions that mint new shares so that all of
+/// them are checked by the issuance policies and count against the tranche the shares are issued from
+pub struct IssuanceCpiAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub asset_mint: AccountInfo<'info>,
+    pub asset_controller: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub identity_registry: AccountInfo<'info>,
+    pub identity_account: AccountInfo<'info>,
+    pub tracker_account: AccountInfo<'info>,
+    pub policy_engine: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub policy_engine_program: AccountInfo<'info>,
+    pub tranche: Option<AccountInfo<'info>>,
+    pub tranche_subscription: Option<AccountInfo<'info>>,
+}
 
-    fn enforce_policy_issuance(
-        &self,
+impl<'info> IssuanceCpiAccounts<'info> {
+    /// mints the shares and enforces the issuance policies on them, returns the issuance timestamp recorded by the tracker
+    pub fn issue(
+        self,
         amount: u64,
         issuance_timestamp: i64,
+        policy_pages: &[AccountInfo<'info>],
         signer_seeds: &[&[&[u8]]],
     ) -> Result<i64> {
+        let accounts = MintTo {
+            mint: self.asset_mint.clone(),
+            to: self.token_account.clone(),
+            authority: self.asset_controller.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program, accounts, signer_seeds);
+        mint_to(cpi_ctx, amount)?;
+
         let accounts = policy_engine::cpi::accounts::EnforcePolicyIssuanceAccounts {
+            asset_mint: self.asset_mint,
+            policy_engine: self.policy_engine,
+            destination_account: self.token_account,
+            identity_registry: self.identity_registry,
+            identity_account: self.identity_account,
+            destination_tracker_account: self.tracker_account,
+            asset_controller: self.asset_controller,
+            payer: self.payer,
+            system_program: self.system_program,
+            tranche: self.tranche,
+            tranche_subscription: self.tranche_subscription,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.policy_engine_program, accounts, signer_seeds)
+            .with_remaining_accounts(policy_pages.to_vec());
+        let res = policy_engine::cpi::enforce_policy_issuance(cpi_ctx, amount, issuance_timestamp)?;
+        Ok(res.get())
+    }
+}
+
+impl<'info> IssueTokens<'info> {
+    fn issuance_accounts(&self) -> IssuanceCpiAccounts<'info> {
+        IssuanceCpiAccounts {
+            payer: self.payer.to_account_info(),
             asset_mint: self.asset_mint.to_account_info(),
-            policy_engine: self.policy_engine.to_account_info(),
-            destination_account: self.token_account.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            token_account: self.token_account.to_account_info(),
             identity_registry: self.identity_registry.to_account_info(),
             identity_account: self.identity_account.to_account_info(),
-            destination_tracker_account: self.tracker_account.to_account_info(),
-            asset_controller: self.asset_controller.to_account_info(),
-            payer: self.payer.to_account_info(),
+            tracker_account: self.tracker_account.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            token_program: self.token_program.to_account_info(),
             system_program: self.system_program.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.policy_engine_program.to_account_info(),
-            accounts,
-            signer_seeds,
-        );
-        let res = policy_engine::cpi::enforce_policy_issuance(cpi_ctx, amount, issuance_timestamp)?;
-        Ok(res.get())
+            policy_engine_program: self.policy_engine_program.to_account_info(),
+            tranche: self.tranche.as_ref().map(|tranche| tranche.to_account_info()),
+            tranche_subscription: self
+                .tranche_subscription
+                .as_ref()
+                .map(|subscription| subscription.to_account_info()),
+        }
     }
 }
 
-pub fn handler(ctx: Context<IssueTokens>, amount: u64, issuance_timestamp: i64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, IssueTokens<'info>>,
+    amount: u64,
+    issuance_timestamp: i64,
+) -> Result<()> {
     require!(
         ctx.accounts.to.key() == ctx.accounts.identity_account.owner
             || ctx.accounts.wallet_identity_account.wallet == ctx.accounts.to.key(),
@@ -110,9 +154,12 @@ pub fn handler(ctx: Context<IssueTokens>, amount: u64, issuance_timestamp: i64)
         asset_mint.as_ref(),
         &get_bump_in_seed_form(&ctx.bumps.asset_controller),
     ];
-    ctx.accounts.issue_tokens(amount, &[&signer_seeds])?;
-    let issuance_timestamp = ctx.accounts
-        .enforce_policy_issuance(amount, issuance_timestamp, &[&signer_seeds])?;
+    let issuance_timestamp = ctx.accounts.issuance_accounts().issue(
+        amount,
+        issuance_timestamp,
+        ctx.remaining_accounts,
+        &[&signer_seeds],
+    )?;
 
     emit_cpi!(IssueEvent {
         amount,
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, AssociatedToken, Create},
+    token_interface::{mint_to, Mint, MintTo, Token2022},
+};
+use identity_registry::{IdentityRegistryAccount, WalletIdentity};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount};
+use rwa_utils::get_bump_in_seed_form;
+
+use crate::{AssetControllerAccount, AssetControllerErrors, IssueEvent};
+
+/// accounts of a recipient in the remaining accounts:
+/// wallet, identity account, wallet identity, tracker account, associated token account,
+/// followed by the tranche subscription when issuing against a tranche
+pub const ISSUANCE_RECIPIENT_ACCOUNTS_LEN: usize = 5;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct IssueTokensBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key(),
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: tranche the issuances are made against, checked by the policy engine
+    #[account(mut)]
+    pub tranche: Option<UncheckedAccount<'info>>,
+}
+
+/// recipient of a batch issuance
+struct IssuanceRecipient<'info> {
+    wallet: AccountInfo<'info>,
+    identity_account: AccountInfo<'info>,
+    tracker_account: AccountInfo<'info>,
+    token_account: AccountInfo<'info>,
+    tranche_subscription: Option<AccountInfo<'info>>,
+}
+
+impl<'info> IssueTokensBatch<'info> {
+    fn recipient_accounts_len(&self) -> usize {
+        ISSUANCE_RECIPIENT_ACCOUNTS_LEN + usize::from(self.tranche.is_some())
+    }
+
+    fn load_recipient(&self, accounts: &[AccountInfo<'info>]) -> Result<IssuanceRecipient<'info>> {
+        let [wallet, identity_account, wallet_identity, tracker_account, token_account, tranche_subscription @ ..] =
+            accounts
+        else {
+            return Err(AssetControllerErrors::InvalidIssuanceBatch.into());
+        };
+
+        require!(
+            wallet_identity.owner == &identity_registry::id(),
+            AssetControllerErrors::InvalidIdentityAccounts
+        );
+        let recipient_wallet_identity =
+            WalletIdentity::try_deserialize(&mut &wallet_identity.data.borrow()[..])?;
+        require!(
+            recipient_wallet_identity.wallet == wallet.key()
+                && recipient_wallet_identity.identity_account == identity_account.key(),
+            AssetControllerErrors::InvalidIdentityAccounts
+        );
+
+        Ok(IssuanceRecipient {
+            wallet: wallet.clone(),
+            identity_account: identity_account.clone(),
+            tracker_account: tracker_account.clone(),
+            token_account: token_account.clone(),
+            tranche_subscription: tranche_subscription.first().cloned(),
+        })
+    }
+
+    fn create_token_account(&self, recipient: &IssuanceRecipient<'info>) -> Result<()> {
+        let accounts = Create {
+            payer: self.payer.to_account_info(),
+            associated_token: recipient.token_account.clone(),
+            authority: recipient.wallet.clone(),
+            mint: self.asset_mint.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.associated_token_program.to_account_info(), accounts);
+        create_idempotent(cpi_ctx)
+    }
+
+    fn issue_tokens(
+        &self,
+        recipient: &IssuanceRecipient<'info>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let accounts = MintTo {
+            mint: self.asset_mint.to_account_info(),
+            to: recipient.token_account.clone(),
+            authority: self.asset_controller.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        mint_to(cpi_ctx, amount)?;
+        Ok(())
+    }
+
+    fn enforce_policy_issuance(
+        &self,
+        recipient: &IssuanceRecipient<'info>,
+        amount: u64,
+        issuance_timestamp: i64,
+        policy_pages: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<i64> {
+        let accounts = policy_engine::cpi::accounts::EnforcePolicyIssuanceAccounts {
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            destination_account: recipient.token_account.clone(),
+            identity_registry: self.identity_registry.to_account_info(),
+            identity_account: recipient.identity_account.clone(),
+            destination_tracker_account: recipient.tracker_account.clone(),
+            asset_controller: self.asset_controller.to_account_info(),
+            payer: self.payer.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            tranche: self
+                .tranche
+                .as_ref()
+                .map(|tranche| tranche.to_account_info()),
+            tranche_subscription: recipient.tranche_subscription.clone(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        )
+        .with_remaining_accounts(policy_pages.to_vec());
+        let res = policy_engine::cpi::enforce_policy_issuance(cpi_ctx, amount, issuance_timestamp)?;
+        Ok(res.get())
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, IssueTokensBatch<'info>>,
+    amounts: Vec<u64>,
+    issuance_timestamp: i64,
+) -> Result<()> {
+    let recipient_accounts_len = ctx.accounts.recipient_accounts_len();
+    let recipients_len = amounts.len() * recipient_accounts_len;
+    require!(
+        !amounts.is_empty()
+            && amounts.iter().all(|amount| *amount > 0)
+            && ctx.remaining_accounts.len() >= recipients_len,
+        AssetControllerErrors::InvalidIssuanceBatch
+    );
+    // remaining accounts after the recipients are the issuance policy pages
+    let (recipient_accounts, policy_pages) = ctx.remaining_accounts.split_at(recipients_len);
+    let recipients = recipient_accounts
+        .chunks(recipient_accounts_len)
+        .map(|accounts| ctx.accounts.load_recipient(accounts))
+        .collect::<Result<Vec<_>>>()?;
+
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+
+    // everything is minted first so the max supply is checked against the batch total
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        ctx.accounts.create_token_account(recipient)?;
+        ctx.accounts
+            .issue_tokens(recipient, *amount, &[&signer_seeds])?;
+    }
+
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        let issuance_timestamp = ctx.accounts.enforce_policy_issuance(
+            recipient,
+            *amount,
+            issuance_timestamp,
+            policy_pages,
+            &[&signer_seeds],
+        )?;
+
+        emit_cpi!(IssueEvent {
+            amount: *amount,
+            issuance_timestamp,
+            wallet: recipient.wallet.key(),
+            mint: asset_mint,
+        });
+    }
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 pub mod issue;
+pub mod issue_batch;
 pub mod revoke;
 pub mod seize;
+pub mod seize_multi;
 
 pub use issue::*;
+pub use issue_batch::*;
 pub use revoke::*;
 pub use seize::*;
+pub use seize_multi::*;
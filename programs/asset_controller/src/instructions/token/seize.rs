@@ -1,6 +1,8 @@
-use crate::{state::*, SeizeEvent};
+use crate::{state::*, AssetControllerErrors, SeizeEvent};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use identity_registry::{IdentityAccount, IdentityRegistryAccount, WalletIdentity};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount, SeizeCounterChanges};
 use rwa_utils::get_bump_in_seed_form;
 use spl_token_2022::instruction::transfer_checked;
 
@@ -22,7 +24,38 @@ pub struct SeizeTokens<'info> {
     pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut)]
     pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub source_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(has_one = identity_registry)]
+    pub destination_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        constraint = source_wallet_identity.identity_account == source_identity_account.key()
+            && source_wallet_identity.wallet == source_token_account.owner
+            @ AssetControllerErrors::InvalidIdentityAccounts
+    )]
+    pub source_wallet_identity: Box<Account<'info, WalletIdentity>>,
+    #[account(
+        constraint = destination_wallet_identity.identity_account == destination_identity_account.key()
+            && destination_wallet_identity.wallet == destination_token_account.owner
+            @ AssetControllerErrors::InvalidIdentityAccounts
+    )]
+    pub destination_wallet_identity: Box<Account<'info, WalletIdentity>>,
+    /// CHECK: checked by the policy engine, updated by the transfer hook
+    #[account(mut)]
+    pub source_tracker_account: UncheckedAccount<'info>,
+    /// CHECK: checked by the policy engine, updated by the transfer hook
+    #[account(mut)]
+    pub destination_tracker_account: UncheckedAccount<'info>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: authority of the policy engine, checked by the policy engine, receives the rent freed by the source tracker
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> SeizeTokens<'info> {
@@ -61,12 +94,60 @@ impl<'info> SeizeTokens<'info> {
         anchor_lang::solana_program::program::invoke_signed(&ix, &account_infos, signer_seeds)
             .map_err(Into::into)
     }
+
+    fn update_counters_on_seize(
+        &self,
+        amount: u64,
+        enforce_limits: bool,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<SeizeCounterChanges> {
+        let accounts = policy_engine::cpi::accounts::UpdateCountersOnSeizeAccounts {
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            source_identity_account: self.source_identity_account.to_account_info(),
+            destination_identity_account: self.destination_identity_account.to_account_info(),
+            source_tracker_account: self.source_tracker_account.to_account_info(),
+            destination_tracker_account: self.destination_tracker_account.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        let res = policy_engine::cpi::update_counters_on_seize(cpi_ctx, amount, enforce_limits)?;
+        Ok(res.get())
+    }
+
+    fn move_issuances_on_seize(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<u64> {
+        let accounts = policy_engine::cpi::accounts::MoveIssuancesOnSeizeAccounts {
+            payer: self.authority.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            rent_receiver: self.rent_receiver.to_account_info(),
+            source_tracker_account: self.source_tracker_account.to_account_info(),
+            destination_tracker_account: self.destination_tracker_account.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        let res = policy_engine::cpi::move_issuances_on_seize(cpi_ctx, amount)?;
+        Ok(res.get())
+    }
 }
 
 pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, SeizeTokens<'info>>,
     amount: u64,
     reason: String,
+    enforce_limits: bool,
 ) -> Result<()> {
     let asset_mint = ctx.accounts.asset_mint.key();
     let signer_seeds = [
@@ -75,6 +156,17 @@ pub fn handler<'info>(
     ];
     ctx.accounts
         .transfer_tokens(amount, &[&signer_seeds], ctx.remaining_accounts)?;
+    // the transfer hook skips holder counters for seizes
+    let counter_changes = ctx.accounts
+        .update_counters_on_seize(amount, enforce_limits, &[&signer_seeds])?;
+    let moved_issuances_amount = if ctx.accounts.destination_identity_account.key()
+        != ctx.accounts.source_identity_account.key()
+    {
+        ctx.accounts
+            .move_issuances_on_seize(amount, &[&signer_seeds])?
+    } else {
+        0
+    };
 
     emit_cpi!(SeizeEvent {
         amount,
@@ -82,6 +174,10 @@ pub fn handler<'info>(
         wallet: ctx.accounts.source_token_account.owner,
         to_wallet: ctx.accounts.destination_token_account.owner,
         mint: ctx.accounts.asset_mint.key(),
+        enforce_limits,
+        decreased_counters: counter_changes.decreased_counters,
+        increased_counters: counter_changes.increased_counters,
+        moved_issuances_amount,
     });
 
     Ok(())
@@ -0,0 +1,273 @@
+use crate::{state::*, AssetControllerErrors, SeizeLegEvent};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use identity_registry::{
+    program::IdentityRegistry, IdentityAccount, IdentityRegistryAccount, WalletIdentity,
+};
+use policy_engine::{program::PolicyEngine, PolicyEngineAccount, SeizeCounterChanges};
+use rwa_utils::get_bump_in_seed_form;
+use spl_token_2022::instruction::transfer_checked;
+
+/// accounts of a seize leg in the remaining accounts:
+/// destination token account, destination wallet identity, destination identity account, destination tracker account
+pub const SEIZE_LEG_ACCOUNTS_LEN: usize = 4;
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct SeizeTokensMulti<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        constraint = asset_controller.authority == authority.key()
+    )]
+    pub asset_controller: Box<Account<'info, AssetControllerAccount>>,
+    #[account(mut)]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub source_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        constraint = source_wallet_identity.identity_account == source_identity_account.key()
+            && source_wallet_identity.wallet == source_token_account.owner
+            @ AssetControllerErrors::InvalidIdentityAccounts
+    )]
+    pub source_wallet_identity: Box<Account<'info, WalletIdentity>>,
+    /// CHECK: checked by the policy engine, updated by the transfer hook
+    #[account(mut)]
+    pub source_tracker_account: UncheckedAccount<'info>,
+    /// CHECK: checked by the transfer hook
+    pub extra_metas_account: UncheckedAccount<'info>,
+    pub identity_registry_program: Program<'info, IdentityRegistry>,
+    pub policy_engine_program: Program<'info, PolicyEngine>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: authority of the policy engine, checked by the policy engine, receives the rent freed by the source tracker
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// destination side of a seize leg
+struct SeizeLeg<'info> {
+    token_account: AccountInfo<'info>,
+    wallet_identity: AccountInfo<'info>,
+    identity_account: AccountInfo<'info>,
+    tracker_account: AccountInfo<'info>,
+    wallet: Pubkey,
+}
+
+impl<'info> SeizeTokensMulti<'info> {
+    fn load_leg(&self, accounts: &[AccountInfo<'info>]) -> Result<SeizeLeg<'info>> {
+        let [token_account, wallet_identity, identity_account, tracker_account] = accounts else {
+            return Err(AssetControllerErrors::InvalidSeizeLegs.into());
+        };
+
+        require!(
+            token_account.owner == self.token_program.key,
+            AssetControllerErrors::InvalidSeizeLegs
+        );
+        let wallet = TokenAccount::try_deserialize(&mut &token_account.data.borrow()[..])?.owner;
+
+        // destination must have an identity in the registry of the asset
+        require!(
+            wallet_identity.owner == self.identity_registry_program.key
+                && identity_account.owner == self.identity_registry_program.key,
+            AssetControllerErrors::InvalidIdentityAccounts
+        );
+        let destination_wallet_identity =
+            WalletIdentity::try_deserialize(&mut &wallet_identity.data.borrow()[..])?;
+        let destination_identity_account =
+            IdentityAccount::try_deserialize(&mut &identity_account.data.borrow()[..])?;
+        require!(
+            destination_identity_account.identity_registry == self.identity_registry.key()
+                && destination_wallet_identity.identity_account == identity_account.key()
+                && destination_wallet_identity.wallet == wallet,
+            AssetControllerErrors::InvalidIdentityAccounts
+        );
+
+        Ok(SeizeLeg {
+            token_account: token_account.clone(),
+            wallet_identity: wallet_identity.clone(),
+            identity_account: identity_account.clone(),
+            tracker_account: tracker_account.clone(),
+            wallet,
+        })
+    }
+
+    fn transfer_tokens(
+        &self,
+        leg: &SeizeLeg<'info>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+        policy_pages: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let mut ix = transfer_checked(
+            self.token_program.key,
+            &self.source_token_account.key(),
+            &self.asset_mint.key(),
+            leg.token_account.key,
+            &self.asset_controller.key(),
+            &[],
+            amount,
+            self.asset_mint.decimals,
+        )?;
+
+        let mut account_infos = vec![
+            self.source_token_account.to_account_info(),
+            self.asset_mint.to_account_info(),
+            leg.token_account.clone(),
+            self.asset_controller.to_account_info(),
+        ];
+
+        // transfer hook accounts, in the order of the extra metas
+        let hook_accounts = [
+            self.extra_metas_account.to_account_info(),
+            self.policy_engine_program.to_account_info(),
+            self.policy_engine.to_account_info(),
+            self.identity_registry_program.to_account_info(),
+            self.identity_registry.to_account_info(),
+            self.source_wallet_identity.to_account_info(),
+            leg.wallet_identity.clone(),
+            self.source_identity_account.to_account_info(),
+            leg.identity_account.clone(),
+            self.source_tracker_account.to_account_info(),
+            leg.tracker_account.clone(),
+        ];
+
+        for account in hook_accounts.iter().chain(policy_pages.iter()) {
+            ix.accounts.push(AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            });
+            account_infos.push(account.clone());
+        }
+
+        anchor_lang::solana_program::program::invoke_signed(&ix, &account_infos, signer_seeds)
+            .map_err(Into::into)
+    }
+
+    fn update_counters_on_seize(
+        &self,
+        leg: &SeizeLeg<'info>,
+        amount: u64,
+        enforce_limits: bool,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<SeizeCounterChanges> {
+        let accounts = policy_engine::cpi::accounts::UpdateCountersOnSeizeAccounts {
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            identity_registry: self.identity_registry.to_account_info(),
+            source_identity_account: self.source_identity_account.to_account_info(),
+            destination_identity_account: leg.identity_account.clone(),
+            source_tracker_account: self.source_tracker_account.to_account_info(),
+            destination_tracker_account: leg.tracker_account.clone(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        let res = policy_engine::cpi::update_counters_on_seize(cpi_ctx, amount, enforce_limits)?;
+        Ok(res.get())
+    }
+
+    fn move_issuances_on_seize(
+        &self,
+        leg: &SeizeLeg<'info>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let accounts = policy_engine::cpi::accounts::MoveIssuancesOnSeizeAccounts {
+            payer: self.payer.to_account_info(),
+            asset_controller: self.asset_controller.to_account_info(),
+            asset_mint: self.asset_mint.to_account_info(),
+            policy_engine: self.policy_engine.to_account_info(),
+            rent_receiver: self.rent_receiver.to_account_info(),
+            source_tracker_account: self.source_tracker_account.to_account_info(),
+            destination_tracker_account: leg.tracker_account.clone(),
+            system_program: self.system_program.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.policy_engine_program.to_account_info(),
+            accounts,
+            signer_seeds,
+        );
+        let res = policy_engine::cpi::move_issuances_on_seize(cpi_ctx, amount)?;
+        Ok(res.get())
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SeizeTokensMulti<'info>>,
+    amounts: Vec<u64>,
+    reason: String,
+    case_reference: String,
+    enforce_limits: bool,
+) -> Result<()> {
+    let legs_len = amounts.len() * SEIZE_LEG_ACCOUNTS_LEN;
+    require!(
+        !amounts.is_empty()
+            && amounts.len() <= u8::MAX as usize
+            && amounts.iter().all(|amount| *amount > 0)
+            && ctx.remaining_accounts.len() >= legs_len,
+        AssetControllerErrors::InvalidSeizeLegs
+    );
+    // remaining accounts after the legs are the transfer policy pages
+    let (leg_accounts, policy_pages) = ctx.remaining_accounts.split_at(legs_len);
+
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [
+        asset_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.asset_controller),
+    ];
+
+    for (index, (amount, accounts)) in amounts
+        .iter()
+        .zip(leg_accounts.chunks(SEIZE_LEG_ACCOUNTS_LEN))
+        .enumerate()
+    {
+        let leg = ctx.accounts.load_leg(accounts)?;
+        ctx.accounts
+            .transfer_tokens(&leg, *amount, &[&signer_seeds], policy_pages)?;
+        // the transfer hook skips holder counters for seizes
+        let counter_changes =
+            ctx.accounts
+                .update_counters_on_seize(&leg, *amount, enforce_limits, &[&signer_seeds])?;
+        let moved_issuances_amount =
+            if leg.identity_account.key() != ctx.accounts.source_identity_account.key() {
+                ctx.accounts
+                    .move_issuances_on_seize(&leg, *amount, &[&signer_seeds])?
+            } else {
+                0
+            };
+
+        emit_cpi!(SeizeLegEvent {
+            case_reference: case_reference.clone(),
+            leg: index as u8,
+            legs: amounts.len() as u8,
+            amount: *amount,
+            reason: reason.clone(),
+            authority: ctx.accounts.authority.key(),
+            wallet: ctx.accounts.source_token_account.owner,
+            to_wallet: leg.wallet,
+            mint: asset_mint,
+            enforce_limits,
+            decreased_counters: counter_changes.decreased_counters,
+            increased_counters: counter_changes.increased_counters,
+            moved_issuances_amount,
+        });
+    }
+
+    Ok(())
+}
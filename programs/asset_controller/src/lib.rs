@@ -29,8 +29,8 @@ pub mod asset_controller {
     }
 
     /// issue shares of the rwa asset
-    pub fn issue_tokens(
-        ctx: Context<IssueTokens>,
+    pub fn issue_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, IssueTokens<'info>>,
         amount: u64,
         issuance_timestamp: i64,
     ) -> Result<()> {
@@ -45,6 +45,15 @@ pub mod asset_controller {
         instructions::update::handler(ctx, args)
     }
 
+    /// issue shares of the rwa asset to several wallets, recipients are passed in the remaining accounts
+    pub fn issue_tokens_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, IssueTokensBatch<'info>>,
+        amounts: Vec<u64>,
+        issuance_timestamp: i64,
+    ) -> Result<()> {
+        instructions::issue_batch::handler(ctx, amounts, issuance_timestamp)
+    }
+
     /// revoke shares of the rwa asset
     pub fn revoke_tokens<'info>(
         ctx: Context<'_, '_, '_, 'info, RevokeTokens<'info>>,
@@ -54,13 +63,28 @@ pub mod asset_controller {
         instructions::revoke::handler(ctx, amount, reason)
     }
 
-    /// seize shares of the rwa asset
+    /// seize shares of the rwa asset. the seized amount takes the newest issuance lots of the source first and
+    /// they keep their issue time on the destination, so seized shares can still be held up by the lock periods
+    /// of the issuance policies while the source keeps its oldest lots. locks of the source are not moved.
     pub fn seize_tokens<'info>(
         ctx: Context<'_, '_, '_, 'info, SeizeTokens<'info>>,
         amount: u64,
         reason: String,
+        enforce_limits: bool,
     ) -> Result<()> {
-        instructions::seize::handler(ctx, amount, reason)
+        instructions::seize::handler(ctx, amount, reason, enforce_limits)
+    }
+
+    /// seize shares of the rwa asset to several destinations, legs are passed in the remaining accounts.
+    /// each leg takes the newest issuance lots left on the source, like `seize_tokens`
+    pub fn seize_tokens_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, SeizeTokensMulti<'info>>,
+        amounts: Vec<u64>,
+        reason: String,
+        case_reference: String,
+        enforce_limits: bool,
+    ) -> Result<()> {
+        instructions::seize_multi::handler(ctx, amounts, reason, case_reference, enforce_limits)
     }
 
     /// memo transfer enable
@@ -73,6 +97,30 @@ pub mod asset_controller {
         instructions::extensions::disable_memo::handler(ctx)
     }
 
+    /// schedule the scaled ui amount multiplier of the asset, for splits and reverse splits
+    pub fn update_multiplier(
+        ctx: Context<UpdateMultiplier>,
+        multiplier: f64,
+        effective_timestamp: i64,
+    ) -> Result<()> {
+        instructions::extensions::update_multiplier::handler(ctx, multiplier, effective_timestamp)
+    }
+
+    /// change the rate of the interest-bearing extension of the asset
+    pub fn update_interest_rate(ctx: Context<UpdateInterestRate>, rate: i16) -> Result<()> {
+        instructions::extensions::update_interest_rate::handler(ctx, rate)
+    }
+
+    /// pause the mints, burns and transfers of a pausable asset
+    pub fn pause_asset(ctx: Context<PauseAsset>) -> Result<()> {
+        instructions::extensions::pause::handler(ctx)
+    }
+
+    /// resume the mints, burns and transfers of a paused asset
+    pub fn resume_asset(ctx: Context<ResumeAsset>) -> Result<()> {
+        instructions::extensions::resume::handler(ctx)
+    }
+
     /// freeze token account
     pub fn freeze_token_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
         instructions::account::freeze::handler(ctx)
@@ -82,4 +130,158 @@ pub mod asset_controller {
     pub fn thaw_token_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
         instructions::account::thaw::handler(ctx)
     }
+
+    /// create and thaw the token account of a wallet attached to an identity, called by the identity registry
+    pub fn activate_wallet(ctx: Context<ActivateWallet>) -> Result<()> {
+        instructions::account::activate_wallet::handler(ctx)
+    }
+
+    /// freeze the token account of a wallet detached from an identity, called by the identity registry
+    pub fn deactivate_wallet(ctx: Context<DeactivateWallet>) -> Result<()> {
+        instructions::account::deactivate_wallet::handler(ctx)
+    }
+
+    /// recover a lost wallet, moving its tokens and identity mapping to a new wallet
+    pub fn recover_wallet<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecoverWallet<'info>>,
+        case_reference: String,
+    ) -> Result<()> {
+        instructions::account::recover_wallet::handler(ctx, case_reference)
+    }
+
+    /// request the redemption of tokens, the amount is locked until the request is settled
+    pub fn request_redemption(
+        ctx: Context<RequestRedemption>,
+        id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redemption::request::handler(ctx, id, amount)
+    }
+
+    /// approve a redemption request, burning the locked tokens
+    pub fn approve_redemption(ctx: Context<ApproveRedemption>) -> Result<()> {
+        instructions::redemption::approve::handler(ctx)
+    }
+
+    /// reject a redemption request, releasing the locked tokens
+    pub fn reject_redemption(ctx: Context<RejectRedemption>, reason: String) -> Result<()> {
+        instructions::redemption::reject::handler(ctx, reason)
+    }
+
+    /// cancel a pending redemption request, releasing the locked tokens
+    pub fn cancel_redemption(ctx: Context<CancelRedemption>) -> Result<()> {
+        instructions::redemption::cancel::handler(ctx)
+    }
+
+    /// close a settled redemption request
+    pub fn close_redemption_request(_ctx: Context<CloseRedemptionRequest>) -> Result<()> {
+        // No additional steps needed.
+        Ok(())
+    }
+
+    /// create an offer to subscribe to the asset with a payment token, the price is payment units per whole share
+    pub fn create_subscription_offer(
+        ctx: Context<CreateSubscriptionOffer>,
+        id: u64,
+        price: u64,
+        cutoff: i64,
+    ) -> Result<()> {
+        instructions::subscription::create_offer::handler(ctx, id, price, cutoff)
+    }
+
+    /// publish the price and cutoff of a subscription offer
+    pub fn update_subscription_offer(
+        ctx: Context<UpdateSubscriptionOffer>,
+        price: u64,
+        cutoff: i64,
+    ) -> Result<()> {
+        instructions::subscription::update_offer::handler(ctx, price, cutoff)
+    }
+
+    /// deposit a payment into a subscription offer at its current price
+    pub fn subscribe(ctx: Context<Subscribe>, id: u64, payment_amount: u64) -> Result<()> {
+        instructions::subscription::subscribe::handler(ctx, id, payment_amount)
+    }
+
+    /// issue the shares of a pending subscription, the issuance policy pages are passed in the remaining accounts
+    pub fn settle_subscription<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleSubscription<'info>>,
+    ) -> Result<()> {
+        instructions::subscription::settle::handler(ctx)
+    }
+
+    /// refund the payment of a pending subscription
+    pub fn refund_subscription(ctx: Context<RefundSubscription>, reason: String) -> Result<()> {
+        instructions::subscription::refund::handler(ctx, reason)
+    }
+
+    /// close a settled or refunded subscription request
+    pub fn close_subscription_request(_ctx: Context<CloseSubscriptionRequest>) -> Result<()> {
+        // No additional steps needed.
+        Ok(())
+    }
+
+    /// fund a distribution to the holders of the asset, paid pro-rata to the balances at the record date
+    /// net of the withholding rate of the country group of each holder
+    pub fn create_distribution(
+        ctx: Context<CreateDistribution>,
+        id: u64,
+        snapshot: DistributionSnapshot,
+        amount: u64,
+        expiry: i64,
+        withholding_rates: Vec<WithholdingRate>,
+    ) -> Result<()> {
+        instructions::distribution::create::handler(
+            ctx,
+            id,
+            snapshot,
+            amount,
+            expiry,
+            withholding_rates,
+        )
+    }
+
+    /// claim the share of an identity in a distribution, the proof is required for merkle snapshots
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        proof: Option<BalanceProof>,
+    ) -> Result<()> {
+        instructions::distribution::claim::handler(ctx, proof)
+    }
+
+    /// reclaim the unclaimed funds of an expired distribution
+    pub fn reclaim_distribution(ctx: Context<ReclaimDistribution>) -> Result<()> {
+        instructions::distribution::reclaim::handler(ctx)
+    }
+
+    /// open a conversion from the shares of one asset into another, signed by the authorities of both assets
+    pub fn create_conversion(
+        ctx: Context<CreateConversion>,
+        config: ConversionConfig,
+    ) -> Result<()> {
+        instructions::conversion::create::handler(ctx, config)
+    }
+
+    /// change the ratio and holding period treatment of a conversion
+    pub fn update_conversion(
+        ctx: Context<UpdateConversion>,
+        config: ConversionConfig,
+    ) -> Result<()> {
+        instructions::conversion::update::handler(ctx, config)
+    }
+
+    /// close a conversion, no further shares can be converted through it
+    pub fn close_conversion(_ctx: Context<CloseConversion>) -> Result<()> {
+        // No additional steps needed.
+        Ok(())
+    }
+
+    /// convert shares of the wallet at the ratio of the conversion, the issuance policy pages of the destination
+    /// asset are passed in the remaining accounts
+    pub fn convert_shares<'info>(
+        ctx: Context<'_, '_, '_, 'info, ConvertShares<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::conversion::convert::handler(ctx, amount)
+    }
 }
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use policy_engine::Issuance;
+
+use crate::AssetControllerErrors;
+
+pub const CONVERSION_SEED: &[u8] = b"conversion";
+
+/// terms of the conversion of one share class into another, set by the authorities of both classes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConversionConfig {
+    /// destination base units received per `ratio_denominator` source base units
+    pub ratio_numerator: u64,
+    pub ratio_denominator: u64,
+    /// issue the destination shares at the issue time of the converted lots instead of the conversion time
+    pub preserve_holding_periods: bool,
+}
+
+impl ConversionConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.ratio_numerator > 0 && self.ratio_denominator > 0,
+            AssetControllerErrors::InvalidConversionRatio
+        );
+        Ok(())
+    }
+}
+
+/// route converting the shares of the source mint into shares of the destination mint
+#[account()]
+#[derive(InitSpace)]
+pub struct ConversionAccount {
+    pub version: u8,
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    /// receives the rent back when the conversion is closed
+    pub payer: Pubkey,
+    pub ratio_numerator: u64,
+    pub ratio_denominator: u64,
+    pub preserve_holding_periods: bool,
+}
+
+impl ConversionAccount {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(
+        source_mint: Pubkey,
+        destination_mint: Pubkey,
+        payer: Pubkey,
+        config: ConversionConfig,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            source_mint,
+            destination_mint,
+            payer,
+            ratio_numerator: config.ratio_numerator,
+            ratio_denominator: config.ratio_denominator,
+            preserve_holding_periods: config.preserve_holding_periods,
+        }
+    }
+
+    pub fn set_config(&mut self, config: ConversionConfig) {
+        self.ratio_numerator = config.ratio_numerator;
+        self.ratio_denominator = config.ratio_denominator;
+        self.preserve_holding_periods = config.preserve_holding_periods;
+    }
+
+    /// destination amount of a source amount at the conversion ratio, rounded down
+    pub fn convert(&self, amount: u64) -> Result<u64> {
+        let converted = (amount as u128)
+            .checked_mul(self.ratio_numerator as u128)
+            .and_then(|value| value.checked_div(self.ratio_denominator as u128))
+            .filter(|converted| *converted <= u64::MAX as u128)
+            .ok_or(AssetControllerErrors::InvalidConversionAmount)?;
+        Ok(converted as u64)
+    }
+
+    /// destination lots of the converted source lots, the part not covered by the lots is issued at the conversion time.
+    /// lots are converted on their cumulative amount so the rounding matches the converted total.
+    pub fn convert_lots(
+        &self,
+        lots: &[Issuance],
+        amount: u64,
+        timestamp: i64,
+    ) -> Result<Vec<Issuance>> {
+        let mut converted_lots = Vec::new();
+        let mut cumulative = 0u64;
+        let mut converted = 0u64;
+        let uncovered = amount - lots.iter().map(|lot| lot.amount).sum::<u64>();
+        let lots = lots
+            .iter()
+            .map(|lot| (lot.amount, lot.issue_time))
+            .chain(std::iter::once((uncovered, timestamp)));
+        for (lot_amount, issue_time) in lots {
+            cumulative += lot_amount;
+            let next = self.convert(cumulative)?;
+            if next > converted {
+                converted_lots.push(Issuance {
+                    amount: next - converted,
+                    issue_time,
+                });
+                converted = next;
+            }
+        }
+        Ok(converted_lots)
+    }
+}
@@ -0,0 +1,206 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::AssetControllerErrors;
+
+pub const DISTRIBUTION_SEED: &[u8] = b"distribution";
+pub const DISTRIBUTION_CLAIM_SEED: &[u8] = b"distribution-claim";
+pub const MAX_WITHHOLDING_RATE_BPS: u16 = 10_000;
+
+/// source of the record date balances a distribution is paid against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum DistributionSnapshot {
+    /// balances are read from the tracker accounts at a policy engine snapshot opened when the distribution is created
+    Tracker,
+    /// sha256 merkle root of `identity_account || balance` leaves committed by the authority, pairs are hashed sorted
+    Merkle { root: [u8; 32], total_balance: u64 },
+}
+
+/// balance of an identity at the record date, proven against the merkle root of the distribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BalanceProof {
+    pub balance: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// share of the claims withheld for the identities of a country group, the group of an identity being `mapping[country]` of the policy engine
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub struct WithholdingRate {
+    pub group: u8,
+    pub rate_bps: u16,
+}
+
+/// payout to the holders of the asset, funds are held by the vault of the distribution
+#[account()]
+#[derive(InitSpace)]
+pub struct DistributionAccount {
+    pub version: u8,
+    pub asset_mint: Pubkey,
+    /// id chosen by the authority, unique per asset
+    pub id: u64,
+    pub payout_mint: Pubkey,
+    pub snapshot: DistributionSnapshot,
+    /// policy engine snapshot of the tracker balances, 0 for merkle snapshots
+    pub snapshot_id: u64,
+    /// sum of the balances at the record date
+    pub snapshot_supply: u64,
+    pub record_timestamp: i64,
+    /// claims are closed after the expiry, the unclaimed funds can then be reclaimed
+    pub expiry: i64,
+    /// amount funded into the vault
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub reclaimed: bool,
+    /// token account receiving the withheld amounts, default when nothing is withheld
+    pub tax_vault: Pubkey,
+    /// groups without a rate are paid in full
+    #[max_len(0)]
+    pub withholding_rates: Vec<WithholdingRate>,
+}
+
+impl DistributionAccount {
+    pub const VERSION: u8 = 1;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        asset_mint: Pubkey,
+        id: u64,
+        payout_mint: Pubkey,
+        snapshot: DistributionSnapshot,
+        snapshot_id: u64,
+        snapshot_supply: u64,
+        record_timestamp: i64,
+        expiry: i64,
+        total_amount: u64,
+        tax_vault: Pubkey,
+        withholding_rates: Vec<WithholdingRate>,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            asset_mint,
+            id,
+            payout_mint,
+            snapshot,
+            snapshot_id,
+            snapshot_supply,
+            record_timestamp,
+            expiry,
+            total_amount,
+            claimed_amount: 0,
+            reclaimed: false,
+            tax_vault,
+            withholding_rates,
+        }
+    }
+
+    pub fn get_space(withholding_rates: &[WithholdingRate]) -> usize {
+        DistributionAccount::INIT_SPACE + withholding_rates.len() * WithholdingRate::INIT_SPACE
+    }
+
+    pub fn validate_withholding_rates(withholding_rates: &[WithholdingRate]) -> Result<()> {
+        for (index, rate) in withholding_rates.iter().enumerate() {
+            require!(
+                rate.rate_bps <= MAX_WITHHOLDING_RATE_BPS
+                    && !withholding_rates[..index]
+                        .iter()
+                        .any(|other| other.group == rate.group),
+                AssetControllerErrors::InvalidWithholdingRates
+            );
+        }
+        Ok(())
+    }
+
+    /// splits a claim into the withheld and net amounts for a country group
+    pub fn withhold(&self, group: u8, amount: u64) -> (u64, u64) {
+        let rate_bps = self
+            .withholding_rates
+            .iter()
+            .find(|rate| rate.group == group)
+            .map_or(0, |rate| rate.rate_bps);
+        let withheld =
+            (amount as u128 * rate_bps as u128 / MAX_WITHHOLDING_RATE_BPS as u128) as u64;
+        (withheld, amount - withheld)
+    }
+
+    /// record date balance of an identity, from the snapshot of its tracker or from a merkle proof
+    pub fn get_balance(
+        &self,
+        identity_account: Pubkey,
+        tracker_balance: Option<u64>,
+        proof: Option<BalanceProof>,
+    ) -> Result<u64> {
+        match (&self.snapshot, tracker_balance, proof) {
+            (DistributionSnapshot::Tracker, Some(balance), None) => Ok(balance),
+            (DistributionSnapshot::Merkle { root, .. }, None, Some(proof)) => {
+                let mut node =
+                    hashv(&[identity_account.as_ref(), &proof.balance.to_le_bytes()]).to_bytes();
+                for sibling in proof.proof.iter() {
+                    node = if node <= *sibling {
+                        hashv(&[&node, sibling]).to_bytes()
+                    } else {
+                        hashv(&[sibling, &node]).to_bytes()
+                    };
+                }
+                require!(node == *root, AssetControllerErrors::InvalidBalanceProof);
+                Ok(proof.balance)
+            }
+            _ => err!(AssetControllerErrors::InvalidBalanceProof),
+        }
+    }
+
+    /// pro-rata share of the funded amount for a record date balance, rounded down
+    pub fn claim(&mut self, balance: u64) -> Result<u64> {
+        let amount = (balance as u128)
+            .checked_mul(self.total_amount as u128)
+            .and_then(|value| value.checked_div(self.snapshot_supply as u128))
+            .ok_or(AssetControllerErrors::InvalidDistributionClaim)? as u64;
+        require!(amount > 0, AssetControllerErrors::InvalidDistributionClaim);
+        self.claimed_amount = self
+            .claimed_amount
+            .checked_add(amount)
+            .filter(|claimed| *claimed <= self.total_amount)
+            .ok_or(AssetControllerErrors::InvalidDistributionClaim)?;
+        Ok(amount)
+    }
+
+    pub fn get_unclaimed_amount(&self) -> u64 {
+        self.total_amount - self.claimed_amount
+    }
+}
+
+/// receipt of the claim of an identity, prevents claiming twice
+#[account()]
+#[derive(InitSpace)]
+pub struct DistributionClaimAccount {
+    pub version: u8,
+    pub distribution: Pubkey,
+    pub identity_account: Pubkey,
+    pub balance: u64,
+    /// gross amount of the claim
+    pub amount: u64,
+    /// part of the amount sent to the tax vault
+    pub withheld_amount: u64,
+    pub claimed_at: i64,
+}
+
+impl DistributionClaimAccount {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(
+        distribution: Pubkey,
+        identity_account: Pubkey,
+        balance: u64,
+        amount: u64,
+        withheld_amount: u64,
+        claimed_at: i64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            distribution,
+            identity_account,
+            balance,
+            amount,
+            withheld_amount,
+            claimed_at,
+        }
+    }
+}
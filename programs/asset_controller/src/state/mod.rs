@@ -1,12 +1,26 @@
+pub mod conversion;
+pub mod distribution;
+pub mod redemption;
 pub mod registry;
+pub mod subscription;
 
+pub use conversion::*;
+pub use distribution::*;
+pub use redemption::*;
 pub use registry::*;
+pub use subscription::*;
 
 use anchor_lang::{solana_program::program_error::ProgramError, AnchorDeserialize, Discriminator};
 use rwa_utils::GeyserProgramAccount;
 
 pub enum AssetControllerAccounts {
     AssetControllerAccount(AssetControllerAccount),
+    RedemptionRequestAccount(RedemptionRequestAccount),
+    SubscriptionOfferAccount(SubscriptionOfferAccount),
+    SubscriptionRequestAccount(SubscriptionRequestAccount),
+    DistributionAccount(DistributionAccount),
+    DistributionClaimAccount(DistributionClaimAccount),
+    ConversionAccount(ConversionAccount),
 }
 
 impl GeyserProgramAccount for AssetControllerAccounts {
@@ -15,6 +29,20 @@ impl GeyserProgramAccount for AssetControllerAccounts {
             AssetControllerAccounts::AssetControllerAccount(_) => {
                 AssetControllerAccount::DISCRIMINATOR
             }
+            AssetControllerAccounts::RedemptionRequestAccount(_) => {
+                RedemptionRequestAccount::DISCRIMINATOR
+            }
+            AssetControllerAccounts::SubscriptionOfferAccount(_) => {
+                SubscriptionOfferAccount::DISCRIMINATOR
+            }
+            AssetControllerAccounts::SubscriptionRequestAccount(_) => {
+                SubscriptionRequestAccount::DISCRIMINATOR
+            }
+            AssetControllerAccounts::DistributionAccount(_) => DistributionAccount::DISCRIMINATOR,
+            AssetControllerAccounts::DistributionClaimAccount(_) => {
+                DistributionClaimAccount::DISCRIMINATOR
+            }
+            AssetControllerAccounts::ConversionAccount(_) => ConversionAccount::DISCRIMINATOR,
         }
     }
 
@@ -36,6 +64,30 @@ impl GeyserProgramAccount for AssetControllerAccounts {
                 let account = AssetControllerAccount::deserialize(account_data)?;
                 Ok(AssetControllerAccounts::AssetControllerAccount(account))
             }
+            RedemptionRequestAccount::DISCRIMINATOR => {
+                let account = RedemptionRequestAccount::deserialize(account_data)?;
+                Ok(AssetControllerAccounts::RedemptionRequestAccount(account))
+            }
+            SubscriptionOfferAccount::DISCRIMINATOR => {
+                let account = SubscriptionOfferAccount::deserialize(account_data)?;
+                Ok(AssetControllerAccounts::SubscriptionOfferAccount(account))
+            }
+            SubscriptionRequestAccount::DISCRIMINATOR => {
+                let account = SubscriptionRequestAccount::deserialize(account_data)?;
+                Ok(AssetControllerAccounts::SubscriptionRequestAccount(account))
+            }
+            DistributionAccount::DISCRIMINATOR => {
+                let account = DistributionAccount::deserialize(account_data)?;
+                Ok(AssetControllerAccounts::DistributionAccount(account))
+            }
+            DistributionClaimAccount::DISCRIMINATOR => {
+                let account = DistributionClaimAccount::deserialize(account_data)?;
+                Ok(AssetControllerAccounts::DistributionClaimAccount(account))
+            }
+            ConversionAccount::DISCRIMINATOR => {
+                let account = ConversionAccount::deserialize(account_data)?;
+                Ok(AssetControllerAccounts::ConversionAccount(account))
+            }
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
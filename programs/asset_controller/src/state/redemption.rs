@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+pub const REDEMPTION_REQUEST_SEED: &[u8] = b"redemption-request";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RedemptionState {
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+/// redemption requested by a holder, the amount stays locked on the tracker while pending
+#[account()]
+#[derive(InitSpace)]
+pub struct RedemptionRequestAccount {
+    pub version: u8,
+    pub asset_mint: Pubkey,
+    /// identity account of the holder
+    pub identity_account: Pubkey,
+    /// wallet holding the tokens to redeem
+    pub wallet: Pubkey,
+    /// receives the rent back when the request is closed
+    pub payer: Pubkey,
+    /// id chosen by the holder, unique per wallet
+    pub id: u64,
+    pub amount: u64,
+    pub state: RedemptionState,
+    pub requested_at: i64,
+    /// time of approval, rejection or cancellation
+    pub resolved_at: i64,
+}
+
+impl RedemptionRequestAccount {
+    pub const VERSION: u8 = 1;
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        asset_mint: Pubkey,
+        identity_account: Pubkey,
+        wallet: Pubkey,
+        payer: Pubkey,
+        id: u64,
+        amount: u64,
+        requested_at: i64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            asset_mint,
+            identity_account,
+            wallet,
+            payer,
+            id,
+            amount,
+            state: RedemptionState::Pending,
+            requested_at,
+            resolved_at: 0,
+        }
+    }
+
+    pub fn resolve(&mut self, state: RedemptionState, timestamp: i64) {
+        self.state = state;
+        self.resolved_at = timestamp;
+    }
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+use crate::AssetControllerErrors;
+
+pub const SUBSCRIPTION_OFFER_SEED: &[u8] = b"subscription-offer";
+pub const SUBSCRIPTION_REQUEST_SEED: &[u8] = b"subscription-request";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SubscriptionState {
+    Pending,
+    Settled,
+    Refunded,
+}
+
+/// offer to subscribe to the asset by depositing a payment token, deposits are held by the vault of the offer
+#[account()]
+#[derive(InitSpace)]
+pub struct SubscriptionOfferAccount {
+    pub version: u8,
+    pub asset_mint: Pubkey,
+    /// id chosen by the authority, unique per asset
+    pub id: u64,
+    pub payment_mint: Pubkey,
+    /// token account receiving the payments of settled subscriptions
+    pub treasury: Pubkey,
+    /// payment base units per whole share
+    pub price: u64,
+    /// deposits are closed after the cutoff, pending subscriptions can then be settled by anyone
+    pub cutoff: i64,
+    /// payments held by the vault for pending subscriptions
+    pub pending_amount: u64,
+}
+
+impl SubscriptionOfferAccount {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(
+        asset_mint: Pubkey,
+        id: u64,
+        payment_mint: Pubkey,
+        treasury: Pubkey,
+        price: u64,
+        cutoff: i64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            asset_mint,
+            id,
+            payment_mint,
+            treasury,
+            price,
+            cutoff,
+            pending_amount: 0,
+        }
+    }
+
+    pub fn update(&mut self, treasury: Pubkey, price: u64, cutoff: i64) {
+        self.treasury = treasury;
+        self.price = price;
+        self.cutoff = cutoff;
+    }
+
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.pending_amount = self
+            .pending_amount
+            .checked_add(amount)
+            .ok_or(AssetControllerErrors::InvalidSubscriptionAmount)?;
+        Ok(())
+    }
+
+    pub fn release(&mut self, amount: u64) -> Result<()> {
+        self.pending_amount = self
+            .pending_amount
+            .checked_sub(amount)
+            .ok_or(AssetControllerErrors::InvalidSubscriptionAmount)?;
+        Ok(())
+    }
+}
+
+/// payment deposited by an investor, the shares are issued when the subscription is settled
+#[account()]
+#[derive(InitSpace)]
+pub struct SubscriptionRequestAccount {
+    pub version: u8,
+    pub asset_mint: Pubkey,
+    pub offer: Pubkey,
+    /// identity account of the investor
+    pub identity_account: Pubkey,
+    /// wallet receiving the shares, and the payment back if refunded
+    pub wallet: Pubkey,
+    /// receives the rent back when the request is closed
+    pub payer: Pubkey,
+    /// id chosen by the investor, unique per wallet and offer
+    pub id: u64,
+    pub payment_amount: u64,
+    /// price of the offer when the payment was deposited
+    pub price: u64,
+    /// shares issued on settlement
+    pub shares: u64,
+    pub state: SubscriptionState,
+    pub requested_at: i64,
+    /// time of settlement or refund
+    pub resolved_at: i64,
+}
+
+impl SubscriptionRequestAccount {
+    pub const VERSION: u8 = 1;
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        asset_mint: Pubkey,
+        offer: Pubkey,
+        identity_account: Pubkey,
+        wallet: Pubkey,
+        payer: Pubkey,
+        id: u64,
+        payment_amount: u64,
+        price: u64,
+        requested_at: i64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            asset_mint,
+            offer,
+            identity_account,
+            wallet,
+            payer,
+            id,
+            payment_amount,
+            price,
+            shares: 0,
+            state: SubscriptionState::Pending,
+            requested_at,
+            resolved_at: 0,
+        }
+    }
+
+    /// shares bought by the payment at the price of the request, rounded down
+    pub fn get_shares(&self, decimals: u8) -> Result<u64> {
+        let shares = (self.payment_amount as u128)
+            .checked_mul(10u128.pow(decimals as u32))
+            .and_then(|value| value.checked_div(self.price as u128))
+            .filter(|shares| *shares <= u64::MAX as u128)
+            .ok_or(AssetControllerErrors::InvalidSubscriptionAmount)?;
+        Ok(shares as u64)
+    }
+
+    pub fn resolve(&mut self, state: SubscriptionState, timestamp: i64) {
+        self.state = state;
+        self.resolved_at = timestamp;
+    }
+}
@@ -18,3 +18,10 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     }
     Ok(())
 }
+
+/// metadata of the scaled ui amount extension events, the multiplier and its effective timestamp in little endian
+pub fn get_multiplier_metadata(multiplier: f64, effective_timestamp: i64) -> Vec<u8> {
+    let mut metadata = multiplier.to_le_bytes().to_vec();
+    metadata.extend_from_slice(&effective_timestamp.to_le_bytes());
+    metadata
+}
@@ -24,4 +24,10 @@ pub enum IdentityRegistryErrors {
     InvalidLevel,
     #[msg("Token account is not empty")]
     TokenAccountNotEmpty,
+    #[msg("Beneficial owners count must be at least 1")]
+    InvalidBeneficialOwners,
+    #[msg("Accounts to thaw or freeze the token account of the wallet are missing")]
+    WalletActivationAccountsMissing,
+    #[msg("Account is not at the version migrated by this instruction")]
+    InvalidAccountVersion,
 }
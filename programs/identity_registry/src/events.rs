@@ -58,3 +58,20 @@ pub struct ChangeCountryEvent {
     pub country: u8,
     pub sender: Pubkey,
 }
+
+#[event]
+pub struct ChangeBeneficialOwnersEvent {
+    pub identity: Pubkey,
+    pub mint: Pubkey,
+    pub beneficial_owners: u32,
+    pub sender: Pubkey,
+}
+
+#[event]
+pub struct ChangeIdentityOwnerEvent {
+    pub identity: Pubkey,
+    pub mint: Pubkey,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+    pub sender: Pubkey,
+}
@@ -16,8 +16,7 @@ pub struct AddLevelToIdentityAccount<'info> {
     pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
     #[account(
         mut,
-        seeds = [identity_registry.key().as_ref(), identity_account.owner.as_ref()],
-        bump,
+        has_one = identity_registry,
         realloc = identity_account.to_account_info().data_len() + IdentityLevel::INIT_SPACE * levels.len(),
         realloc::zero = false,
         realloc::payer = payer,
@@ -37,8 +36,8 @@ pub struct AddLevelToIdentityAccount<'info> {
     pub asset_mint: UncheckedAccount<'info>,
 }
 
-pub fn handler(
-    ctx: Context<AddLevelToIdentityAccount>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddLevelToIdentityAccount<'info>>,
     levels: Vec<u8>,
     expiries: Vec<i64>,
     enforce_limits: bool,
@@ -60,8 +59,10 @@ pub fn handler(
         ctx.accounts.tracker_account.to_account_info(),
         ctx.accounts.policy_engine.to_account_info(),
         ctx.accounts.policy_engine_program.to_account_info(),
+        ctx.remaining_accounts,
         &new_levels,
         ctx.accounts.identity_account.country,
+        ctx.accounts.identity_account.beneficial_owners,
         enforce_limits,
         &[&signer_seeds[..]],
     )?;
@@ -1,4 +1,7 @@
-use crate::{state::*, AttachWalletToIdentityEvent, IdentityAccount, IdentityRegistryAccount, IdentityRegistryErrors};
+use crate::{
+    cpi_activate_wallet, is_frozen_by_default, state::*, AttachWalletToIdentityEvent, IdentityAccount,
+    IdentityRegistryAccount, IdentityRegistryErrors, WalletActivationAccounts, ASSET_CONTROLLER_ID,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
@@ -33,6 +36,65 @@ pub struct AttachWalletToIdentity<'info> {
     pub wallet_identity: Box<Account<'info, WalletIdentity>>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: hardcoded address check, provided for assets whose token accounts are frozen by default
+    #[account(address = ASSET_CONTROLLER_ID)]
+    pub asset_controller_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub asset_controller: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi, wallet whose associated token account is thawed
+    pub token_account_owner: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub associated_token_program: Option<UncheckedAccount<'info>>,
+    /// signs when the asset controller thaws or freezes the token account itself, as during a wallet recovery
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        seeds::program = ASSET_CONTROLLER_ID,
+    )]
+    pub asset_controller_signer: Option<Signer<'info>>,
+}
+
+impl AttachWalletToIdentity<'_> {
+    /// thaws the token account of the wallet through the asset controller when the activation accounts are provided
+    fn activate_wallet(&self, wallet: Pubkey, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let Some(activation) = WalletActivationAccounts::new(
+            self.asset_controller_signer.is_none()
+                && is_frozen_by_default(&self.asset_mint.to_account_info())?,
+            self.asset_controller_program.as_ref().map(|account| account.to_account_info()),
+            self.asset_controller.as_ref().map(|account| account.to_account_info()),
+            self.token_account.as_ref().map(|account| account.to_account_info()),
+            self.token_program.as_ref().map(|account| account.to_account_info()),
+        )?
+        else {
+            return Ok(());
+        };
+        let (Some(token_account_owner), Some(associated_token_program)) =
+            (&self.token_account_owner, &self.associated_token_program)
+        else {
+            return err!(IdentityRegistryErrors::WalletActivationAccountsMissing);
+        };
+        require_keys_eq!(
+            token_account_owner.key(),
+            wallet,
+            IdentityRegistryErrors::UnauthorizedSigner
+        );
+        cpi_activate_wallet(
+            self.payer.to_account_info(),
+            self.identity_registry.to_account_info(),
+            self.asset_mint.to_account_info(),
+            token_account_owner.to_account_info(),
+            activation,
+            associated_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            signer_seeds,
+        )
+    }
 }
 
 pub fn handler(ctx: Context<AttachWalletToIdentity>, wallet: Pubkey) -> Result<()> {
@@ -46,6 +108,10 @@ pub fn handler(ctx: Context<AttachWalletToIdentity>, wallet: Pubkey) -> Result<(
     ctx.accounts.wallet_identity.identity_account = ctx.accounts.identity_account.key();
     ctx.accounts.wallet_identity.wallet = wallet;
 
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let signer_seeds = [asset_mint.as_ref(), &[ctx.accounts.identity_registry.bump][..]];
+    ctx.accounts.activate_wallet(wallet, &[&signer_seeds[..]])?;
+
     emit_cpi!(AttachWalletToIdentityEvent {
         identity: ctx.accounts.identity_account.key(),
         mint: ctx.accounts.identity_registry.asset_mint,
@@ -0,0 +1,69 @@
+use crate::utils::POLICY_ENGINE_ID;
+use crate::{cpi_enforce_policy_on_levels_change, state::*, ChangeBeneficialOwnersEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ChangeBeneficialOwners<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = identity_registry.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(
+        mut,
+        has_one = identity_registry,
+    )]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+
+    #[account(address = POLICY_ENGINE_ID)]
+    /// CHECK: hardcoded address check
+    pub policy_engine_program: UncheckedAccount<'info>,
+    /// CHECK: checked in cpi
+    #[account(mut)]
+    pub policy_engine: UncheckedAccount<'info>,
+    /// CHECK: checked in cpi
+    pub tracker_account: UncheckedAccount<'info>,
+    /// CHECK: checked in cpi
+    pub asset_mint: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ChangeBeneficialOwners<'info>>,
+    beneficial_owners: u32,
+    enforce_limits: bool,
+) -> Result<()> {
+    let signer_seeds = [
+        &ctx.accounts.asset_mint.key().to_bytes()[..],
+        &[ctx.accounts.identity_registry.bump][..],
+    ];
+
+    ctx.accounts
+        .identity_account
+        .set_beneficial_owners(beneficial_owners)?;
+
+    cpi_enforce_policy_on_levels_change(
+        ctx.accounts.identity_account.to_account_info(),
+        ctx.accounts.identity_registry.to_account_info(),
+        ctx.accounts.asset_mint.to_account_info(),
+        ctx.accounts.tracker_account.to_account_info(),
+        ctx.accounts.policy_engine.to_account_info(),
+        ctx.accounts.policy_engine_program.to_account_info(),
+        ctx.remaining_accounts,
+        &ctx.accounts.identity_account.levels,
+        ctx.accounts.identity_account.country,
+        beneficial_owners,
+        enforce_limits,
+        &[&signer_seeds[..]],
+    )?;
+
+    emit_cpi!(ChangeBeneficialOwnersEvent {
+        identity: ctx.accounts.identity_account.key(),
+        mint: ctx.accounts.identity_registry.asset_mint,
+        beneficial_owners,
+        sender: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
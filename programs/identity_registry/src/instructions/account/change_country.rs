@@ -14,8 +14,7 @@ pub struct ChangeCountry<'info> {
     pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
     #[account(
         mut,
-        seeds = [identity_registry.key().as_ref(), identity_account.owner.as_ref()],
-        bump,
+        has_one = identity_registry,
     )]
     pub identity_account: Box<Account<'info, IdentityAccount>>,
 
@@ -31,7 +30,11 @@ pub struct ChangeCountry<'info> {
     pub asset_mint: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<ChangeCountry>, new_country: u8, enforce_limits: bool) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ChangeCountry<'info>>,
+    new_country: u8,
+    enforce_limits: bool,
+) -> Result<()> {
     let signer_seeds = [
         &ctx.accounts.asset_mint.key().to_bytes()[..],
         &[ctx.accounts.identity_registry.bump][..],
@@ -47,8 +50,10 @@ pub fn handler(ctx: Context<ChangeCountry>, new_country: u8, enforce_limits: boo
         ctx.accounts.tracker_account.to_account_info(),
         ctx.accounts.policy_engine.to_account_info(),
         ctx.accounts.policy_engine_program.to_account_info(),
+        ctx.remaining_accounts,
         &ctx.accounts.identity_account.levels,
         new_country,
+        ctx.accounts.identity_account.beneficial_owners,
         enforce_limits,
         &[&signer_seeds[..]],
     )?;
@@ -0,0 +1,44 @@
+use crate::{state::*, ChangeIdentityOwnerEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+#[event_cpi]
+pub struct ChangeIdentityOwner<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = identity_registry.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    /// the identity keeps the address derived from its first owner
+    #[account(
+        mut,
+        has_one = identity_registry,
+    )]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    /// the new owner must be a wallet of the identity
+    #[account(
+        seeds = [new_owner.as_ref(), asset_mint.key().as_ref()],
+        bump,
+        has_one = identity_account,
+    )]
+    pub wallet_identity: Box<Account<'info, WalletIdentity>>,
+    /// CHECK: checked by the identity registry
+    pub asset_mint: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ChangeIdentityOwner>, new_owner: Pubkey) -> Result<()> {
+    let previous_owner = ctx.accounts.identity_account.owner;
+    ctx.accounts.identity_account.set_owner(new_owner)?;
+
+    emit_cpi!(ChangeIdentityOwnerEvent {
+        identity: ctx.accounts.identity_account.key(),
+        mint: ctx.accounts.identity_registry.asset_mint,
+        previous_owner,
+        owner: new_owner,
+        sender: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
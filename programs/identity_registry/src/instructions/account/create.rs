@@ -1,4 +1,7 @@
-use crate::{cpi_create_tracker_account, state::*, CreatedIdentityEvent, POLICY_ENGINE_ID};
+use crate::{
+    cpi_activate_wallet, cpi_create_tracker_account, is_frozen_by_default, state::*, CreatedIdentityEvent,
+    IdentityRegistryErrors, WalletActivationAccounts, ASSET_CONTROLLER_ID, POLICY_ENGINE_ID,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -11,7 +14,7 @@ pub struct CreateIdentityAccount<'info> {
         constraint = identity_registry.authority == signer.key()
     )]
     pub signer: Signer<'info>,
-    #[account()]
+    #[account(mut)]
     pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
     #[account(
         init,
@@ -39,9 +42,61 @@ pub struct CreateIdentityAccount<'info> {
     #[account(mut)]
     pub tracker_account: UncheckedAccount<'info>,
     /// CHECK: checked in cpi
+    #[account(address = identity_registry.asset_mint)]
     pub asset_mint: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: hardcoded address check, provided for assets whose token accounts are frozen by default
+    #[account(address = ASSET_CONTROLLER_ID)]
+    pub asset_controller_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub asset_controller: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi, wallet whose associated token account is thawed
+    pub token_account_owner: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub associated_token_program: Option<UncheckedAccount<'info>>,
+}
+
+impl CreateIdentityAccount<'_> {
+    /// thaws the token account of the wallet through the asset controller when the activation accounts are provided
+    fn activate_wallet(&self, wallet: Pubkey, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let Some(activation) = WalletActivationAccounts::new(
+            is_frozen_by_default(&self.asset_mint)?,
+            self.asset_controller_program.as_ref().map(|account| account.to_account_info()),
+            self.asset_controller.as_ref().map(|account| account.to_account_info()),
+            self.token_account.as_ref().map(|account| account.to_account_info()),
+            self.token_program.as_ref().map(|account| account.to_account_info()),
+        )?
+        else {
+            return Ok(());
+        };
+        let (Some(token_account_owner), Some(associated_token_program)) =
+            (&self.token_account_owner, &self.associated_token_program)
+        else {
+            return err!(IdentityRegistryErrors::WalletActivationAccountsMissing);
+        };
+        require_keys_eq!(
+            token_account_owner.key(),
+            wallet,
+            IdentityRegistryErrors::UnauthorizedSigner
+        );
+        cpi_activate_wallet(
+            self.payer.to_account_info(),
+            self.identity_registry.to_account_info(),
+            self.asset_mint.to_account_info(),
+            token_account_owner.to_account_info(),
+            activation,
+            associated_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            signer_seeds,
+        )
+    }
 }
 
 pub fn handler(
@@ -60,6 +115,7 @@ pub fn handler(
     ));
     ctx.accounts.wallet_identity.identity_account = ctx.accounts.identity_account.key();
     ctx.accounts.wallet_identity.wallet = owner;
+    ctx.accounts.identity_registry.identity_count += 1;
     
     let signer_seeds = [
         &ctx.accounts.asset_mint.key().to_bytes()[..],
@@ -77,6 +133,7 @@ pub fn handler(
         ctx.accounts.identity_account.owner,
         &[&signer_seeds[..]],
     )?;
+    ctx.accounts.activate_wallet(owner, &[&signer_seeds[..]])?;
 
     emit_cpi!(CreatedIdentityEvent {
         identity: ctx.accounts.identity_account.key(),
@@ -1,4 +1,7 @@
-use crate::{state::*, DetachWalletFromIdentityEvent, IdentityAccount, IdentityRegistryErrors};
+use crate::{
+    cpi_deactivate_wallet, is_frozen_by_default, state::*, DetachWalletFromIdentityEvent, IdentityAccount,
+    IdentityRegistryErrors, WalletActivationAccounts, ASSET_CONTROLLER_ID,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{token_2022::Token2022, token_interface::{Mint, TokenAccount}};
 
@@ -37,11 +40,45 @@ pub struct DetachWalletFromIdentity<'info> {
     pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     
     pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: hardcoded address check, provided for assets whose token accounts are frozen by default
+    #[account(address = ASSET_CONTROLLER_ID)]
+    pub asset_controller_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub asset_controller: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// signs when the asset controller thaws or freezes the token account itself, as during a wallet recovery
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        seeds::program = ASSET_CONTROLLER_ID,
+    )]
+    pub asset_controller_signer: Option<Signer<'info>>,
 }
 
 pub fn handler(ctx: Context<DetachWalletFromIdentity>) -> Result<()> {
     ctx.accounts.identity_account.remove_wallet()?;
 
+    // freeze the token account of the wallet through the asset controller when the activation accounts are provided
+    if let Some(activation) = WalletActivationAccounts::new(
+        ctx.accounts.asset_controller_signer.is_none()
+            && is_frozen_by_default(&ctx.accounts.asset_mint.to_account_info())?,
+        ctx.accounts.asset_controller_program.as_ref().map(|account| account.to_account_info()),
+        ctx.accounts.asset_controller.as_ref().map(|account| account.to_account_info()),
+        Some(ctx.accounts.token_account.to_account_info()),
+        ctx.accounts.token_program.as_ref().map(|account| account.to_account_info()),
+    )? {
+        let asset_mint = ctx.accounts.asset_mint.key();
+        let signer_seeds = [asset_mint.as_ref(), &[ctx.accounts.identity_registry.bump][..]];
+        cpi_deactivate_wallet(
+            ctx.accounts.identity_registry.to_account_info(),
+            ctx.accounts.asset_mint.to_account_info(),
+            activation,
+            &[&signer_seeds[..]],
+        )?;
+    }
+
     emit_cpi!(DetachWalletFromIdentityEvent {
         identity: ctx.accounts.identity_account.key(),
         mint: ctx.accounts.identity_registry.asset_mint,
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use rwa_utils::migrate_account;
+
+use crate::{state::*, IdentityRegistryErrors};
+
+#[derive(Accounts)]
+pub struct MigrateIdentityAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [identity_registry.asset_mint.key().as_ref()],
+        bump,
+    )]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    /// CHECK: version 1 identity, checked by the migration against the registry
+    #[account(mut)]
+    pub identity_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// rewrites a version 1 identity to the current layout with a single beneficial owner and counts it in the
+/// registry, callable by anyone as every identity is migrated exactly once
+pub fn handler(ctx: Context<MigrateIdentityAccount>) -> Result<()> {
+    let identity_registry = ctx.accounts.identity_registry.key();
+    migrate_account(
+        &ctx.accounts.identity_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &crate::id(),
+        IdentityRegistryErrors::InvalidAccountVersion,
+        |identity: IdentityAccountV1| {
+            require_keys_eq!(identity.identity_registry, identity_registry, ErrorCode::ConstraintHasOne);
+            Ok(IdentityAccount::from(identity))
+        },
+    )?;
+    ctx.accounts.identity_registry.identity_count += 1;
+    Ok(())
+}
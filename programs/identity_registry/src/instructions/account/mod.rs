@@ -1,15 +1,21 @@
 pub mod add;
 pub mod attach_wallet_to_identity;
+pub mod change_beneficial_owners;
 pub mod change_country;
+pub mod change_owner;
 pub mod create;
 pub mod detach_wallet_from_identity;
+pub mod migrate;
 pub mod remove;
 pub mod revoke;
 
 pub use add::*;
 pub use attach_wallet_to_identity::*;
+pub use change_beneficial_owners::*;
 pub use change_country::*;
+pub use change_owner::*;
 pub use create::*;
 pub use detach_wallet_from_identity::*;
+pub use migrate::*;
 pub use remove::*;
 pub use revoke::*;
@@ -15,8 +15,7 @@ pub struct RemoveLevelFromIdentityAccount<'info> {
     pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
     #[account(
         mut,
-        seeds = [identity_registry.key().as_ref(), identity_account.owner.as_ref()],
-        bump,
+        has_one = identity_registry,
     )]
     pub identity_account: Box<Account<'info, IdentityAccount>>,
     pub system_program: Program<'info, System>,
@@ -33,8 +32,8 @@ pub struct RemoveLevelFromIdentityAccount<'info> {
     pub asset_mint: UncheckedAccount<'info>,
 }
 
-pub fn handler(
-    ctx: Context<RemoveLevelFromIdentityAccount>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveLevelFromIdentityAccount<'info>>,
     levels: Vec<u8>,
     enforce_limits: bool,
 ) -> Result<()> {
@@ -55,8 +54,10 @@ pub fn handler(
         ctx.accounts.tracker_account.to_account_info(),
         ctx.accounts.policy_engine.to_account_info(),
         ctx.accounts.policy_engine_program.to_account_info(),
+        ctx.remaining_accounts,
         &previous_levels,
         ctx.accounts.identity_account.country,
+        ctx.accounts.identity_account.beneficial_owners,
         enforce_limits,
         &[&signer_seeds[..]],
     )?;
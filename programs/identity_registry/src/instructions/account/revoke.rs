@@ -1,4 +1,7 @@
-use crate::{cpi_remove_tracker_account, state::*, RemovedIdentityEvent, POLICY_ENGINE_ID};
+use crate::{
+    cpi_deactivate_wallet, cpi_remove_tracker_account, is_frozen_by_default, state::*, RemovedIdentityEvent,
+    WalletActivationAccounts, ASSET_CONTROLLER_ID, POLICY_ENGINE_ID,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -12,6 +15,7 @@ pub struct RevokeIdentityAccount<'info> {
     )]
     pub signer: Signer<'info>,
     #[account(
+        mut,
         seeds = [identity_registry.asset_mint.key().as_ref()],
         bump,
     )]
@@ -19,8 +23,7 @@ pub struct RevokeIdentityAccount<'info> {
     #[account(
         mut,
         close = payer,
-        seeds = [identity_registry.key().as_ref(), identity_account.owner.as_ref()],
-        bump,
+        has_one = identity_registry,
         constraint = identity_account.owner == owner,
         constraint = identity_account.num_wallets == 1
     )]
@@ -42,11 +45,24 @@ pub struct RevokeIdentityAccount<'info> {
     #[account(mut)]
     pub tracker_account: UncheckedAccount<'info>,
     /// CHECK: checked in cpi
+    #[account(address = identity_registry.asset_mint)]
     pub asset_mint: UncheckedAccount<'info>,
+
+    /// CHECK: hardcoded address check, provided for assets whose token accounts are frozen by default
+    #[account(address = ASSET_CONTROLLER_ID)]
+    pub asset_controller_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub asset_controller: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi
+    pub token_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: checked in cpi, associated token account of the owner, skipped if it was never created
+    #[account(mut)]
+    pub token_account: Option<UncheckedAccount<'info>>,
 }
 
 pub fn handler(ctx: Context<RevokeIdentityAccount>, _owner: Pubkey) -> Result<()> {
-    
+    ctx.accounts.identity_registry.identity_count -= 1;
+
     let signer_seeds = [
         &ctx.accounts.asset_mint.key().to_bytes()[..],
         &[ctx.accounts.identity_registry.bump][..],
@@ -62,6 +78,24 @@ pub fn handler(ctx: Context<RevokeIdentityAccount>, _owner: Pubkey) -> Result<()
         &[&signer_seeds[..]],
     )?;
 
+    // freeze the token account of the owner through the asset controller when the activation accounts are provided
+    if let Some(activation) = WalletActivationAccounts::new(
+        is_frozen_by_default(&ctx.accounts.asset_mint)?,
+        ctx.accounts.asset_controller_program.as_ref().map(|account| account.to_account_info()),
+        ctx.accounts.asset_controller.as_ref().map(|account| account.to_account_info()),
+        ctx.accounts.token_account.as_ref().map(|account| account.to_account_info()),
+        ctx.accounts.token_program.as_ref().map(|account| account.to_account_info()),
+    )? {
+        if !activation.token_account.data_is_empty() {
+            cpi_deactivate_wallet(
+                ctx.accounts.identity_registry.to_account_info(),
+                ctx.accounts.asset_mint.to_account_info(),
+                activation,
+                &[&signer_seeds[..]],
+            )?;
+        }
+    }
+
     emit_cpi!(RemovedIdentityEvent {
         identity: ctx.accounts.identity_account.key(),
         mint: ctx.accounts.identity_registry.asset_mint,
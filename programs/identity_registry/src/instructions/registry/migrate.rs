@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use rwa_utils::migrate_account;
+
+use crate::{state::*, IdentityRegistryErrors};
+
+#[derive(Accounts)]
+pub struct MigrateIdentityRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub signer: Signer<'info>,
+    /// CHECK: version 1 registry, checked by the migration against the signer
+    #[account(mut)]
+    pub identity_registry: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// rewrites a version 1 registry to the current layout, must run before its identities are migrated
+pub fn handler(ctx: Context<MigrateIdentityRegistry>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    migrate_account(
+        &ctx.accounts.identity_registry.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &crate::id(),
+        IdentityRegistryErrors::InvalidAccountVersion,
+        |registry: IdentityRegistryAccountV1| {
+            require_keys_eq!(registry.authority, signer, IdentityRegistryErrors::UnauthorizedSigner);
+            Ok(IdentityRegistryAccount::from(registry))
+        },
+    )?;
+    Ok(())
+}
@@ -1,3 +1,5 @@
 pub mod create;
+pub mod migrate;
 
 pub use create::*;
+pub use migrate::*;
@@ -43,8 +43,8 @@ pub mod identity_registry {
     }
 
     /// add level to identity account
-    pub fn add_level_to_identity_account(
-        ctx: Context<AddLevelToIdentityAccount>,
+    pub fn add_level_to_identity_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLevelToIdentityAccount<'info>>,
         levels: Vec<u8>,
         expiries: Vec<i64>,
         enforce_limits: bool,
@@ -53,8 +53,8 @@ pub mod identity_registry {
     }
 
     /// remove level from identity account
-    pub fn remove_level_from_identity_account(
-        ctx: Context<RemoveLevelFromIdentityAccount>,
+    pub fn remove_level_from_identity_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveLevelFromIdentityAccount<'info>>,
         levels: Vec<u8>,
         enforce_limits: bool,
     ) -> Result<()> {
@@ -82,11 +82,37 @@ pub mod identity_registry {
         instructions::account::detach_wallet_from_identity::handler(ctx)
     }
 
-    pub fn change_country(
-        ctx: Context<ChangeCountry>,
+    pub fn change_country<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeCountry<'info>>,
         new_country: u8,
         enforce_limits: bool,
     ) -> Result<()> {
         instructions::account::change_country::handler(ctx, new_country, enforce_limits)
     }
+    /// set the number of beneficial owners used by look-through counters
+    pub fn change_beneficial_owners<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeBeneficialOwners<'info>>,
+        beneficial_owners: u32,
+        enforce_limits: bool,
+    ) -> Result<()> {
+        instructions::account::change_beneficial_owners::handler(ctx, beneficial_owners, enforce_limits)
+    }
+    /// hand the identity over to another of its wallets, used when the owner wallet is recovered
+    pub fn change_identity_owner(
+        ctx: Context<ChangeIdentityOwner>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::account::change_owner::handler(ctx, new_owner)
+    }
+
+    /// migrations of the accounts created by version 1 of the program
+    /// migrate identity registry, before any of its identities
+    pub fn migrate_identity_registry(ctx: Context<MigrateIdentityRegistry>) -> Result<()> {
+        instructions::registry::migrate::handler(ctx)
+    }
+
+    /// migrate identity account
+    pub fn migrate_identity_account(ctx: Context<MigrateIdentityAccount>) -> Result<()> {
+        instructions::account::migrate::handler(ctx)
+    }
 }
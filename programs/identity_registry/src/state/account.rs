@@ -15,6 +15,8 @@ pub struct IdentityAccount {
     pub num_wallets: u16,
     /// country code of the user
     pub country: u8,
+    /// number of beneficial owners behind this identity, used by look-through counters
+    pub beneficial_owners: u32,
     // identity levels corresponding to the user
     #[max_len(1)] // initial length is 1
     pub levels: Vec<IdentityLevel>,
@@ -27,7 +29,8 @@ pub struct IdentityLevel {
 }
 
 impl IdentityAccount {
-    pub const VERSION: u8 = 1;
+    /// 2 inserted the beneficial owners before the levels, version 1 identities are migrated by `migrate_identity_account`
+    pub const VERSION: u8 = 2;
     pub fn new(
         owner: Pubkey,
         identity_registry: Pubkey,
@@ -42,6 +45,7 @@ impl IdentityAccount {
             levels: vec![IdentityLevel { level, expiry }],
             num_wallets: 1,
             country,
+            beneficial_owners: 1,
         }
     }
 
@@ -82,8 +86,19 @@ impl IdentityAccount {
         Ok(())
     }
 
+    pub fn set_owner(&mut self, owner: Pubkey) -> Result<()> {
+        self.owner = owner;
+        Ok(())
+    }
+
     pub fn set_country(&mut self, country: u8) -> Result<()> {
         self.country = country;
         Ok(())
     }
+
+    pub fn set_beneficial_owners(&mut self, beneficial_owners: u32) -> Result<()> {
+        require!(beneficial_owners > 0, IdentityRegistryErrors::InvalidBeneficialOwners);
+        self.beneficial_owners = beneficial_owners;
+        Ok(())
+    }
 }
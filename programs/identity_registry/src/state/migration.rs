@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use super::{IdentityAccount, IdentityLevel, IdentityRegistryAccount};
+
+/// layout of the identity registry before the identity count
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct IdentityRegistryAccountV1 {
+    pub version: u8,
+    pub bump: u8,
+    pub asset_mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl From<IdentityRegistryAccountV1> for IdentityRegistryAccount {
+    /// the count is rebuilt as the identities of the registry are migrated
+    fn from(registry: IdentityRegistryAccountV1) -> Self {
+        Self {
+            version: Self::VERSION,
+            bump: registry.bump,
+            asset_mint: registry.asset_mint,
+            authority: registry.authority,
+            identity_count: 0,
+        }
+    }
+}
+
+/// layout of the identity account before the beneficial owners
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct IdentityAccountV1 {
+    pub version: u8,
+    pub identity_registry: Pubkey,
+    pub owner: Pubkey,
+    pub num_wallets: u16,
+    pub country: u8,
+    pub levels: Vec<IdentityLevel>,
+}
+
+impl From<IdentityAccountV1> for IdentityAccount {
+    fn from(identity: IdentityAccountV1) -> Self {
+        Self {
+            version: Self::VERSION,
+            identity_registry: identity.identity_registry,
+            owner: identity.owner,
+            num_wallets: identity.num_wallets,
+            country: identity.country,
+            beneficial_owners: 1,
+            levels: identity.levels,
+        }
+    }
+}
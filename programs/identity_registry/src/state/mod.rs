@@ -1,8 +1,10 @@
 pub mod account;
+pub mod migration;
 pub mod registry;
 pub mod wallet_identity;
 
 pub use account::*;
+pub use migration::*;
 pub use registry::*;
 pub use wallet_identity::*;
 
@@ -11,6 +11,8 @@ pub struct IdentityRegistryAccount {
     pub asset_mint: Pubkey,
     /// authority to manage the registry
     pub authority: Pubkey,
+    /// number of identity accounts, checked by the counter reconciliations of the policy engine
+    pub identity_count: u64,
 }
 
 /// level if attached to user account, will skip any policy checks
@@ -31,7 +33,8 @@ pub const NO_TRACKER_LEVEL: IdentityLevel = IdentityLevel {
 };
 
 impl IdentityRegistryAccount {
-    pub const VERSION: u8 = 1;
+    /// 2 appended the identity count, version 1 registries are migrated by `migrate_identity_registry`
+    pub const VERSION: u8 = 2;
     pub fn new(
         &mut self,
         asset_mint: Pubkey,
@@ -42,5 +45,6 @@ impl IdentityRegistryAccount {
         self.authority = authority;
         self.version = Self::VERSION;
         self.bump = bump;
+        self.identity_count = 0;
     }
 }
@@ -2,10 +2,15 @@ use anchor_lang::{
     prelude::*,
     solana_program::{instruction::Instruction, program::invoke_signed, pubkey::Pubkey},
 };
+use anchor_spl::token_interface::spl_token_2022::{
+    extension::{default_account_state::DefaultAccountState, BaseStateWithExtensions, StateWithExtensions},
+    state::{AccountState, Mint},
+};
 
-use crate::IdentityLevel;
+use crate::{IdentityLevel, IdentityRegistryErrors};
 
 pub const POLICY_ENGINE_ID: Pubkey = pubkey!("FsE8mCJyvgMzqJbfHbJQm3iuf3cRZC6n2vZi1Q8rQCy2");
+pub const ASSET_CONTROLLER_ID: Pubkey = pubkey!("7tXjmbkZVY3Gmg9kDBebcNXT1yC5pyoxxXVLwdbv9tvP");
 
 pub fn get_identity_registry_pda(asset_mint: Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[asset_mint.as_ref()], &crate::ID).0
@@ -18,35 +23,45 @@ pub fn cpi_enforce_policy_on_levels_change<'info>(
     tracker_account: AccountInfo<'info>,
     policy_engine: AccountInfo<'info>,
     policy_program: AccountInfo<'info>,
+    policy_pages: &[AccountInfo<'info>],
     new_levels: &[IdentityLevel],
     new_country: u8,
+    new_beneficial_owners: u32,
     enforce_limits: bool,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     let mut data: Vec<u8> = vec![236, 18, 123, 137, 253, 244, 32, 248];
     data.extend(new_levels.try_to_vec()?);
     data.extend(new_country.try_to_vec()?);
+    data.extend(new_beneficial_owners.try_to_vec()?);
     data.extend((!enforce_limits).try_to_vec()?);
+    let mut accounts = vec![
+        // Mutable as authority is used as rent receiver on account closure.
+        AccountMeta::new_readonly(identity_account.key(), false),
+        AccountMeta::new_readonly(identity_registry.key(), true),
+        AccountMeta::new_readonly(asset_mint.key(), false),
+        AccountMeta::new_readonly(tracker_account.key(), false),
+        AccountMeta::new(policy_engine.key(), false),
+    ];
+    let mut account_infos = vec![
+        identity_account,
+        identity_registry,
+        asset_mint,
+        tracker_account,
+        policy_engine,
+    ];
+    // levels change policy pages, forwarded as remaining accounts
+    for page in policy_pages.iter() {
+        accounts.push(AccountMeta::new_readonly(page.key(), false));
+        account_infos.push(page.clone());
+    }
     invoke_signed(
         &Instruction {
             program_id: policy_program.key(),
-            accounts: vec![
-                // Mutable as authority is used as rent receiver on account closure.
-                AccountMeta::new_readonly(identity_account.key(), false),
-                AccountMeta::new_readonly(identity_registry.key(), true),
-                AccountMeta::new_readonly(asset_mint.key(), false),
-                AccountMeta::new_readonly(tracker_account.key(), false),
-                AccountMeta::new(policy_engine.key(), false),
-            ],
+            accounts,
             data,
         },
-        &[
-            identity_account,
-            identity_registry,
-            asset_mint,
-            tracker_account,
-            policy_engine,
-        ],
+        &account_infos,
         signer_seeds,
     )?;
 
@@ -129,4 +144,125 @@ pub fn cpi_create_tracker_account<'info>(
     )?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// whether the token accounts of the asset are created frozen, from the default account state extension of the mint
+pub fn is_frozen_by_default(asset_mint: &AccountInfo) -> Result<bool> {
+    let data = asset_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint
+        .get_extension::<DefaultAccountState>()
+        .is_ok_and(|extension| extension.state == AccountState::Frozen as u8))
+}
+
+/// accounts of the asset controller used to thaw or freeze the token account of a wallet,
+/// provided for assets whose token accounts are frozen by default
+pub struct WalletActivationAccounts<'info> {
+    pub asset_controller_program: AccountInfo<'info>,
+    pub asset_controller: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> WalletActivationAccounts<'info> {
+    /// none when the asset controller program is not provided, the other accounts are then required.
+    /// the accounts must be provided when `required`, for assets whose token accounts are frozen by default
+    pub fn new(
+        required: bool,
+        asset_controller_program: Option<AccountInfo<'info>>,
+        asset_controller: Option<AccountInfo<'info>>,
+        token_account: Option<AccountInfo<'info>>,
+        token_program: Option<AccountInfo<'info>>,
+    ) -> Result<Option<Self>> {
+        let Some(asset_controller_program) = asset_controller_program else {
+            require!(!required, IdentityRegistryErrors::WalletActivationAccountsMissing);
+            return Ok(None);
+        };
+        match (asset_controller, token_account, token_program) {
+            (Some(asset_controller), Some(token_account), Some(token_program)) => Ok(Some(Self {
+                asset_controller_program,
+                asset_controller,
+                token_account,
+                token_program,
+            })),
+            _ => err!(IdentityRegistryErrors::WalletActivationAccountsMissing),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn cpi_activate_wallet<'info>(
+    payer: AccountInfo<'info>,
+    identity_registry: AccountInfo<'info>,
+    asset_mint: AccountInfo<'info>,
+    wallet: AccountInfo<'info>,
+    activation: WalletActivationAccounts<'info>,
+    associated_token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let data = vec![30, 25, 98, 66, 55, 173, 223, 213];
+    invoke_signed(
+        &Instruction {
+            program_id: activation.asset_controller_program.key(),
+            accounts: vec![
+                AccountMeta::new(payer.key(), true),
+                AccountMeta::new_readonly(identity_registry.key(), true),
+                AccountMeta::new_readonly(asset_mint.key(), false),
+                AccountMeta::new_readonly(activation.asset_controller.key(), false),
+                AccountMeta::new_readonly(wallet.key(), false),
+                AccountMeta::new(activation.token_account.key(), false),
+                AccountMeta::new_readonly(activation.token_program.key(), false),
+                AccountMeta::new_readonly(associated_token_program.key(), false),
+                AccountMeta::new_readonly(system_program.key(), false),
+            ],
+            data,
+        },
+        &[
+            payer,
+            identity_registry,
+            asset_mint,
+            activation.asset_controller,
+            wallet,
+            activation.token_account,
+            activation.token_program,
+            associated_token_program,
+            system_program,
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+pub fn cpi_deactivate_wallet<'info>(
+    identity_registry: AccountInfo<'info>,
+    asset_mint: AccountInfo<'info>,
+    activation: WalletActivationAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let data = vec![32, 67, 58, 106, 174, 138, 49, 224];
+    invoke_signed(
+        &Instruction {
+            program_id: activation.asset_controller_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(identity_registry.key(), true),
+                AccountMeta::new_readonly(asset_mint.key(), false),
+                AccountMeta::new_readonly(activation.asset_controller.key(), false),
+                AccountMeta::new(activation.token_account.key(), false),
+                AccountMeta::new_readonly(activation.token_program.key(), false),
+            ],
+            data,
+        },
+        &[
+            identity_registry,
+            asset_mint,
+            activation.asset_controller,
+            activation.token_account,
+            activation.token_program,
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -95,4 +95,70 @@ pub enum PolicyEngineErrors {
     LockIndexNotFound,
     #[msg("Counter Limit index not found")]
     CounterLimitIndexNotFound,
+    #[msg("Direct changes are disabled, changes must be proposed")]
+    DirectChangesDisabled,
+    #[msg("Pending change is not executable yet")]
+    ChangeNotExecutable,
+    #[msg("Timelock delay cannot be negative")]
+    InvalidTimelockDelay,
+    #[msg("Counter limit is already violated by the current counter values")]
+    CounterLimitViolated,
+    #[msg("Identity accounts must be reconciled in ascending order")]
+    IdentityAccountsNotSorted,
+    #[msg("Counters were added or removed since the reconciliation started")]
+    ReconciliationOutdated,
+    #[msg("Transfer policy pages must use one of the reserved extra account meta slots")]
+    InvalidPolicyPageIndex,
+    #[msg("Invalid policy page account")]
+    InvalidPolicyPage,
+    #[msg("A policy page referenced by the policy engine was not provided")]
+    PolicyPageMissing,
+    #[msg("Policy page still has policies attached")]
+    PolicyPageNotEmpty,
+    #[msg("Policy cannot be evaluated on one of the selected events")]
+    InvalidPolicyApplicability,
+    #[msg("Affiliate outbound volume over the rolling 3 month window exceeds the allowance")]
+    AffiliateVolumeLimitExceeded,
+    #[msg("Invalid tranche configuration")]
+    InvalidTrancheConfig,
+    #[msg("Tranche is not open for issuance")]
+    TrancheNotOpen,
+    #[msg("Tranche cap exceeded")]
+    TrancheCapExceeded,
+    #[msg("Subscribed amount is outside the tranche per-investor limits")]
+    TrancheSubscriptionOutOfBounds,
+    #[msg("Identity is not eligible for the tranche")]
+    TrancheIdentityNotEligible,
+    #[msg("Tranche subscription account is required when issuing against a tranche")]
+    TrancheSubscriptionMissing,
+    #[msg("Snapshot balance is not available for this tracker")]
+    SnapshotUnavailable,
+    #[msg("Invalid proposal configuration")]
+    InvalidProposalConfig,
+    #[msg("Proposal is not open for voting")]
+    ProposalNotOpen,
+    #[msg("Invalid vote option")]
+    InvalidVoteOption,
+    #[msg("Voting power of the identity is not delegated to the voter")]
+    VoteNotDelegated,
+    #[msg("Identity is not eligible to vote on the proposal")]
+    IdentityNotEligibleToVote,
+    #[msg("Identity has no voting power at the record date")]
+    NoVotingPower,
+    #[msg("Proposal can only be finalized once after its end")]
+    ProposalNotFinalizable,
+    #[msg("Proposal must be finalized first")]
+    ProposalNotFinalized,
+    #[msg("Voting power cannot be delegated to the same identity")]
+    InvalidDelegation,
+    #[msg("Snapshot is still in use by a proposal or a distribution")]
+    SnapshotInUse,
+    #[msg("Country group still counts holders in a country counter")]
+    CountryGroupNotEmpty,
+    #[msg("Balances or identities changed since the reconciliation started")]
+    ReconciliationStale,
+    #[msg("Reconciliation did not process every identity account of the registry")]
+    ReconciliationIncomplete,
+    #[msg("Account is not at the version migrated by this instruction")]
+    InvalidAccountVersion,
 }
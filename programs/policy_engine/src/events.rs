@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{CounterLimit, IdentityFilter, IssuancePolicies, PolicyType};
+use crate::{
+    CounterLimit, EnforcementMode, IdentityFilter, IssuancePolicies, PolicyApplicability,
+    PolicyEngineChange, PolicyPageScope, PolicyType, ProposalConfig, Timelock, TrancheConfig,
+};
 
 #[event]
 pub struct TransferEvent {
@@ -41,6 +44,13 @@ pub struct ChangedCountersEvent {
     pub added_counters: Vec<u8>
 }
 
+#[event]
+pub struct ChangedCountryCountersEvent {
+    pub mint: Pubkey,
+    pub removed_country_counters: Vec<u8>,
+    pub added_country_counters: Vec<u8>
+}
+
 #[event]
 pub struct ChangedIssuancePoliciesEvent {
     pub mint: Pubkey,
@@ -68,7 +78,9 @@ pub struct AttachPolicyEvent {
     pub mint: Pubkey,
     pub policy_type: PolicyType,
     pub identity_filter: IdentityFilter,
-    pub custom_error: u8
+    pub custom_error: u8,
+    pub enforcement_mode: EnforcementMode,
+    pub applicability: PolicyApplicability
 }
 
 #[event]
@@ -77,4 +89,233 @@ pub struct DetachPolicyEvent {
     pub policy_type: PolicyType,
     pub identity_filter: IdentityFilter,
     pub custom_error: u8
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ChangedPolicyEnforcementModeEvent {
+    pub mint: Pubkey,
+    pub hash: String,
+    pub enforcement_mode: EnforcementMode,
+    pub previous_enforcement_mode: EnforcementMode
+}
+
+#[event]
+pub struct ChangedPolicyApplicabilityEvent {
+    pub mint: Pubkey,
+    pub hash: String,
+    pub applicability: PolicyApplicability,
+    pub previous_applicability: PolicyApplicability
+}
+
+#[event]
+pub struct ChangedCounterLimitEnforcementModeEvent {
+    pub mint: Pubkey,
+    pub index: u8,
+    pub enforcement_mode: EnforcementMode,
+    pub previous_enforcement_mode: EnforcementMode
+}
+
+/// emitted instead of failing when a monitored policy is violated
+#[event]
+pub struct PolicyViolationEvent {
+    pub mint: Pubkey,
+    pub hash: String,
+    pub policy_type: PolicyType,
+    pub error_code: u32
+}
+
+/// emitted instead of failing when a monitored counter limit is violated
+#[event]
+pub struct CounterLimitViolationEvent {
+    pub mint: Pubkey,
+    pub counter_limit: CounterLimit,
+    pub error_code: u32
+}
+
+/// emitted when a counter limit's warning threshold is crossed, value is a percentage for percentage limits
+#[event]
+pub struct CounterLimitWarningEvent {
+    pub mint: Pubkey,
+    pub counter_limit: CounterLimit,
+    pub value: u64,
+    pub above_threshold: bool
+}
+
+#[event]
+pub struct ChangedTimelockEvent {
+    pub mint: Pubkey,
+    pub timelock: Timelock,
+    pub previous_timelock: Timelock
+}
+
+#[event]
+pub struct ProposedChangeEvent {
+    pub mint: Pubkey,
+    pub id: u64,
+    pub change: PolicyEngineChange,
+    pub executable_at: i64
+}
+
+#[event]
+pub struct CancelledChangeEvent {
+    pub mint: Pubkey,
+    pub id: u64,
+    pub change: PolicyEngineChange
+}
+
+#[event]
+pub struct ExecutedChangeEvent {
+    pub mint: Pubkey,
+    pub id: u64,
+    pub change: PolicyEngineChange
+}
+
+#[event]
+pub struct StartedReconciliationEvent {
+    pub mint: Pubkey,
+    pub reconciliation: Pubkey
+}
+
+/// emitted on commit for every counter, or country group, whose value differs from the reconciled one
+#[event]
+pub struct CounterMismatchEvent {
+    pub mint: Pubkey,
+    pub counter_id: u8,
+    pub group: Option<u8>,
+    pub value: u64,
+    pub reconciled_value: u64
+}
+
+#[event]
+pub struct CommittedReconciliationEvent {
+    pub mint: Pubkey,
+    pub processed_identities: u64,
+    pub mismatches: u32
+}
+
+#[event]
+pub struct CancelledReconciliationEvent {
+    pub mint: Pubkey,
+    pub processed_identities: u64
+}
+
+#[event]
+pub struct CreatedPolicyPageEvent {
+    pub mint: Pubkey,
+    pub page: Pubkey,
+    pub index: u8,
+    pub scope: PolicyPageScope
+}
+
+#[event]
+pub struct ClosedPolicyPageEvent {
+    pub mint: Pubkey,
+    pub index: u8
+}
+
+#[event]
+pub struct AttachPolicyToPageEvent {
+    pub mint: Pubkey,
+    pub page_index: u8,
+    pub policy_type: PolicyType,
+    pub identity_filter: IdentityFilter,
+    pub custom_error: u8,
+    pub enforcement_mode: EnforcementMode,
+    pub applicability: PolicyApplicability
+}
+
+#[event]
+pub struct DetachPolicyFromPageEvent {
+    pub mint: Pubkey,
+    pub page_index: u8,
+    pub policy_type: PolicyType,
+    pub identity_filter: IdentityFilter,
+    pub custom_error: u8
+}
+
+#[event]
+pub struct UpdatedExtraMetasEvent {
+    pub mint: Pubkey
+}
+
+#[event]
+pub struct CreatedTrancheEvent {
+    pub mint: Pubkey,
+    pub tranche: Pubkey,
+    pub id: u64,
+    pub config: TrancheConfig
+}
+
+#[event]
+pub struct ChangedTrancheEvent {
+    pub mint: Pubkey,
+    pub tranche: Pubkey,
+    pub config: TrancheConfig
+}
+
+#[event]
+pub struct ClosedTrancheEvent {
+    pub mint: Pubkey,
+    pub tranche: Pubkey,
+    pub issued: u64
+}
+
+#[event]
+pub struct OpenedSnapshotEvent {
+    pub mint: Pubkey,
+    pub id: u64,
+    pub record_timestamp: i64,
+    pub supply: u64,
+}
+
+#[event]
+pub struct CreatedProposalEvent {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub snapshot_id: u64,
+    pub config: ProposalConfig,
+}
+
+#[event]
+pub struct VoteCastEvent {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub identity_account: Pubkey,
+    pub voter: Pubkey,
+    pub option: u8,
+    pub weight: u64,
+}
+
+#[event]
+pub struct FinalizedProposalEvent {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub tallies: Vec<u64>,
+    pub total_votes: u64,
+    pub snapshot_supply: u64,
+    pub quorum_reached: bool,
+    /// none without votes or on a tie
+    pub winning_option: Option<u8>,
+}
+
+#[event]
+pub struct ClosedProposalEvent {
+    pub mint: Pubkey,
+    pub proposal: Pubkey,
+}
+
+#[event]
+pub struct DelegatedVotesEvent {
+    pub mint: Pubkey,
+    pub identity_account: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct UndelegatedVotesEvent {
+    pub mint: Pubkey,
+    pub identity_account: Pubkey,
+    pub delegate: Pubkey,
+}
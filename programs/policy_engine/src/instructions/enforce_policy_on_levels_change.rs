@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use identity_registry::{IdentityAccount, IdentityLevel, IdentityRegistryAccount};
 
-use crate::{PolicyEngineAccount, TrackerAccount};
+use crate::{load_policy_pages, PolicyEngineAccount, PolicyPageScope, TrackerAccount};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct NewLevelsArgs {
@@ -37,8 +37,10 @@ pub fn handler(
     ctx: Context<EnforcePolicyOnLevelsChange>,
     new_levels: NewLevelsArgs,
     new_country: u8,
+    new_beneficial_owners: u32,
     enforce_limits: bool,
 ) -> Result<()> {
+    let policy_engine_key = ctx.accounts.policy_engine.key();
     let engine = ctx.accounts.policy_engine.as_mut();
     let balance = ctx.accounts.tracker_account.total_amount;
 
@@ -55,14 +57,25 @@ pub fn handler(
         .collect();
 
     if balance != 0 {
+        engine.record_holders_change();
+        // remaining accounts are the levels change policy pages
+        let pages = load_policy_pages(
+            engine,
+            policy_engine_key,
+            ctx.remaining_accounts,
+            PolicyPageScope::LevelsChange,
+        )?;
         engine.update_and_enforce_policy_and_counters_on_levels_change(
             previous_levels,
             &new_levels,
             ctx.accounts.identity_account.country,
             new_country,
+            ctx.accounts.identity_account.beneficial_owners,
+            new_beneficial_owners,
             timestamp,
             balance,
             enforce_limits,
+            &pages,
         )?;
     }
 
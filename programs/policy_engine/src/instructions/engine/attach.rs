@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::*, AttachPolicyEvent};
+use crate::{state::*, AttachPolicyEvent, PolicyEngineErrors};
 
 #[derive(Accounts)]
 #[instruction(identity_filter: IdentityFilter, policy_type: PolicyType)]
@@ -13,6 +13,7 @@ pub struct AttachToPolicyEngine<'info> {
     )]
     pub signer: Signer<'info>,
     #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
         realloc = policy_engine.to_account_info().data_len() + Policy::get_new_space(&identity_filter),
         realloc::zero = false,
         realloc::payer = payer,
@@ -26,17 +27,21 @@ pub fn handler(
     identity_filter: IdentityFilter,
     policy_type: PolicyType,
     custom_error: u8,
+    enforcement_mode: EnforcementMode,
+    applicability: Option<PolicyApplicability>,
 ) -> Result<()> {
     let policy_account_address = ctx.accounts.policy_engine.key();
     ctx.accounts
         .policy_engine
-        .attach(policy_account_address, policy_type.clone(), identity_filter.clone(), custom_error)?;
+        .attach(policy_account_address, policy_type.clone(), identity_filter.clone(), custom_error, enforcement_mode, applicability)?;
 
     emit_cpi!(AttachPolicyEvent {
         mint: ctx.accounts.policy_engine.asset_mint,
         policy_type,
         identity_filter,
-        custom_error
+        custom_error,
+        enforcement_mode,
+        applicability: ctx.accounts.policy_engine.policies.last().unwrap().applicability
     });
     Ok(())
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, AttachPolicyToPageEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(identity_filter: IdentityFilter)]
+#[event_cpi]
+pub struct AttachToPolicyPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        realloc = policy_page.to_account_info().data_len() + Policy::get_new_space(&identity_filter),
+        realloc::zero = false,
+        realloc::payer = payer,
+    )]
+    pub policy_page: Box<Account<'info, PolicyPageAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AttachToPolicyPage>,
+    identity_filter: IdentityFilter,
+    policy_type: PolicyType,
+    custom_error: u8,
+    enforcement_mode: EnforcementMode,
+    applicability: Option<PolicyApplicability>,
+) -> Result<()> {
+    ctx.accounts
+        .policy_page
+        .attach(policy_type.clone(), identity_filter.clone(), custom_error, enforcement_mode, applicability)?;
+
+    emit_cpi!(AttachPolicyToPageEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        page_index: ctx.accounts.policy_page.index,
+        policy_type,
+        identity_filter,
+        custom_error,
+        enforcement_mode,
+        applicability: ctx.accounts.policy_page.policies.last().unwrap().applicability
+    });
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, CancelledChangeEvent};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelChange<'info> {
+    /// CHECK: receives the rent of the pending change, checked against the stored payer
+    #[account(mut,
+        address = pending_change.payer,
+    )]
+    pub rent_receiver: AccountInfo<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        close = rent_receiver,
+        has_one = policy_engine,
+    )]
+    pub pending_change: Box<Account<'info, PendingChangeAccount>>,
+}
+
+pub fn handler(ctx: Context<CancelChange>) -> Result<()> {
+    emit_cpi!(CancelledChangeEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        id: ctx.accounts.pending_change.id,
+        change: ctx.accounts.pending_change.change.clone()
+    });
+    Ok(())
+}
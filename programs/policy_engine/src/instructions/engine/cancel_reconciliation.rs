@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, CancelledReconciliationEvent};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelReconciliation<'info> {
+    /// CHECK: receives the rent of the reconciliation, checked against the stored payer
+    #[account(mut,
+        address = reconciliation.payer,
+    )]
+    pub rent_receiver: AccountInfo<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        close = rent_receiver,
+        has_one = policy_engine,
+    )]
+    pub reconciliation: Box<Account<'info, ReconciliationAccount>>,
+}
+
+pub fn handler(ctx: Context<CancelReconciliation>) -> Result<()> {
+    emit_cpi!(CancelledReconciliationEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        processed_identities: ctx.accounts.reconciliation.processed_identities
+    });
+    Ok(())
+}
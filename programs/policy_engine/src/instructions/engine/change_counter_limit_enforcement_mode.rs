@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ChangedCounterLimitEnforcementModeEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ChangeCounterLimitEnforcementMode<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ChangeCounterLimitEnforcementMode>,
+    index: u8,
+    enforcement_mode: EnforcementMode,
+) -> Result<()> {
+    let previous_enforcement_mode = ctx
+        .accounts
+        .policy_engine
+        .change_counter_limit_enforcement_mode(index, enforcement_mode)?;
+
+    emit_cpi!(ChangedCounterLimitEnforcementModeEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        index,
+        enforcement_mode,
+        previous_enforcement_mode
+    });
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{resize_account, state::*, ChangedCountryCountersEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(removed_country_counters: Vec<u8>, added_country_counters: Vec<CountryCounter>)]
+#[event_cpi]
+pub struct ChangeCountryCounters<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ChangeCountryCounters>,
+    removed_country_counters: Vec<u8>,
+    added_country_counters: Vec<CountryCounter>,
+    skip_limit_validation: bool,
+) -> Result<()> {
+    let added_ids = added_country_counters.iter().map(|c| c.id).collect();
+    let size_diff = ctx.accounts.policy_engine.update_country_counters(
+        removed_country_counters.clone(),
+        added_country_counters,
+        skip_limit_validation,
+    )?;
+
+    emit_cpi!(ChangedCountryCountersEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        removed_country_counters,
+        added_country_counters: added_ids
+    });
+
+    resize_account(
+        &ctx.accounts.policy_engine.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        size_diff,
+    )
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::*, ChangedIssuancePoliciesEvent};
+use crate::{state::*, ChangedIssuancePoliciesEvent, PolicyEngineErrors};
 
 #[derive(Accounts)]
 #[event_cpi]
@@ -11,7 +11,9 @@ pub struct ChangeIssuancePolicies<'info> {
         constraint = policy_engine.authority == signer.key()
     )]
     pub signer: Signer<'info>,
-    #[account(mut)]
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
     pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
 }
 
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::*, ChangedMappingEvent, PolicyEngineErrors};
+use crate::{resize_account, state::*, ChangedMappingEvent, PolicyEngineErrors};
 
 #[derive(Accounts)]
 #[event_cpi]
@@ -11,8 +11,11 @@ pub struct ChangeMapping<'info> {
         constraint = policy_engine.authority == signer.key()
     )]
     pub signer: Signer<'info>,
-    #[account(mut)]
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
     pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
@@ -27,9 +30,10 @@ pub fn handler(
 
     let previous_mapping = (&mapping_source).iter().map(|source| ctx.accounts.policy_engine.mapping[*source as usize]).collect::<Vec<_>>();
 
-    ctx.accounts
+    let size_diff = ctx
+        .accounts
         .policy_engine
-        .change_mapping(mapping_source.clone(), mapping_value.clone());
+        .change_mapping(mapping_source.clone(), mapping_value.clone())?;
 
     emit_cpi!(ChangedMappingEvent {
         mint: ctx.accounts.policy_engine.asset_mint,
@@ -38,5 +42,10 @@ pub fn handler(
         previous_mapping
     });
 
-    Ok(())
+    resize_account(
+        &ctx.accounts.policy_engine.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        size_diff,
+    )
 }
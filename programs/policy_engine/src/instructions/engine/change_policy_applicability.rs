@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ChangedPolicyApplicabilityEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ChangePolicyApplicability<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ChangePolicyApplicability>,
+    hash: String,
+    applicability: PolicyApplicability,
+) -> Result<()> {
+    let previous_applicability = ctx
+        .accounts
+        .policy_engine
+        .change_policy_applicability(&hash, applicability)?;
+
+    emit_cpi!(ChangedPolicyApplicabilityEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        hash,
+        applicability,
+        previous_applicability
+    });
+    Ok(())
+}
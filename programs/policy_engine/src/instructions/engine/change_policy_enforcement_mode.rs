@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ChangedPolicyEnforcementModeEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ChangePolicyEnforcementMode<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<ChangePolicyEnforcementMode>,
+    hash: String,
+    enforcement_mode: EnforcementMode,
+) -> Result<()> {
+    let previous_enforcement_mode = ctx
+        .accounts
+        .policy_engine
+        .change_policy_enforcement_mode(&hash, enforcement_mode)?;
+
+    emit_cpi!(ChangedPolicyEnforcementModeEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        hash,
+        enforcement_mode,
+        previous_enforcement_mode
+    });
+    Ok(())
+}
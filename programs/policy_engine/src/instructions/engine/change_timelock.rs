@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ChangedTimelockEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ChangeTimelock<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+}
+
+pub fn handler(ctx: Context<ChangeTimelock>, timelock: Timelock) -> Result<()> {
+    let previous_timelock = ctx.accounts.policy_engine.timelock.clone();
+
+    ctx.accounts
+        .policy_engine
+        .change_timelock(timelock.clone())?;
+
+    emit_cpi!(ChangedTimelockEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        timelock,
+        previous_timelock
+    });
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ChangedTrancheEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(config: TrancheConfig)]
+#[event_cpi]
+pub struct ChangeTranche<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        realloc = 8 + TrancheAccount::get_space(&config.identity_filter),
+        realloc::zero = false,
+        realloc::payer = payer,
+    )]
+    pub tranche: Box<Account<'info, TrancheAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ChangeTranche>, config: TrancheConfig) -> Result<()> {
+    config.validate()?;
+    // the cap cannot go below what was already issued through the tranche
+    require!(
+        config.cap == 0 || config.cap >= ctx.accounts.tranche.issued,
+        PolicyEngineErrors::InvalidTrancheConfig
+    );
+    ctx.accounts.tranche.set_config(config.clone());
+
+    emit_cpi!(ChangedTrancheEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        tranche: ctx.accounts.tranche.key(),
+        config
+    });
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ClosedPolicyPageEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ClosePolicyPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+        realloc = policy_engine.to_account_info().data_len() - PolicyPageRef::INIT_SPACE,
+        realloc::zero = false,
+        realloc::payer = payer,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        constraint = policy_page.policies.is_empty() @ PolicyEngineErrors::PolicyPageNotEmpty,
+        close = rent_receiver,
+    )]
+    pub policy_page: Box<Account<'info, PolicyPageAccount>>,
+    /// CHECK: receives the rent of the page
+    #[account(mut,
+        address = policy_page.payer,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClosePolicyPage>) -> Result<()> {
+    let index = ctx.accounts.policy_page.index;
+    ctx.accounts.policy_engine.remove_policy_page(index)?;
+
+    emit_cpi!(ClosedPolicyPageEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        index
+    });
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ClosedTrancheEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CloseTranche<'info> {
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        close = rent_receiver,
+    )]
+    pub tranche: Box<Account<'info, TrancheAccount>>,
+    /// CHECK: receives the rent of the tranche
+    #[account(mut,
+        address = tranche.payer,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseTranche>) -> Result<()> {
+    emit_cpi!(ClosedTrancheEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        tranche: ctx.accounts.tranche.key(),
+        issued: ctx.accounts.tranche.issued
+    });
+    Ok(())
+}
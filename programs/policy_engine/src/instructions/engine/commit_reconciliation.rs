@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use identity_registry::IdentityRegistryAccount;
+
+use crate::{state::*, CommittedReconciliationEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CommitReconciliation<'info> {
+    /// CHECK: receives the rent of the reconciliation, checked against the stored payer
+    #[account(mut,
+        address = reconciliation.payer,
+    )]
+    pub rent_receiver: AccountInfo<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        constraint = identity_registry.asset_mint == policy_engine.asset_mint @ PolicyEngineErrors::InvalidIdentityAccount,
+    )]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(mut,
+        close = rent_receiver,
+        has_one = policy_engine,
+    )]
+    pub reconciliation: Box<Account<'info, ReconciliationAccount>>,
+}
+
+pub fn handler(ctx: Context<CommitReconciliation>) -> Result<()> {
+    let mismatches = ctx
+        .accounts
+        .reconciliation
+        .commit(&mut ctx.accounts.policy_engine, ctx.accounts.identity_registry.identity_count)?;
+    let mismatch_count = mismatches.len() as u32;
+
+    for mismatch in mismatches {
+        emit_cpi!(mismatch);
+    }
+
+    emit_cpi!(CommittedReconciliationEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        processed_identities: ctx.accounts.reconciliation.processed_identities,
+        mismatches: mismatch_count
+    });
+    Ok(())
+}
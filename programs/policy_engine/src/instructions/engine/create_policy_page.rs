@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, CreatedPolicyPageEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+#[event_cpi]
+pub struct CreatePolicyPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+        realloc = policy_engine.to_account_info().data_len() + PolicyPageRef::INIT_SPACE,
+        realloc::zero = false,
+        realloc::payer = payer,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        init,
+        space = 8 + PolicyPageAccount::INIT_SPACE,
+        seeds = [POLICY_PAGE_SEED, policy_engine.key().as_ref(), &[index]],
+        bump,
+        payer = payer,
+    )]
+    pub policy_page: Box<Account<'info, PolicyPageAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreatePolicyPage>, index: u8, scope: PolicyPageScope) -> Result<()> {
+    ctx.accounts.policy_engine.add_policy_page(index, scope)?;
+    ctx.accounts.policy_page.set_inner(PolicyPageAccount::new(
+        ctx.accounts.policy_engine.key(),
+        ctx.accounts.payer.key(),
+        index,
+        scope,
+    ));
+
+    emit_cpi!(CreatedPolicyPageEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        page: ctx.accounts.policy_page.key(),
+        index,
+        scope
+    });
+    Ok(())
+}
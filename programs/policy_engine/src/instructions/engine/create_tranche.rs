@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, CreatedTrancheEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(id: u64, config: TrancheConfig)]
+#[event_cpi]
+pub struct CreateTranche<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        init,
+        space = 8 + TrancheAccount::get_space(&config.identity_filter),
+        seeds = [TRANCHE_SEED, policy_engine.key().as_ref(), &id.to_le_bytes()],
+        bump,
+        payer = payer,
+    )]
+    pub tranche: Box<Account<'info, TrancheAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateTranche>, id: u64, config: TrancheConfig) -> Result<()> {
+    config.validate()?;
+    ctx.accounts.tranche.set_inner(TrancheAccount::new(
+        ctx.accounts.policy_engine.key(),
+        ctx.accounts.payer.key(),
+        id,
+        config.clone(),
+    ));
+
+    emit_cpi!(CreatedTrancheEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        tranche: ctx.accounts.tranche.key(),
+        id,
+        config
+    });
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::*, DetachPolicyEvent};
+use crate::{state::*, DetachPolicyEvent, PolicyEngineErrors};
 
 #[derive(Accounts)]
 #[instruction(hash: String)]
@@ -13,6 +13,7 @@ pub struct DetachFromPolicyEngine<'info> {
     )]
     pub signer: Signer<'info>,
     #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
         realloc = policy_engine.to_account_info().data_len() - policy_engine.get_policy_space(&hash)?,
         realloc::zero = false,
         realloc::payer = payer,
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, DetachPolicyFromPageEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(hash: String)]
+#[event_cpi]
+pub struct DetachFromPolicyPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        realloc = policy_page.to_account_info().data_len() - policy_page.get_policy_space(&hash)?,
+        realloc::zero = false,
+        realloc::payer = payer,
+    )]
+    pub policy_page: Box<Account<'info, PolicyPageAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DetachFromPolicyPage>, hash: String) -> Result<()> {
+    let policy = ctx.accounts.policy_page.detach(hash)?;
+
+    emit_cpi!(DetachPolicyFromPageEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        page_index: ctx.accounts.policy_page.index,
+        policy_type: policy.policy_type,
+        identity_filter: policy.identity_filter,
+        custom_error: policy.custom_error
+    });
+    Ok(())
+}
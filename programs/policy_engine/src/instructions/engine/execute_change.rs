@@ -0,0 +1,182 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, CreateAccount},
+};
+
+use crate::{resize_account, state::*, ExecutedChangeEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteChange<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: receives the rent of the pending change, checked against the stored payer
+    #[account(mut,
+        address = pending_change.payer,
+    )]
+    pub rent_receiver: AccountInfo<'info>,
+    #[account(mut,
+        close = rent_receiver,
+        has_one = policy_engine,
+        constraint = pending_change.is_executable(Clock::get()?.unix_timestamp, policy_engine.timelock.delay) @ PolicyEngineErrors::ChangeNotExecutable,
+    )]
+    pub pending_change: Box<Account<'info, PendingChangeAccount>>,
+    /// CHECK: page targeted by a policy page change, checked against the page index of the change
+    #[account(mut)]
+    pub policy_page: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the rent of a closed policy page, checked against the page payer
+    #[account(mut)]
+    pub policy_page_rent_receiver: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteChange<'info> {
+    /// returns the page account passed for the change with its bump
+    fn policy_page_info(&self, index: u8) -> Result<(AccountInfo<'info>, u8)> {
+        let policy_page = self
+            .policy_page
+            .as_ref()
+            .ok_or(PolicyEngineErrors::InvalidPolicyPage)?;
+        let (address, bump) = Pubkey::find_program_address(
+            &[POLICY_PAGE_SEED, self.policy_engine.key().as_ref(), &[index]],
+            &crate::id(),
+        );
+        require_keys_eq!(policy_page.key(), address, PolicyEngineErrors::InvalidPolicyPage);
+        Ok((policy_page.to_account_info(), bump))
+    }
+
+    fn create_policy_page(&self, index: u8, scope: PolicyPageScope) -> Result<()> {
+        let (policy_page, bump) = self.policy_page_info(index)?;
+        let policy_engine = self.policy_engine.key();
+        let space = 8 + PolicyPageAccount::INIT_SPACE;
+        create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.payer.to_account_info(),
+                    to: policy_page.clone(),
+                },
+                &[&[POLICY_PAGE_SEED, policy_engine.as_ref(), &[index], &[bump]]],
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::id(),
+        )?;
+        let page = PolicyPageAccount::new(policy_engine, self.payer.key(), index, scope);
+        page.try_serialize(&mut &mut policy_page.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+
+    fn load_policy_page(policy_page: &AccountInfo<'info>) -> Result<PolicyPageAccount> {
+        require_keys_eq!(*policy_page.owner, crate::id(), PolicyEngineErrors::InvalidPolicyPage);
+        PolicyPageAccount::try_deserialize(&mut &policy_page.try_borrow_data()?[..])
+    }
+
+    /// changes the policies of a page, `change` returning the account size difference
+    fn change_policy_page(
+        &self,
+        index: u8,
+        change: impl FnOnce(&mut PolicyPageAccount) -> Result<i32>,
+    ) -> Result<()> {
+        let (policy_page, _) = self.policy_page_info(index)?;
+        let mut page = Self::load_policy_page(&policy_page)?;
+        let size_diff = change(&mut page)?;
+        // grow before writing the page, shrink after
+        if size_diff > 0 {
+            resize_account(
+                &policy_page,
+                &self.payer.to_account_info(),
+                &self.system_program.to_account_info(),
+                size_diff,
+            )?;
+        }
+        page.try_serialize(&mut &mut policy_page.try_borrow_mut_data()?[..])?;
+        if size_diff < 0 {
+            resize_account(
+                &policy_page,
+                &self.payer.to_account_info(),
+                &self.system_program.to_account_info(),
+                size_diff,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn close_policy_page(&self, index: u8) -> Result<()> {
+        let (policy_page, _) = self.policy_page_info(index)?;
+        let page = Self::load_policy_page(&policy_page)?;
+        require!(page.policies.is_empty(), PolicyEngineErrors::PolicyPageNotEmpty);
+        let rent_receiver = self
+            .policy_page_rent_receiver
+            .as_ref()
+            .ok_or(PolicyEngineErrors::InvalidPolicyPage)?;
+        require_keys_eq!(rent_receiver.key(), page.payer, PolicyEngineErrors::InvalidPolicyPage);
+        // closes the page the way the `close` constraint does
+        let lamports = policy_page.lamports();
+        **rent_receiver.to_account_info().lamports.borrow_mut() =
+            rent_receiver.lamports().checked_add(lamports).unwrap();
+        **policy_page.lamports.borrow_mut() = 0;
+        policy_page.assign(&System::id());
+        policy_page.realloc(0, false).map_err(Into::into)
+    }
+
+    /// applies the page side of a policy page change, the engine side being applied by `apply_change`
+    fn apply_policy_page_change(&self, change: PolicyEngineChange) -> Result<()> {
+        match change {
+            PolicyEngineChange::CreatePolicyPage { index, scope } => {
+                self.create_policy_page(index, scope)
+            }
+            PolicyEngineChange::AttachToPolicyPage {
+                index,
+                identity_filter,
+                policy_type,
+                custom_error,
+                enforcement_mode,
+                applicability,
+            } => self.change_policy_page(index, |page| {
+                let space = Policy::get_new_space(&identity_filter) as i32;
+                page.attach(policy_type, identity_filter, custom_error, enforcement_mode, applicability)?;
+                Ok(space)
+            }),
+            PolicyEngineChange::DetachFromPolicyPage { index, hash } => {
+                self.change_policy_page(index, |page| {
+                    let space = page.get_policy_space(&hash)? as i32;
+                    page.detach(hash)?;
+                    Ok(-space)
+                })
+            }
+            PolicyEngineChange::ClosePolicyPage { index } => self.close_policy_page(index),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn handler(ctx: Context<ExecuteChange>) -> Result<()> {
+    let policy_account_address = ctx.accounts.policy_engine.key();
+    let change = ctx.accounts.pending_change.change.clone();
+
+    let size_diff = ctx
+        .accounts
+        .policy_engine
+        .apply_change(policy_account_address, change.clone())?;
+    ctx.accounts.apply_policy_page_change(change.clone())?;
+
+    emit_cpi!(ExecutedChangeEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        id: ctx.accounts.pending_change.id,
+        change
+    });
+
+    resize_account(
+        &ctx.accounts.policy_engine.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        size_diff,
+    )
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use rwa_utils::migrate_account;
+
+use crate::{state::*, PolicyEngineErrors};
+
+#[derive(Accounts)]
+pub struct MigratePolicyEngine<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub signer: Signer<'info>,
+    /// CHECK: version 1 engine, checked by the migration against the signer
+    #[account(mut)]
+    pub policy_engine: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// rewrites a version 1 policy engine to the current layout, keeping its policies, counters and limits enforced
+pub fn handler(ctx: Context<MigratePolicyEngine>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    migrate_account(
+        &ctx.accounts.policy_engine.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &crate::id(),
+        PolicyEngineErrors::InvalidAccountVersion,
+        |engine: PolicyEngineAccountV1| {
+            require_keys_eq!(engine.authority, signer, PolicyEngineErrors::UnauthorizedSigner);
+            Ok(PolicyEngineAccount::from(engine))
+        },
+    )?;
+    Ok(())
+}
@@ -1,17 +1,61 @@
 pub mod attach;
+pub mod attach_to_policy_page;
+pub mod cancel_change;
+pub mod cancel_reconciliation;
+pub mod change_counter_limit_enforcement_mode;
 pub mod change_counter_limits;
 pub mod change_counters;
+pub mod change_country_counters;
 pub mod change_issuance_policies;
 pub mod change_mapping;
+pub mod change_policy_applicability;
+pub mod change_policy_enforcement_mode;
+pub mod change_timelock;
+pub mod change_tranche;
+pub mod close_policy_page;
+pub mod close_tranche;
+pub mod commit_reconciliation;
 pub mod create;
+pub mod create_policy_page;
+pub mod create_tranche;
 pub mod detach;
+pub mod detach_from_policy_page;
+pub mod execute_change;
+pub mod migrate_policy_engine;
+pub mod open_snapshot;
+pub mod propose_change;
+pub mod reconcile_counters;
 pub mod set_counters;
+pub mod start_reconciliation;
+pub mod update_extra_metas;
 
 pub use attach::*;
+pub use attach_to_policy_page::*;
+pub use cancel_change::*;
+pub use cancel_reconciliation::*;
+pub use change_counter_limit_enforcement_mode::*;
 pub use change_counter_limits::*;
 pub use change_counters::*;
+pub use change_country_counters::*;
 pub use change_issuance_policies::*;
 pub use change_mapping::*;
+pub use change_policy_applicability::*;
+pub use change_policy_enforcement_mode::*;
+pub use change_timelock::*;
+pub use change_tranche::*;
+pub use close_policy_page::*;
+pub use close_tranche::*;
+pub use commit_reconciliation::*;
 pub use create::*;
+pub use create_policy_page::*;
+pub use create_tranche::*;
 pub use detach::*;
+pub use detach_from_policy_page::*;
+pub use execute_change::*;
+pub use migrate_policy_engine::*;
+pub use open_snapshot::*;
+pub use propose_change::*;
+pub use reconcile_counters::*;
 pub use set_counters::*;
+pub use start_reconciliation::*;
+pub use update_extra_metas::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{state::*, OpenedSnapshotEvent};
+
+#[derive(Accounts)]
+#[instruction()]
+#[event_cpi]
+pub struct OpenSnapshot<'info> {
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut, has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// the record date is the current time, trackers checkpoint their balance on their next change
+pub fn handler(ctx: Context<OpenSnapshot>) -> Result<()> {
+    let record_timestamp = Clock::get()?.unix_timestamp;
+    let supply = ctx.accounts.asset_mint.supply;
+    ctx.accounts
+        .policy_engine
+        .snapshot
+        .open(record_timestamp, supply)?;
+
+    emit_cpi!(OpenedSnapshotEvent {
+        mint: ctx.accounts.asset_mint.key(),
+        id: ctx.accounts.policy_engine.snapshot.id,
+        record_timestamp,
+        supply,
+    });
+
+    Ok(())
+}
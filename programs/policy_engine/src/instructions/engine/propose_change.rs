@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ProposedChangeEvent};
+
+#[derive(Accounts)]
+#[instruction(change: PolicyEngineChange)]
+#[event_cpi]
+pub struct ProposeChange<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        init,
+        space = 8 + PendingChangeAccount::get_space(&change)?,
+        seeds = [PENDING_CHANGE_SEED, policy_engine.key().as_ref(), &policy_engine.next_change_id.to_le_bytes()],
+        bump,
+        payer = payer,
+    )]
+    pub pending_change: Box<Account<'info, PendingChangeAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeChange>, change: PolicyEngineChange) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let id = ctx.accounts.policy_engine.next_change_id;
+
+    ctx.accounts.pending_change.set_inner(PendingChangeAccount::new(
+        ctx.accounts.policy_engine.key(),
+        id,
+        ctx.accounts.payer.key(),
+        timestamp,
+        ctx.accounts.policy_engine.timelock.delay,
+        change.clone(),
+    ));
+    ctx.accounts.policy_engine.next_change_id += 1;
+
+    emit_cpi!(ProposedChangeEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        id,
+        change,
+        executable_at: ctx.accounts.pending_change.executable_at
+    });
+    Ok(())
+}
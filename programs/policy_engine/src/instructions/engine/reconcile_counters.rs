@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+
+use crate::{state::*, PolicyEngineErrors};
+
+#[derive(Accounts)]
+pub struct ReconcileCounters<'info> {
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        constraint = identity_registry.asset_mint == policy_engine.asset_mint @ PolicyEngineErrors::InvalidIdentityAccount,
+    )]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        constraint = !reconciliation.is_outdated(&policy_engine) @ PolicyEngineErrors::ReconciliationOutdated,
+    )]
+    pub reconciliation: Box<Account<'info, ReconciliationAccount>>,
+}
+
+/// remaining accounts are (identity account, tracker account) pairs, sorted by identity account
+pub fn handler(ctx: Context<ReconcileCounters>) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        PolicyEngineErrors::InvalidInstructionData
+    );
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let (identity_account_info, tracker_account_info) = (&accounts[0], &accounts[1]);
+
+        require!(
+            identity_account_info.owner == &identity_registry::id(),
+            PolicyEngineErrors::InvalidIdentityAccount
+        );
+        let identity_account =
+            IdentityAccount::try_deserialize(&mut &identity_account_info.data.borrow()[..])?;
+        require!(
+            identity_account.identity_registry == ctx.accounts.identity_registry.key(),
+            PolicyEngineErrors::InvalidIdentityAccount
+        );
+
+        require!(
+            tracker_account_info.owner == &crate::id(),
+            PolicyEngineErrors::TrackerAccountOwnerMismatch
+        );
+        let tracker_account =
+            TrackerAccount::try_deserialize(&mut &tracker_account_info.data.borrow()[..])?;
+        require!(
+            tracker_account.identity_account == identity_account_info.key()
+                && tracker_account.asset_mint == ctx.accounts.policy_engine.asset_mint,
+            PolicyEngineErrors::TrackerAccountOwnerMismatch
+        );
+
+        ctx.accounts.reconciliation.add_identity(
+            &ctx.accounts.policy_engine,
+            identity_account_info.key(),
+            &identity_account.levels,
+            identity_account.country,
+            identity_account.beneficial_owners,
+            tracker_account.total_amount,
+        )?;
+    }
+    Ok(())
+}
@@ -11,7 +11,9 @@ pub struct SetCounters<'info> {
         constraint = policy_engine.authority == signer.key()
     )]
     pub signer: Signer<'info>,
-    #[account(mut)]
+    #[account(mut,
+        constraint = policy_engine.timelock.direct_changes_enabled @ PolicyEngineErrors::DirectChangesDisabled,
+    )]
     pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
 }
 
@@ -19,6 +21,7 @@ pub fn handler(
     ctx: Context<SetCounters>,
     changed_counters: Vec<u8>,
     values: Vec<u64>,
+    skip_limit_validation: bool,
 ) -> Result<()> {
     require!(
         changed_counters.len() == values.len(),
@@ -27,7 +30,7 @@ pub fn handler(
 
     ctx.accounts
         .policy_engine
-        .set_counters(changed_counters.clone(), values.clone())?;
+        .set_counters(changed_counters.clone(), values.clone(), skip_limit_validation)?;
 
     emit_cpi!(SetCounterValueEvent {
         mint: ctx.accounts.policy_engine.asset_mint,
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, StartedReconciliationEvent};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct StartReconciliation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        init,
+        space = 8 + ReconciliationAccount::get_space(&policy_engine),
+        seeds = [RECONCILIATION_SEED, policy_engine.key().as_ref()],
+        bump,
+        payer = payer,
+    )]
+    pub reconciliation: Box<Account<'info, ReconciliationAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<StartReconciliation>) -> Result<()> {
+    ctx.accounts.reconciliation.set_inner(ReconciliationAccount::new(
+        ctx.accounts.policy_engine.key(),
+        ctx.accounts.payer.key(),
+        &ctx.accounts.policy_engine,
+    ));
+
+    emit_cpi!(StartedReconciliationEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        reconciliation: ctx.accounts.reconciliation.key()
+    });
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use rwa_utils::META_LIST_ACCOUNT_SEED;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    get_extra_account_metas, get_meta_list_size, resize_account, state::*, UpdatedExtraMetasEvent,
+};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateExtraMetas<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        has_one = asset_mint,
+    )]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account()]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+        seeds = [META_LIST_ACCOUNT_SEED, asset_mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: extra metas account
+    pub extra_metas_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// rewrites the extra metas of mints created before the current account list, e.g. without the policy page slots
+pub fn handler(ctx: Context<UpdateExtraMetas>) -> Result<()> {
+    let extra_metas_account = ctx.accounts.extra_metas_account.to_account_info();
+    let size_diff = get_meta_list_size()? as i32 - extra_metas_account.data_len() as i32;
+    if size_diff != 0 {
+        resize_account(
+            &extra_metas_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            size_diff,
+        )?;
+    }
+
+    let metas = get_extra_account_metas()?;
+    let mut data = extra_metas_account.try_borrow_mut_data()?;
+    ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &metas)?;
+    drop(data);
+
+    emit_cpi!(UpdatedExtraMetasEvent {
+        mint: ctx.accounts.asset_mint.key()
+    });
+    Ok(())
+}
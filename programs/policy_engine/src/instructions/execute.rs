@@ -1,5 +1,5 @@
 use crate::{
-    assert_is_transferring, verify_pda, PolicyEngineAccount, PolicyEngineErrors, Side, TrackerAccount, LOCKED_LEVEL, PLATFORM_WALLET_LEVEL, US_COMPLIANCE_LEVEL
+    assert_is_transferring, load_policy_pages, verify_pda, PolicyEngineAccount, PolicyEngineErrors, PolicyEvent, PolicyPageScope, Side, TrackerAccount, LOCKED_LEVEL, PLATFORM_WALLET_LEVEL, US_COMPLIANCE_LEVEL
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate, token_interface::{get_mint_extension_data, Mint, TokenAccount}};
@@ -169,10 +169,15 @@ pub fn handler(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
             );
         }
 
+        source_tracker_account.checkpoint(&policy_engine_account.snapshot);
         source_tracker_account.update_transfer_history(
             amount,
             Side::Sell,
         )?;
+        // seizes are not sales and stay out of the affiliate volume
+        if !is_permanent_delegate {
+            source_tracker_account.outbound_volume.record(amount, timestamp)?;
+        }
         let source_tracker_account_data = source_tracker_account.try_to_vec()?;
         let source_tracker_account_data_len = source_tracker_account_data.len();
         ctx.accounts.source_tracker_account.data.borrow_mut()
@@ -189,6 +194,7 @@ pub fn handler(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
     );
 
     if !self_transfer {
+        destination_tracker_account.checkpoint(&policy_engine_account.snapshot);
         destination_tracker_account.update_transfer_history(
             amount,
             Side::Buy,
@@ -204,17 +210,18 @@ pub fn handler(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
     let destination_balance = destination_tracker_account.total_amount;
 
 
+    // seizes update the counters through update_counters_on_seize once the transfer is done
     if !is_permanent_delegate && !self_transfer {
         let decreased_counters = if source_balance == 0 {
             // source has 0 balance
-            policy_engine_account.decrease_holders_count(&source_identity_account.levels, source_identity_account.country)?
+            policy_engine_account.decrease_holders_count(&source_identity_account.levels, source_identity_account.country, source_identity_account.beneficial_owners)?
         } else {
             vec![]
         };
         let increased_counters = if destination_balance == amount {
             // destination has 0 balance
             policy_engine_account
-                .increase_holders_count(&destination_identity_account.levels, destination_identity_account.country)?
+                .increase_holders_count(&destination_identity_account.levels, destination_identity_account.country, destination_identity_account.beneficial_owners)?
         } else {
             vec![]
         };
@@ -224,31 +231,45 @@ pub fn handler(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
         }
 
         if !increased_counters.is_empty() {
-            policy_engine_account.enforce_counters_on_increment(&increased_counters)?;
+            policy_engine_account.enforce_counters_on_increment(&increased_counters, destination_identity_account.country)?;
         }
 
         if !increased_counters.is_empty() || !decreased_counters.is_empty() {
+            policy_engine_account.record_holders_change();
             let data = policy_engine_account.try_to_vec()?;
             let len = data.len();
             ctx.accounts.policy_engine_account.data.borrow_mut()[8..8 + len].copy_from_slice(&data);
         }
     }
 
-    if !is_permanent_delegate {
-        // evaluate policies
-        policy_engine_account.enforce_policy(
-            amount,
-            timestamp,
-            &source_identity_account.levels,
-            source_identity_account.country,
-            &destination_identity_account.levels,
-            destination_identity_account.country,
-            source_balance,
-            destination_balance,
-            self_transfer,
-            is_platform_wallet,
-        )?;
-    }
+    // transfer policy pages are resolved from the extra metas after the fixed accounts
+    let pages = load_policy_pages(
+        &policy_engine_account,
+        ctx.accounts.policy_engine_account.key(),
+        ctx.remaining_accounts,
+        PolicyPageScope::Transfer,
+    )?;
+
+    // permanent delegate transfers are seizes and only evaluate the policies applying to them
+    let event = if is_permanent_delegate { PolicyEvent::Seize } else { PolicyEvent::Transfer };
+
+    // evaluate policies
+    policy_engine_account.enforce_policy(
+        amount,
+        timestamp,
+        &source_identity_account.levels,
+        source_identity_account.country,
+        &destination_identity_account.levels,
+        destination_identity_account.country,
+        source_balance,
+        destination_balance,
+        self_transfer,
+        is_platform_wallet,
+        ctx.accounts.asset_mint.supply,
+        source_tracker_account.outbound_volume.total(timestamp),
+        &pages,
+        event,
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+
+use crate::{state::*, PolicyEngineErrors, VoteCastEvent};
+
+#[derive(Accounts)]
+#[instruction(option: u8)]
+#[event_cpi]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// owner of the voter identity
+    pub signer: Signer<'info>,
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: checked through the policy engine, the identity registry and the tracker
+    pub asset_mint: UncheckedAccount<'info>,
+    #[account(mut, has_one = policy_engine)]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(
+        has_one = identity_registry,
+        constraint = voter_identity_account.owner == signer.key(),
+    )]
+    pub voter_identity_account: Box<Account<'info, IdentityAccount>>,
+    /// identity whose balance is voted, the voter identity itself or an identity delegating to it
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(has_one = asset_mint, has_one = identity_account)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    /// CHECK: delegation of the identity, empty when the identity votes itself
+    #[account(
+        seeds = [DELEGATION_SEED, policy_engine.key().as_ref(), identity_account.key().as_ref()],
+        bump,
+    )]
+    pub delegation: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [VOTE_SEED, proposal.key().as_ref(), identity_account.key().as_ref()],
+        bump,
+    )]
+    pub vote: Box<Account<'info, VoteAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// votes the record date balance of an identity, once per proposal.
+/// a delegated balance can only be voted by the delegate.
+pub fn handler(ctx: Context<CastVote>, option: u8) -> Result<()> {
+    let voter = ctx.accounts.voter_identity_account.key();
+    let identity_account = ctx.accounts.identity_account.key();
+    if ctx.accounts.delegation.data_is_empty() {
+        require_keys_eq!(
+            voter,
+            identity_account,
+            PolicyEngineErrors::VoteNotDelegated
+        );
+    } else {
+        let delegation = DelegationAccount::try_deserialize(
+            &mut &ctx.accounts.delegation.try_borrow_data()?[..],
+        )?;
+        require_keys_eq!(
+            delegation.delegate,
+            voter,
+            PolicyEngineErrors::VoteNotDelegated
+        );
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    ctx.accounts
+        .policy_engine
+        .enforce_filters_single(
+            &ctx.accounts.identity_account.levels,
+            ctx.accounts.identity_account.country,
+            &proposal.identity_filter,
+        )
+        .map_err(|_| PolicyEngineErrors::IdentityNotEligibleToVote)?;
+
+    let weight = ctx
+        .accounts
+        .tracker_account
+        .get_snapshot_balance(&ctx.accounts.policy_engine.snapshot, proposal.snapshot_id)?;
+    require!(weight > 0, PolicyEngineErrors::NoVotingPower);
+    let timestamp = Clock::get()?.unix_timestamp;
+    proposal.vote(option, weight, timestamp)?;
+
+    ctx.accounts.vote.set_inner(VoteAccount {
+        version: VoteAccount::VERSION,
+        proposal: proposal.key(),
+        identity_account,
+        voter,
+        option,
+        weight,
+        voted_at: timestamp,
+    });
+
+    emit_cpi!(VoteCastEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        proposal: proposal.key(),
+        identity_account,
+        voter,
+        option,
+        weight,
+    });
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, ClosedProposalEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CloseProposal<'info> {
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        constraint = proposal.finalized @ PolicyEngineErrors::ProposalNotFinalized,
+        close = rent_receiver,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    /// CHECK: receives the rent of the proposal
+    #[account(mut,
+        address = proposal.payer,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseProposal>) -> Result<()> {
+    emit_cpi!(ClosedProposalEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        proposal: ctx.accounts.proposal.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, CreatedProposalEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[instruction(id: u64, config: ProposalConfig)]
+#[event_cpi]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = policy_engine.authority == signer.key()
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        init,
+        space = 8 + ProposalAccount::get_space(&config),
+        seeds = [PROPOSAL_SEED, policy_engine.key().as_ref(), &id.to_le_bytes()],
+        bump,
+        payer = payer,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// the record date of the proposal is the latest snapshot of the policy engine,
+/// which stays readable until the end of the voting window
+pub fn handler(ctx: Context<CreateProposal>, id: u64, config: ProposalConfig) -> Result<()> {
+    config.validate()?;
+    let policy_engine = ctx.accounts.policy_engine.key();
+    let snapshot = &mut ctx.accounts.policy_engine.snapshot;
+    require!(snapshot.id > 0, PolicyEngineErrors::SnapshotUnavailable);
+    snapshot.reserve(config.end_timestamp);
+    ctx.accounts.proposal.set_inner(ProposalAccount::new(
+        policy_engine,
+        ctx.accounts.payer.key(),
+        id,
+        snapshot.id,
+        snapshot.supply,
+        config.clone(),
+    ));
+
+    emit_cpi!(CreatedProposalEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        proposal: ctx.accounts.proposal.key(),
+        id,
+        snapshot_id: ctx.accounts.proposal.snapshot_id,
+        config,
+    });
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+
+use crate::{state::*, DelegatedVotesEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// owner of the delegating identity
+    pub signer: Signer<'info>,
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: checked through the policy engine and the identity registry
+    pub asset_mint: UncheckedAccount<'info>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(
+        has_one = identity_registry,
+        constraint = identity_account.owner == signer.key(),
+    )]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        has_one = identity_registry,
+        constraint = delegate_identity_account.key() != identity_account.key() @ PolicyEngineErrors::InvalidDelegation,
+    )]
+    pub delegate_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DelegationAccount::INIT_SPACE,
+        seeds = [DELEGATION_SEED, policy_engine.key().as_ref(), identity_account.key().as_ref()],
+        bump,
+    )]
+    pub delegation: Box<Account<'info, DelegationAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DelegateVotes>) -> Result<()> {
+    ctx.accounts.delegation.set_inner(DelegationAccount {
+        version: DelegationAccount::VERSION,
+        policy_engine: ctx.accounts.policy_engine.key(),
+        payer: ctx.accounts.payer.key(),
+        identity_account: ctx.accounts.identity_account.key(),
+        delegate: ctx.accounts.delegate_identity_account.key(),
+    });
+
+    emit_cpi!(DelegatedVotesEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        identity_account: ctx.accounts.identity_account.key(),
+        delegate: ctx.accounts.delegate_identity_account.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::*, FinalizedProposalEvent, PolicyEngineErrors};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct FinalizeProposal<'info> {
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(mut, has_one = policy_engine)]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+}
+
+/// permissionless once the voting window has ended, emits the final tally
+pub fn handler(ctx: Context<FinalizeProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.finalized && Clock::get()?.unix_timestamp >= proposal.end_timestamp,
+        PolicyEngineErrors::ProposalNotFinalizable
+    );
+    proposal.finalized = true;
+
+    emit_cpi!(FinalizedProposalEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        proposal: proposal.key(),
+        id: proposal.id,
+        tallies: proposal.tallies.clone(),
+        total_votes: proposal.get_total_votes(),
+        snapshot_supply: proposal.snapshot_supply,
+        quorum_reached: proposal.is_quorum_reached(),
+        winning_option: proposal.get_winning_option(),
+    });
+    Ok(())
+}
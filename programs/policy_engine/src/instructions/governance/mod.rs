@@ -0,0 +1,13 @@
+pub mod cast_vote;
+pub mod close_proposal;
+pub mod create_proposal;
+pub mod delegate_votes;
+pub mod finalize_proposal;
+pub mod undelegate_votes;
+
+pub use cast_vote::*;
+pub use close_proposal::*;
+pub use create_proposal::*;
+pub use delegate_votes::*;
+pub use finalize_proposal::*;
+pub use undelegate_votes::*;
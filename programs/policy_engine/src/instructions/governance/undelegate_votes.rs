@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use identity_registry::IdentityAccount;
+
+use crate::{state::*, UndelegatedVotesEvent};
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UndelegateVotes<'info> {
+    /// owner of the delegating identity
+    pub signer: Signer<'info>,
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(
+        constraint = identity_account.owner == signer.key(),
+    )]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut,
+        has_one = policy_engine,
+        has_one = identity_account,
+        close = rent_receiver,
+    )]
+    pub delegation: Box<Account<'info, DelegationAccount>>,
+    /// CHECK: receives the rent of the delegation
+    #[account(mut,
+        address = delegation.payer,
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+/// balances already voted by the delegate stay counted
+pub fn handler(ctx: Context<UndelegateVotes>) -> Result<()> {
+    emit_cpi!(UndelegatedVotesEvent {
+        mint: ctx.accounts.policy_engine.asset_mint,
+        identity_account: ctx.accounts.identity_account.key(),
+        delegate: ctx.accounts.delegation.delegate,
+    });
+    Ok(())
+}
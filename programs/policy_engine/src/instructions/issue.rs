@@ -1,4 +1,8 @@
-use crate::{Issuance, PolicyEngineAccount, TrackerAccount, ASSET_CONTROLLER_ID};
+use crate::{
+    load_policy_pages, Issuance, LockEvent, PolicyEngineAccount, PolicyEngineErrors, PolicyPageScope,
+    TrackerAccount, TrancheAccount, TrancheSubscriptionAccount, ASSET_CONTROLLER_ID,
+    TRANCHE_LOCK_REASON, TRANCHE_SUBSCRIPTION_SEED,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use identity_registry::{IdentityAccount, IdentityRegistryAccount};
@@ -33,13 +37,29 @@ pub struct EnforcePolicyIssuanceAccounts<'info> {
     #[account(has_one = identity_registry)]
     pub identity_account: Box<Account<'info, IdentityAccount>>,
     #[account(mut,
-        realloc = 8 + TrackerAccount::get_current_space(&destination_tracker_account) + Issuance::INIT_SPACE,
+        realloc = 8 + TrackerAccount::get_current_space(&destination_tracker_account) + Issuance::INIT_SPACE
+            + tranche.as_ref().map_or(0, |tranche| tranche.get_lock_space()),
         realloc::zero = false,
         realloc::payer = payer,
         has_one = identity_account
     )]
     pub destination_tracker_account: Box<Account<'info, TrackerAccount>>,
     pub system_program: Program<'info, System>,
+    /// tranche the issuance is made against, if any
+    #[account(mut, has_one = policy_engine)]
+    pub tranche: Option<Box<Account<'info, TrancheAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TrancheSubscriptionAccount::INIT_SPACE,
+        seeds = [
+            TRANCHE_SUBSCRIPTION_SEED,
+            tranche.as_ref().map_or(Pubkey::default(), |tranche| tranche.key()).as_ref(),
+            identity_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub tranche_subscription: Option<Box<Account<'info, TrancheSubscriptionAccount>>>,
 }
 
 pub fn handler(
@@ -54,24 +74,77 @@ pub fn handler(
         Clock::get()?.unix_timestamp,
     );
 
+    tracker_account.checkpoint(&ctx.accounts.policy_engine.snapshot);
     tracker_account.new_issuance(amount, issuance_timestamp)?;
 
+    if let Some(tranche) = ctx.accounts.tranche.as_mut() {
+        let subscription = ctx
+            .accounts
+            .tranche_subscription
+            .as_mut()
+            .ok_or(PolicyEngineErrors::TrancheSubscriptionMissing)?;
+        if subscription.version == 0 {
+            subscription.version = TrancheSubscriptionAccount::VERSION;
+            subscription.tranche = tranche.key();
+            subscription.identity_account = ctx.accounts.identity_account.key();
+        }
+        ctx.accounts
+            .policy_engine
+            .enforce_filters_single(
+                &ctx.accounts.identity_account.levels,
+                ctx.accounts.identity_account.country,
+                &tranche.identity_filter,
+            )
+            .map_err(|_| PolicyEngineErrors::TrancheIdentityNotEligible)?;
+        tranche.subscribe(subscription, amount, Clock::get()?.unix_timestamp)?;
+        if tranche.lock_period > 0 {
+            let release_timestamp = issuance_timestamp
+                .checked_add(tranche.lock_period)
+                .ok_or(PolicyEngineErrors::BalanceOverflow)?;
+            tracker_account.add_lock(
+                amount,
+                release_timestamp,
+                TRANCHE_LOCK_REASON,
+                tranche.key().to_string(),
+            )?;
+            emit!(LockEvent {
+                amount,
+                release_timestamp,
+                reason: TRANCHE_LOCK_REASON,
+                reason_string: tranche.key().to_string(),
+                identity: ctx.accounts.identity_account.key(),
+            });
+        }
+    }
+
     if tracker_account.total_amount == amount {
+        ctx.accounts.policy_engine.record_holders_change();
         let changed_counters = ctx.accounts.policy_engine.increase_holders_count(
             &ctx.accounts.identity_account.levels,
             ctx.accounts.identity_account.country,
+            ctx.accounts.identity_account.beneficial_owners,
+        )?;
+        ctx.accounts.policy_engine.enforce_counters_on_increment(
+            &changed_counters,
+            ctx.accounts.identity_account.country,
         )?;
-        ctx.accounts
-            .policy_engine
-            .enforce_counters_on_increment(&changed_counters)?;
     }
 
+    // remaining accounts are the issuance policy pages
+    let pages = load_policy_pages(
+        &ctx.accounts.policy_engine,
+        ctx.accounts.policy_engine.key(),
+        ctx.remaining_accounts,
+        PolicyPageScope::Issuance,
+    )?;
+
     // evaluate policies
     ctx.accounts.policy_engine.enforce_policy_issuance(
         ctx.accounts.asset_mint.supply,
         &ctx.accounts.identity_account.levels,
         ctx.accounts.identity_account.country,
         Some(&tracker_account),
+        &pages,
     )?;
     Ok(issuance_timestamp)
 }
@@ -0,0 +1,54 @@
+use crate::{
+    Lock, LockEvent, PolicyEngineErrors, TrackerAccount, ASSET_CONTROLLER_ID,
+    REDEMPTION_LOCK_REASON,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+
+#[derive(Accounts)]
+pub struct LockOnRedemptionAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        seeds::program = ASSET_CONTROLLER_ID,
+    )]
+    pub asset_controller: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut,
+        realloc = 8 + TrackerAccount::get_current_space(&tracker_account) + Lock::INIT_SPACE,
+        realloc::zero = false,
+        realloc::payer = payer,
+        has_one = identity_account
+    )]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// locks the amount of a redemption request until the issuer settles it or the holder cancels it
+pub fn handler(ctx: Context<LockOnRedemptionAccounts>, amount: u64, request: Pubkey) -> Result<()> {
+    let tracker_account: &mut TrackerAccount = &mut ctx.accounts.tracker_account;
+
+    require!(
+        tracker_account.get_transferable_balance(Clock::get()?.unix_timestamp)? >= amount,
+        PolicyEngineErrors::TokensLocked
+    );
+
+    tracker_account.add_lock(amount, 0, REDEMPTION_LOCK_REASON, request.to_string())?;
+
+    emit!(LockEvent {
+        amount,
+        release_timestamp: 0,
+        reason: REDEMPTION_LOCK_REASON,
+        reason_string: request.to_string(),
+        identity: ctx.accounts.identity_account.key(),
+    });
+
+    Ok(())
+}
@@ -1,13 +1,27 @@
 pub mod enforce_policy_on_levels_change;
 pub mod engine;
 pub mod execute;
+pub mod governance;
 pub mod issue;
+pub mod lock_on_redemption;
+pub mod move_issuances_on_seize;
+pub mod open_snapshot_on_distribution;
+pub mod take_issuances_on_conversion;
 pub mod tracker;
+pub mod unlock_on_redemption;
 pub mod update_counters_on_burn;
+pub mod update_counters_on_seize;
 
 pub use enforce_policy_on_levels_change::*;
 pub use engine::*;
 pub use execute::*;
+pub use governance::*;
 pub use issue::*;
+pub use lock_on_redemption::*;
+pub use move_issuances_on_seize::*;
+pub use open_snapshot_on_distribution::*;
+pub use take_issuances_on_conversion::*;
 pub use tracker::*;
+pub use unlock_on_redemption::*;
 pub use update_counters_on_burn::*;
+pub use update_counters_on_seize::*;
@@ -0,0 +1,59 @@
+use crate::{resize_account, Issuance, PolicyEngineAccount, TrackerAccount};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct MoveIssuancesOnSeizeAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub asset_controller: Signer<'info>,
+    #[account(
+        token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+        mint::authority = asset_controller
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: authority of the policy engine, receives the rent freed by the shrinking tracker
+    #[account(mut, address = policy_engine.authority)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    #[account(mut, has_one = asset_mint)]
+    pub source_tracker_account: Box<Account<'info, TrackerAccount>>,
+    #[account(
+        mut,
+        has_one = asset_mint,
+        constraint = destination_tracker_account.key() != source_tracker_account.key()
+    )]
+    pub destination_tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// moves the issuances backing a seized amount to the destination so holding periods carry over.
+/// the newest lots are taken first, see `TrackerAccount::take_issuances`. returns the amount of issuances moved.
+pub fn handler(ctx: Context<MoveIssuancesOnSeizeAccounts>, amount: u64) -> Result<u64> {
+    let source_issuances_count = ctx.accounts.source_tracker_account.issuances.len();
+    let issuances = ctx.accounts.source_tracker_account.take_issuances(amount);
+    let moved_amount = issuances.iter().map(|issuance| issuance.amount).sum();
+    let moved_issuances_count = issuances.len();
+    ctx.accounts
+        .destination_tracker_account
+        .add_issuances(issuances);
+
+    let removed_issuances_count =
+        source_issuances_count - ctx.accounts.source_tracker_account.issuances.len();
+    resize_account(
+        &ctx.accounts.source_tracker_account.to_account_info(),
+        &ctx.accounts.rent_receiver.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        -((removed_issuances_count * Issuance::INIT_SPACE) as i32),
+    )?;
+    resize_account(
+        &ctx.accounts.destination_tracker_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        (moved_issuances_count * Issuance::INIT_SPACE) as i32,
+    )?;
+
+    Ok(moved_amount)
+}
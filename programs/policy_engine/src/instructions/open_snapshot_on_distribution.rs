@@ -0,0 +1,35 @@
+use crate::{OpenedSnapshotEvent, PolicyEngineAccount, ASSET_CONTROLLER_ID};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct OpenSnapshotOnDistributionAccounts<'info> {
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        seeds::program = ASSET_CONTROLLER_ID,
+    )]
+    pub asset_controller: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+}
+
+/// opens the record date snapshot of a distribution, kept readable until the distribution expires.
+/// returns the id of the snapshot.
+pub fn handler(ctx: Context<OpenSnapshotOnDistributionAccounts>, expiry: i64) -> Result<u64> {
+    let record_timestamp = Clock::get()?.unix_timestamp;
+    let supply = ctx.accounts.asset_mint.supply;
+    let snapshot = &mut ctx.accounts.policy_engine.snapshot;
+    snapshot.open(record_timestamp, supply)?;
+    snapshot.reserve(expiry);
+
+    emit!(OpenedSnapshotEvent {
+        mint: ctx.accounts.asset_mint.key(),
+        id: snapshot.id,
+        record_timestamp,
+        supply,
+    });
+
+    Ok(snapshot.id)
+}
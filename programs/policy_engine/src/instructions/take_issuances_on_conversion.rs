@@ -0,0 +1,39 @@
+use crate::{resize_account, Issuance, PolicyEngineAccount, TrackerAccount};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct TakeIssuancesOnConversionAccounts<'info> {
+    pub asset_controller: Signer<'info>,
+    #[account(
+        token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+        mint::authority = asset_controller
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: authority of the policy engine, receives the rent freed by the shrinking tracker
+    #[account(mut, address = policy_engine.authority)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    #[account(mut, has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// removes the issuances backing a converted amount from the tracker of the burnt shares.
+/// returns the removed issuances so their holding periods can carry over to the converted shares.
+pub fn handler(ctx: Context<TakeIssuancesOnConversionAccounts>, amount: u64) -> Result<Vec<Issuance>> {
+    let issuances_count = ctx.accounts.tracker_account.issuances.len();
+    let issuances = ctx.accounts.tracker_account.take_issuances(amount);
+
+    let removed_issuances_count = issuances_count - ctx.accounts.tracker_account.issuances.len();
+    resize_account(
+        &ctx.accounts.tracker_account.to_account_info(),
+        &ctx.accounts.rent_receiver.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        -((removed_issuances_count * Issuance::INIT_SPACE) as i32),
+    )?;
+
+    Ok(issuances)
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::{PolicyEngineAccount, TrackerAccount};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct GetSnapshotBalance<'info> {
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: checked through the policy engine and the tracker
+    pub asset_mint: UncheckedAccount<'info>,
+    #[account(has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+}
+
+pub fn handler(ctx: Context<GetSnapshotBalance>, snapshot_id: u64) -> Result<u64> {
+    ctx.accounts
+        .tracker_account
+        .get_snapshot_balance(&ctx.accounts.policy_engine.snapshot, snapshot_id)
+}
@@ -0,0 +1,26 @@
+use crate::{PolicyEngineErrors, TrackerAccount, TrackerAccountV1};
+use anchor_lang::prelude::*;
+use rwa_utils::migrate_account;
+
+#[derive(Accounts)]
+pub struct MigrateTrackerAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: version 1 tracker, checked by the migration
+    #[account(mut)]
+    pub tracker_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// rewrites a version 1 tracker to the current layout, callable by anyone as the balances are kept as is
+pub fn handler(ctx: Context<MigrateTrackerAccount>) -> Result<()> {
+    migrate_account(
+        &ctx.accounts.tracker_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &crate::id(),
+        PolicyEngineErrors::InvalidAccountVersion,
+        |tracker: TrackerAccountV1| Ok(TrackerAccount::from(tracker)),
+    )?;
+    Ok(())
+}
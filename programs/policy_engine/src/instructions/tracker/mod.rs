@@ -1,9 +1,15 @@
 pub mod add_lock;
 pub mod remove_lock;
+pub mod remove_expired_tranche_locks;
 pub mod create;
 pub mod close;
+pub mod get_snapshot_balance;
+pub mod migrate_tracker_account;
 
 pub use add_lock::*;
 pub use remove_lock::*;
+pub use remove_expired_tranche_locks::*;
 pub use create::*;
 pub use close::*;
+pub use get_snapshot_balance::*;
+pub use migrate_tracker_account::*;
@@ -0,0 +1,47 @@
+use crate::{resize_account, Lock, PolicyEngineAccount, TrackerAccount, UnlockEvent, TRANCHE_LOCK_REASON};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RemoveExpiredTrancheLocksAccounts<'info> {
+    #[account()]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    /// CHECK: authority of the policy engine, receives the rent freed by the tracker
+    #[account(mut, address = policy_engine.authority)]
+    pub rent_receiver: UncheckedAccount<'info>,
+    #[account(mut, has_one = asset_mint)]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// removes the tranche locks released before now, callable by anyone as expired locks no longer restrict transfers
+pub fn handler(ctx: Context<RemoveExpiredTrancheLocksAccounts>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let identity = ctx.accounts.tracker_account.identity_account;
+    let locks = ctx
+        .accounts
+        .tracker_account
+        .remove_expired_locks_by_reason(TRANCHE_LOCK_REASON, timestamp);
+
+    resize_account(
+        &ctx.accounts.tracker_account.to_account_info(),
+        &ctx.accounts.rent_receiver.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        -((locks.len() * Lock::INIT_SPACE) as i32),
+    )?;
+
+    for lock in locks {
+        emit_cpi!(UnlockEvent {
+            amount: lock.amount,
+            release_timestamp: lock.release_time,
+            reason: lock.reason,
+            reason_string: lock.reason_string,
+            identity,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::{Lock, TrackerAccount, UnlockEvent, ASSET_CONTROLLER_ID, REDEMPTION_LOCK_REASON};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+
+#[derive(Accounts)]
+pub struct UnlockOnRedemptionAccounts<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [asset_mint.key().as_ref()],
+        bump,
+        seeds::program = ASSET_CONTROLLER_ID,
+    )]
+    pub asset_controller: Signer<'info>,
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(mut,
+        realloc = 8 + TrackerAccount::get_current_space(&tracker_account) - Lock::INIT_SPACE,
+        realloc::zero = false,
+        realloc::payer = payer,
+        has_one = identity_account
+    )]
+    pub tracker_account: Box<Account<'info, TrackerAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// releases the lock of a redemption request once it is settled or cancelled
+pub fn handler(ctx: Context<UnlockOnRedemptionAccounts>, request: Pubkey) -> Result<()> {
+    let tracker_account: &mut TrackerAccount = &mut ctx.accounts.tracker_account;
+
+    let lock = tracker_account.remove_lock_by_reason(REDEMPTION_LOCK_REASON, &request.to_string())?;
+
+    emit!(UnlockEvent {
+        amount: lock.amount,
+        release_timestamp: lock.release_time,
+        reason: lock.reason,
+        reason_string: lock.reason_string,
+        identity: ctx.accounts.identity_account.key(),
+    });
+
+    Ok(())
+}
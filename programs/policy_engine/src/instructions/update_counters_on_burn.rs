@@ -32,12 +32,21 @@ pub struct UpdateCountersOnBurnAccounts<'info> {
 pub fn handler(ctx: Context<UpdateCountersOnBurnAccounts>, amount: u64) -> Result<()> {
     let tracker_account: &mut TrackerAccount = &mut ctx.accounts.destination_tracker_account;
 
+    tracker_account.checkpoint(&ctx.accounts.policy_engine.snapshot);
     tracker_account.update_balance_burn(amount)?;
 
+    ctx.accounts.policy_engine.enforce_policy_on_burn(
+        &ctx.accounts.identity_account.levels,
+        ctx.accounts.identity_account.country,
+        tracker_account.total_amount,
+    )?;
+
     if tracker_account.total_amount == 0u64 {
+        ctx.accounts.policy_engine.record_holders_change();
         let changed_counters = ctx.accounts.policy_engine.decrease_holders_count(
             &ctx.accounts.identity_account.levels,
             ctx.accounts.identity_account.country,
+            ctx.accounts.identity_account.beneficial_owners,
         )?;
         ctx.accounts
             .policy_engine
@@ -0,0 +1,88 @@
+use crate::{PolicyEngineAccount, TrackerAccount};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use identity_registry::{IdentityAccount, IdentityRegistryAccount};
+
+/// holder counters changed by a seize
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct SeizeCounterChanges {
+    pub decreased_counters: Vec<u8>,
+    pub increased_counters: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct UpdateCountersOnSeizeAccounts<'info> {
+    pub asset_controller: Signer<'info>,
+    #[account(
+        token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+        mint::authority = asset_controller
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, has_one = asset_mint)]
+    pub policy_engine: Box<Account<'info, PolicyEngineAccount>>,
+    #[account(has_one = asset_mint)]
+    pub identity_registry: Box<Account<'info, IdentityRegistryAccount>>,
+    #[account(has_one = identity_registry)]
+    pub source_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(has_one = identity_registry)]
+    pub destination_identity_account: Box<Account<'info, IdentityAccount>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref(), source_identity_account.key().as_ref()],
+        bump,
+    )]
+    pub source_tracker_account: Box<Account<'info, TrackerAccount>>,
+    #[account(
+        seeds = [asset_mint.key().as_ref(), destination_identity_account.key().as_ref()],
+        bump,
+    )]
+    pub destination_tracker_account: Box<Account<'info, TrackerAccount>>,
+}
+
+/// called after the seize transfer, trackers already hold the post transfer balances
+pub fn handler(
+    ctx: Context<UpdateCountersOnSeizeAccounts>,
+    amount: u64,
+    enforce_limits: bool,
+) -> Result<SeizeCounterChanges> {
+    let mut changes = SeizeCounterChanges::default();
+
+    if ctx.accounts.source_identity_account.key() == ctx.accounts.destination_identity_account.key() {
+        return Ok(changes);
+    }
+
+    let source_identity = &ctx.accounts.source_identity_account;
+    let destination_identity = &ctx.accounts.destination_identity_account;
+    let engine = ctx.accounts.policy_engine.as_mut();
+
+    if ctx.accounts.source_tracker_account.total_amount == 0 {
+        engine.record_holders_change();
+        changes.decreased_counters = engine.decrease_holders_count(
+            &source_identity.levels,
+            source_identity.country,
+            source_identity.beneficial_owners,
+        )?;
+    }
+    if ctx.accounts.destination_tracker_account.total_amount == amount {
+        engine.record_holders_change();
+        changes.increased_counters = engine.increase_holders_count(
+            &destination_identity.levels,
+            destination_identity.country,
+            destination_identity.beneficial_owners,
+        )?;
+    }
+
+    if enforce_limits {
+        if !changes.decreased_counters.is_empty() {
+            engine.enforce_counters_on_decrement(&changes.decreased_counters)?;
+        }
+        if !changes.increased_counters.is_empty() {
+            engine.enforce_counters_on_increment(
+                &changes.increased_counters,
+                destination_identity.country,
+            )?;
+        }
+    }
+
+    Ok(changes)
+}
@@ -37,8 +37,35 @@ pub mod policy_engine {
         identity_filter: IdentityFilter,
         policy_type: PolicyType,
         custom_error: u8,
+        enforcement_mode: EnforcementMode,
+        applicability: Option<PolicyApplicability>,
     ) -> Result<()> {
-        instructions::engine::attach::handler(ctx, identity_filter, policy_type, custom_error)
+        instructions::engine::attach::handler(
+            ctx,
+            identity_filter,
+            policy_type,
+            custom_error,
+            enforcement_mode,
+            applicability,
+        )
+    }
+
+    /// switch a policy between enforce, monitor and disabled
+    pub fn change_policy_enforcement_mode(
+        ctx: Context<ChangePolicyEnforcementMode>,
+        hash: String,
+        enforcement_mode: EnforcementMode,
+    ) -> Result<()> {
+        instructions::engine::change_policy_enforcement_mode::handler(ctx, hash, enforcement_mode)
+    }
+
+    /// choose the lifecycle events a policy is evaluated on
+    pub fn change_policy_applicability(
+        ctx: Context<ChangePolicyApplicability>,
+        hash: String,
+        applicability: PolicyApplicability,
+    ) -> Result<()> {
+        instructions::engine::change_policy_applicability::handler(ctx, hash, applicability)
     }
 
     /// remove policy
@@ -49,6 +76,99 @@ pub mod policy_engine {
         instructions::engine::detach::handler(ctx, hash)
     }
 
+    /// create an overflow page holding policies evaluated by the given scope
+    pub fn create_policy_page(
+        ctx: Context<CreatePolicyPage>,
+        index: u8,
+        scope: PolicyPageScope,
+    ) -> Result<()> {
+        instructions::engine::create_policy_page::handler(ctx, index, scope)
+    }
+
+    /// close an empty policy page
+    pub fn close_policy_page(ctx: Context<ClosePolicyPage>) -> Result<()> {
+        instructions::engine::close_policy_page::handler(ctx)
+    }
+
+    /// attach a policy to a policy page
+    pub fn attach_to_policy_page(
+        ctx: Context<AttachToPolicyPage>,
+        identity_filter: IdentityFilter,
+        policy_type: PolicyType,
+        custom_error: u8,
+        enforcement_mode: EnforcementMode,
+        applicability: Option<PolicyApplicability>,
+    ) -> Result<()> {
+        instructions::engine::attach_to_policy_page::handler(
+            ctx,
+            identity_filter,
+            policy_type,
+            custom_error,
+            enforcement_mode,
+            applicability,
+        )
+    }
+
+    /// remove a policy from a policy page
+    pub fn detach_from_policy_page(ctx: Context<DetachFromPolicyPage>, hash: String) -> Result<()> {
+        instructions::engine::detach_from_policy_page::handler(ctx, hash)
+    }
+
+    /// create an offering tranche issuances can be made against
+    pub fn create_tranche(ctx: Context<CreateTranche>, id: u64, config: TrancheConfig) -> Result<()> {
+        instructions::engine::create_tranche::handler(ctx, id, config)
+    }
+
+    /// change the window, caps, eligibility and lock period of a tranche
+    pub fn change_tranche(ctx: Context<ChangeTranche>, config: TrancheConfig) -> Result<()> {
+        instructions::engine::change_tranche::handler(ctx, config)
+    }
+
+    /// close a tranche
+    pub fn close_tranche(ctx: Context<CloseTranche>) -> Result<()> {
+        instructions::engine::close_tranche::handler(ctx)
+    }
+
+    /// open a record date snapshot of the holder balances at the current time
+    pub fn open_snapshot(ctx: Context<OpenSnapshot>) -> Result<()> {
+        instructions::engine::open_snapshot::handler(ctx)
+    }
+
+    /// create a proposal voted with the balances at the record date of the latest snapshot
+    pub fn create_proposal(ctx: Context<CreateProposal>, id: u64, config: ProposalConfig) -> Result<()> {
+        instructions::governance::create_proposal::handler(ctx, id, config)
+    }
+
+    /// vote the record date balance of an identity, owned or delegated to the voter
+    pub fn cast_vote(ctx: Context<CastVote>, option: u8) -> Result<()> {
+        instructions::governance::cast_vote::handler(ctx, option)
+    }
+
+    /// end a proposal after its voting window and emit the final tally
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        instructions::governance::finalize_proposal::handler(ctx)
+    }
+
+    /// close a finalized proposal
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::governance::close_proposal::handler(ctx)
+    }
+
+    /// delegate the voting power of an identity to another identity
+    pub fn delegate_votes(ctx: Context<DelegateVotes>) -> Result<()> {
+        instructions::governance::delegate_votes::handler(ctx)
+    }
+
+    /// revoke the delegation of the voting power of an identity
+    pub fn undelegate_votes(ctx: Context<UndelegateVotes>) -> Result<()> {
+        instructions::governance::undelegate_votes::handler(ctx)
+    }
+
+    /// rewrite the extra metas of an existing mint to the current account list
+    pub fn update_extra_metas(ctx: Context<UpdateExtraMetas>) -> Result<()> {
+        instructions::engine::update_extra_metas::handler(ctx)
+    }
+
     /// create tracker account
     pub fn create_tracker_account(ctx: Context<CreateTrackerAccount>, owner: Pubkey) -> Result<()> {
         instructions::tracker::create::handler(ctx, owner)
@@ -81,16 +201,62 @@ pub mod policy_engine {
         instructions::update_counters_on_burn::handler(ctx, amount)
     }
 
+    pub fn update_counters_on_seize(
+        ctx: Context<UpdateCountersOnSeizeAccounts>,
+        amount: u64,
+        enforce_limits: bool,
+    ) -> Result<SeizeCounterChanges> {
+        instructions::update_counters_on_seize::handler(ctx, amount, enforce_limits)
+    }
+
+    pub fn move_issuances_on_seize(
+        ctx: Context<MoveIssuancesOnSeizeAccounts>,
+        amount: u64,
+    ) -> Result<u64> {
+        instructions::move_issuances_on_seize::handler(ctx, amount)
+    }
+
+    pub fn take_issuances_on_conversion(
+        ctx: Context<TakeIssuancesOnConversionAccounts>,
+        amount: u64,
+    ) -> Result<Vec<Issuance>> {
+        instructions::take_issuances_on_conversion::handler(ctx, amount)
+    }
+
+    pub fn lock_on_redemption(
+        ctx: Context<LockOnRedemptionAccounts>,
+        amount: u64,
+        request: Pubkey,
+    ) -> Result<()> {
+        instructions::lock_on_redemption::handler(ctx, amount, request)
+    }
+
+    pub fn unlock_on_redemption(
+        ctx: Context<UnlockOnRedemptionAccounts>,
+        request: Pubkey,
+    ) -> Result<()> {
+        instructions::unlock_on_redemption::handler(ctx, request)
+    }
+
+    pub fn open_snapshot_on_distribution(
+        ctx: Context<OpenSnapshotOnDistributionAccounts>,
+        expiry: i64,
+    ) -> Result<u64> {
+        instructions::open_snapshot_on_distribution::handler(ctx, expiry)
+    }
+
     pub fn enforce_policy_on_levels_change(
         ctx: Context<EnforcePolicyOnLevelsChange>,
         new_levels: NewLevelsArgs,
         new_country: u8,
+        new_beneficial_owners: u32,
         enforce_limits: bool,
     ) -> Result<()> {
         instructions::enforce_policy_on_levels_change::handler(
             ctx,
             new_levels,
             new_country,
+            new_beneficial_owners,
             enforce_limits,
         )
     }
@@ -99,19 +265,73 @@ pub mod policy_engine {
         ctx: Context<ChangeCounters>,
         removed_counters: Vec<u8>,
         added_counters: Vec<Counter>,
+        skip_limit_validation: bool,
+    ) -> Result<()> {
+        instructions::engine::change_counters::handler(
+            ctx,
+            removed_counters,
+            added_counters,
+            skip_limit_validation,
+        )
+    }
+
+    /// add or remove counters keeping one holder count per country group
+    pub fn change_country_counters(
+        ctx: Context<ChangeCountryCounters>,
+        removed_country_counters: Vec<u8>,
+        added_country_counters: Vec<CountryCounter>,
+        skip_limit_validation: bool,
     ) -> Result<()> {
-        instructions::engine::change_counters::handler(ctx, removed_counters, added_counters)
+        instructions::engine::change_country_counters::handler(
+            ctx,
+            removed_country_counters,
+            added_country_counters,
+            skip_limit_validation,
+        )
+    }
+
+    /// start recomputing the counters from the tracker accounts
+    pub fn start_reconciliation(ctx: Context<StartReconciliation>) -> Result<()> {
+        instructions::engine::start_reconciliation::handler(ctx)
+    }
+
+    /// count a batch of (identity account, tracker account) pairs passed as remaining accounts
+    pub fn reconcile_counters(ctx: Context<ReconcileCounters>) -> Result<()> {
+        instructions::engine::reconcile_counters::handler(ctx)
+    }
+
+    /// overwrite the counters with the reconciled values
+    pub fn commit_reconciliation(ctx: Context<CommitReconciliation>) -> Result<()> {
+        instructions::engine::commit_reconciliation::handler(ctx)
+    }
+
+    pub fn cancel_reconciliation(ctx: Context<CancelReconciliation>) -> Result<()> {
+        instructions::engine::cancel_reconciliation::handler(ctx)
     }
 
     pub fn change_counter_limits(
         ctx: Context<ChangeCounterLimits>,
         removed_counter_limits: Vec<u8>,
         added_counter_limits: Vec<CounterLimit>,
+        skip_limit_validation: bool,
     ) -> Result<()> {
         instructions::engine::change_counter_limits::handler(
             ctx,
             removed_counter_limits,
             added_counter_limits,
+            skip_limit_validation,
+        )
+    }
+
+    pub fn change_counter_limit_enforcement_mode(
+        ctx: Context<ChangeCounterLimitEnforcementMode>,
+        index: u8,
+        enforcement_mode: EnforcementMode,
+    ) -> Result<()> {
+        instructions::engine::change_counter_limit_enforcement_mode::handler(
+            ctx,
+            index,
+            enforcement_mode,
         )
     }
 
@@ -130,12 +350,33 @@ pub mod policy_engine {
         instructions::engine::change_issuance_policies::handler(ctx, issuance_policies)
     }
 
+    /// change the delay of queued changes and whether direct changes are allowed
+    pub fn change_timelock(ctx: Context<ChangeTimelock>, timelock: Timelock) -> Result<()> {
+        instructions::engine::change_timelock::handler(ctx, timelock)
+    }
+
+    /// queue a configuration change, executable once the timelock delay has passed
+    pub fn propose_change(ctx: Context<ProposeChange>, change: PolicyEngineChange) -> Result<()> {
+        instructions::engine::propose_change::handler(ctx, change)
+    }
+
+    /// drop a queued configuration change
+    pub fn cancel_change(ctx: Context<CancelChange>) -> Result<()> {
+        instructions::engine::cancel_change::handler(ctx)
+    }
+
+    /// apply a queued configuration change
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        instructions::engine::execute_change::handler(ctx)
+    }
+
     pub fn set_counters(
         ctx: Context<SetCounters>,
         changed_counters: Vec<u8>,
         values: Vec<u64>,
+        skip_limit_validation: bool,
     ) -> Result<()> {
-        instructions::engine::set_counters::handler(ctx, changed_counters, values)
+        instructions::engine::set_counters::handler(ctx, changed_counters, values, skip_limit_validation)
     }
 
     pub fn add_lock(ctx: Context<AddLockAccounts>, amount: u64, release_timestamp: i64, reason: u64, reason_string: String) -> Result<()> {
@@ -145,4 +386,22 @@ pub mod policy_engine {
     pub fn remove_lock(ctx: Context<RemoveLockAccounts>, index: u8) -> Result<()> {
         instructions::tracker::remove_lock::handler(ctx, index)
     }
+
+    pub fn remove_expired_tranche_locks(ctx: Context<RemoveExpiredTrancheLocksAccounts>) -> Result<()> {
+        instructions::tracker::remove_expired_tranche_locks::handler(ctx)
+    }
+
+    /// balance of a tracker at the record date of a snapshot
+    pub fn get_snapshot_balance(ctx: Context<GetSnapshotBalance>, snapshot_id: u64) -> Result<u64> {
+        instructions::tracker::get_snapshot_balance::handler(ctx, snapshot_id)
+    }
+
+    /// migrations of the accounts created by version 1 of the program
+    pub fn migrate_policy_engine(ctx: Context<MigratePolicyEngine>) -> Result<()> {
+        instructions::engine::migrate_policy_engine::handler(ctx)
+    }
+
+    pub fn migrate_tracker_account(ctx: Context<MigrateTrackerAccount>) -> Result<()> {
+        instructions::tracker::migrate_tracker_account::handler(ctx)
+    }
 }
@@ -3,9 +3,15 @@ use identity_registry::IdentityLevel;
 use num_enum::IntoPrimitive;
 use serde::{Deserialize, Serialize};
 
-use crate::{get_custom_error, PolicyEngineErrors};
+use crate::{
+    resolve_custom_error, CounterLimitViolationEvent, CounterLimitWarningEvent, PolicyEngineErrors,
+    PolicyViolationEvent,
+};
 
-use super::TrackerAccount;
+use super::{
+    PolicyEngineChange, PolicyPageAccount, PolicyPageRef, PolicyPageScope, Snapshot, TrackerAccount,
+    MAX_TRANSFER_POLICY_PAGES,
+};
 
 #[repr(u8)]
 #[derive(
@@ -97,6 +103,29 @@ pub enum FilterComparison {
     And,
 }
 
+/// how a policy or counter limit reacts when it is violated
+#[repr(u8)]
+#[derive(
+    IntoPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+    Clone,
+    InitSpace,
+    Copy,
+    Debug,
+    Serialize,
+    Deserialize,
+    PartialEq,
+)]
+pub enum EnforcementMode {
+    /// violations fail the operation
+    Enforce,
+    /// violations are only reported through an event
+    Monitor,
+    /// the policy or limit is not evaluated
+    Disabled,
+}
+
 #[repr(u8)]
 #[derive(
     AnchorDeserialize, AnchorSerialize, Clone, InitSpace, Debug, Serialize, Deserialize, PartialEq,
@@ -171,6 +200,14 @@ pub struct PolicyEngineAccount {
     /// policies to apply on issuance
     /// these are partially for storage only
     pub issuance_policies: IssuancePolicies,
+    /// delay applied to proposed configuration changes
+    pub timelock: Timelock,
+    /// id assigned to the next proposed configuration change
+    pub next_change_id: u64,
+    /// record date snapshot checkpointed by the trackers
+    pub snapshot: Snapshot,
+    /// incremented when an identity starts or stops holding or the levels of a holder change, outdates running reconciliations
+    pub holders_sequence: u64,
     /// policies to check on transfers or balance changes
     #[max_len(0)]
     pub policies: Vec<Policy>,
@@ -180,21 +217,51 @@ pub struct PolicyEngineAccount {
     /// limits to apply on existing counters
     #[max_len(0)]
     pub counter_limits: Vec<CounterLimit>,
+    /// counters keeping one holder count per country group
+    #[max_len(0)]
+    pub country_counters: Vec<CountryCounter>,
+    /// overflow pages holding additional policies
+    #[max_len(0)]
+    pub policy_pages: Vec<PolicyPageRef>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
 pub struct IssuancePolicies {
     pub disallow_backdating: bool,
+    /// in base units, like the supply, before the scaled ui amount multiplier of the mint
     pub max_supply: u64,
     pub us_lock_period: i64,
     pub non_us_lock_period: i64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
+pub struct Timelock {
+    /// seconds between proposing a change and it becoming executable
+    pub delay: i64,
+    /// whether the authority can still change the engine without going through the queue
+    pub direct_changes_enabled: bool,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
 pub struct Counter {
     pub value: u64,
     pub id: u8,
+    /// counts every beneficial owner of an identity instead of the identity once
+    pub count_beneficial_owners: bool,
+    pub identity_filter: IdentityFilter,
+}
+
+/// holders counter keeping one value per country group, the group of an identity being `mapping[country]`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
+pub struct CountryCounter {
+    /// shares the id space of `counters`
+    pub id: u8,
+    /// counts every beneficial owner of an identity instead of the identity once
+    pub count_beneficial_owners: bool,
     pub identity_filter: IdentityFilter,
+    /// value of each country group in use, padded with zeros when added and when the mapping uses new groups
+    #[max_len(0)]
+    pub values: Vec<u64>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
@@ -203,41 +270,154 @@ pub enum CounterLimit {
         max: u64,
         min: u64,
         counter_id: u8,
+        enforcement_mode: EnforcementMode,
+        /// emits a warning event when the counter crosses this value, 0 to disable
+        warning_threshold: u64,
     },
     GroupedHoldersLimit {
         max: u64,
         min: u64,
         #[max_len(0)]
         counters: Vec<u8>,
+        enforcement_mode: EnforcementMode,
+        /// emits a warning event when any of the counters crosses this value, 0 to disable
+        warning_threshold: u64,
     },
     PercentageLimit {
         higher_counter_id: u8,
         lower_counter_id: u8,
         min_percentage: u8,
         max_percentage: u8,
+        enforcement_mode: EnforcementMode,
+        /// emits a warning event when the percentage crosses this value, 0 to disable
+        warning_percentage: u8,
+    },
+    CountryHoldersLimit {
+        max: u64,
+        counter_id: u8,
+        /// country groups the limit applies to, all groups if empty
+        #[max_len(0)]
+        groups: Vec<u8>,
+        enforcement_mode: EnforcementMode,
+        /// emits a warning event when any of the groups crosses this value, 0 to disable
+        warning_threshold: u64,
     },
 }
 
 impl CounterLimit {
     pub fn get_space(&self) -> usize {
         match self {
-            CounterLimit::GroupedHoldersLimit {
-                max: _,
-                min: _,
-                counters,
-            } => CounterLimit::INIT_SPACE + counters.len(),
+            CounterLimit::GroupedHoldersLimit { counters, .. } => {
+                CounterLimit::INIT_SPACE + counters.len()
+            }
+            CounterLimit::CountryHoldersLimit { groups, .. } => {
+                CounterLimit::INIT_SPACE + groups.len()
+            }
             _ => CounterLimit::INIT_SPACE,
         }
     }
+
+    pub fn enforcement_mode(&self) -> EnforcementMode {
+        match self {
+            CounterLimit::HoldersLimit {
+                enforcement_mode, ..
+            }
+            | CounterLimit::GroupedHoldersLimit {
+                enforcement_mode, ..
+            }
+            | CounterLimit::PercentageLimit {
+                enforcement_mode, ..
+            }
+            | CounterLimit::CountryHoldersLimit {
+                enforcement_mode, ..
+            } => *enforcement_mode,
+        }
+    }
+
+    pub fn set_enforcement_mode(&mut self, mode: EnforcementMode) {
+        match self {
+            CounterLimit::HoldersLimit {
+                enforcement_mode, ..
+            }
+            | CounterLimit::GroupedHoldersLimit {
+                enforcement_mode, ..
+            }
+            | CounterLimit::PercentageLimit {
+                enforcement_mode, ..
+            }
+            | CounterLimit::CountryHoldersLimit {
+                enforcement_mode, ..
+            } => *enforcement_mode = mode,
+        }
+    }
+
+    pub fn references(&self, counter_id: u8) -> bool {
+        match self {
+            CounterLimit::HoldersLimit { counter_id: id, .. }
+            | CounterLimit::CountryHoldersLimit { counter_id: id, .. } => *id == counter_id,
+            CounterLimit::GroupedHoldersLimit { counters, .. } => counters.contains(&counter_id),
+            CounterLimit::PercentageLimit {
+                higher_counter_id,
+                lower_counter_id,
+                ..
+            } => *higher_counter_id == counter_id || *lower_counter_id == counter_id,
+        }
+    }
+
+    /// fails with `error` when enforced, only emits a violation event when monitored
+    pub fn on_violation(&self, asset_mint: Pubkey, error: PolicyEngineErrors) -> Result<()> {
+        if self.enforcement_mode() == EnforcementMode::Enforce {
+            return Err(error.into());
+        }
+        emit!(CounterLimitViolationEvent {
+            mint: asset_mint,
+            counter_limit: self.clone(),
+            error_code: error.into(),
+        });
+        Ok(())
+    }
 }
 
 impl Counter {
+    /// weight of an identity matching this counter
+    pub fn get_weight(&self, beneficial_owners: u32) -> u64 {
+        if self.count_beneficial_owners {
+            beneficial_owners as u64
+        } else {
+            1
+        }
+    }
+
     pub fn get_space(&self) -> usize {
-        self.identity_filter.get_space() + 9
+        self.identity_filter.get_space() + 10
     }
 
     pub fn get_new_space(filter: &IdentityFilter) -> usize {
-        filter.get_space() + 9
+        filter.get_space() + 10
+    }
+}
+
+impl CountryCounter {
+    /// one group per possible mapping value
+    pub const MAX_GROUPS: usize = 256;
+
+    /// weight of an identity matching this counter
+    pub fn get_weight(&self, beneficial_owners: u32) -> u64 {
+        if self.count_beneficial_owners {
+            beneficial_owners as u64
+        } else {
+            1
+        }
+    }
+
+    pub fn get_space(&self) -> usize {
+        self.identity_filter.get_space() + 6 + 8 * self.values.len()
+    }
+
+    fn value_mut(&mut self, group: u8) -> Result<&mut u64> {
+        self.values
+            .get_mut(group as usize)
+            .ok_or(PolicyEngineErrors::CounterNotFound.into())
     }
 }
 
@@ -248,21 +428,137 @@ pub struct Policy {
     pub identity_filter: IdentityFilter,
     pub policy_type: PolicyType,
     pub custom_error: u8,
+    pub enforcement_mode: EnforcementMode,
+    pub applicability: PolicyApplicability,
 }
 
 impl Policy {
     pub fn get_space(&self) -> usize {
-        self.identity_filter.get_space() + PolicyType::INIT_SPACE + 69
+        self.identity_filter.get_space() + PolicyType::INIT_SPACE + PolicyApplicability::INIT_SPACE + 70
     }
 
     pub fn get_new_space(filter: &IdentityFilter) -> usize {
-        filter.get_space() + PolicyType::INIT_SPACE + 69
+        filter.get_space() + PolicyType::INIT_SPACE + PolicyApplicability::INIT_SPACE + 70
+    }
+
+    /// fails with `error` when enforced, only emits a violation event when monitored
+    pub fn on_violation(&self, asset_mint: Pubkey, error: PolicyEngineErrors) -> Result<()> {
+        if self.enforcement_mode == EnforcementMode::Enforce {
+            return Err(error.into());
+        }
+        emit!(PolicyViolationEvent {
+            mint: asset_mint,
+            hash: self.hash.clone(),
+            policy_type: self.policy_type.clone(),
+            error_code: error.into(),
+        });
+        Ok(())
+    }
+
+    /// same as `on_violation`, but lets the policy's custom error override the fallback error
+    pub fn on_custom_violation(
+        &self,
+        asset_mint: Pubkey,
+        fallback_error: PolicyEngineErrors,
+    ) -> Result<()> {
+        self.on_violation(
+            asset_mint,
+            resolve_custom_error(self.custom_error, fallback_error),
+        )
+    }
+}
+
+/// lifecycle events a policy can be evaluated on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PolicyEvent {
+    Transfer,
+    Issuance,
+    Burn,
+    Seize,
+    LevelsChange,
+}
+
+/// lifecycle events a policy is evaluated on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq)]
+pub struct PolicyApplicability {
+    pub transfer: bool,
+    pub issuance: bool,
+    /// burn and revoke
+    pub burn: bool,
+    /// transfers made by the permanent delegate
+    pub seize: bool,
+    /// levels, country and beneficial owners changes
+    pub levels_change: bool,
+}
+
+impl PolicyApplicability {
+    /// events each policy type was evaluated on before applicability was configurable
+    pub fn default_for(policy_type: &PolicyType) -> Self {
+        let balance_policy = matches!(
+            policy_type,
+            PolicyType::MaxBalance { .. } | PolicyType::MinBalance { .. } | PolicyType::MinMaxBalance { .. }
+        );
+        Self {
+            transfer: true,
+            issuance: balance_policy
+                || matches!(
+                    policy_type,
+                    PolicyType::IdentityApproval | PolicyType::ForbiddenIdentityGroup
+                ),
+            burn: false,
+            seize: false,
+            levels_change: balance_policy,
+        }
+    }
+
+    /// events with an evaluation for the policy type
+    pub fn supported_by(policy_type: &PolicyType) -> Self {
+        Self {
+            transfer: true,
+            issuance: Self::default_for(policy_type).issuance
+                || *policy_type == PolicyType::TransferPause,
+            burn: matches!(
+                policy_type,
+                PolicyType::MinBalance { .. } | PolicyType::MinMaxBalance { .. }
+            ),
+            seize: true,
+            levels_change: Self::default_for(policy_type).levels_change,
+        }
+    }
+
+    pub fn applies_to(&self, event: PolicyEvent) -> bool {
+        match event {
+            PolicyEvent::Transfer => self.transfer,
+            PolicyEvent::Issuance => self.issuance,
+            PolicyEvent::Burn => self.burn,
+            PolicyEvent::Seize => self.seize,
+            PolicyEvent::LevelsChange => self.levels_change,
+        }
+    }
+
+    /// true if every event enabled here is also enabled in `other`
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        (!self.transfer || other.transfer)
+            && (!self.issuance || other.issuance)
+            && (!self.burn || other.burn)
+            && (!self.seize || other.seize)
+            && (!self.levels_change || other.levels_change)
+    }
+
+    pub fn validate(&self, policy_type: &PolicyType) -> Result<()> {
+        require!(
+            self.is_subset_of(&Self::supported_by(policy_type)),
+            PolicyEngineErrors::InvalidPolicyApplicability
+        );
+        Ok(())
     }
 }
 
 #[derive(
     AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug, Serialize, Deserialize,
 )]
+/// amounts and limits are in base units, before the scaled ui amount multiplier of the mint.
+/// splits do not change the base units held, so the limits keep applying to the same holdings.
 pub enum PolicyType {
     IdentityApproval,
     TransactionAmountLimit { limit: u64 },
@@ -275,6 +571,9 @@ pub enum PolicyType {
     ForbiddenIdentityGroup,
     ForceFullTransfer,
     BlockFlowbackEndTime { time: i64 },
+    /// rule 144: outbound volume of the source over the rolling 3 month window is capped
+    /// at the greater of 1% of the supply and `amount`
+    AffiliateVolumeLimit { amount: u64 },
 }
 
 pub fn get_policy_engine_pda(asset_mint: Pubkey) -> Pubkey {
@@ -283,7 +582,9 @@ pub fn get_policy_engine_pda(asset_mint: Pubkey) -> Pubkey {
 }
 
 impl PolicyEngineAccount {
-    pub const VERSION: u8 = 1;
+    /// 2 added the timelock, snapshot, holders sequence, country counters and policy pages and changed the
+    /// counter and policy layouts, version 1 engines are migrated by `migrate_policy_engine`
+    pub const VERSION: u8 = 2;
     pub fn new(
         authority: Pubkey,
         asset_mint: Pubkey,
@@ -299,22 +600,217 @@ impl PolicyEngineAccount {
                 us_lock_period: 0,
                 non_us_lock_period: 0,
             },
+            timelock: Timelock {
+                delay: 0,
+                direct_changes_enabled: true,
+            },
+            next_change_id: 0,
+            snapshot: Snapshot::default(),
+            holders_sequence: 0,
             policies: vec![],
             counters: vec![],
             counter_limits: vec![],
+            country_counters: vec![],
+            policy_pages: vec![],
         }
     }
 
-    pub fn change_mapping(&mut self, mapping_source: Vec<u8>, mapping_value: Vec<u8>) {
+    /// records a holder change, outdating reconciliations started before it
+    pub fn record_holders_change(&mut self) {
+        self.holders_sequence = self.holders_sequence.wrapping_add(1);
+    }
+
+    /// country groups in use, up to the highest group of the mapping
+    pub fn get_group_count(&self) -> usize {
+        self.mapping.iter().max().map_or(1, |group| *group as usize + 1)
+    }
+
+    /// remaps countries and resizes the country counters to the groups in use, returns the account size difference.
+    /// the values of a group are not split by country, so a country can only leave a group without counted holders.
+    pub fn change_mapping(&mut self, mapping_source: Vec<u8>, mapping_value: Vec<u8>) -> Result<i32> {
+        let is_group_empty = |engine: &Self, group: usize| {
+            engine
+                .country_counters
+                .iter()
+                .all(|counter| counter.values.get(group).copied().unwrap_or_default() == 0)
+        };
         for (src, dst) in mapping_source.iter().zip(mapping_value.iter()) {
+            let previous_group = self.mapping[*src as usize];
+            require!(
+                previous_group == *dst || is_group_empty(self, previous_group as usize),
+                PolicyEngineErrors::CountryGroupNotEmpty
+            );
             self.mapping[*src as usize] = *dst;
         }
+
+        let group_count = self.get_group_count();
+        let mut space: i32 = 0;
+        for group in group_count..CountryCounter::MAX_GROUPS {
+            require!(is_group_empty(self, group), PolicyEngineErrors::CountryGroupNotEmpty);
+        }
+        for counter in self.country_counters.iter_mut() {
+            space += 8 * (group_count as i32 - counter.values.len() as i32);
+            counter.values.resize(group_count, 0);
+        }
+        Ok(space)
     }
 
     pub fn change_issuance_policies(&mut self, issuance_policies: IssuancePolicies) {
         self.issuance_policies = issuance_policies;
     }
 
+    pub fn change_timelock(&mut self, timelock: Timelock) -> Result<()> {
+        require!(timelock.delay >= 0, PolicyEngineErrors::InvalidTimelockDelay);
+        self.timelock = timelock;
+        Ok(())
+    }
+
+    /// applies a queued change, returns the account size difference
+    pub fn apply_change(&mut self, policy_account: Pubkey, change: PolicyEngineChange) -> Result<i32> {
+        match change {
+            PolicyEngineChange::AttachPolicy {
+                identity_filter,
+                policy_type,
+                custom_error,
+                enforcement_mode,
+                applicability,
+            } => {
+                let space = Policy::get_new_space(&identity_filter) as i32;
+                self.attach(
+                    policy_account,
+                    policy_type,
+                    identity_filter,
+                    custom_error,
+                    enforcement_mode,
+                    applicability,
+                )?;
+                Ok(space)
+            }
+            PolicyEngineChange::DetachPolicy { hash } => {
+                let space = self.get_policy_space(&hash)? as i32;
+                self.detach(hash)?;
+                Ok(-space)
+            }
+            PolicyEngineChange::ChangePolicyEnforcementMode { hash, enforcement_mode } => {
+                self.change_policy_enforcement_mode(&hash, enforcement_mode)?;
+                Ok(0)
+            }
+            PolicyEngineChange::ChangePolicyApplicability { hash, applicability } => {
+                self.change_policy_applicability(&hash, applicability)?;
+                Ok(0)
+            }
+            PolicyEngineChange::ChangeCounters {
+                removed_counters,
+                added_counters,
+                skip_limit_validation,
+            } => self.update_counters(removed_counters, added_counters, skip_limit_validation),
+            PolicyEngineChange::ChangeCountryCounters {
+                removed_country_counters,
+                added_country_counters,
+                skip_limit_validation,
+            } => self.update_country_counters(
+                removed_country_counters,
+                added_country_counters,
+                skip_limit_validation,
+            ),
+            PolicyEngineChange::ChangeCounterLimits {
+                removed_counter_limits,
+                added_counter_limits,
+                skip_limit_validation,
+            } => {
+                if removed_counter_limits.iter().any(|idx| *idx as usize >= self.counter_limits.len()) {
+                    return Err(PolicyEngineErrors::CounterLimitIndexNotFound.into());
+                }
+                self.update_counter_limits(
+                    removed_counter_limits,
+                    added_counter_limits,
+                    skip_limit_validation,
+                )
+            }
+            PolicyEngineChange::ChangeCounterLimitEnforcementMode { index, enforcement_mode } => {
+                self.change_counter_limit_enforcement_mode(index, enforcement_mode)?;
+                Ok(0)
+            }
+            PolicyEngineChange::ChangeMapping {
+                mapping_source,
+                mapping_value,
+            } => {
+                require!(
+                    mapping_source.len() == mapping_value.len(),
+                    PolicyEngineErrors::InvalidInstructionData
+                );
+                self.change_mapping(mapping_source, mapping_value)
+            }
+            PolicyEngineChange::ChangeIssuancePolicies { issuance_policies } => {
+                self.change_issuance_policies(issuance_policies);
+                Ok(0)
+            }
+            PolicyEngineChange::ChangeTimelock { timelock } => {
+                self.change_timelock(timelock)?;
+                Ok(0)
+            }
+            PolicyEngineChange::SetCounters {
+                changed_counters,
+                values,
+                skip_limit_validation,
+            } => {
+                require!(
+                    changed_counters.len() == values.len(),
+                    PolicyEngineErrors::InvalidInstructionData
+                );
+                self.set_counters(changed_counters, values, skip_limit_validation)?;
+                Ok(0)
+            }
+            // only the page references live on the engine, the pages are changed by the execution
+            PolicyEngineChange::CreatePolicyPage { index, scope } => {
+                self.add_policy_page(index, scope)?;
+                Ok(PolicyPageRef::INIT_SPACE as i32)
+            }
+            PolicyEngineChange::ClosePolicyPage { index } => {
+                self.remove_policy_page(index)?;
+                Ok(-(PolicyPageRef::INIT_SPACE as i32))
+            }
+            PolicyEngineChange::AttachToPolicyPage { index, .. }
+            | PolicyEngineChange::DetachFromPolicyPage { index, .. } => {
+                require!(
+                    self.policy_pages.iter().any(|page| page.index == index),
+                    PolicyEngineErrors::InvalidPolicyPage
+                );
+                Ok(0)
+            }
+        }
+    }
+
+    pub fn add_policy_page(&mut self, index: u8, scope: PolicyPageScope) -> Result<()> {
+        require!(
+            scope != PolicyPageScope::Transfer || index < MAX_TRANSFER_POLICY_PAGES,
+            PolicyEngineErrors::InvalidPolicyPageIndex
+        );
+        self.policy_pages.push(PolicyPageRef { index, scope });
+        Ok(())
+    }
+
+    pub fn remove_policy_page(&mut self, index: u8) -> Result<PolicyPageRef> {
+        let position = self
+            .policy_pages
+            .iter()
+            .position(|page| page.index == index)
+            .ok_or(PolicyEngineErrors::InvalidPolicyPage)?;
+        Ok(self.policy_pages.remove(position))
+    }
+
+    /// policies of the engine followed by the policies of the given pages, keeping the ones evaluated on `event`
+    fn policies_with_pages<'a>(
+        &'a self,
+        pages: &'a [PolicyPageAccount],
+        event: PolicyEvent,
+    ) -> impl Iterator<Item = &'a Policy> {
+        self.policies
+            .iter()
+            .chain(pages.iter().flat_map(|page| page.policies.iter()))
+            .filter(move |policy| policy.applicability.applies_to(event))
+    }
+
     pub fn get_policy_space(&self, hash: &str) -> Result<usize> {
         if let Some(index) = self.policies.iter().position(|policy| policy.hash == hash) {
             return Ok(self.policies[index].get_space());
@@ -348,20 +844,75 @@ impl PolicyEngineAccount {
         policy_type: PolicyType,
         identity_filter: IdentityFilter,
         custom_error: u8,
+        enforcement_mode: EnforcementMode,
+        applicability: Option<PolicyApplicability>,
     ) -> Result<()> {
         let hash = Self::hash_policy(policy_account, &policy_type, &identity_filter);
         if self.policies.iter().any(|policy| policy.hash == hash) {
             return Err(PolicyEngineErrors::PolicyAlreadyExists.into());
         }
+        let applicability =
+            applicability.unwrap_or_else(|| PolicyApplicability::default_for(&policy_type));
+        applicability.validate(&policy_type)?;
         self.policies.push(Policy {
             hash,
             identity_filter,
             policy_type,
             custom_error,
+            enforcement_mode,
+            applicability,
         });
         Ok(())
     }
 
+    /// returns the previous applicability of the policy
+    pub fn change_policy_applicability(
+        &mut self,
+        hash: &str,
+        applicability: PolicyApplicability,
+    ) -> Result<PolicyApplicability> {
+        let policy = self
+            .policies
+            .iter_mut()
+            .find(|policy| policy.hash == hash)
+            .ok_or(PolicyEngineErrors::PolicyNotFound)?;
+        applicability.validate(&policy.policy_type)?;
+        let previous_applicability = policy.applicability;
+        policy.applicability = applicability;
+        Ok(previous_applicability)
+    }
+
+    /// returns the previous enforcement mode of the policy
+    pub fn change_policy_enforcement_mode(
+        &mut self,
+        hash: &str,
+        enforcement_mode: EnforcementMode,
+    ) -> Result<EnforcementMode> {
+        let policy = self
+            .policies
+            .iter_mut()
+            .find(|policy| policy.hash == hash)
+            .ok_or(PolicyEngineErrors::PolicyNotFound)?;
+        let previous_enforcement_mode = policy.enforcement_mode;
+        policy.enforcement_mode = enforcement_mode;
+        Ok(previous_enforcement_mode)
+    }
+
+    /// returns the previous enforcement mode of the counter limit
+    pub fn change_counter_limit_enforcement_mode(
+        &mut self,
+        index: u8,
+        enforcement_mode: EnforcementMode,
+    ) -> Result<EnforcementMode> {
+        let counter_limit = self
+            .counter_limits
+            .get_mut(index as usize)
+            .ok_or(PolicyEngineErrors::CounterLimitIndexNotFound)?;
+        let previous_enforcement_mode = counter_limit.enforcement_mode();
+        counter_limit.set_enforcement_mode(enforcement_mode);
+        Ok(previous_enforcement_mode)
+    }
+
     pub fn detach(&mut self, hash: String) -> Result<Policy> {
         if let Some(index) = self.policies.iter().position(|policy| policy.hash == hash) {
             return Ok(self.policies.remove(index));
@@ -382,13 +933,17 @@ impl PolicyEngineAccount {
         identity: &[IdentityLevel],
         country: u8,
         tracker_account: Option<&TrackerAccount>,
+        pages: &[PolicyPageAccount],
     ) -> Result<()> {
         require!(
             self.issuance_policies.max_supply == 0 || self.issuance_policies.max_supply >= supply,
             PolicyEngineErrors::MaxSupplyExceeded
         );
 
-        for policy in self.policies.iter() {
+        for policy in self.policies_with_pages(pages, PolicyEvent::Issuance) {
+            if policy.enforcement_mode == EnforcementMode::Disabled {
+                continue;
+            }
             match &policy.policy_type {
                 PolicyType::IdentityApproval => {
                     if self.enforce_filters_single(
@@ -396,7 +951,7 @@ impl PolicyEngineAccount {
                         country,
                         &policy.identity_filter,
                     ).is_err() {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::IdentityFilterFailed)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::IdentityFilterFailed)?;
                     }
                 }
                 PolicyType::MaxBalance { limit } => {
@@ -410,7 +965,7 @@ impl PolicyEngineAccount {
                     {
                         if let Some(dst_tracker) = tracker_account {
                             if dst_tracker.total_amount > *limit {
-                                get_custom_error(policy.custom_error, PolicyEngineErrors::MaxBalanceExceeded)?;
+                                policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MaxBalanceExceeded)?;
                             }
                         }
                     }
@@ -426,7 +981,7 @@ impl PolicyEngineAccount {
                     {
                         if let Some(dst_tracker) = tracker_account {
                             if dst_tracker.total_amount < *limit {
-                                get_custom_error(policy.custom_error, PolicyEngineErrors::MinBalanceExceeded)?;
+                                policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                             }
                         }
                     }
@@ -442,16 +997,27 @@ impl PolicyEngineAccount {
                     {
                         if let Some(dst_tracker) = tracker_account {
                             if dst_tracker.total_amount > *max {
-                                get_custom_error(policy.custom_error, PolicyEngineErrors::MaxBalanceExceeded)?;
+                                policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MaxBalanceExceeded)?;
                             }
                             if dst_tracker.total_amount < *min {
-                                get_custom_error(policy.custom_error, PolicyEngineErrors::MinBalanceExceeded)?;
+                                policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                             }
                         }
                     }
                 }
-                /* When the token is paused, issuance can still occur
-                PolicyType::TransferPause => */
+                // issuance can still occur while the token is paused, unless the policy applies to issuance
+                PolicyType::TransferPause => {
+                    if self
+                        .enforce_filters_single(
+                            identity,
+                            country,
+                            &policy.identity_filter,
+                        )
+                        .is_ok()
+                    {
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::TransferPaused)?;
+                    }
+                }
                 PolicyType::ForbiddenIdentityGroup => {
                     if self
                         .enforce_filters_single(
@@ -461,7 +1027,34 @@ impl PolicyEngineAccount {
                         )
                         .is_ok()
                     {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::ForbiddenIdentityGroup)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::ForbiddenIdentityGroup)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// enforces the balance policies applying to burns on the remaining balance
+    pub fn enforce_policy_on_burn(
+        &self,
+        identity: &[IdentityLevel],
+        country: u8,
+        balance: u64,
+    ) -> Result<()> {
+        for policy in self.policies_with_pages(&[], PolicyEvent::Burn) {
+            if policy.enforcement_mode == EnforcementMode::Disabled {
+                continue;
+            }
+            match &policy.policy_type {
+                PolicyType::MinBalance { limit: min } | PolicyType::MinMaxBalance { min, .. } => {
+                    if self
+                        .enforce_filters_single(identity, country, &policy.identity_filter)
+                        .is_ok()
+                        && balance < *min
+                    {
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                     }
                 }
                 _ => {}
@@ -485,8 +1078,15 @@ impl PolicyEngineAccount {
         destination_balance: u64,
         self_transfer: bool,
         is_platform_wallet: bool,
+        supply: u64,
+        source_outbound_volume: u64,
+        pages: &[PolicyPageAccount],
+        event: PolicyEvent,
     ) -> Result<()> {
-        for policy in self.policies.iter() {
+        for policy in self.policies_with_pages(pages, event) {
+            if policy.enforcement_mode == EnforcementMode::Disabled {
+                continue;
+            }
             match &policy.policy_type {
                 PolicyType::IdentityApproval => {
                     if !self_transfer && self
@@ -499,7 +1099,7 @@ impl PolicyEngineAccount {
                         )
                         .is_err()
                     {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::IdentityFilterFailed)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::IdentityFilterFailed)?;
                     }
                 }
                 PolicyType::TransactionAmountLimit { limit } => {
@@ -514,7 +1114,7 @@ impl PolicyEngineAccount {
                         .is_ok()
                         && transfer_amount > *limit
                     {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::TransactionAmountLimitExceeded)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::TransactionAmountLimitExceeded)?;
                     }
                 }
                 PolicyType::MaxBalance { limit } => {
@@ -530,7 +1130,7 @@ impl PolicyEngineAccount {
                             .is_ok()
                     {
                         if destination_balance > *limit {
-                            get_custom_error(policy.custom_error, PolicyEngineErrors::MaxBalanceExceeded)?;
+                            policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MaxBalanceExceeded)?;
                         }
                     }
                 }
@@ -545,7 +1145,7 @@ impl PolicyEngineAccount {
                             .is_ok()
                         {
                             if source_balance < *limit {
-                                get_custom_error(policy.custom_error, PolicyEngineErrors::MinBalanceExceeded)?;
+                                policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                             }
                         }
                         if self
@@ -557,7 +1157,7 @@ impl PolicyEngineAccount {
                             .is_ok()
                         {
                             if destination_balance < *limit {
-                                get_custom_error(policy.custom_error, PolicyEngineErrors::MinBalanceExceeded)?;
+                                policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                             }
                         }
                     }
@@ -573,7 +1173,7 @@ impl PolicyEngineAccount {
                         )
                         .is_ok()
                     {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::TransferPaused)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::TransferPaused)?;
                     }
                 }
                 PolicyType::ForceFullTransfer => {
@@ -588,7 +1188,7 @@ impl PolicyEngineAccount {
                         .is_ok()
                         && source_balance != 0
                     {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::ForceFullTransfer)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::ForceFullTransfer)?;
                     }
                 }
                 PolicyType::ForbiddenIdentityGroup => {
@@ -602,7 +1202,7 @@ impl PolicyEngineAccount {
                         )
                         .is_ok()
                     {
-                        get_custom_error(policy.custom_error, PolicyEngineErrors::ForbiddenIdentityGroup)?;
+                        policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::ForbiddenIdentityGroup)?;
                     }
                 }
                 PolicyType::MinMaxBalance { min, max } => {
@@ -617,10 +1217,10 @@ impl PolicyEngineAccount {
                         .is_ok()
                     {
                         if source_balance < *min || destination_balance < *min {
-                            get_custom_error(policy.custom_error, PolicyEngineErrors::MinBalanceExceeded)?;
+                            policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                         }
                         if source_balance > *max || destination_balance > *max {
-                            get_custom_error(policy.custom_error, PolicyEngineErrors::MaxBalanceExceeded)?;
+                            policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::MaxBalanceExceeded)?;
                         }
                     }
                 }
@@ -636,7 +1236,28 @@ impl PolicyEngineAccount {
                         .is_ok()
                     {
                         if *time == 0 || *time > timestamp {
-                            get_custom_error(policy.custom_error, PolicyEngineErrors::Flowback)?;
+                            policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::Flowback)?;
+                        }
+                    }
+                }
+                PolicyType::AffiliateVolumeLimit { amount } => {
+                    if !self_transfer
+                        && self
+                            .enforce_filters_single(
+                                source_identity,
+                                source_country,
+                                &policy.identity_filter,
+                            )
+                            .is_ok()
+                    {
+                        let allowance = u64::max(supply / 100, *amount);
+                        // logged so simulations show what the affiliate can still sell
+                        msg!(
+                            "Affiliate volume allowance remaining: {}",
+                            allowance.saturating_sub(source_outbound_volume)
+                        );
+                        if source_outbound_volume > allowance {
+                            policy.on_custom_violation(self.asset_mint, PolicyEngineErrors::AffiliateVolumeLimitExceeded)?;
                         }
                     }
                 }
@@ -649,6 +1270,7 @@ impl PolicyEngineAccount {
         &mut self,
         identity: &[IdentityLevel],
         country: u8,
+        beneficial_owners: u32,
     ) -> Result<Vec<u8>> {
         let mut changed_counters = Vec::new();
         for counter in self.counters.iter() {
@@ -660,11 +1282,20 @@ impl PolicyEngineAccount {
             }
         }
 
+        let mut previous_values = Vec::new();
         for counter in self.counters.iter_mut() {
             if changed_counters.contains(&counter.id) {
-                counter.value = counter.value.checked_sub(1).ok_or(PolicyEngineErrors::CounterUnderflow)?;
+                previous_values.push((counter.id, counter.value));
+                counter.value = counter
+                    .value
+                    .checked_sub(counter.get_weight(beneficial_owners))
+                    .ok_or(PolicyEngineErrors::CounterUnderflow)?;
             }
         }
+        let previous_group_values =
+            self.update_country_counters_on_holder_change(identity, country, beneficial_owners, false)?;
+        self.emit_counter_limit_warnings(&previous_values, &previous_group_values);
+        changed_counters.extend(previous_group_values.iter().map(|(id, _, _)| *id));
 
         Ok(changed_counters)
     }
@@ -673,6 +1304,7 @@ impl PolicyEngineAccount {
         &mut self,
         identity: &[IdentityLevel],
         country: u8,
+        beneficial_owners: u32,
     ) -> Result<Vec<u8>> {
         let mut changed_counters = Vec::new();
 
@@ -685,81 +1317,136 @@ impl PolicyEngineAccount {
             }
         }
 
+        let mut previous_values = Vec::new();
         for counter in self.counters.iter_mut() {
             if changed_counters.contains(&counter.id) {
-                counter.value = counter.value.checked_add(1).ok_or(PolicyEngineErrors::CounterOverflow)?;
+                previous_values.push((counter.id, counter.value));
+                counter.value = counter
+                    .value
+                    .checked_add(counter.get_weight(beneficial_owners))
+                    .ok_or(PolicyEngineErrors::CounterOverflow)?;
             }
         }
+        let previous_group_values =
+            self.update_country_counters_on_holder_change(identity, country, beneficial_owners, true)?;
+        self.emit_counter_limit_warnings(&previous_values, &previous_group_values);
+        changed_counters.extend(previous_group_values.iter().map(|(id, _, _)| *id));
 
         Ok(changed_counters)
     }
 
-    pub fn enforce_counters_on_increment(&self, incremented_counters: &[u8]) -> Result<()> {
+    /// adds or removes the weight of an identity in its country group of every matching country counter.
+    /// returns the previous (id, group, value) of the changed groups.
+    fn update_country_counters_on_holder_change(
+        &mut self,
+        identity: &[IdentityLevel],
+        country: u8,
+        beneficial_owners: u32,
+        increase: bool,
+    ) -> Result<Vec<(u8, u8, u64)>> {
+        let group = self.mapping[country as usize];
+        let mut changed_counters = Vec::new();
+        for counter in self.country_counters.iter() {
+            if self
+                .enforce_filters_single(identity, country, &counter.identity_filter)
+                .is_ok()
+            {
+                changed_counters.push(counter.id);
+            }
+        }
+
+        let mut previous_group_values = Vec::new();
+        for counter in self.country_counters.iter_mut() {
+            if changed_counters.contains(&counter.id) {
+                let id = counter.id;
+                let weight = counter.get_weight(beneficial_owners);
+                let value = counter.value_mut(group)?;
+                previous_group_values.push((id, group, *value));
+                *value = if increase {
+                    value
+                        .checked_add(weight)
+                        .ok_or(PolicyEngineErrors::CounterOverflow)?
+                } else {
+                    value
+                        .checked_sub(weight)
+                        .ok_or(PolicyEngineErrors::CounterUnderflow)?
+                };
+            }
+        }
+
+        Ok(previous_group_values)
+    }
+
+    /// `country` is the country of the identity that was counted, used to resolve its country group
+    pub fn enforce_counters_on_increment(&self, incremented_counters: &[u8], country: u8) -> Result<()> {
         for counter_limit in self.counter_limits.iter() {
+            if counter_limit.enforcement_mode() == EnforcementMode::Disabled {
+                continue;
+            }
             match counter_limit {
                 CounterLimit::HoldersLimit {
                     max,
-                    min: _,
                     counter_id,
+                    ..
                 } => {
-                    if incremented_counters.contains(&counter_id) {
+                    if incremented_counters.contains(counter_id) {
                         let counter = self
                             .counters
                             .iter()
                             .find(|counter| counter.id == *counter_id)
                             .unwrap();
                         if *max < counter.value {
-                            return Err(PolicyEngineErrors::HoldersLimitExceeded.into());
+                            counter_limit.on_violation(self.asset_mint, PolicyEngineErrors::HoldersLimitExceeded)?;
                         }
                     }
                 }
                 CounterLimit::GroupedHoldersLimit {
                     max,
-                    min: _,
                     counters,
+                    ..
                 } => {
                     if incremented_counters.iter().any(|id| counters.contains(id)) {
-                        for counter_id in counters.iter() {
+                        let exceeded = counters.iter().any(|counter_id| {
                             let counter = self
                                 .counters
                                 .iter()
                                 .find(|counter| counter.id == *counter_id)
                                 .unwrap();
-                            if *max < counter.value {
-                                return Err(PolicyEngineErrors::HoldersLimitExceeded.into());
-                            }
+                            *max < counter.value
+                        });
+                        if exceeded {
+                            counter_limit.on_violation(self.asset_mint, PolicyEngineErrors::HoldersLimitExceeded)?;
                         }
                     }
                 }
                 CounterLimit::PercentageLimit {
                     higher_counter_id,
                     lower_counter_id,
-                    min_percentage,
-                    max_percentage,
+                    ..
                 } => {
-                    if incremented_counters.contains(&higher_counter_id)
-                        || incremented_counters.contains(&lower_counter_id)
+                    if incremented_counters.contains(higher_counter_id)
+                        || incremented_counters.contains(lower_counter_id)
                     {
-                        let higher_counter = self
-                            .counters
-                            .iter()
-                            .find(|counter| counter.id == *higher_counter_id)
-                            .unwrap()
-                            .value;
-                        let lower_counter = self
-                            .counters
+                        self.enforce_percentage_limit(counter_limit)?;
+                    }
+                }
+                CounterLimit::CountryHoldersLimit {
+                    max,
+                    counter_id,
+                    groups,
+                    ..
+                } => {
+                    let group = self.mapping[country as usize];
+                    if incremented_counters.contains(counter_id)
+                        && (groups.is_empty() || groups.contains(&group))
+                    {
+                        let counter = self
+                            .country_counters
                             .iter()
-                            .find(|counter| counter.id == *lower_counter_id)
-                            .unwrap()
-                            .value;
-
-                        let percent = lower_counter * 100 / higher_counter;
-
-                        if *max_percentage < 100 && percent > *max_percentage as u64 {
-                            return Err(PolicyEngineErrors::PercentageLimitExceeded.into());
-                        }
-                        if *min_percentage > 0 && percent < *min_percentage as u64 {
-                            return Err(PolicyEngineErrors::PercentageLimitExceeded.into());
+                            .find(|counter| counter.id == *counter_id)
+                            .unwrap();
+                        if *max < counter.values[group as usize] {
+                            counter_limit.on_violation(self.asset_mint, PolicyEngineErrors::HoldersLimitExceeded)?;
                         }
                     }
                 }
@@ -771,76 +1458,200 @@ impl PolicyEngineAccount {
 
     pub fn enforce_counters_on_decrement(&self, decremented_counters: &[u8]) -> Result<()> {
         for counter_limit in self.counter_limits.iter() {
+            if counter_limit.enforcement_mode() == EnforcementMode::Disabled {
+                continue;
+            }
             match counter_limit {
                 CounterLimit::HoldersLimit {
-                    max: _,
                     min,
                     counter_id,
+                    ..
                 } => {
-                    if decremented_counters.contains(&counter_id) {
+                    if decremented_counters.contains(counter_id) {
                         let counter = self
                             .counters
                             .iter()
                             .find(|counter| counter.id == *counter_id)
                             .unwrap();
                         if *min > counter.value {
-                            return Err(PolicyEngineErrors::HoldersLimitExceeded.into());
+                            counter_limit.on_violation(self.asset_mint, PolicyEngineErrors::HoldersLimitExceeded)?;
                         }
                     }
                 }
                 CounterLimit::GroupedHoldersLimit {
-                    max: _,
                     min,
                     counters,
+                    ..
                 } => {
-                    for counter_id in counters.iter() {
-                        if decremented_counters.contains(counter_id) {
-                            let counter = self
-                                .counters
-                                .iter()
-                                .find(|counter| counter.id == *counter_id)
-                                .unwrap();
-                            if *min > counter.value {
-                                return Err(PolicyEngineErrors::HoldersLimitExceeded.into());
-                            }
-                        }
+                    let exceeded = counters.iter().any(|counter_id| {
+                        decremented_counters.contains(counter_id)
+                            && *min
+                                > self
+                                    .counters
+                                    .iter()
+                                    .find(|counter| counter.id == *counter_id)
+                                    .unwrap()
+                                    .value
+                    });
+                    if exceeded {
+                        counter_limit.on_violation(self.asset_mint, PolicyEngineErrors::HoldersLimitExceeded)?;
                     }
                 }
                 CounterLimit::PercentageLimit {
                     higher_counter_id,
                     lower_counter_id,
-                    min_percentage,
-                    max_percentage,
+                    ..
                 } => {
-                    if decremented_counters.contains(&higher_counter_id)
-                        || decremented_counters.contains(&lower_counter_id)
+                    if decremented_counters.contains(higher_counter_id)
+                        || decremented_counters.contains(lower_counter_id)
                     {
-                        let higher_counter = self
-                            .counters
-                            .iter()
-                            .find(|counter| counter.id == *higher_counter_id)
-                            .unwrap()
-                            .value;
-                        let lower_counter = self
-                            .counters
-                            .iter()
-                            .find(|counter| counter.id == *lower_counter_id)
-                            .unwrap()
-                            .value;
+                        self.enforce_percentage_limit(counter_limit)?;
+                    }
+                }
+                // country limits only have an upper bound
+                CounterLimit::CountryHoldersLimit { .. } => {}
+            }
+        }
 
-                        let percent = lower_counter * 100 / higher_counter;
+        Ok(())
+    }
 
-                        if *max_percentage < 100 && percent > *max_percentage as u64 {
-                            return Err(PolicyEngineErrors::PercentageLimitExceeded.into());
-                        }
-                        if *min_percentage > 0 && percent < *min_percentage as u64 {
-                            return Err(PolicyEngineErrors::PercentageLimitExceeded.into());
-                        }
-                    }
+    /// emits an event for every counter limit whose warning threshold was crossed, in either direction,
+    /// by the counter changes. takes the values of the changed counters and country groups before they were updated.
+    pub fn emit_counter_limit_warnings(
+        &self,
+        previous_values: &[(u8, u64)],
+        previous_group_values: &[(u8, u8, u64)],
+    ) {
+        let get_values = |counter_id: u8| {
+            self.counters
+                .iter()
+                .find(|counter| counter.id == counter_id)
+                .map(|counter| {
+                    let previous_value = previous_values
+                        .iter()
+                        .find(|(id, _)| *id == counter.id)
+                        .map_or(counter.value, |(_, value)| *value);
+                    (previous_value, counter.value)
+                })
+        };
+        let get_percentage = |higher: u64, lower: u64| {
+            if higher == 0 {
+                0
+            } else {
+                lower * 100 / higher
+            }
+        };
+
+        for counter_limit in self.counter_limits.iter() {
+            if counter_limit.enforcement_mode() == EnforcementMode::Disabled {
+                continue;
+            }
+            let (threshold, values) = match counter_limit {
+                CounterLimit::HoldersLimit {
+                    counter_id,
+                    warning_threshold,
+                    ..
+                } => (*warning_threshold, get_values(*counter_id)),
+                CounterLimit::GroupedHoldersLimit {
+                    counters,
+                    warning_threshold,
+                    ..
+                } => (
+                    *warning_threshold,
+                    counters
+                        .iter()
+                        .filter_map(|counter_id| get_values(*counter_id))
+                        .reduce(|a, b| (a.0.max(b.0), a.1.max(b.1))),
+                ),
+                CounterLimit::PercentageLimit {
+                    higher_counter_id,
+                    lower_counter_id,
+                    warning_percentage,
+                    ..
+                } => (
+                    *warning_percentage as u64,
+                    get_values(*higher_counter_id)
+                        .zip(get_values(*lower_counter_id))
+                        .map(|(higher, lower)| {
+                            (
+                                get_percentage(higher.0, lower.0),
+                                get_percentage(higher.1, lower.1),
+                            )
+                        }),
+                ),
+                CounterLimit::CountryHoldersLimit {
+                    counter_id,
+                    groups,
+                    warning_threshold,
+                    ..
+                } => (
+                    *warning_threshold,
+                    self.country_counters
+                        .iter()
+                        .find(|counter| counter.id == *counter_id)
+                        .and_then(|counter| {
+                            previous_group_values
+                                .iter()
+                                .filter(|(id, group, _)| {
+                                    *id == *counter_id && (groups.is_empty() || groups.contains(group))
+                                })
+                                .map(|(_, group, previous_value)| {
+                                    (*previous_value, counter.values[*group as usize])
+                                })
+                                .reduce(|a, b| (a.0.max(b.0), a.1.max(b.1)))
+                        }),
+                ),
+            };
+
+            if threshold == 0 {
+                continue;
+            }
+            if let Some((previous_value, value)) = values {
+                let was_above = previous_value >= threshold;
+                let is_above = value >= threshold;
+                if was_above != is_above {
+                    emit!(CounterLimitWarningEvent {
+                        mint: self.asset_mint,
+                        counter_limit: counter_limit.clone(),
+                        value,
+                        above_threshold: is_above,
+                    });
                 }
             }
         }
+    }
 
+    fn enforce_percentage_limit(&self, counter_limit: &CounterLimit) -> Result<()> {
+        if let CounterLimit::PercentageLimit {
+            higher_counter_id,
+            lower_counter_id,
+            min_percentage,
+            max_percentage,
+            ..
+        } = counter_limit
+        {
+            let higher_counter = self
+                .counters
+                .iter()
+                .find(|counter| counter.id == *higher_counter_id)
+                .unwrap()
+                .value;
+            let lower_counter = self
+                .counters
+                .iter()
+                .find(|counter| counter.id == *lower_counter_id)
+                .unwrap()
+                .value;
+
+            let percent = lower_counter * 100 / higher_counter;
+
+            if (*max_percentage < 100 && percent > *max_percentage as u64)
+                || (*min_percentage > 0 && percent < *min_percentage as u64)
+            {
+                counter_limit.on_violation(self.asset_mint, PolicyEngineErrors::PercentageLimitExceeded)?;
+            }
+        }
         Ok(())
     }
 
@@ -848,11 +1659,14 @@ impl PolicyEngineAccount {
         &mut self,
         previous_levels: &[IdentityLevel],
         previous_country: u8,
+        previous_beneficial_owners: u32,
         new_levels: &[IdentityLevel],
         new_country: u8,
+        new_beneficial_owners: u32,
     ) -> Result<()> {
         let mut incremented_counters = Vec::new();
         let mut decremented_counters = Vec::new();
+        let mut weights = Vec::new();
 
         for counter in self.counters.iter() {
             let prev_match = self
@@ -867,23 +1681,91 @@ impl PolicyEngineAccount {
                 .enforce_filters_single(new_levels, new_country, &counter.identity_filter)
                 .is_ok();
 
-            if prev_match && !new_match {
-                decremented_counters.push(counter.id);
-            } else if !prev_match && new_match {
+            let previous_weight = if prev_match { counter.get_weight(previous_beneficial_owners) } else { 0 };
+            let new_weight = if new_match { counter.get_weight(new_beneficial_owners) } else { 0 };
+
+            match previous_weight.cmp(&new_weight) {
+                std::cmp::Ordering::Greater => decremented_counters.push(counter.id),
+                std::cmp::Ordering::Less => incremented_counters.push(counter.id),
+                std::cmp::Ordering::Equal => {}
+            }
+            weights.push((previous_weight, new_weight));
+        }
+
+        let mut previous_values = Vec::new();
+        for (counter, (previous_weight, new_weight)) in self.counters.iter_mut().zip(weights) {
+            if previous_weight != new_weight {
+                previous_values.push((counter.id, counter.value));
+                counter.value = (counter.value + new_weight)
+                    .checked_sub(previous_weight)
+                    .ok_or(PolicyEngineErrors::CounterUnderflow)?;
+            }
+        }
+
+        // country counters also move between groups when the country mapping changes
+        let previous_group = self.mapping[previous_country as usize];
+        let new_group = self.mapping[new_country as usize];
+        let mut country_weights = Vec::new();
+
+        for counter in self.country_counters.iter() {
+            let prev_match = self
+                .enforce_filters_single(
+                    previous_levels,
+                    previous_country,
+                    &counter.identity_filter,
+                )
+                .is_ok();
+
+            let new_match = self
+                .enforce_filters_single(new_levels, new_country, &counter.identity_filter)
+                .is_ok();
+
+            let previous_weight = if prev_match { counter.get_weight(previous_beneficial_owners) } else { 0 };
+            let new_weight = if new_match { counter.get_weight(new_beneficial_owners) } else { 0 };
+
+            if new_weight > 0 && (previous_group != new_group || new_weight > previous_weight) {
                 incremented_counters.push(counter.id);
             }
+            if previous_weight > 0 && (previous_group != new_group || previous_weight > new_weight) {
+                decremented_counters.push(counter.id);
+            }
+            country_weights.push((previous_weight, new_weight));
         }
 
-        for counter in self.counters.iter_mut() {
-            if incremented_counters.contains(&counter.id) {
-                counter.value += 1;
-            } else if decremented_counters.contains(&counter.id) {
-                counter.value -= 1;
+        let mut previous_group_values = Vec::new();
+        for (counter, (previous_weight, new_weight)) in
+            self.country_counters.iter_mut().zip(country_weights)
+        {
+            let id = counter.id;
+            if previous_group == new_group {
+                if previous_weight != new_weight {
+                    let value = counter.value_mut(new_group)?;
+                    previous_group_values.push((id, new_group, *value));
+                    *value = (*value + new_weight)
+                        .checked_sub(previous_weight)
+                        .ok_or(PolicyEngineErrors::CounterUnderflow)?;
+                }
+                continue;
+            }
+            if previous_weight > 0 {
+                let value = counter.value_mut(previous_group)?;
+                previous_group_values.push((id, previous_group, *value));
+                *value = value
+                    .checked_sub(previous_weight)
+                    .ok_or(PolicyEngineErrors::CounterUnderflow)?;
+            }
+            if new_weight > 0 {
+                let value = counter.value_mut(new_group)?;
+                previous_group_values.push((id, new_group, *value));
+                *value = value
+                    .checked_add(new_weight)
+                    .ok_or(PolicyEngineErrors::CounterOverflow)?;
             }
         }
 
-        self.enforce_counters_on_increment(&incremented_counters)?;
+        self.enforce_counters_on_increment(&incremented_counters, new_country)?;
         self.enforce_counters_on_decrement(&decremented_counters)?;
+        self.emit_counter_limit_warnings(&previous_values, &previous_group_values);
 
         Ok(())
     }
@@ -895,8 +1777,12 @@ impl PolicyEngineAccount {
         timestamp: i64,
         balance: u64,
         enforce_limits: bool,
+        pages: &[PolicyPageAccount],
     ) -> Result<()> {
-        for policy in self.policies.iter() {
+        for policy in self.policies_with_pages(pages, PolicyEvent::LevelsChange) {
+            if policy.enforcement_mode == EnforcementMode::Disabled {
+                continue;
+            }
             match &policy.policy_type {
                 PolicyType::MinMaxBalance { min, max } => {
                     if enforce_limits
@@ -909,9 +1795,9 @@ impl PolicyEngineAccount {
                             .is_ok()
                     {
                         if balance < *min  {
-                            return Err(PolicyEngineErrors::MinBalanceExceeded.into());
+                            policy.on_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                         } else if balance > *max {
-                            return Err(PolicyEngineErrors::MaxBalanceExceeded.into());
+                            policy.on_violation(self.asset_mint, PolicyEngineErrors::MaxBalanceExceeded)?;
                         }
                     }
                 }
@@ -926,7 +1812,7 @@ impl PolicyEngineAccount {
                             .is_ok()
                     {
                         if balance > *limit {
-                            return Err(PolicyEngineErrors::MaxBalanceExceeded.into());
+                            policy.on_violation(self.asset_mint, PolicyEngineErrors::MaxBalanceExceeded)?;
                         }
                     }
                 }
@@ -941,7 +1827,7 @@ impl PolicyEngineAccount {
                             .is_ok()
                     {
                         if balance < *limit {
-                            return Err(PolicyEngineErrors::MinBalanceExceeded.into());
+                            policy.on_violation(self.asset_mint, PolicyEngineErrors::MinBalanceExceeded)?;
                         }
                     }
                 }
@@ -957,15 +1843,20 @@ impl PolicyEngineAccount {
         new_levels: &[IdentityLevel],
         previous_country: u8,
         new_country: u8,
+        previous_beneficial_owners: u32,
+        new_beneficial_owners: u32,
         timestamp: i64,
         balance: u64,
         enforce_limits: bool,
+        pages: &[PolicyPageAccount],
     ) -> Result<()> {
         self.update_counters_on_levels_change(
             previous_levels,
             previous_country,
+            previous_beneficial_owners,
             new_levels,
             new_country,
+            new_beneficial_owners,
         )?;
         self.enforce_policy_on_levels_change(
             new_levels,
@@ -973,6 +1864,7 @@ impl PolicyEngineAccount {
             timestamp,
             balance,
             enforce_limits,
+            pages,
         )
     }
 
@@ -980,9 +1872,11 @@ impl PolicyEngineAccount {
         &mut self,
         removed_counters: Vec<u8>,
         added_counters: Vec<Counter>,
+        skip_limit_validation: bool,
     ) -> Result<i32> {
 
         let mut space: i32 = 0;
+        let mut changed_counters = removed_counters.clone();
 
         for removed_counter in removed_counters.iter() {
             let pos = self.counters.iter().position(|c| c.id == *removed_counter)
@@ -991,20 +1885,68 @@ impl PolicyEngineAccount {
         }
 
         for added_counter in added_counters {
-            if self.counters.iter().any(|c| c.id == added_counter.id) {
+            if self.counter_id_exists(added_counter.id) {
                 return Err(PolicyEngineErrors::CounterIdAlreadyExists.into());
             }
             space += added_counter.get_space() as i32;
+            changed_counters.push(added_counter.id);
             self.counters.push(added_counter);
         }
 
+        if !skip_limit_validation {
+            self.validate_counter_limits(self.get_counter_limit_indexes(&changed_counters))?;
+        }
+
+        Ok(space)
+    }
+
+    pub fn update_country_counters(
+        &mut self,
+        removed_country_counters: Vec<u8>,
+        added_country_counters: Vec<CountryCounter>,
+        skip_limit_validation: bool,
+    ) -> Result<i32> {
+        let mut space: i32 = 0;
+        let mut changed_counters = removed_country_counters.clone();
+
+        for removed_counter in removed_country_counters.iter() {
+            let pos = self.country_counters.iter().position(|c| c.id == *removed_counter)
+                .ok_or(PolicyEngineErrors::CounterIdNotFound)?;
+            space -= self.country_counters.remove(pos).get_space() as i32;
+        }
+
+        for mut added_counter in added_country_counters {
+            if self.counter_id_exists(added_counter.id) {
+                return Err(PolicyEngineErrors::CounterIdAlreadyExists.into());
+            }
+            require!(
+                added_counter.values.len() <= self.get_group_count(),
+                PolicyEngineErrors::InvalidInstructionData
+            );
+            added_counter.values.resize(self.get_group_count(), 0);
+            space += added_counter.get_space() as i32;
+            changed_counters.push(added_counter.id);
+            self.country_counters.push(added_counter);
+        }
+
+        if !skip_limit_validation {
+            self.validate_counter_limits(self.get_counter_limit_indexes(&changed_counters))?;
+        }
+
         Ok(space)
     }
 
+    /// counters and country counters share the same ids
+    fn counter_id_exists(&self, counter_id: u8) -> bool {
+        self.counters.iter().any(|c| c.id == counter_id)
+            || self.country_counters.iter().any(|c| c.id == counter_id)
+    }
+
     pub fn update_counter_limits(
         &mut self,
         removed_counter_limits: Vec<u8>,
         added_counter_limits: Vec<CounterLimit>,
+        skip_limit_validation: bool,
     ) -> Result<i32> {
         let mut space: i32 = -removed_counter_limits
             .iter()
@@ -1017,11 +1959,104 @@ impl PolicyEngineAccount {
             .map(|limit| limit.get_space() as i32)
             .sum::<i32>();
 
+        let first_added = self.counter_limits.len();
         self.counter_limits.extend(added_counter_limits);
 
+        if !skip_limit_validation {
+            self.validate_counter_limits(first_added..self.counter_limits.len())?;
+        }
+
         Ok(space)
     }
 
+    /// indexes of the counter limits depending on any of the given counters
+    pub fn get_counter_limit_indexes(&self, counter_ids: &[u8]) -> Vec<usize> {
+        self.counter_limits
+            .iter()
+            .enumerate()
+            .filter(|(_, limit)| counter_ids.iter().any(|id| limit.references(*id)))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn get_counter_value(&self, counter_id: u8) -> Result<u64> {
+        self.counters
+            .iter()
+            .find(|counter| counter.id == counter_id)
+            .map(|counter| counter.value)
+            .ok_or(PolicyEngineErrors::CounterNotFound.into())
+    }
+
+    /// checks the upper bounds of enforced counter limits against the current counter values.
+    /// lower bounds are skipped as they are naturally unmet before issuance.
+    /// fails with the index of the offending limit and the counter value (or percentage) that violates it.
+    pub fn validate_counter_limits(&self, indexes: impl IntoIterator<Item = usize>) -> Result<()> {
+        for index in indexes {
+            let counter_limit = &self.counter_limits[index];
+            let violation = match counter_limit {
+                CounterLimit::HoldersLimit {
+                    max, counter_id, ..
+                } => {
+                    let value = self.get_counter_value(*counter_id)?;
+                    (value > *max).then_some(value)
+                }
+                CounterLimit::GroupedHoldersLimit { max, counters, .. } => {
+                    let mut violation = None;
+                    for counter_id in counters.iter() {
+                        let value = self.get_counter_value(*counter_id)?;
+                        if value > *max && violation.is_none() {
+                            violation = Some(value);
+                        }
+                    }
+                    violation
+                }
+                CounterLimit::PercentageLimit {
+                    higher_counter_id,
+                    lower_counter_id,
+                    max_percentage,
+                    ..
+                } => {
+                    let higher_counter = self.get_counter_value(*higher_counter_id)?;
+                    let lower_counter = self.get_counter_value(*lower_counter_id)?;
+                    if higher_counter == 0 || *max_percentage >= 100 {
+                        None
+                    } else {
+                        let percent = lower_counter * 100 / higher_counter;
+                        (percent > *max_percentage as u64).then_some(percent)
+                    }
+                }
+                CounterLimit::CountryHoldersLimit {
+                    max,
+                    counter_id,
+                    groups,
+                    ..
+                } => {
+                    let counter = self
+                        .country_counters
+                        .iter()
+                        .find(|counter| counter.id == *counter_id)
+                        .ok_or(PolicyEngineErrors::CounterNotFound)?;
+                    counter
+                        .values
+                        .iter()
+                        .enumerate()
+                        .filter(|(group, _)| groups.is_empty() || groups.contains(&(*group as u8)))
+                        .map(|(_, value)| *value)
+                        .find(|value| value > max)
+                }
+            };
+
+            if let Some(value) = violation {
+                if counter_limit.enforcement_mode() == EnforcementMode::Enforce {
+                    return Err(
+                        error!(PolicyEngineErrors::CounterLimitViolated).with_values((index, value))
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn match_data(
         &self,
         identity: &[IdentityLevel],
@@ -1170,7 +2205,7 @@ impl PolicyEngineAccount {
         }
     }
 
-    fn enforce_filters_single(
+    pub fn enforce_filters_single(
         &self,
         identity: &[IdentityLevel],
         country: u8,
@@ -1288,7 +2323,12 @@ impl PolicyEngineAccount {
         }
     }
 
-    pub fn set_counters(&mut self, changed_counters: Vec<u8>, values: Vec<u64>) -> Result<()> {
+    pub fn set_counters(
+        &mut self,
+        changed_counters: Vec<u8>,
+        values: Vec<u64>,
+        skip_limit_validation: bool,
+    ) -> Result<()> {
         for (i, counter_id) in changed_counters.iter().enumerate() {
             let counter = self
                 .counters
@@ -1299,6 +2339,9 @@ impl PolicyEngineAccount {
                 counter.value = values[i];
             }
         }
+        if !skip_limit_validation {
+            self.validate_counter_limits(self.get_counter_limit_indexes(&changed_counters))?;
+        }
         Ok(())
     }
 }
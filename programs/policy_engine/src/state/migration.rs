@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+use super::{
+    Counter, CounterLimit, EnforcementMode, IdentityFilter, IssuancePolicies, Issuance, Lock,
+    OutboundVolume, Policy, PolicyApplicability, PolicyEngineAccount, PolicyType, Snapshot,
+    Timelock, TrackerAccount,
+};
+
+/// layout of the tracker account before the outbound volume and the snapshot checkpoints
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TrackerAccountV1 {
+    pub version: u8,
+    pub asset_mint: Pubkey,
+    pub identity_account: Pubkey,
+    pub total_amount: u64,
+    pub issuances: Vec<Issuance>,
+    pub locks: Vec<Lock>,
+}
+
+impl From<TrackerAccountV1> for TrackerAccount {
+    fn from(tracker: TrackerAccountV1) -> Self {
+        Self {
+            version: Self::VERSION,
+            asset_mint: tracker.asset_mint,
+            identity_account: tracker.identity_account,
+            total_amount: tracker.total_amount,
+            issuances: tracker.issuances,
+            locks: tracker.locks,
+            outbound_volume: OutboundVolume::default(),
+            checkpoints: Default::default(),
+        }
+    }
+}
+
+/// layout of the policies before enforcement modes and applicability
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PolicyV1 {
+    pub hash: String,
+    pub identity_filter: IdentityFilter,
+    pub policy_type: PolicyType,
+    pub custom_error: u8,
+}
+
+/// layout of the counters before beneficial owners were counted
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CounterV1 {
+    pub value: u64,
+    pub id: u8,
+    pub identity_filter: IdentityFilter,
+}
+
+/// layout of the counter limits before enforcement modes and warnings
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum CounterLimitV1 {
+    HoldersLimit {
+        max: u64,
+        min: u64,
+        counter_id: u8,
+    },
+    GroupedHoldersLimit {
+        max: u64,
+        min: u64,
+        counters: Vec<u8>,
+    },
+    PercentageLimit {
+        higher_counter_id: u8,
+        lower_counter_id: u8,
+        min_percentage: u8,
+        max_percentage: u8,
+    },
+}
+
+/// layout of the policy engine before the timelock, snapshots, country counters and policy pages
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PolicyEngineAccountV1 {
+    pub version: u8,
+    pub asset_mint: Pubkey,
+    pub authority: Pubkey,
+    pub mapping: [u8; 256],
+    pub issuance_policies: IssuancePolicies,
+    pub policies: Vec<PolicyV1>,
+    pub counters: Vec<CounterV1>,
+    pub counter_limits: Vec<CounterLimitV1>,
+}
+
+impl From<PolicyV1> for Policy {
+    /// policies were always enforced, on the events of their type
+    fn from(policy: PolicyV1) -> Self {
+        Self {
+            applicability: PolicyApplicability::default_for(&policy.policy_type),
+            hash: policy.hash,
+            identity_filter: policy.identity_filter,
+            policy_type: policy.policy_type,
+            custom_error: policy.custom_error,
+            enforcement_mode: EnforcementMode::Enforce,
+        }
+    }
+}
+
+impl From<CounterV1> for Counter {
+    fn from(counter: CounterV1) -> Self {
+        Self {
+            value: counter.value,
+            id: counter.id,
+            count_beneficial_owners: false,
+            identity_filter: counter.identity_filter,
+        }
+    }
+}
+
+impl From<CounterLimitV1> for CounterLimit {
+    fn from(counter_limit: CounterLimitV1) -> Self {
+        let enforcement_mode = EnforcementMode::Enforce;
+        match counter_limit {
+            CounterLimitV1::HoldersLimit {
+                max,
+                min,
+                counter_id,
+            } => CounterLimit::HoldersLimit {
+                max,
+                min,
+                counter_id,
+                enforcement_mode,
+                warning_threshold: 0,
+            },
+            CounterLimitV1::GroupedHoldersLimit { max, min, counters } => {
+                CounterLimit::GroupedHoldersLimit {
+                    max,
+                    min,
+                    counters,
+                    enforcement_mode,
+                    warning_threshold: 0,
+                }
+            }
+            CounterLimitV1::PercentageLimit {
+                higher_counter_id,
+                lower_counter_id,
+                min_percentage,
+                max_percentage,
+            } => CounterLimit::PercentageLimit {
+                higher_counter_id,
+                lower_counter_id,
+                min_percentage,
+                max_percentage,
+                enforcement_mode,
+                warning_percentage: 0,
+            },
+        }
+    }
+}
+
+impl From<PolicyEngineAccountV1> for PolicyEngineAccount {
+    /// changes stay direct with no delay until the authority sets a timelock
+    fn from(engine: PolicyEngineAccountV1) -> Self {
+        Self {
+            version: Self::VERSION,
+            asset_mint: engine.asset_mint,
+            authority: engine.authority,
+            mapping: engine.mapping,
+            issuance_policies: engine.issuance_policies,
+            timelock: Timelock {
+                delay: 0,
+                direct_changes_enabled: true,
+            },
+            next_change_id: 0,
+            snapshot: Snapshot::default(),
+            holders_sequence: 0,
+            policies: engine.policies.into_iter().map(Into::into).collect(),
+            counters: engine.counters.into_iter().map(Into::into).collect(),
+            counter_limits: engine.counter_limits.into_iter().map(Into::into).collect(),
+            country_counters: vec![],
+            policy_pages: vec![],
+        }
+    }
+}
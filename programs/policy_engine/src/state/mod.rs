@@ -1,15 +1,37 @@
 pub mod engine;
+pub mod migration;
+pub mod pending_change;
+pub mod policy_page;
+pub mod proposal;
+pub mod reconciliation;
+pub mod snapshot;
 pub mod track;
+pub mod tranche;
 
 pub use engine::*;
+pub use migration::*;
+pub use pending_change::*;
+pub use policy_page::*;
+pub use proposal::*;
+pub use reconciliation::*;
+pub use snapshot::*;
 pub use track::*;
+pub use tranche::*;
 
 use anchor_lang::{solana_program::program_error::ProgramError, AnchorDeserialize, Discriminator};
 use rwa_utils::GeyserProgramAccount;
 
 pub enum PolicyEngineAccounts {
-    PolicyEngineAccount(PolicyEngineAccount),
+    PolicyEngineAccount(Box<PolicyEngineAccount>),
     TrackerAccount(TrackerAccount),
+    PendingChangeAccount(PendingChangeAccount),
+    ReconciliationAccount(ReconciliationAccount),
+    PolicyPageAccount(PolicyPageAccount),
+    TrancheAccount(TrancheAccount),
+    TrancheSubscriptionAccount(TrancheSubscriptionAccount),
+    ProposalAccount(ProposalAccount),
+    VoteAccount(VoteAccount),
+    DelegationAccount(DelegationAccount),
 }
 
 impl GeyserProgramAccount for PolicyEngineAccounts {
@@ -17,6 +39,16 @@ impl GeyserProgramAccount for PolicyEngineAccounts {
         match self {
             PolicyEngineAccounts::PolicyEngineAccount(_) => PolicyEngineAccount::DISCRIMINATOR,
             PolicyEngineAccounts::TrackerAccount(_) => TrackerAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::PendingChangeAccount(_) => PendingChangeAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::ReconciliationAccount(_) => ReconciliationAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::PolicyPageAccount(_) => PolicyPageAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::TrancheAccount(_) => TrancheAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::TrancheSubscriptionAccount(_) => {
+                TrancheSubscriptionAccount::DISCRIMINATOR
+            }
+            PolicyEngineAccounts::ProposalAccount(_) => ProposalAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::VoteAccount(_) => VoteAccount::DISCRIMINATOR,
+            PolicyEngineAccounts::DelegationAccount(_) => DelegationAccount::DISCRIMINATOR,
         }
     }
 
@@ -36,12 +68,44 @@ impl GeyserProgramAccount for PolicyEngineAccounts {
         match discriminator {
             PolicyEngineAccount::DISCRIMINATOR => {
                 let account = PolicyEngineAccount::deserialize(account_data)?;
-                Ok(PolicyEngineAccounts::PolicyEngineAccount(account))
+                Ok(PolicyEngineAccounts::PolicyEngineAccount(Box::new(account)))
             }
             TrackerAccount::DISCRIMINATOR => {
                 let account = TrackerAccount::deserialize(account_data)?;
                 Ok(PolicyEngineAccounts::TrackerAccount(account))
             }
+            PendingChangeAccount::DISCRIMINATOR => {
+                let account = PendingChangeAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::PendingChangeAccount(account))
+            }
+            ReconciliationAccount::DISCRIMINATOR => {
+                let account = ReconciliationAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::ReconciliationAccount(account))
+            }
+            PolicyPageAccount::DISCRIMINATOR => {
+                let account = PolicyPageAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::PolicyPageAccount(account))
+            }
+            TrancheAccount::DISCRIMINATOR => {
+                let account = TrancheAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::TrancheAccount(account))
+            }
+            TrancheSubscriptionAccount::DISCRIMINATOR => {
+                let account = TrancheSubscriptionAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::TrancheSubscriptionAccount(account))
+            }
+            ProposalAccount::DISCRIMINATOR => {
+                let account = ProposalAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::ProposalAccount(account))
+            }
+            VoteAccount::DISCRIMINATOR => {
+                let account = VoteAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::VoteAccount(account))
+            }
+            DelegationAccount::DISCRIMINATOR => {
+                let account = DelegationAccount::deserialize(account_data)?;
+                Ok(PolicyEngineAccounts::DelegationAccount(account))
+            }
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    Counter, CounterLimit, CountryCounter, EnforcementMode, IdentityFilter, IssuancePolicies,
+    PolicyApplicability, PolicyPageScope, PolicyType, Timelock,
+};
+
+pub const PENDING_CHANGE_SEED: &[u8] = b"pending-change";
+
+#[account()]
+pub struct PendingChangeAccount {
+    pub version: u8,
+    /// policy engine the change applies to
+    pub policy_engine: Pubkey,
+    /// sequential id of the change, part of the pda seeds
+    pub id: u64,
+    /// receives the rent back once the change is executed or cancelled
+    pub payer: Pubkey,
+    pub proposed_at: i64,
+    /// earliest execution time at the delay in force when proposed, the delay at execution time applies
+    pub executable_at: i64,
+    pub change: PolicyEngineChange,
+}
+
+/// configuration changes that can be queued on a policy engine
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum PolicyEngineChange {
+    AttachPolicy {
+        identity_filter: IdentityFilter,
+        policy_type: PolicyType,
+        custom_error: u8,
+        enforcement_mode: EnforcementMode,
+        applicability: Option<PolicyApplicability>,
+    },
+    DetachPolicy {
+        hash: String,
+    },
+    ChangePolicyEnforcementMode {
+        hash: String,
+        enforcement_mode: EnforcementMode,
+    },
+    ChangeCounters {
+        removed_counters: Vec<u8>,
+        added_counters: Vec<Counter>,
+        skip_limit_validation: bool,
+    },
+    ChangeCounterLimits {
+        removed_counter_limits: Vec<u8>,
+        added_counter_limits: Vec<CounterLimit>,
+        skip_limit_validation: bool,
+    },
+    ChangeCounterLimitEnforcementMode {
+        index: u8,
+        enforcement_mode: EnforcementMode,
+    },
+    ChangeMapping {
+        mapping_source: Vec<u8>,
+        mapping_value: Vec<u8>,
+    },
+    ChangeIssuancePolicies {
+        issuance_policies: IssuancePolicies,
+    },
+    ChangeTimelock {
+        timelock: Timelock,
+    },
+    ChangeCountryCounters {
+        removed_country_counters: Vec<u8>,
+        added_country_counters: Vec<CountryCounter>,
+        skip_limit_validation: bool,
+    },
+    ChangePolicyApplicability {
+        hash: String,
+        applicability: PolicyApplicability,
+    },
+    SetCounters {
+        changed_counters: Vec<u8>,
+        values: Vec<u64>,
+        skip_limit_validation: bool,
+    },
+    /// the page changes are applied to the page account passed to the execution
+    CreatePolicyPage {
+        index: u8,
+        scope: PolicyPageScope,
+    },
+    AttachToPolicyPage {
+        index: u8,
+        identity_filter: IdentityFilter,
+        policy_type: PolicyType,
+        custom_error: u8,
+        enforcement_mode: EnforcementMode,
+        applicability: Option<PolicyApplicability>,
+    },
+    DetachFromPolicyPage {
+        index: u8,
+        hash: String,
+    },
+    ClosePolicyPage {
+        index: u8,
+    },
+}
+
+impl PendingChangeAccount {
+    pub const VERSION: u8 = 1;
+    const BASE_SPACE: usize = 1 + 32 + 8 + 32 + 8 + 8;
+
+    pub fn get_space(change: &PolicyEngineChange) -> Result<usize> {
+        Ok(Self::BASE_SPACE + change.try_to_vec()?.len())
+    }
+
+    pub fn new(
+        policy_engine: Pubkey,
+        id: u64,
+        payer: Pubkey,
+        proposed_at: i64,
+        delay: i64,
+        change: PolicyEngineChange,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            policy_engine,
+            id,
+            payer,
+            proposed_at,
+            executable_at: proposed_at.saturating_add(delay),
+            change,
+        }
+    }
+
+    /// a delay raised after the change was proposed also applies to it
+    pub fn is_executable(&self, timestamp: i64, delay: i64) -> bool {
+        timestamp >= self.proposed_at.saturating_add(delay)
+    }
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    EnforcementMode, IdentityFilter, Policy, PolicyApplicability, PolicyEngineAccount,
+    PolicyEngineErrors, PolicyType,
+};
+
+pub const POLICY_PAGE_SEED: &[u8] = b"policy-page";
+/// transfer pages are resolved through the extra account metas, which reserve this many slots
+pub const MAX_TRANSFER_POLICY_PAGES: u8 = 4;
+
+/// operation evaluating the policies of a page, so each instruction only loads the pages it needs
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq)]
+pub enum PolicyPageScope {
+    Transfer,
+    Issuance,
+    LevelsChange,
+}
+
+impl PolicyPageScope {
+    /// events evaluating the policies of a page with this scope, seizes going through the transfer hook
+    pub fn applicability(&self) -> PolicyApplicability {
+        PolicyApplicability {
+            transfer: *self == PolicyPageScope::Transfer,
+            issuance: *self == PolicyPageScope::Issuance,
+            burn: false,
+            seize: *self == PolicyPageScope::Transfer,
+            levels_change: *self == PolicyPageScope::LevelsChange,
+        }
+    }
+}
+
+/// reference to a policy page, kept on the policy engine
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
+pub struct PolicyPageRef {
+    pub index: u8,
+    pub scope: PolicyPageScope,
+}
+
+/// overflow storage for policies that do not fit in the policy engine account
+#[account()]
+#[derive(InitSpace)]
+pub struct PolicyPageAccount {
+    pub version: u8,
+    pub policy_engine: Pubkey,
+    pub payer: Pubkey,
+    pub index: u8,
+    pub scope: PolicyPageScope,
+    #[max_len(0)]
+    pub policies: Vec<Policy>,
+}
+
+impl PolicyPageAccount {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(policy_engine: Pubkey, payer: Pubkey, index: u8, scope: PolicyPageScope) -> Self {
+        Self {
+            version: Self::VERSION,
+            policy_engine,
+            payer,
+            index,
+            scope,
+            policies: vec![],
+        }
+    }
+
+    pub fn get_policy_space(&self, hash: &str) -> Result<usize> {
+        self.policies
+            .iter()
+            .find(|policy| policy.hash == hash)
+            .map(|policy| policy.get_space())
+            .ok_or(PolicyEngineErrors::PolicyNotFound.into())
+    }
+
+    pub fn attach(
+        &mut self,
+        policy_type: PolicyType,
+        identity_filter: IdentityFilter,
+        custom_error: u8,
+        enforcement_mode: EnforcementMode,
+        applicability: Option<PolicyApplicability>,
+    ) -> Result<()> {
+        let hash =
+            PolicyEngineAccount::hash_policy(self.policy_engine, &policy_type, &identity_filter);
+        if self.policies.iter().any(|policy| policy.hash == hash) {
+            return Err(PolicyEngineErrors::PolicyAlreadyExists.into());
+        }
+        let scope = self.scope.applicability();
+        // by default the policy keeps the events of its type the page is evaluated on
+        let applicability = applicability.unwrap_or_else(|| {
+            let default = PolicyApplicability::default_for(&policy_type);
+            PolicyApplicability {
+                transfer: default.transfer && scope.transfer,
+                issuance: default.issuance && scope.issuance,
+                burn: default.burn && scope.burn,
+                seize: default.seize && scope.seize,
+                levels_change: default.levels_change && scope.levels_change,
+            }
+        });
+        applicability.validate(&policy_type)?;
+        require!(
+            applicability.is_subset_of(&scope),
+            PolicyEngineErrors::InvalidPolicyApplicability
+        );
+        self.policies.push(Policy {
+            hash,
+            identity_filter,
+            policy_type,
+            custom_error,
+            enforcement_mode,
+            applicability,
+        });
+        Ok(())
+    }
+
+    pub fn detach(&mut self, hash: String) -> Result<Policy> {
+        if let Some(index) = self.policies.iter().position(|policy| policy.hash == hash) {
+            return Ok(self.policies.remove(index));
+        }
+        Err(PolicyEngineErrors::PolicyNotFound.into())
+    }
+}
+
+/// loads the pages of `scope` among `accounts`, failing if a page of that scope referenced by the engine is missing.
+/// uninitialized accounts are skipped since the hook receives every reserved slot.
+pub fn load_policy_pages(
+    policy_engine: &PolicyEngineAccount,
+    policy_engine_key: Pubkey,
+    accounts: &[AccountInfo],
+    scope: PolicyPageScope,
+) -> Result<Vec<PolicyPageAccount>> {
+    let mut pages = Vec::new();
+    for account in accounts.iter() {
+        if account.data_is_empty() {
+            continue;
+        }
+        require!(
+            account.owner == &crate::id(),
+            PolicyEngineErrors::InvalidPolicyPage
+        );
+        let page = PolicyPageAccount::try_deserialize(&mut &account.data.borrow()[..])?;
+        require!(
+            page.policy_engine == policy_engine_key,
+            PolicyEngineErrors::InvalidPolicyPage
+        );
+        if page.scope == scope
+            && !pages
+                .iter()
+                .any(|loaded: &PolicyPageAccount| loaded.index == page.index)
+        {
+            pages.push(page);
+        }
+    }
+
+    for page_ref in policy_engine.policy_pages.iter() {
+        if page_ref.scope == scope {
+            require!(
+                pages.iter().any(|page| page.index == page_ref.index),
+                PolicyEngineErrors::PolicyPageMissing
+            );
+        }
+    }
+    Ok(pages)
+}
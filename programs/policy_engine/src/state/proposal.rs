@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::{IdentityFilter, PolicyEngineErrors};
+
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const VOTE_SEED: &[u8] = b"vote";
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+pub const MAX_PROPOSAL_OPTIONS: usize = 16;
+pub const MAX_QUORUM_BPS: u16 = 10_000;
+
+/// question put to the holders, set by the authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposalConfig {
+    pub question: String,
+    pub options: Vec<String>,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    /// share of the supply at the record date that must vote for the result to be valid
+    pub quorum_bps: u16,
+    /// identities whose balances can be voted
+    pub identity_filter: IdentityFilter,
+}
+
+impl ProposalConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.question.is_empty()
+                && self.options.len() >= 2
+                && self.options.len() <= MAX_PROPOSAL_OPTIONS
+                && self.options.iter().all(|option| !option.is_empty())
+                && self.start_timestamp < self.end_timestamp
+                && self.quorum_bps <= MAX_QUORUM_BPS,
+            PolicyEngineErrors::InvalidProposalConfig
+        );
+        Ok(())
+    }
+}
+
+/// vote of the holders, weighted by their balances at the record date of a snapshot
+#[account()]
+#[derive(InitSpace)]
+pub struct ProposalAccount {
+    pub version: u8,
+    pub policy_engine: Pubkey,
+    pub payer: Pubkey,
+    pub id: u64,
+    /// snapshot the balances are read from
+    pub snapshot_id: u64,
+    /// supply at the record date, the quorum is computed against it
+    pub snapshot_supply: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub quorum_bps: u16,
+    pub finalized: bool,
+    pub identity_filter: IdentityFilter,
+    #[max_len(0)]
+    pub question: String,
+    #[max_len(0, 0)]
+    pub options: Vec<String>,
+    /// votes per option, in the order of the options
+    #[max_len(0)]
+    pub tallies: Vec<u64>,
+}
+
+impl ProposalAccount {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(
+        policy_engine: Pubkey,
+        payer: Pubkey,
+        id: u64,
+        snapshot_id: u64,
+        snapshot_supply: u64,
+        config: ProposalConfig,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            policy_engine,
+            payer,
+            id,
+            snapshot_id,
+            snapshot_supply,
+            start_timestamp: config.start_timestamp,
+            end_timestamp: config.end_timestamp,
+            quorum_bps: config.quorum_bps,
+            finalized: false,
+            identity_filter: config.identity_filter,
+            question: config.question,
+            tallies: vec![0; config.options.len()],
+            options: config.options,
+        }
+    }
+
+    pub fn get_space(config: &ProposalConfig) -> usize {
+        ProposalAccount::INIT_SPACE - IdentityFilter::INIT_SPACE
+            + config.identity_filter.get_space()
+            + config.question.len()
+            + config
+                .options
+                .iter()
+                .map(|option| 4 + option.len() + 8)
+                .sum::<usize>()
+    }
+
+    /// adds the weight to the tally of the option
+    pub fn vote(&mut self, option: u8, weight: u64, timestamp: i64) -> Result<()> {
+        require!(
+            timestamp >= self.start_timestamp && timestamp < self.end_timestamp,
+            PolicyEngineErrors::ProposalNotOpen
+        );
+        let tally = self
+            .tallies
+            .get_mut(option as usize)
+            .ok_or(PolicyEngineErrors::InvalidVoteOption)?;
+        *tally = tally
+            .checked_add(weight)
+            .ok_or(PolicyEngineErrors::BalanceOverflow)?;
+        Ok(())
+    }
+
+    pub fn get_total_votes(&self) -> u64 {
+        self.tallies.iter().sum()
+    }
+
+    pub fn is_quorum_reached(&self) -> bool {
+        self.get_total_votes() as u128 * MAX_QUORUM_BPS as u128
+            >= self.snapshot_supply as u128 * self.quorum_bps as u128
+    }
+
+    /// option with the most votes, none without votes or on a tie
+    pub fn get_winning_option(&self) -> Option<u8> {
+        let max = *self.tallies.iter().max()?;
+        let mut winners = self
+            .tallies
+            .iter()
+            .enumerate()
+            .filter(|(_, tally)| **tally == max);
+        match (winners.next(), winners.next()) {
+            (Some((index, _)), None) if max > 0 => Some(index as u8),
+            _ => None,
+        }
+    }
+}
+
+/// vote cast with the balance of an identity, prevents voting a balance twice
+#[account()]
+#[derive(InitSpace)]
+pub struct VoteAccount {
+    pub version: u8,
+    pub proposal: Pubkey,
+    /// identity whose balance was voted
+    pub identity_account: Pubkey,
+    /// identity that cast the vote, the delegate when the voting power was delegated
+    pub voter: Pubkey,
+    pub option: u8,
+    pub weight: u64,
+    pub voted_at: i64,
+}
+
+impl VoteAccount {
+    pub const VERSION: u8 = 1;
+}
+
+/// voting power of an identity delegated to another identity, for all the proposals of the asset
+#[account()]
+#[derive(InitSpace)]
+pub struct DelegationAccount {
+    pub version: u8,
+    pub policy_engine: Pubkey,
+    pub payer: Pubkey,
+    pub identity_account: Pubkey,
+    pub delegate: Pubkey,
+}
+
+impl DelegationAccount {
+    pub const VERSION: u8 = 1;
+}
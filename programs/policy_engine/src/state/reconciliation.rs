@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use identity_registry::IdentityLevel;
+
+use crate::{CounterMismatchEvent, PolicyEngineAccount, PolicyEngineErrors};
+
+pub const RECONCILIATION_SEED: &[u8] = b"reconciliation";
+
+/// scratch account recomputing the counters of a policy engine from its tracker accounts
+#[account()]
+pub struct ReconciliationAccount {
+    pub version: u8,
+    /// policy engine being reconciled
+    pub policy_engine: Pubkey,
+    /// receives the rent back once the reconciliation is committed or cancelled
+    pub payer: Pubkey,
+    /// identity accounts are processed in ascending order so none is counted twice
+    pub last_identity_account: Pubkey,
+    pub processed_identities: u64,
+    /// holders sequence of the policy engine when the reconciliation started
+    pub holders_sequence: u64,
+    pub counters: Vec<ReconciledCounter>,
+    pub country_counters: Vec<ReconciledCountryCounter>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ReconciledCounter {
+    pub id: u8,
+    pub value: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ReconciledCountryCounter {
+    pub id: u8,
+    pub values: Vec<u64>,
+}
+
+impl ReconciliationAccount {
+    pub const VERSION: u8 = 1;
+    const BASE_SPACE: usize = 1 + 32 + 32 + 32 + 8 + 8 + 4 + 4;
+
+    pub fn get_space(policy_engine: &PolicyEngineAccount) -> usize {
+        Self::BASE_SPACE
+            + policy_engine.counters.len() * (1 + 8)
+            + policy_engine.country_counters.len() * (1 + 4 + 8 * policy_engine.get_group_count())
+    }
+
+    pub fn new(policy_engine: Pubkey, payer: Pubkey, engine: &PolicyEngineAccount) -> Self {
+        Self {
+            version: Self::VERSION,
+            policy_engine,
+            payer,
+            last_identity_account: Pubkey::default(),
+            processed_identities: 0,
+            holders_sequence: engine.holders_sequence,
+            counters: engine
+                .counters
+                .iter()
+                .map(|counter| ReconciledCounter {
+                    id: counter.id,
+                    value: 0,
+                })
+                .collect(),
+            country_counters: engine
+                .country_counters
+                .iter()
+                .map(|counter| ReconciledCountryCounter {
+                    id: counter.id,
+                    values: vec![0; engine.get_group_count()],
+                })
+                .collect(),
+        }
+    }
+
+    /// whether counters were added or removed, or the country groups changed, since the reconciliation started
+    pub fn is_outdated(&self, engine: &PolicyEngineAccount) -> bool {
+        self.counters.len() != engine.counters.len()
+            || self.country_counters.len() != engine.country_counters.len()
+            || self
+                .counters
+                .iter()
+                .zip(engine.counters.iter())
+                .any(|(reconciled, counter)| reconciled.id != counter.id)
+            || self
+                .country_counters
+                .iter()
+                .zip(engine.country_counters.iter())
+                .any(|(reconciled, counter)| {
+                    reconciled.id != counter.id || reconciled.values.len() != counter.values.len()
+                })
+    }
+
+    /// counts the identity in every counter it matches, identities without balance are only marked as processed
+    pub fn add_identity(
+        &mut self,
+        engine: &PolicyEngineAccount,
+        identity_account: Pubkey,
+        levels: &[IdentityLevel],
+        country: u8,
+        beneficial_owners: u32,
+        balance: u64,
+    ) -> Result<()> {
+        require!(
+            identity_account > self.last_identity_account,
+            PolicyEngineErrors::IdentityAccountsNotSorted
+        );
+        self.last_identity_account = identity_account;
+        self.processed_identities += 1;
+
+        if balance == 0 {
+            return Ok(());
+        }
+
+        for (reconciled, counter) in self.counters.iter_mut().zip(engine.counters.iter()) {
+            if engine
+                .enforce_filters_single(levels, country, &counter.identity_filter)
+                .is_ok()
+            {
+                reconciled.value = reconciled
+                    .value
+                    .checked_add(counter.get_weight(beneficial_owners))
+                    .ok_or(PolicyEngineErrors::CounterOverflow)?;
+            }
+        }
+
+        let group = engine.mapping[country as usize] as usize;
+        for (reconciled, counter) in self
+            .country_counters
+            .iter_mut()
+            .zip(engine.country_counters.iter())
+        {
+            if engine
+                .enforce_filters_single(levels, country, &counter.identity_filter)
+                .is_ok()
+            {
+                reconciled.values[group] = reconciled.values[group]
+                    .checked_add(counter.get_weight(beneficial_owners))
+                    .ok_or(PolicyEngineErrors::CounterOverflow)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// overwrites the engine counters with the reconciled values, returns a mismatch event for every value that changed.
+    /// every identity of the registry must have been processed with no holder change since the start.
+    pub fn commit(
+        &self,
+        engine: &mut PolicyEngineAccount,
+        identity_count: u64,
+    ) -> Result<Vec<CounterMismatchEvent>> {
+        require!(
+            !self.is_outdated(engine),
+            PolicyEngineErrors::ReconciliationOutdated
+        );
+        require!(
+            self.holders_sequence == engine.holders_sequence,
+            PolicyEngineErrors::ReconciliationStale
+        );
+        require!(
+            self.processed_identities == identity_count,
+            PolicyEngineErrors::ReconciliationIncomplete
+        );
+        let mint = engine.asset_mint;
+        let mut mismatches = Vec::new();
+
+        for (reconciled, counter) in self.counters.iter().zip(engine.counters.iter_mut()) {
+            if reconciled.value != counter.value {
+                mismatches.push(CounterMismatchEvent {
+                    mint,
+                    counter_id: counter.id,
+                    group: None,
+                    value: counter.value,
+                    reconciled_value: reconciled.value,
+                });
+                counter.value = reconciled.value;
+            }
+        }
+
+        for (reconciled, counter) in self
+            .country_counters
+            .iter()
+            .zip(engine.country_counters.iter_mut())
+        {
+            for (group, (reconciled_value, value)) in reconciled
+                .values
+                .iter()
+                .zip(counter.values.iter_mut())
+                .enumerate()
+            {
+                if reconciled_value != value {
+                    mismatches.push(CounterMismatchEvent {
+                        mint,
+                        counter_id: counter.id,
+                        group: Some(group as u8),
+                        value: *value,
+                        reconciled_value: *reconciled_value,
+                    });
+                    *value = *reconciled_value;
+                }
+            }
+        }
+
+        Ok(mismatches)
+    }
+}
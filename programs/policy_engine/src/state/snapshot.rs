@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::PolicyEngineErrors;
+
+/// number of consecutive snapshots whose balances stay readable, one checkpoint slot per snapshot in the trackers
+pub const MAX_CHECKPOINTS: usize = 4;
+
+/// latest record date snapshot of the holder balances, id 0 when none was opened
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug, Default)]
+pub struct Snapshot {
+    pub id: u64,
+    pub record_timestamp: i64,
+    /// supply of the asset at the record date
+    pub supply: u64,
+    /// time until which the readable snapshots are in use by proposals or distributions, slot `id % MAX_CHECKPOINTS`
+    pub reserved_until: [i64; MAX_CHECKPOINTS],
+}
+
+/// balance of a tracker before its first change after a snapshot was opened
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug, Default)]
+pub struct Checkpoint {
+    pub snapshot_id: u64,
+    pub balance: u64,
+}
+
+impl Snapshot {
+    pub fn slot(id: u64) -> usize {
+        (id % MAX_CHECKPOINTS as u64) as usize
+    }
+
+    /// the new snapshot takes the slot of the oldest readable one, which must no longer be in use
+    pub fn open(&mut self, record_timestamp: i64, supply: u64) -> Result<()> {
+        self.id = self
+            .id
+            .checked_add(1)
+            .ok_or(PolicyEngineErrors::BalanceOverflow)?;
+        let slot = Self::slot(self.id);
+        require!(
+            record_timestamp >= self.reserved_until[slot],
+            PolicyEngineErrors::SnapshotInUse
+        );
+        self.reserved_until[slot] = 0;
+        self.record_timestamp = record_timestamp;
+        self.supply = supply;
+        Ok(())
+    }
+
+    /// keeps the current snapshot readable until the given time
+    pub fn reserve(&mut self, until: i64) {
+        let slot = Self::slot(self.id);
+        self.reserved_until[slot] = self.reserved_until[slot].max(until);
+    }
+
+    /// balances of the last `MAX_CHECKPOINTS` snapshots can be read from the trackers
+    pub fn is_readable(&self, snapshot_id: u64) -> bool {
+        snapshot_id > 0
+            && snapshot_id <= self.id
+            && self.id - snapshot_id < MAX_CHECKPOINTS as u64
+    }
+}
@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::{PolicyEngineErrors, Side};
+use crate::{Checkpoint, PolicyEngineErrors, Side, Snapshot, MAX_CHECKPOINTS};
 
 pub const MAX_TRANSFER_HISTORY: usize = 25;
+/// rolling window of outbound volume, 13 weeks covering roughly 3 months
+pub const VOLUME_WINDOW_BUCKETS: usize = 13;
+pub const VOLUME_BUCKET_DURATION: i64 = 7 * 24 * 60 * 60;
+/// reason of the locks backing pending redemption requests, the reason string is the request address
+pub const REDEMPTION_LOCK_REASON: u64 = u64::MAX;
 
 #[account()]
 #[derive(InitSpace)]
@@ -20,6 +25,11 @@ pub struct TrackerAccount {
 
     #[max_len(0)]
     pub locks: Vec<Lock>,
+
+    pub outbound_volume: OutboundVolume,
+
+    /// balances at the record dates of the recent snapshots, slot `snapshot_id % MAX_CHECKPOINTS`
+    pub checkpoints: [Checkpoint; MAX_CHECKPOINTS],
 }
 
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
@@ -37,8 +47,58 @@ pub struct Lock {
     pub reason_string: String,
 }
 
+/// outbound volume of the identity, kept in weekly buckets so the tracker does not grow in the transfer hook
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct OutboundVolume {
+    /// week of the most recently written bucket
+    pub last_bucket: i64,
+    pub buckets: [u64; VOLUME_WINDOW_BUCKETS],
+}
+
+impl OutboundVolume {
+    fn bucket(timestamp: i64) -> i64 {
+        timestamp.div_euclid(VOLUME_BUCKET_DURATION)
+    }
+
+    fn slot(bucket: i64) -> usize {
+        bucket.rem_euclid(VOLUME_WINDOW_BUCKETS as i64) as usize
+    }
+
+    pub fn record(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        let current = Self::bucket(timestamp);
+        let elapsed = current - self.last_bucket;
+        if elapsed >= VOLUME_WINDOW_BUCKETS as i64 {
+            self.buckets = [0; VOLUME_WINDOW_BUCKETS];
+        } else {
+            // clear the buckets that left the window since the last write
+            for bucket in self.last_bucket + 1..=current {
+                self.buckets[Self::slot(bucket)] = 0;
+            }
+        }
+        if elapsed > 0 {
+            self.last_bucket = current;
+        }
+        let slot = Self::slot(self.last_bucket);
+        self.buckets[slot] = self.buckets[slot]
+            .checked_add(amount)
+            .ok_or(PolicyEngineErrors::BalanceOverflow)?;
+        Ok(())
+    }
+
+    /// volume of the current week and the previous 12 weeks
+    pub fn total(&self, timestamp: i64) -> u64 {
+        let current = Self::bucket(timestamp);
+        (0..VOLUME_WINDOW_BUCKETS as i64)
+            .map(|age| self.last_bucket - age)
+            .filter(|bucket| current - bucket < VOLUME_WINDOW_BUCKETS as i64)
+            .map(|bucket| self.buckets[Self::slot(bucket)])
+            .sum()
+    }
+}
+
 impl TrackerAccount {
-    pub const VERSION: u8 = 1;
+    /// 2 appended the outbound volume and the snapshot checkpoints, version 1 trackers are migrated by `migrate_tracker_account`
+    pub const VERSION: u8 = 2;
     pub fn new(asset_mint: Pubkey, owner: Pubkey) -> Self {
         Self {
             version: Self::VERSION,
@@ -47,6 +107,8 @@ impl TrackerAccount {
             total_amount: 0,
             issuances: vec![],
             locks: vec![],
+            outbound_volume: OutboundVolume::default(),
+            checkpoints: Default::default(),
         }
     }
 
@@ -69,6 +131,36 @@ impl TrackerAccount {
         Ok(())
     }
 
+    /// records the balance before its first change after the snapshot was opened, call before changing it
+    pub fn checkpoint(&mut self, snapshot: &Snapshot) {
+        let latest = self
+            .checkpoints
+            .iter()
+            .map(|checkpoint| checkpoint.snapshot_id)
+            .max()
+            .unwrap_or_default();
+        if snapshot.id > latest {
+            self.checkpoints[Snapshot::slot(snapshot.id)] = Checkpoint {
+                snapshot_id: snapshot.id,
+                balance: self.total_amount,
+            };
+        }
+    }
+
+    /// balance at the record date of a snapshot, the balance checkpointed by the first change since then
+    pub fn get_snapshot_balance(&self, snapshot: &Snapshot, snapshot_id: u64) -> Result<u64> {
+        require!(
+            snapshot.is_readable(snapshot_id),
+            PolicyEngineErrors::SnapshotUnavailable
+        );
+        Ok(self
+            .checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.snapshot_id >= snapshot_id)
+            .min_by_key(|checkpoint| checkpoint.snapshot_id)
+            .map_or(self.total_amount, |checkpoint| checkpoint.balance))
+    }
+
     pub fn new_issuance(&mut self, amount: u64, issue_time: i64) -> Result<()> {
         self.issuances.push(Issuance {
             amount,
@@ -78,6 +170,35 @@ impl TrackerAccount {
         Ok(())
     }
 
+    /// removes up to amount from the issuances, newest first so holding periods are not shortened.
+    /// the removed lots keep their issue time.
+    pub fn take_issuances(&mut self, amount: u64) -> Vec<Issuance> {
+        self.issuances.sort_by_key(|issuance| issuance.issue_time);
+        let mut taken = Vec::new();
+        let mut remaining = amount;
+        while remaining > 0 {
+            let Some(issuance) = self.issuances.last_mut() else {
+                break;
+            };
+            let moved = u64::min(issuance.amount, remaining);
+            issuance.amount -= moved;
+            remaining -= moved;
+            taken.push(Issuance {
+                amount: moved,
+                issue_time: issuance.issue_time,
+            });
+            if issuance.amount == 0 {
+                self.issuances.pop();
+            }
+        }
+        taken
+    }
+
+    /// adds issuances taken from another tracker, the balance is updated by the transfer itself
+    pub fn add_issuances(&mut self, issuances: Vec<Issuance>) {
+        self.issuances.extend(issuances);
+    }
+
     pub fn update_balance_burn(&mut self, amount: u64) -> Result<()> {
         self.total_amount = self.total_amount.checked_sub(amount).ok_or(PolicyEngineErrors::BalanceUnderflow)?;
         Ok(())
@@ -98,6 +219,24 @@ impl TrackerAccount {
         Ok(())
     }
 
+    /// removes the locks of a reason released at the timestamp, locks without release time are kept
+    pub fn remove_expired_locks_by_reason(&mut self, reason: u64, timestamp: i64) -> Vec<Lock> {
+        let (expired, locks) = self.locks.drain(..).partition(|lock| {
+            lock.reason == reason && lock.release_time != 0 && lock.release_time <= timestamp
+        });
+        self.locks = locks;
+        expired
+    }
+
+    pub fn remove_lock_by_reason(&mut self, reason: u64, reason_string: &str) -> Result<Lock> {
+        let index = self
+            .locks
+            .iter()
+            .position(|lock| lock.reason == reason && lock.reason_string == reason_string)
+            .ok_or(PolicyEngineErrors::LockIndexNotFound)?;
+        self.remove_lock(index)
+    }
+
     pub fn remove_lock(&mut self, index: usize) -> Result<Lock> {
         if index >= self.locks.len() {
             return Err(PolicyEngineErrors::LockIndexNotFound.into());
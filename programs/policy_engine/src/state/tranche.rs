@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::{IdentityFilter, PolicyEngineErrors};
+
+pub const TRANCHE_SEED: &[u8] = b"tranche";
+pub const TRANCHE_SUBSCRIPTION_SEED: &[u8] = b"tranche-subscription";
+/// reason of the locks enforcing the lock period of a tranche, the reason string is the tranche address
+pub const TRANCHE_LOCK_REASON: u64 = u64::MAX - 1;
+
+/// rules of a tranche, set by the authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TrancheConfig {
+    pub open_timestamp: i64,
+    pub close_timestamp: i64,
+    /// max amount issued through the tranche, 0 for no cap
+    pub cap: u64,
+    /// min amount subscribed by an investor across the issuances of the tranche
+    pub min_subscription: u64,
+    /// max amount subscribed by an investor across the issuances of the tranche, 0 for no max
+    pub max_subscription: u64,
+    /// seconds the tokens issued through the tranche stay locked, 0 for no lock
+    pub lock_period: i64,
+    /// identities allowed to subscribe
+    pub identity_filter: IdentityFilter,
+}
+
+impl TrancheConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.open_timestamp < self.close_timestamp
+                && self.lock_period >= 0
+                && (self.max_subscription == 0 || self.min_subscription <= self.max_subscription)
+                && (self.cap == 0 || self.min_subscription <= self.cap),
+            PolicyEngineErrors::InvalidTrancheConfig
+        );
+        Ok(())
+    }
+}
+
+/// offering tranche issuances can be made against
+#[account()]
+#[derive(InitSpace)]
+pub struct TrancheAccount {
+    pub version: u8,
+    pub policy_engine: Pubkey,
+    pub payer: Pubkey,
+    pub id: u64,
+    pub open_timestamp: i64,
+    pub close_timestamp: i64,
+    pub cap: u64,
+    pub min_subscription: u64,
+    pub max_subscription: u64,
+    pub lock_period: i64,
+    /// amount issued through the tranche so far
+    pub issued: u64,
+    pub identity_filter: IdentityFilter,
+}
+
+impl TrancheAccount {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(policy_engine: Pubkey, payer: Pubkey, id: u64, config: TrancheConfig) -> Self {
+        Self {
+            version: Self::VERSION,
+            policy_engine,
+            payer,
+            id,
+            open_timestamp: config.open_timestamp,
+            close_timestamp: config.close_timestamp,
+            cap: config.cap,
+            min_subscription: config.min_subscription,
+            max_subscription: config.max_subscription,
+            lock_period: config.lock_period,
+            issued: 0,
+            identity_filter: config.identity_filter,
+        }
+    }
+
+    pub fn get_space(identity_filter: &IdentityFilter) -> usize {
+        TrancheAccount::INIT_SPACE - IdentityFilter::INIT_SPACE + identity_filter.get_space()
+    }
+
+    pub fn set_config(&mut self, config: TrancheConfig) {
+        self.open_timestamp = config.open_timestamp;
+        self.close_timestamp = config.close_timestamp;
+        self.cap = config.cap;
+        self.min_subscription = config.min_subscription;
+        self.max_subscription = config.max_subscription;
+        self.lock_period = config.lock_period;
+        self.identity_filter = config.identity_filter;
+    }
+
+    /// space needed on the tracker for the lock of an issuance
+    pub fn get_lock_space(&self) -> usize {
+        if self.lock_period > 0 {
+            crate::Lock::INIT_SPACE
+        } else {
+            0
+        }
+    }
+
+    /// validates the window, cap and subscription limits, recording the issuance
+    pub fn subscribe(
+        &mut self,
+        subscription: &mut TrancheSubscriptionAccount,
+        amount: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        require!(
+            timestamp >= self.open_timestamp && timestamp < self.close_timestamp,
+            PolicyEngineErrors::TrancheNotOpen
+        );
+        self.issued = self
+            .issued
+            .checked_add(amount)
+            .ok_or(PolicyEngineErrors::BalanceOverflow)?;
+        require!(
+            self.cap == 0 || self.issued <= self.cap,
+            PolicyEngineErrors::TrancheCapExceeded
+        );
+        subscription.subscribed = subscription
+            .subscribed
+            .checked_add(amount)
+            .ok_or(PolicyEngineErrors::BalanceOverflow)?;
+        require!(
+            subscription.subscribed >= self.min_subscription
+                && (self.max_subscription == 0 || subscription.subscribed <= self.max_subscription),
+            PolicyEngineErrors::TrancheSubscriptionOutOfBounds
+        );
+        Ok(())
+    }
+}
+
+/// amount subscribed by an investor to a tranche
+#[account()]
+#[derive(InitSpace)]
+pub struct TrancheSubscriptionAccount {
+    pub version: u8,
+    pub tranche: Pubkey,
+    pub identity_account: Pubkey,
+    pub subscribed: u64,
+}
+
+impl TrancheSubscriptionAccount {
+    pub const VERSION: u8 = 1;
+}
@@ -1,4 +1,4 @@
-use crate::PolicyEngineErrors;
+use crate::{PolicyEngineErrors, MAX_TRANSFER_POLICY_PAGES, POLICY_PAGE_SEED};
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHookAccount;
 use anchor_spl::token_2022::spl_token_2022::extension::BaseStateWithExtensions;
@@ -15,12 +15,16 @@ pub const LOCKED_LEVEL: u8 = 2;
 pub const US_COMPLIANCE_LEVEL: u8 = 11;
 
 pub fn get_custom_error(error_code: u8, fallback_error: PolicyEngineErrors) -> Result<()> {
+    Err(resolve_custom_error(error_code, fallback_error).into())
+}
+
+pub fn resolve_custom_error(error_code: u8, fallback_error: PolicyEngineErrors) -> PolicyEngineErrors {
     match error_code {
-        50 => return Err(PolicyEngineErrors::ForceAccredited.into()),
-        51 => return Err(PolicyEngineErrors::ForceAccreditedUS.into()),
-        52 => return Err(PolicyEngineErrors::TokenPaused.into()),
-        53 => return Err(PolicyEngineErrors::InvestorFullyLocked.into()),
-        _ => return Err(fallback_error.into())
+        50 => PolicyEngineErrors::ForceAccredited,
+        51 => PolicyEngineErrors::ForceAccreditedUS,
+        52 => PolicyEngineErrors::TokenPaused,
+        53 => PolicyEngineErrors::InvestorFullyLocked,
+        _ => fallback_error,
     }
 }
 
@@ -89,12 +93,52 @@ pub fn verify_pda(address: Pubkey, seeds: &[&[u8]], program_id: &Pubkey) -> Resu
     Ok(())
 }
 
+/// reallocs an account by size_diff bytes, topping up or refunding rent to the payer
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size_diff: i32,
+) -> Result<()> {
+    let space = if size_diff > 0 {
+        account.data_len() + size_diff as usize
+    } else {
+        account.data_len() - size_diff.unsigned_abs() as usize
+    };
+
+    let rent = Rent::get()?.minimum_balance(space);
+
+    match rent.cmp(&account.lamports()) {
+        std::cmp::Ordering::Greater => {
+            anchor_lang::system_program::transfer(
+                anchor_lang::context::CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                rent.checked_sub(account.lamports()).unwrap(),
+            )?;
+        }
+        std::cmp::Ordering::Less => {
+            let diff = account.lamports().checked_sub(rent).unwrap();
+            **payer.lamports.borrow_mut() = payer.lamports().checked_add(diff).unwrap();
+            **account.lamports.borrow_mut() = account.lamports().checked_sub(diff).unwrap();
+        }
+        std::cmp::Ordering::Equal => {}
+    }
+
+    account.realloc(space, false)?;
+    Ok(())
+}
+
 pub fn get_meta_list_size() -> Result<usize> {
     Ok(ExtraAccountMetaList::size_of(get_extra_account_metas()?.len()).unwrap())
 }
 
 pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
-    Ok(vec![
+    let mut metas = vec![
         // [5] policy engine account
         ExtraAccountMeta::new_with_seeds(&[Seed::AccountKey { index: 1 }], false, true)?,
         // [6] identity program
@@ -170,5 +214,20 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
             false,
             true,
         )?,
-    ])
+    ];
+    // [14..18] transfer policy pages, missing ones are passed as empty accounts
+    for index in 0..MAX_TRANSFER_POLICY_PAGES {
+        metas.push(ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: POLICY_PAGE_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::Literal { bytes: vec![index] },
+            ],
+            false,
+            false,
+        )?);
+    }
+    Ok(metas)
 }
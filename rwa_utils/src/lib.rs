@@ -1,8 +1,10 @@
 pub mod constants;
 pub mod geyser;
+pub mod migration;
 
 pub use constants::*;
 pub use geyser::*;
+pub use migration::*;
 
 pub fn get_bump_in_seed_form(bump: &u8) -> [u8; 1] {
     let bump_val = *bump;
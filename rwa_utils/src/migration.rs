@@ -0,0 +1,60 @@
+use anchor_lang::{
+    error::{Error, ErrorCode},
+    prelude::*,
+    system_program::{transfer, Transfer},
+    AccountSerialize, AnchorDeserialize, AnchorSerialize, Discriminator,
+};
+
+/// version written by the first layout of the rwa accounts, right after the discriminator
+pub const LEGACY_ACCOUNT_VERSION: u8 = 1;
+
+/// rewrites an account from its version 1 layout `L` to the current layout `T`, growing it by the size
+/// difference of the two layouts. returns the migrated account data.
+pub fn migrate_account<'info, L, T>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    version_error: impl Into<Error>,
+    migrate: impl FnOnce(L) -> Result<T>,
+) -> Result<T>
+where
+    L: AnchorSerialize + AnchorDeserialize,
+    T: AccountSerialize + AnchorSerialize + Discriminator,
+{
+    require_keys_eq!(*account.owner, *program_id, ErrorCode::AccountOwnedByWrongProgram);
+    let (legacy, legacy_len) = {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() > T::DISCRIMINATOR.len() && data.starts_with(T::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let data = &data[T::DISCRIMINATOR.len()..];
+        if data[0] != LEGACY_ACCOUNT_VERSION {
+            return Err(version_error.into());
+        }
+        let legacy = L::deserialize(&mut &data[..])?;
+        let legacy_len = legacy.try_to_vec()?.len();
+        (legacy, legacy_len)
+    };
+
+    let migrated = migrate(legacy)?;
+    // fields are only added by the migrations, so the account never shrinks
+    let space = account.data_len() + migrated.try_to_vec()?.len().saturating_sub(legacy_len);
+    let rent = Rent::get()?.minimum_balance(space);
+    if rent > account.lamports() {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - account.lamports(),
+        )?;
+    }
+    account.realloc(space, false)?;
+    migrated.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    Ok(migrated)
+}